@@ -3,11 +3,12 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
+import '../directory_explorer/media_file.dart';
 import '../frb_generated.dart';
 import '../movie_data/movie_data.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `init_tracing_subscriber`
+// These functions are ignored because they are not marked as `pub`: `active_profile_saver`, `get_builtin_list_items`, `init_tracing_subscriber`, `initiate_db`, `media_getter`, `set_in_builtin_list`
 
 Future<String> start({
   required String path,
@@ -21,26 +22,513 @@ Future<String> start({
   token: token,
 );
 
+/// Fetches the metadata and artwork of a stored movie again, returning the movies saved.
+Future<int> refreshMovieMetadata({
+  required PlatformInt64 mediaId,
+  required String token,
+}) => RustLib.instance.api.crateApiMediaRefreshMovieMetadata(
+  mediaId: mediaId,
+  token: token,
+);
+
+/// Fetches the details and picture of a stored person again, returning the persons saved.
+Future<int> refreshPersonMetadata({
+  required PlatformInt64 personTmdbId,
+  required String token,
+}) => RustLib.instance.api.crateApiMediaRefreshPersonMetadata(
+  personTmdbId: personTmdbId,
+  token: token,
+);
+
+/// Fetches the metadata of every stored movie again, returning the movies saved.
+Future<int> refreshLibraryMetadata({required String token}) =>
+    RustLib.instance.api.crateApiMediaRefreshLibraryMetadata(token: token);
+
+/// Fetches the metadata of the movies not refreshed within `max_age_days` days again.
+Future<int> refreshStaleMetadata({
+  required int maxAgeDays,
+  required String token,
+}) => RustLib.instance.api.crateApiMediaRefreshStaleMetadata(
+  maxAgeDays: maxAgeDays,
+  token: token,
+);
+
 Future<List<MovieSnapshot>> getMediaSnapshots({required String mediaType}) =>
     RustLib.instance.api.crateApiMediaGetMediaSnapshots(mediaType: mediaType);
 
 Future<MediaData> getMedia({required PlatformInt64 mediaId}) =>
     RustLib.instance.api.crateApiMediaGetMedia(mediaId: mediaId);
 
+/// Returns the chapters of a media for the scene list and chapter seeking, empty until its
+/// file is probed with `probe_media_files`.
+Future<List<MediaChapter>> getMediaChapters({required PlatformInt64 mediaId}) =>
+    RustLib.instance.api.crateApiMediaGetMediaChapters(mediaId: mediaId);
+
 Future<List<PersonSnapshot>> getMediaCast({required PlatformInt64 mediaId}) =>
     RustLib.instance.api.crateApiMediaGetMediaCast(mediaId: mediaId);
 
 Future<List<PersonSnapshot>> getMediaCrew({required PlatformInt64 mediaId}) =>
     RustLib.instance.api.crateApiMediaGetMediaCrew(mediaId: mediaId);
 
+/// Sets a movie field by hand and locks it against scans and refreshes.
+/// Editable fields are "title", "original_title", "summary", "tagline", "release_date" and "certification".
+/// Only "release_date" can be cleared with a None value.
+Future<void> editMediaField({
+  required PlatformInt64 mediaId,
+  required String field,
+  String? value,
+}) => RustLib.instance.api.crateApiMediaEditMediaField(
+  mediaId: mediaId,
+  field: field,
+  value: value,
+);
+
+/// Replaces the genres of a movie by hand and locks them.
+/// Every genre id must be one of the stored TMDB genres.
+Future<void> editMediaGenres({
+  required PlatformInt64 mediaId,
+  required Int64List genreIds,
+}) => RustLib.instance.api.crateApiMediaEditMediaGenres(
+  mediaId: mediaId,
+  genreIds: genreIds,
+);
+
+/// Sets the "poster" or "backdrop" of a movie to a local image and locks it.
+Future<void> editMediaArtwork({
+  required PlatformInt64 mediaId,
+  required String kind,
+  required String imagePath,
+}) => RustLib.instance.api.crateApiMediaEditMediaArtwork(
+  mediaId: mediaId,
+  kind: kind,
+  imagePath: imagePath,
+);
+
+Future<List<String>> getMediaLockedFields({required PlatformInt64 mediaId}) =>
+    RustLib.instance.api.crateApiMediaGetMediaLockedFields(mediaId: mediaId);
+
+/// Unlocks an edited field and reverts it to the latest provider data.
+Future<void> unlockMediaField({
+  required PlatformInt64 mediaId,
+  required String field,
+}) => RustLib.instance.api.crateApiMediaUnlockMediaField(
+  mediaId: mediaId,
+  field: field,
+);
+
+/// Removes a movie from the library with its watch states and list entries, e.g. once its
+/// file is deleted. A later scan finding the file again adds it back.
+Future<void> removeMedia({required PlatformInt64 mediaId}) =>
+    RustLib.instance.api.crateApiMediaRemoveMedia(mediaId: mediaId);
+
+/// Sends every library change as it happens, so views refresh without polling.
+/// Ends once the Dart stream is closed, which an idle stream notices through the
+/// `KeepAlive` events sent every `EVENT_KEEP_ALIVE_INTERVAL`.
+Stream<LibraryEvent> watchLibraryEvents() =>
+    RustLib.instance.api.crateApiMediaWatchLibraryEvents();
+
+/// Returns the media most like a movie by genres, credits, keywords and era, most similar first.
+Future<List<MovieSnapshot>> getSimilarMedia({
+  required PlatformInt64 mediaId,
+  required int limit,
+}) => RustLib.instance.api.crateApiMediaGetSimilarMedia(
+  mediaId: mediaId,
+  limit: limit,
+);
+
+/// Recommends unwatched media for each recently watched movie of the active profile,
+/// with at most `limit` media per watched movie.
+Future<List<WatchRecommendation>> getWatchRecommendations({
+  required int limit,
+}) => RustLib.instance.api.crateApiMediaGetWatchRecommendations(limit: limit);
+
+Future<List<CollectionSnapshot>> getCollections() =>
+    RustLib.instance.api.crateApiMediaGetCollections();
+
+Future<CollectionDetail> getCollection({required PlatformInt64 collectionId}) =>
+    RustLib.instance.api.crateApiMediaGetCollection(collectionId: collectionId);
+
+Future<List<CollectionPartSnapshot>> getCollectionMissingParts({
+  required PlatformInt64 collectionId,
+}) => RustLib.instance.api.crateApiMediaGetCollectionMissingParts(
+  collectionId: collectionId,
+);
+
+/// Lists the values of a facet kind: "genre", "studio", "country", "language" or "keyword".
+Future<List<FacetSnapshot>> getFacets({required String kind}) =>
+    RustLib.instance.api.crateApiMediaGetFacets(kind: kind);
+
+Future<List<FacetSnapshot>> getMediaFacets({required PlatformInt64 mediaId}) =>
+    RustLib.instance.api.crateApiMediaGetMediaFacets(mediaId: mediaId);
+
+Future<List<MovieSnapshot>> getMediaSnapshotsByFacet({
+  required String kind,
+  required String key,
+}) => RustLib.instance.api.crateApiMediaGetMediaSnapshotsByFacet(
+  kind: kind,
+  key: key,
+);
+
+Future<void> markMediaWatched({
+  required PlatformInt64 mediaId,
+  required bool watched,
+}) => RustLib.instance.api.crateApiMediaMarkMediaWatched(
+  mediaId: mediaId,
+  watched: watched,
+);
+
+/// Records the playback position of a media, in seconds, marking it watched near the end.
+Future<void> reportPlaybackProgress({
+  required PlatformInt64 mediaId,
+  required double position,
+  required double duration,
+}) => RustLib.instance.api.crateApiMediaReportPlaybackProgress(
+  mediaId: mediaId,
+  position: position,
+  duration: duration,
+);
+
+Future<PlaybackState> getPlaybackState({required PlatformInt64 mediaId}) =>
+    RustLib.instance.api.crateApiMediaGetPlaybackState(mediaId: mediaId);
+
+Future<List<PlaybackEntry>> getContinueWatching({required int limit}) =>
+    RustLib.instance.api.crateApiMediaGetContinueWatching(limit: limit);
+
+Future<List<PlaybackEntry>> getRecentlyWatched({required int limit}) =>
+    RustLib.instance.api.crateApiMediaGetRecentlyWatched(limit: limit);
+
+/// Sets or clears the rating the active profile gives to a media, from 0 to 10.
+Future<void> rateMedia({required PlatformInt64 mediaId, double? rating}) =>
+    RustLib.instance.api.crateApiMediaRateMedia(
+      mediaId: mediaId,
+      rating: rating,
+    );
+
+Future<List<Profile>> getProfiles() =>
+    RustLib.instance.api.crateApiMediaGetProfiles();
+
+Future<Profile> getActiveProfile() =>
+    RustLib.instance.api.crateApiMediaGetActiveProfile();
+
+/// Creates a profile and returns it. An empty or missing PIN leaves the profile unlocked.
+/// With a `max_certification`, `hide_unrated` also hides the titles without a US rating.
+Future<Profile> createProfile({
+  required String name,
+  String? avatar,
+  String? pin,
+  String? maxCertification,
+  required bool hideUnrated,
+  String? preferredLanguage,
+}) => RustLib.instance.api.crateApiMediaCreateProfile(
+  name: name,
+  avatar: avatar,
+  pin: pin,
+  maxCertification: maxCertification,
+  hideUnrated: hideUnrated,
+  preferredLanguage: preferredLanguage,
+);
+
+/// Makes a profile the active one, its PIN being required when it has one.
+Future<void> switchProfile({required PlatformInt64 profileId, String? pin}) =>
+    RustLib.instance.api.crateApiMediaSwitchProfile(
+      profileId: profileId,
+      pin: pin,
+    );
+
+/// Deletes a profile with its watch history, ratings, lists, smart collections and home screen.
+///
+/// Takes the PIN of the deleted profile, or of the active profile when only that one has a PIN.
+/// Deleting the active profile can leave no profile active, `get_active_profile` then failing
+/// until a profile is picked with `switch_profile`.
+Future<void> deleteProfile({required PlatformInt64 profileId, String? pin}) =>
+    RustLib.instance.api.crateApiMediaDeleteProfile(
+      profileId: profileId,
+      pin: pin,
+    );
+
+/// Lists the favourites, the watchlist and the custom lists of the active profile.
+Future<List<MediaList>> getLists() =>
+    RustLib.instance.api.crateApiMediaGetLists();
+
+/// Returns a zero-based page of the media of a list, in list order.
+Future<List<MovieSnapshot>> getListItems({
+  required PlatformInt64 listId,
+  required int page,
+  required int pageSize,
+}) => RustLib.instance.api.crateApiMediaGetListItems(
+  listId: listId,
+  page: page,
+  pageSize: pageSize,
+);
+
+Future<PlatformInt64> createList({required String name}) =>
+    RustLib.instance.api.crateApiMediaCreateList(name: name);
+
+Future<void> renameList({
+  required PlatformInt64 listId,
+  required String name,
+}) => RustLib.instance.api.crateApiMediaRenameList(listId: listId, name: name);
+
+Future<void> deleteList({required PlatformInt64 listId}) =>
+    RustLib.instance.api.crateApiMediaDeleteList(listId: listId);
+
+Future<void> addToList({
+  required PlatformInt64 listId,
+  required PlatformInt64 mediaId,
+}) => RustLib.instance.api.crateApiMediaAddToList(
+  listId: listId,
+  mediaId: mediaId,
+);
+
+Future<void> removeFromList({
+  required PlatformInt64 listId,
+  required PlatformInt64 mediaId,
+}) => RustLib.instance.api.crateApiMediaRemoveFromList(
+  listId: listId,
+  mediaId: mediaId,
+);
+
+/// Moves a media of a list to a new zero-based position.
+Future<void> moveListItem({
+  required PlatformInt64 listId,
+  required PlatformInt64 mediaId,
+  required int position,
+}) => RustLib.instance.api.crateApiMediaMoveListItem(
+  listId: listId,
+  mediaId: mediaId,
+  position: position,
+);
+
+Future<void> setFavourite({
+  required PlatformInt64 mediaId,
+  required bool favourite,
+}) => RustLib.instance.api.crateApiMediaSetFavourite(
+  mediaId: mediaId,
+  favourite: favourite,
+);
+
+Future<void> setInWatchlist({
+  required PlatformInt64 mediaId,
+  required bool inWatchlist,
+}) => RustLib.instance.api.crateApiMediaSetInWatchlist(
+  mediaId: mediaId,
+  inWatchlist: inWatchlist,
+);
+
+Future<List<MovieSnapshot>> getFavourites({
+  required int page,
+  required int pageSize,
+}) => RustLib.instance.api.crateApiMediaGetFavourites(
+  page: page,
+  pageSize: pageSize,
+);
+
+Future<List<MovieSnapshot>> getWatchlist({
+  required int page,
+  required int pageSize,
+}) => RustLib.instance.api.crateApiMediaGetWatchlist(
+  page: page,
+  pageSize: pageSize,
+);
+
+/// Lists the smart collections of the active profile.
+Future<List<SmartCollection>> getSmartCollections() =>
+    RustLib.instance.api.crateApiMediaGetSmartCollections();
+
+Future<PlatformInt64> createSmartCollection({
+  required String name,
+  required SmartQuery query,
+}) => RustLib.instance.api.crateApiMediaCreateSmartCollection(
+  name: name,
+  query: query,
+);
+
+Future<void> editSmartCollection({
+  required PlatformInt64 collectionId,
+  required String name,
+  required SmartQuery query,
+}) => RustLib.instance.api.crateApiMediaEditSmartCollection(
+  collectionId: collectionId,
+  name: name,
+  query: query,
+);
+
+Future<void> deleteSmartCollection({required PlatformInt64 collectionId}) =>
+    RustLib.instance.api.crateApiMediaDeleteSmartCollection(
+      collectionId: collectionId,
+    );
+
+/// Evaluates a query without saving it, returning a zero-based page of the matching media.
+/// A random sort is shuffled with `seed`, to be kept while paging through the same results.
+Future<List<MovieSnapshot>> previewSmartCollection({
+  required SmartQuery query,
+  required int page,
+  required int pageSize,
+  required int seed,
+}) => RustLib.instance.api.crateApiMediaPreviewSmartCollection(
+  query: query,
+  page: page,
+  pageSize: pageSize,
+  seed: seed,
+);
+
+/// Evaluates a saved smart collection, returning a zero-based page of the matching media.
+/// A random sort is shuffled with `seed`, to be kept while paging through the same results.
+Future<List<MovieSnapshot>> getSmartCollectionItems({
+  required PlatformInt64 collectionId,
+  required int page,
+  required int pageSize,
+  required int seed,
+}) => RustLib.instance.api.crateApiMediaGetSmartCollectionItems(
+  collectionId: collectionId,
+  page: page,
+  pageSize: pageSize,
+  seed: seed,
+);
+
+/// Assembles the enabled home screen rows of the active profile, leaving out the empty ones.
+Future<List<HomeRow>> getHomeFeed() =>
+    RustLib.instance.api.crateApiMediaGetHomeFeed();
+
+/// Lists every home screen row of the active profile in order, including the disabled ones.
+Future<List<HomeRowConfig>> getHomeFeedRows() =>
+    RustLib.instance.api.crateApiMediaGetHomeFeedRows();
+
+/// Saves the order, visibility and size of the home screen rows of the active profile.
+Future<void> setHomeFeedRows({required List<HomeRowConfig> rows}) =>
+    RustLib.instance.api.crateApiMediaSetHomeFeedRows(rows: rows);
+
 Future<PersonData> getPerson({required PlatformInt64 personTmdbId}) =>
     RustLib.instance.api.crateApiMediaGetPerson(personTmdbId: personTmdbId);
 
+/// Lists the library movies of a person grouped by department.
+/// When a TMDB token is given, the titles credited on TMDB but not owned are listed too.
+Future<PersonFilmography> getPersonFilmography({
+  required PlatformInt64 personTmdbId,
+  String? token,
+}) => RustLib.instance.api.crateApiMediaGetPersonFilmography(
+  personTmdbId: personTmdbId,
+  token: token,
+);
+
+Future<CreditFilter> getCreditFilter() =>
+    RustLib.instance.api.crateApiMediaGetCreditFilter();
+
+/// Saves the credit filter rules, used by every following scan.
+Future<void> setCreditFilter({required CreditFilter filter}) =>
+    RustLib.instance.api.crateApiMediaSetCreditFilter(filter: filter);
+
+/// Removes already stored credits that the current rules would filter out.
+Future<int> reapplyCreditFilter() =>
+    RustLib.instance.api.crateApiMediaReapplyCreditFilter();
+
+Future<List<SearchResult>> quickSearch({
+  required String query,
+  required int limit,
+}) => RustLib.instance.api.crateApiMediaQuickSearch(query: query, limit: limit);
+
 Future<void> tempoMountSmb() =>
     RustLib.instance.api.crateApiMediaTempoMountSmb();
 
 Future<void> tempoUnmountSmb() =>
     RustLib.instance.api.crateApiMediaTempoUnmountSmb();
 
+/// Starts serving the library files and the REST api over HTTP, `port` 0 picking a free one,
+/// and returns the port. Players then need no mount to play an item.
+/// The server only listens on the loopback interface unless `allow_network` is set,
+/// other devices then needing to be paired with `create_pairing_code`.
+Future<int> startStreamServer({
+  required MediaSource source,
+  required int port,
+  required bool allowNetwork,
+}) => RustLib.instance.api.crateApiMediaStartStreamServer(
+  source: source,
+  port: port,
+  allowNetwork: allowNetwork,
+);
+
+Future<void> stopStreamServer() =>
+    RustLib.instance.api.crateApiMediaStopStreamServer();
+
+/// Returns the URL of a media item on the running streaming server.
+Future<String> getStreamUrl({required PlatformInt64 mediaId}) =>
+    RustLib.instance.api.crateApiMediaGetStreamUrl(mediaId: mediaId);
+
+/// Reads the technical info of the library files from their container headers and returns
+/// the number of files probed. Only the files never probed are read unless `reprobe_all` is set.
+Future<int> probeMediaFiles({
+  required MediaSource source,
+  required bool reprobeAll,
+}) => RustLib.instance.api.crateApiMediaProbeMediaFiles(
+  source: source,
+  reprobeAll: reprobeAll,
+);
+
+/// Finds the subtitle files lying next to the library videos, as "Movie.en.forced.srt",
+/// and returns the number of media whose subtitles changed.
+Future<int> discoverSubtitleFiles({required MediaSource source}) =>
+    RustLib.instance.api.crateApiMediaDiscoverSubtitleFiles(source: source);
+
+Future<List<SubtitleFile>> getSubtitleFiles({required PlatformInt64 mediaId}) =>
+    RustLib.instance.api.crateApiMediaGetSubtitleFiles(mediaId: mediaId);
+
+/// Returns the URL a subtitle file is served at as WebVTT on the running streaming server.
+Future<String> getSubtitleUrl({
+  required PlatformInt64 mediaId,
+  required PlatformInt64 subtitleId,
+}) => RustLib.instance.api.crateApiMediaGetSubtitleUrl(
+  mediaId: mediaId,
+  subtitleId: subtitleId,
+);
+
+/// Generates posters and backdrops from video frames for the media without artwork, and the
+/// seek preview sprites of the probed media, with ffmpeg. Returns the number of media given
+/// new images. Generated images are only made again when `regenerate` is set.
+///
+/// A SMB library is read through the streaming server, which must be running.
+Future<int> generateMediaThumbnails({
+  required MediaSource source,
+  required bool regenerate,
+}) => RustLib.instance.api.crateApiMediaGenerateMediaThumbnails(
+  source: source,
+  regenerate: regenerate,
+);
+
+/// Returns the URL of the WebVTT index of the seek previews of a media on the running
+/// streaming server, None until they are generated.
+Future<String?> getTrickplayUrl({required PlatformInt64 mediaId}) =>
+    RustLib.instance.api.crateApiMediaGetTrickplayUrl(mediaId: mediaId);
+
+/// Whether media the player can not play as is can be remuxed or transcoded,
+/// which needs ffmpeg in the `PATH` or at `FFMPEG_PATH`.
+bool isTranscodingAvailable() =>
+    RustLib.instance.api.crateApiMediaIsTranscodingAvailable();
+
+/// Decides whether a media is played as is, remuxed or transcoded for the player of the app,
+/// starting the HLS session it needs on the running streaming server.
+Future<PlaybackDecision> getPlaybackDecision({
+  required PlatformInt64 mediaId,
+  required PlaybackRequest request,
+}) => RustLib.instance.api.crateApiMediaGetPlaybackDecision(
+  mediaId: mediaId,
+  request: request,
+);
+
+/// Stops a transcoding session once playback ends, deleting its segments.
+Future<void> stopTranscoding({required String sessionId}) =>
+    RustLib.instance.api.crateApiMediaStopTranscoding(sessionId: sessionId);
+
+/// Returns a one-time code pairing a device with the active profile, valid for 5 minutes.
+Future<String> createPairingCode() =>
+    RustLib.instance.api.crateApiMediaCreatePairingCode();
+
+/// Lists the devices paired with the network api, of every profile.
+Future<List<PairedDevice>> getPairedDevices() =>
+    RustLib.instance.api.crateApiMediaGetPairedDevices();
+
+/// Revokes the token of a paired device, which then has to be paired again.
+Future<void> revokeDevice({required PlatformInt64 deviceId}) =>
+    RustLib.instance.api.crateApiMediaRevokeDevice(deviceId: deviceId);
+
 Future<void> openVideo({required String path}) =>
     RustLib.instance.api.crateApiMediaOpenVideo(path: path);
@@ -0,0 +1,25 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+
+part 'media_file.freezed.dart';
+
+/// Where the library files are read from, the stored file paths being relative to it.
+@freezed
+sealed class MediaSource with _$MediaSource {
+  const MediaSource._();
+
+  /// A SMB share, e.g. "\\\\server\\share", read with the given credentials.
+  const factory MediaSource.smb({
+    required String path,
+    required String username,
+    required String password,
+  }) = MediaSource_Smb;
+  /// A local or already mounted folder.
+  const factory MediaSource.local({required String root}) = MediaSource_Local;
+}
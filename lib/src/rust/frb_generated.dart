@@ -6,6 +6,7 @@
 import 'api/media.dart';
 import 'dart:async';
 import 'dart:convert';
+import 'directory_explorer/media_file.dart';
 import 'frb_generated.dart';
 import 'frb_generated.io.dart'
     if (dart.library.js_interop) 'frb_generated.web.dart';
@@ -67,7 +68,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 1975334914;
+  int get rustContentHash => 642637354;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -78,28 +79,296 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<void> crateApiMediaAddToList({
+    required PlatformInt64 listId,
+    required PlatformInt64 mediaId,
+  });
+
+  Future<PlatformInt64> crateApiMediaCreateList({required String name});
+
+  Future<String> crateApiMediaCreatePairingCode();
+
+  Future<Profile> crateApiMediaCreateProfile({
+    required String name,
+    String? avatar,
+    String? pin,
+    String? maxCertification,
+    required bool hideUnrated,
+    String? preferredLanguage,
+  });
+
+  Future<PlatformInt64> crateApiMediaCreateSmartCollection({
+    required String name,
+    required SmartQuery query,
+  });
+
+  Future<void> crateApiMediaDeleteList({required PlatformInt64 listId});
+
+  Future<void> crateApiMediaDeleteProfile({
+    required PlatformInt64 profileId,
+    String? pin,
+  });
+
+  Future<void> crateApiMediaDeleteSmartCollection({
+    required PlatformInt64 collectionId,
+  });
+
+  Future<int> crateApiMediaDiscoverSubtitleFiles({required MediaSource source});
+
+  Future<void> crateApiMediaEditMediaArtwork({
+    required PlatformInt64 mediaId,
+    required String kind,
+    required String imagePath,
+  });
+
+  Future<void> crateApiMediaEditMediaField({
+    required PlatformInt64 mediaId,
+    required String field,
+    String? value,
+  });
+
+  Future<void> crateApiMediaEditMediaGenres({
+    required PlatformInt64 mediaId,
+    required Int64List genreIds,
+  });
+
+  Future<void> crateApiMediaEditSmartCollection({
+    required PlatformInt64 collectionId,
+    required String name,
+    required SmartQuery query,
+  });
+
+  Future<int> crateApiMediaGenerateMediaThumbnails({
+    required MediaSource source,
+    required bool regenerate,
+  });
+
+  Future<Profile> crateApiMediaGetActiveProfile();
+
+  Future<CollectionDetail> crateApiMediaGetCollection({
+    required PlatformInt64 collectionId,
+  });
+
+  Future<List<CollectionPartSnapshot>> crateApiMediaGetCollectionMissingParts({
+    required PlatformInt64 collectionId,
+  });
+
+  Future<List<CollectionSnapshot>> crateApiMediaGetCollections();
+
+  Future<List<PlaybackEntry>> crateApiMediaGetContinueWatching({
+    required int limit,
+  });
+
+  Future<CreditFilter> crateApiMediaGetCreditFilter();
+
+  Future<List<FacetSnapshot>> crateApiMediaGetFacets({required String kind});
+
+  Future<List<MovieSnapshot>> crateApiMediaGetFavourites({
+    required int page,
+    required int pageSize,
+  });
+
+  Future<List<HomeRow>> crateApiMediaGetHomeFeed();
+
+  Future<List<HomeRowConfig>> crateApiMediaGetHomeFeedRows();
+
+  Future<List<MovieSnapshot>> crateApiMediaGetListItems({
+    required PlatformInt64 listId,
+    required int page,
+    required int pageSize,
+  });
+
+  Future<List<MediaList>> crateApiMediaGetLists();
+
   Future<MediaData> crateApiMediaGetMedia({required PlatformInt64 mediaId});
 
   Future<List<PersonSnapshot>> crateApiMediaGetMediaCast({
     required PlatformInt64 mediaId,
   });
 
+  Future<List<MediaChapter>> crateApiMediaGetMediaChapters({
+    required PlatformInt64 mediaId,
+  });
+
   Future<List<PersonSnapshot>> crateApiMediaGetMediaCrew({
     required PlatformInt64 mediaId,
   });
 
+  Future<List<FacetSnapshot>> crateApiMediaGetMediaFacets({
+    required PlatformInt64 mediaId,
+  });
+
+  Future<List<String>> crateApiMediaGetMediaLockedFields({
+    required PlatformInt64 mediaId,
+  });
+
   Future<List<MovieSnapshot>> crateApiMediaGetMediaSnapshots({
     required String mediaType,
   });
 
+  Future<List<MovieSnapshot>> crateApiMediaGetMediaSnapshotsByFacet({
+    required String kind,
+    required String key,
+  });
+
+  Future<List<PairedDevice>> crateApiMediaGetPairedDevices();
+
   Future<PersonData> crateApiMediaGetPerson({
     required PlatformInt64 personTmdbId,
   });
 
+  Future<PersonFilmography> crateApiMediaGetPersonFilmography({
+    required PlatformInt64 personTmdbId,
+    String? token,
+  });
+
+  Future<PlaybackDecision> crateApiMediaGetPlaybackDecision({
+    required PlatformInt64 mediaId,
+    required PlaybackRequest request,
+  });
+
+  Future<PlaybackState> crateApiMediaGetPlaybackState({
+    required PlatformInt64 mediaId,
+  });
+
+  Future<List<Profile>> crateApiMediaGetProfiles();
+
+  Future<List<PlaybackEntry>> crateApiMediaGetRecentlyWatched({
+    required int limit,
+  });
+
+  Future<List<MovieSnapshot>> crateApiMediaGetSimilarMedia({
+    required PlatformInt64 mediaId,
+    required int limit,
+  });
+
+  Future<List<MovieSnapshot>> crateApiMediaGetSmartCollectionItems({
+    required PlatformInt64 collectionId,
+    required int page,
+    required int pageSize,
+    required int seed,
+  });
+
+  Future<List<SmartCollection>> crateApiMediaGetSmartCollections();
+
+  Future<String> crateApiMediaGetStreamUrl({required PlatformInt64 mediaId});
+
+  Future<List<SubtitleFile>> crateApiMediaGetSubtitleFiles({
+    required PlatformInt64 mediaId,
+  });
+
+  Future<String> crateApiMediaGetSubtitleUrl({
+    required PlatformInt64 mediaId,
+    required PlatformInt64 subtitleId,
+  });
+
+  Future<String?> crateApiMediaGetTrickplayUrl({
+    required PlatformInt64 mediaId,
+  });
+
+  Future<List<WatchRecommendation>> crateApiMediaGetWatchRecommendations({
+    required int limit,
+  });
+
+  Future<List<MovieSnapshot>> crateApiMediaGetWatchlist({
+    required int page,
+    required int pageSize,
+  });
+
   Future<void> crateApiMediaInitApp();
 
+  bool crateApiMediaIsTranscodingAvailable();
+
+  Future<void> crateApiMediaMarkMediaWatched({
+    required PlatformInt64 mediaId,
+    required bool watched,
+  });
+
+  Future<void> crateApiMediaMoveListItem({
+    required PlatformInt64 listId,
+    required PlatformInt64 mediaId,
+    required int position,
+  });
+
   Future<void> crateApiMediaOpenVideo({required String path});
 
+  Future<List<MovieSnapshot>> crateApiMediaPreviewSmartCollection({
+    required SmartQuery query,
+    required int page,
+    required int pageSize,
+    required int seed,
+  });
+
+  Future<int> crateApiMediaProbeMediaFiles({
+    required MediaSource source,
+    required bool reprobeAll,
+  });
+
+  Future<List<SearchResult>> crateApiMediaQuickSearch({
+    required String query,
+    required int limit,
+  });
+
+  Future<void> crateApiMediaRateMedia({
+    required PlatformInt64 mediaId,
+    double? rating,
+  });
+
+  Future<int> crateApiMediaReapplyCreditFilter();
+
+  Future<int> crateApiMediaRefreshLibraryMetadata({required String token});
+
+  Future<int> crateApiMediaRefreshMovieMetadata({
+    required PlatformInt64 mediaId,
+    required String token,
+  });
+
+  Future<int> crateApiMediaRefreshPersonMetadata({
+    required PlatformInt64 personTmdbId,
+    required String token,
+  });
+
+  Future<int> crateApiMediaRefreshStaleMetadata({
+    required int maxAgeDays,
+    required String token,
+  });
+
+  Future<void> crateApiMediaRemoveFromList({
+    required PlatformInt64 listId,
+    required PlatformInt64 mediaId,
+  });
+
+  Future<void> crateApiMediaRemoveMedia({required PlatformInt64 mediaId});
+
+  Future<void> crateApiMediaRenameList({
+    required PlatformInt64 listId,
+    required String name,
+  });
+
+  Future<void> crateApiMediaReportPlaybackProgress({
+    required PlatformInt64 mediaId,
+    required double position,
+    required double duration,
+  });
+
+  Future<void> crateApiMediaRevokeDevice({required PlatformInt64 deviceId});
+
+  Future<void> crateApiMediaSetCreditFilter({required CreditFilter filter});
+
+  Future<void> crateApiMediaSetFavourite({
+    required PlatformInt64 mediaId,
+    required bool favourite,
+  });
+
+  Future<void> crateApiMediaSetHomeFeedRows({
+    required List<HomeRowConfig> rows,
+  });
+
+  Future<void> crateApiMediaSetInWatchlist({
+    required PlatformInt64 mediaId,
+    required bool inWatchlist,
+  });
+
   Future<String> crateApiMediaStart({
     required String path,
     required String username,
@@ -107,9 +376,31 @@ abstract class RustLibApi extends BaseApi {
     required String token,
   });
 
+  Future<int> crateApiMediaStartStreamServer({
+    required MediaSource source,
+    required int port,
+    required bool allowNetwork,
+  });
+
+  Future<void> crateApiMediaStopStreamServer();
+
+  Future<void> crateApiMediaStopTranscoding({required String sessionId});
+
+  Future<void> crateApiMediaSwitchProfile({
+    required PlatformInt64 profileId,
+    String? pin,
+  });
+
   Future<void> crateApiMediaTempoMountSmb();
 
   Future<void> crateApiMediaTempoUnmountSmb();
+
+  Future<void> crateApiMediaUnlockMediaField({
+    required PlatformInt64 mediaId,
+    required String field,
+  });
+
+  Stream<LibraryEvent> crateApiMediaWatchLibraryEvents();
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -121,11 +412,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<MediaData> crateApiMediaGetMedia({required PlatformInt64 mediaId}) {
+  Future<void> crateApiMediaAddToList({
+    required PlatformInt64 listId,
+    required PlatformInt64 mediaId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(listId, serializer);
           sse_encode_i_64(mediaId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
@@ -135,28 +430,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_media_data,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMediaGetMediaConstMeta,
-        argValues: [mediaId],
+        constMeta: kCrateApiMediaAddToListConstMeta,
+        argValues: [listId, mediaId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMediaGetMediaConstMeta =>
-      const TaskConstMeta(debugName: "get_media", argNames: ["mediaId"]);
+  TaskConstMeta get kCrateApiMediaAddToListConstMeta => const TaskConstMeta(
+    debugName: "add_to_list",
+    argNames: ["listId", "mediaId"],
+  );
 
   @override
-  Future<List<PersonSnapshot>> crateApiMediaGetMediaCast({
-    required PlatformInt64 mediaId,
-  }) {
+  Future<PlatformInt64> crateApiMediaCreateList({required String name}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(mediaId, serializer);
+          sse_encode_String(name, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -165,28 +460,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_person_snapshot,
+          decodeSuccessData: sse_decode_i_64,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMediaGetMediaCastConstMeta,
-        argValues: [mediaId],
+        constMeta: kCrateApiMediaCreateListConstMeta,
+        argValues: [name],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMediaGetMediaCastConstMeta =>
-      const TaskConstMeta(debugName: "get_media_cast", argNames: ["mediaId"]);
+  TaskConstMeta get kCrateApiMediaCreateListConstMeta =>
+      const TaskConstMeta(debugName: "create_list", argNames: ["name"]);
 
   @override
-  Future<List<PersonSnapshot>> crateApiMediaGetMediaCrew({
-    required PlatformInt64 mediaId,
-  }) {
+  Future<String> crateApiMediaCreatePairingCode() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(mediaId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -195,28 +487,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_person_snapshot,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMediaGetMediaCrewConstMeta,
-        argValues: [mediaId],
+        constMeta: kCrateApiMediaCreatePairingCodeConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMediaGetMediaCrewConstMeta =>
-      const TaskConstMeta(debugName: "get_media_crew", argNames: ["mediaId"]);
+  TaskConstMeta get kCrateApiMediaCreatePairingCodeConstMeta =>
+      const TaskConstMeta(debugName: "create_pairing_code", argNames: []);
 
   @override
-  Future<List<MovieSnapshot>> crateApiMediaGetMediaSnapshots({
-    required String mediaType,
+  Future<Profile> crateApiMediaCreateProfile({
+    required String name,
+    String? avatar,
+    String? pin,
+    String? maxCertification,
+    required bool hideUnrated,
+    String? preferredLanguage,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(mediaType, serializer);
+          sse_encode_String(name, serializer);
+          sse_encode_opt_String(avatar, serializer);
+          sse_encode_opt_String(pin, serializer);
+          sse_encode_opt_String(maxCertification, serializer);
+          sse_encode_bool(hideUnrated, serializer);
+          sse_encode_opt_String(preferredLanguage, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -225,31 +527,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_movie_snapshot,
+          decodeSuccessData: sse_decode_profile,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMediaGetMediaSnapshotsConstMeta,
-        argValues: [mediaType],
+        constMeta: kCrateApiMediaCreateProfileConstMeta,
+        argValues: [name, avatar, pin, maxCertification, hideUnrated, preferredLanguage],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMediaGetMediaSnapshotsConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_media_snapshots",
-        argNames: ["mediaType"],
-      );
+  TaskConstMeta get kCrateApiMediaCreateProfileConstMeta => const TaskConstMeta(
+    debugName: "create_profile",
+    argNames: ["name", "avatar", "pin", "maxCertification", "hideUnrated", "preferredLanguage"],
+  );
 
   @override
-  Future<PersonData> crateApiMediaGetPerson({
-    required PlatformInt64 personTmdbId,
+  Future<PlatformInt64> crateApiMediaCreateSmartCollection({
+    required String name,
+    required SmartQuery query,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_i_64(personTmdbId, serializer);
+          sse_encode_String(name, serializer);
+          sse_encode_box_autoadd_smart_query(query, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -258,25 +561,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_person_data,
+          decodeSuccessData: sse_decode_i_64,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMediaGetPersonConstMeta,
-        argValues: [personTmdbId],
+        constMeta: kCrateApiMediaCreateSmartCollectionConstMeta,
+        argValues: [name, query],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMediaGetPersonConstMeta =>
-      const TaskConstMeta(debugName: "get_person", argNames: ["personTmdbId"]);
+  TaskConstMeta get kCrateApiMediaCreateSmartCollectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_smart_collection",
+        argNames: ["name", "query"],
+      );
 
   @override
-  Future<void> crateApiMediaInitApp() {
+  Future<void> crateApiMediaDeleteList({required PlatformInt64 listId}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(listId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -286,25 +593,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMediaInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMediaDeleteListConstMeta,
+        argValues: [listId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMediaInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiMediaDeleteListConstMeta =>
+      const TaskConstMeta(debugName: "delete_list", argNames: ["listId"]);
 
   @override
-  Future<void> crateApiMediaOpenVideo({required String path}) {
+  Future<void> crateApiMediaDeleteProfile({
+    required PlatformInt64 profileId,
+    String? pin,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(path, serializer);
+          sse_encode_i_64(profileId, serializer);
+          sse_encode_opt_String(pin, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -316,31 +627,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMediaOpenVideoConstMeta,
-        argValues: [path],
+        constMeta: kCrateApiMediaDeleteProfileConstMeta,
+        argValues: [profileId, pin],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMediaOpenVideoConstMeta =>
-      const TaskConstMeta(debugName: "open_video", argNames: ["path"]);
+  TaskConstMeta get kCrateApiMediaDeleteProfileConstMeta => const TaskConstMeta(
+    debugName: "delete_profile",
+    argNames: ["profileId", "pin"],
+  );
 
   @override
-  Future<String> crateApiMediaStart({
-    required String path,
-    required String username,
-    required String password,
-    required String token,
+  Future<void> crateApiMediaDeleteSmartCollection({
+    required PlatformInt64 collectionId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(path, serializer);
-          sse_encode_String(username, serializer);
-          sse_encode_String(password, serializer);
-          sse_encode_String(token, serializer);
+          sse_encode_i_64(collectionId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -349,27 +656,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMediaStartConstMeta,
-        argValues: [path, username, password, token],
+        constMeta: kCrateApiMediaDeleteSmartCollectionConstMeta,
+        argValues: [collectionId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMediaStartConstMeta => const TaskConstMeta(
-    debugName: "start",
-    argNames: ["path", "username", "password", "token"],
-  );
+  TaskConstMeta get kCrateApiMediaDeleteSmartCollectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_smart_collection",
+        argNames: ["collectionId"],
+      );
 
   @override
-  Future<void> crateApiMediaTempoMountSmb() {
+  Future<int> crateApiMediaDiscoverSubtitleFiles({
+    required MediaSource source,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_media_source(source, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -378,25 +689,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_u_32,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMediaTempoMountSmbConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMediaDiscoverSubtitleFilesConstMeta,
+        argValues: [source],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMediaTempoMountSmbConstMeta =>
-      const TaskConstMeta(debugName: "tempo_mount_smb", argNames: []);
+  TaskConstMeta get kCrateApiMediaDiscoverSubtitleFilesConstMeta =>
+      const TaskConstMeta(
+        debugName: "discover_subtitle_files",
+        argNames: ["source"],
+      );
 
   @override
-  Future<void> crateApiMediaTempoUnmountSmb() {
+  Future<void> crateApiMediaEditMediaArtwork({
+    required PlatformInt64 mediaId,
+    required String kind,
+    required String imagePath,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          sse_encode_String(kind, serializer);
+          sse_encode_String(imagePath, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -408,431 +729,5392 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiMediaTempoUnmountSmbConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMediaEditMediaArtworkConstMeta,
+        argValues: [mediaId, kind, imagePath],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiMediaTempoUnmountSmbConstMeta =>
-      const TaskConstMeta(debugName: "tempo_unmount_smb", argNames: []);
+  TaskConstMeta get kCrateApiMediaEditMediaArtworkConstMeta =>
+      const TaskConstMeta(
+        debugName: "edit_media_artwork",
+        argNames: ["mediaId", "kind", "imagePath"],
+      );
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+  @override
+  Future<void> crateApiMediaEditMediaField({
+    required PlatformInt64 mediaId,
+    required String field,
+    String? value,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          sse_encode_String(field, serializer);
+          sse_encode_opt_String(value, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 11,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaEditMediaFieldConstMeta,
+        argValues: [mediaId, field, value],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaEditMediaFieldConstMeta =>
+      const TaskConstMeta(
+        debugName: "edit_media_field",
+        argNames: ["mediaId", "field", "value"],
+      );
+
+  @override
+  Future<void> crateApiMediaEditMediaGenres({
+    required PlatformInt64 mediaId,
+    required Int64List genreIds,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          sse_encode_list_prim_i_64_strict(genreIds, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 12,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaEditMediaGenresConstMeta,
+        argValues: [mediaId, genreIds],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaEditMediaGenresConstMeta =>
+      const TaskConstMeta(
+        debugName: "edit_media_genres",
+        argNames: ["mediaId", "genreIds"],
+      );
+
+  @override
+  Future<void> crateApiMediaEditSmartCollection({
+    required PlatformInt64 collectionId,
+    required String name,
+    required SmartQuery query,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(collectionId, serializer);
+          sse_encode_String(name, serializer);
+          sse_encode_box_autoadd_smart_query(query, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 13,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaEditSmartCollectionConstMeta,
+        argValues: [collectionId, name, query],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaEditSmartCollectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "edit_smart_collection",
+        argNames: ["collectionId", "name", "query"],
+      );
+
+  @override
+  Future<int> crateApiMediaGenerateMediaThumbnails({
+    required MediaSource source,
+    required bool regenerate,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_media_source(source, serializer);
+          sse_encode_bool(regenerate, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 14,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGenerateMediaThumbnailsConstMeta,
+        argValues: [source, regenerate],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGenerateMediaThumbnailsConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_media_thumbnails",
+        argNames: ["source", "regenerate"],
+      );
+
+  @override
+  Future<Profile> crateApiMediaGetActiveProfile() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 15,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_profile,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetActiveProfileConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetActiveProfileConstMeta =>
+      const TaskConstMeta(debugName: "get_active_profile", argNames: []);
+
+  @override
+  Future<CollectionDetail> crateApiMediaGetCollection({
+    required PlatformInt64 collectionId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(collectionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_collection_detail,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetCollectionConstMeta,
+        argValues: [collectionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetCollectionConstMeta => const TaskConstMeta(
+    debugName: "get_collection",
+    argNames: ["collectionId"],
+  );
+
+  @override
+  Future<List<CollectionPartSnapshot>> crateApiMediaGetCollectionMissingParts({
+    required PlatformInt64 collectionId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(collectionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_collection_part_snapshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetCollectionMissingPartsConstMeta,
+        argValues: [collectionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetCollectionMissingPartsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_collection_missing_parts",
+        argNames: ["collectionId"],
+      );
+
+  @override
+  Future<List<CollectionSnapshot>> crateApiMediaGetCollections() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_collection_snapshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetCollectionsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetCollectionsConstMeta =>
+      const TaskConstMeta(debugName: "get_collections", argNames: []);
+
+  @override
+  Future<List<PlaybackEntry>> crateApiMediaGetContinueWatching({
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_playback_entry,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetContinueWatchingConstMeta,
+        argValues: [limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetContinueWatchingConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_continue_watching",
+        argNames: ["limit"],
+      );
+
+  @override
+  Future<CreditFilter> crateApiMediaGetCreditFilter() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 20,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_credit_filter,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetCreditFilterConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetCreditFilterConstMeta =>
+      const TaskConstMeta(debugName: "get_credit_filter", argNames: []);
+
+  @override
+  Future<List<FacetSnapshot>> crateApiMediaGetFacets({required String kind}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(kind, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 21,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_facet_snapshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetFacetsConstMeta,
+        argValues: [kind],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetFacetsConstMeta =>
+      const TaskConstMeta(debugName: "get_facets", argNames: ["kind"]);
+
+  @override
+  Future<List<MovieSnapshot>> crateApiMediaGetFavourites({
+    required int page,
+    required int pageSize,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(page, serializer);
+          sse_encode_u_32(pageSize, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_movie_snapshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetFavouritesConstMeta,
+        argValues: [page, pageSize],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetFavouritesConstMeta => const TaskConstMeta(
+    debugName: "get_favourites",
+    argNames: ["page", "pageSize"],
+  );
+
+  @override
+  Future<List<HomeRow>> crateApiMediaGetHomeFeed() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 23,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_home_row,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetHomeFeedConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetHomeFeedConstMeta =>
+      const TaskConstMeta(debugName: "get_home_feed", argNames: []);
+
+  @override
+  Future<List<HomeRowConfig>> crateApiMediaGetHomeFeedRows() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_home_row_config,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetHomeFeedRowsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetHomeFeedRowsConstMeta =>
+      const TaskConstMeta(debugName: "get_home_feed_rows", argNames: []);
+
+  @override
+  Future<List<MovieSnapshot>> crateApiMediaGetListItems({
+    required PlatformInt64 listId,
+    required int page,
+    required int pageSize,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(listId, serializer);
+          sse_encode_u_32(page, serializer);
+          sse_encode_u_32(pageSize, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 25,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_movie_snapshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetListItemsConstMeta,
+        argValues: [listId, page, pageSize],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetListItemsConstMeta => const TaskConstMeta(
+    debugName: "get_list_items",
+    argNames: ["listId", "page", "pageSize"],
+  );
+
+  @override
+  Future<List<MediaList>> crateApiMediaGetLists() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 26,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_media_list,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetListsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetListsConstMeta =>
+      const TaskConstMeta(debugName: "get_lists", argNames: []);
+
+  @override
+  Future<MediaData> crateApiMediaGetMedia({required PlatformInt64 mediaId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_media_data,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetMediaConstMeta,
+        argValues: [mediaId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetMediaConstMeta =>
+      const TaskConstMeta(debugName: "get_media", argNames: ["mediaId"]);
+
+  @override
+  Future<List<PersonSnapshot>> crateApiMediaGetMediaCast({
+    required PlatformInt64 mediaId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 28,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_person_snapshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetMediaCastConstMeta,
+        argValues: [mediaId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetMediaCastConstMeta =>
+      const TaskConstMeta(debugName: "get_media_cast", argNames: ["mediaId"]);
+
+  @override
+  Future<List<MediaChapter>> crateApiMediaGetMediaChapters({
+    required PlatformInt64 mediaId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_media_chapter,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetMediaChaptersConstMeta,
+        argValues: [mediaId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetMediaChaptersConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_media_chapters",
+        argNames: ["mediaId"],
+      );
+
+  @override
+  Future<List<PersonSnapshot>> crateApiMediaGetMediaCrew({
+    required PlatformInt64 mediaId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_person_snapshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetMediaCrewConstMeta,
+        argValues: [mediaId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetMediaCrewConstMeta =>
+      const TaskConstMeta(debugName: "get_media_crew", argNames: ["mediaId"]);
+
+  @override
+  Future<List<FacetSnapshot>> crateApiMediaGetMediaFacets({
+    required PlatformInt64 mediaId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_facet_snapshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetMediaFacetsConstMeta,
+        argValues: [mediaId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetMediaFacetsConstMeta =>
+      const TaskConstMeta(debugName: "get_media_facets", argNames: ["mediaId"]);
+
+  @override
+  Future<List<String>> crateApiMediaGetMediaLockedFields({
+    required PlatformInt64 mediaId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetMediaLockedFieldsConstMeta,
+        argValues: [mediaId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetMediaLockedFieldsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_media_locked_fields",
+        argNames: ["mediaId"],
+      );
+
+  @override
+  Future<List<MovieSnapshot>> crateApiMediaGetMediaSnapshots({
+    required String mediaType,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(mediaType, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_movie_snapshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetMediaSnapshotsConstMeta,
+        argValues: [mediaType],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetMediaSnapshotsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_media_snapshots",
+        argNames: ["mediaType"],
+      );
+
+  @override
+  Future<List<MovieSnapshot>> crateApiMediaGetMediaSnapshotsByFacet({
+    required String kind,
+    required String key,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(kind, serializer);
+          sse_encode_String(key, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_movie_snapshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetMediaSnapshotsByFacetConstMeta,
+        argValues: [kind, key],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetMediaSnapshotsByFacetConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_media_snapshots_by_facet",
+        argNames: ["kind", "key"],
+      );
+
+  @override
+  Future<List<PairedDevice>> crateApiMediaGetPairedDevices() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_paired_device,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetPairedDevicesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetPairedDevicesConstMeta =>
+      const TaskConstMeta(debugName: "get_paired_devices", argNames: []);
+
+  @override
+  Future<PersonData> crateApiMediaGetPerson({
+    required PlatformInt64 personTmdbId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(personTmdbId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 36,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_person_data,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetPersonConstMeta,
+        argValues: [personTmdbId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetPersonConstMeta =>
+      const TaskConstMeta(debugName: "get_person", argNames: ["personTmdbId"]);
+
+  @override
+  Future<PersonFilmography> crateApiMediaGetPersonFilmography({
+    required PlatformInt64 personTmdbId,
+    String? token,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(personTmdbId, serializer);
+          sse_encode_opt_String(token, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_person_filmography,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetPersonFilmographyConstMeta,
+        argValues: [personTmdbId, token],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetPersonFilmographyConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_person_filmography",
+        argNames: ["personTmdbId", "token"],
+      );
+
+  @override
+  Future<PlaybackDecision> crateApiMediaGetPlaybackDecision({
+    required PlatformInt64 mediaId,
+    required PlaybackRequest request,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          sse_encode_box_autoadd_playback_request(request, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_playback_decision,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetPlaybackDecisionConstMeta,
+        argValues: [mediaId, request],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetPlaybackDecisionConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_playback_decision",
+        argNames: ["mediaId", "request"],
+      );
+
+  @override
+  Future<PlaybackState> crateApiMediaGetPlaybackState({
+    required PlatformInt64 mediaId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_playback_state,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetPlaybackStateConstMeta,
+        argValues: [mediaId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetPlaybackStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_playback_state",
+        argNames: ["mediaId"],
+      );
+
+  @override
+  Future<List<Profile>> crateApiMediaGetProfiles() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_profile,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetProfilesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetProfilesConstMeta =>
+      const TaskConstMeta(debugName: "get_profiles", argNames: []);
+
+  @override
+  Future<List<PlaybackEntry>> crateApiMediaGetRecentlyWatched({
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_playback_entry,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetRecentlyWatchedConstMeta,
+        argValues: [limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetRecentlyWatchedConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_recently_watched",
+        argNames: ["limit"],
+      );
+
+  @override
+  Future<List<MovieSnapshot>> crateApiMediaGetSimilarMedia({
+    required PlatformInt64 mediaId,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_movie_snapshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetSimilarMediaConstMeta,
+        argValues: [mediaId, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetSimilarMediaConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_similar_media",
+        argNames: ["mediaId", "limit"],
+      );
+
+  @override
+  Future<List<MovieSnapshot>> crateApiMediaGetSmartCollectionItems({
+    required PlatformInt64 collectionId,
+    required int page,
+    required int pageSize,
+    required int seed,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(collectionId, serializer);
+          sse_encode_u_32(page, serializer);
+          sse_encode_u_32(pageSize, serializer);
+          sse_encode_u_32(seed, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_movie_snapshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetSmartCollectionItemsConstMeta,
+        argValues: [collectionId, page, pageSize, seed],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetSmartCollectionItemsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_smart_collection_items",
+        argNames: ["collectionId", "page", "pageSize", "seed"],
+      );
+
+  @override
+  Future<List<SmartCollection>> crateApiMediaGetSmartCollections() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_smart_collection,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetSmartCollectionsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetSmartCollectionsConstMeta =>
+      const TaskConstMeta(debugName: "get_smart_collections", argNames: []);
+
+  @override
+  Future<String> crateApiMediaGetStreamUrl({required PlatformInt64 mediaId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetStreamUrlConstMeta,
+        argValues: [mediaId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetStreamUrlConstMeta =>
+      const TaskConstMeta(debugName: "get_stream_url", argNames: ["mediaId"]);
+
+  @override
+  Future<List<SubtitleFile>> crateApiMediaGetSubtitleFiles({
+    required PlatformInt64 mediaId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_subtitle_file,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetSubtitleFilesConstMeta,
+        argValues: [mediaId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetSubtitleFilesConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_subtitle_files",
+        argNames: ["mediaId"],
+      );
+
+  @override
+  Future<String> crateApiMediaGetSubtitleUrl({
+    required PlatformInt64 mediaId,
+    required PlatformInt64 subtitleId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          sse_encode_i_64(subtitleId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetSubtitleUrlConstMeta,
+        argValues: [mediaId, subtitleId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetSubtitleUrlConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_subtitle_url",
+        argNames: ["mediaId", "subtitleId"],
+      );
+
+  @override
+  Future<String?> crateApiMediaGetTrickplayUrl({
+    required PlatformInt64 mediaId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetTrickplayUrlConstMeta,
+        argValues: [mediaId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetTrickplayUrlConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_trickplay_url",
+        argNames: ["mediaId"],
+      );
+
+  @override
+  Future<List<WatchRecommendation>> crateApiMediaGetWatchRecommendations({
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_watch_recommendation,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetWatchRecommendationsConstMeta,
+        argValues: [limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetWatchRecommendationsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_watch_recommendations",
+        argNames: ["limit"],
+      );
+
+  @override
+  Future<List<MovieSnapshot>> crateApiMediaGetWatchlist({
+    required int page,
+    required int pageSize,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(page, serializer);
+          sse_encode_u_32(pageSize, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_movie_snapshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaGetWatchlistConstMeta,
+        argValues: [page, pageSize],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaGetWatchlistConstMeta => const TaskConstMeta(
+    debugName: "get_watchlist",
+    argNames: ["page", "pageSize"],
+  );
+
+  @override
+  Future<void> crateApiMediaInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 51,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMediaInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  bool crateApiMediaIsTranscodingAvailable() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMediaIsTranscodingAvailableConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaIsTranscodingAvailableConstMeta =>
+      const TaskConstMeta(debugName: "is_transcoding_available", argNames: []);
+
+  @override
+  Future<void> crateApiMediaMarkMediaWatched({
+    required PlatformInt64 mediaId,
+    required bool watched,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          sse_encode_bool(watched, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaMarkMediaWatchedConstMeta,
+        argValues: [mediaId, watched],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaMarkMediaWatchedConstMeta =>
+      const TaskConstMeta(
+        debugName: "mark_media_watched",
+        argNames: ["mediaId", "watched"],
+      );
+
+  @override
+  Future<void> crateApiMediaMoveListItem({
+    required PlatformInt64 listId,
+    required PlatformInt64 mediaId,
+    required int position,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(listId, serializer);
+          sse_encode_i_64(mediaId, serializer);
+          sse_encode_u_32(position, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaMoveListItemConstMeta,
+        argValues: [listId, mediaId, position],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaMoveListItemConstMeta => const TaskConstMeta(
+    debugName: "move_list_item",
+    argNames: ["listId", "mediaId", "position"],
+  );
+
+  @override
+  Future<void> crateApiMediaOpenVideo({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaOpenVideoConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaOpenVideoConstMeta =>
+      const TaskConstMeta(debugName: "open_video", argNames: ["path"]);
+
+  @override
+  Future<List<MovieSnapshot>> crateApiMediaPreviewSmartCollection({
+    required SmartQuery query,
+    required int page,
+    required int pageSize,
+    required int seed,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_smart_query(query, serializer);
+          sse_encode_u_32(page, serializer);
+          sse_encode_u_32(pageSize, serializer);
+          sse_encode_u_32(seed, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_movie_snapshot,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaPreviewSmartCollectionConstMeta,
+        argValues: [query, page, pageSize, seed],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaPreviewSmartCollectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "preview_smart_collection",
+        argNames: ["query", "page", "pageSize", "seed"],
+      );
+
+  @override
+  Future<int> crateApiMediaProbeMediaFiles({
+    required MediaSource source,
+    required bool reprobeAll,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_media_source(source, serializer);
+          sse_encode_bool(reprobeAll, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaProbeMediaFilesConstMeta,
+        argValues: [source, reprobeAll],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaProbeMediaFilesConstMeta =>
+      const TaskConstMeta(
+        debugName: "probe_media_files",
+        argNames: ["source", "reprobeAll"],
+      );
+
+  @override
+  Future<List<SearchResult>> crateApiMediaQuickSearch({
+    required String query,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaQuickSearchConstMeta,
+        argValues: [query, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaQuickSearchConstMeta => const TaskConstMeta(
+    debugName: "quick_search",
+    argNames: ["query", "limit"],
+  );
+
+  @override
+  Future<void> crateApiMediaRateMedia({
+    required PlatformInt64 mediaId,
+    double? rating,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          sse_encode_opt_box_autoadd_f_32(rating, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaRateMediaConstMeta,
+        argValues: [mediaId, rating],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaRateMediaConstMeta => const TaskConstMeta(
+    debugName: "rate_media",
+    argNames: ["mediaId", "rating"],
+  );
+
+  @override
+  Future<int> crateApiMediaReapplyCreditFilter() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaReapplyCreditFilterConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaReapplyCreditFilterConstMeta =>
+      const TaskConstMeta(debugName: "reapply_credit_filter", argNames: []);
+
+  @override
+  Future<int> crateApiMediaRefreshLibraryMetadata({required String token}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(token, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaRefreshLibraryMetadataConstMeta,
+        argValues: [token],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaRefreshLibraryMetadataConstMeta =>
+      const TaskConstMeta(
+        debugName: "refresh_library_metadata",
+        argNames: ["token"],
+      );
+
+  @override
+  Future<int> crateApiMediaRefreshMovieMetadata({
+    required PlatformInt64 mediaId,
+    required String token,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          sse_encode_String(token, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 62,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaRefreshMovieMetadataConstMeta,
+        argValues: [mediaId, token],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaRefreshMovieMetadataConstMeta =>
+      const TaskConstMeta(
+        debugName: "refresh_movie_metadata",
+        argNames: ["mediaId", "token"],
+      );
+
+  @override
+  Future<int> crateApiMediaRefreshPersonMetadata({
+    required PlatformInt64 personTmdbId,
+    required String token,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(personTmdbId, serializer);
+          sse_encode_String(token, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 63,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaRefreshPersonMetadataConstMeta,
+        argValues: [personTmdbId, token],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaRefreshPersonMetadataConstMeta =>
+      const TaskConstMeta(
+        debugName: "refresh_person_metadata",
+        argNames: ["personTmdbId", "token"],
+      );
+
+  @override
+  Future<int> crateApiMediaRefreshStaleMetadata({
+    required int maxAgeDays,
+    required String token,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(maxAgeDays, serializer);
+          sse_encode_String(token, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 64,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaRefreshStaleMetadataConstMeta,
+        argValues: [maxAgeDays, token],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaRefreshStaleMetadataConstMeta =>
+      const TaskConstMeta(
+        debugName: "refresh_stale_metadata",
+        argNames: ["maxAgeDays", "token"],
+      );
+
+  @override
+  Future<void> crateApiMediaRemoveFromList({
+    required PlatformInt64 listId,
+    required PlatformInt64 mediaId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(listId, serializer);
+          sse_encode_i_64(mediaId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaRemoveFromListConstMeta,
+        argValues: [listId, mediaId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaRemoveFromListConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_from_list",
+        argNames: ["listId", "mediaId"],
+      );
+
+  @override
+  Future<void> crateApiMediaRemoveMedia({required PlatformInt64 mediaId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaRemoveMediaConstMeta,
+        argValues: [mediaId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaRemoveMediaConstMeta =>
+      const TaskConstMeta(debugName: "remove_media", argNames: ["mediaId"]);
+
+  @override
+  Future<void> crateApiMediaRenameList({
+    required PlatformInt64 listId,
+    required String name,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(listId, serializer);
+          sse_encode_String(name, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaRenameListConstMeta,
+        argValues: [listId, name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaRenameListConstMeta => const TaskConstMeta(
+    debugName: "rename_list",
+    argNames: ["listId", "name"],
+  );
+
+  @override
+  Future<void> crateApiMediaReportPlaybackProgress({
+    required PlatformInt64 mediaId,
+    required double position,
+    required double duration,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          sse_encode_f_64(position, serializer);
+          sse_encode_f_64(duration, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 68,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaReportPlaybackProgressConstMeta,
+        argValues: [mediaId, position, duration],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaReportPlaybackProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "report_playback_progress",
+        argNames: ["mediaId", "position", "duration"],
+      );
+
+  @override
+  Future<void> crateApiMediaRevokeDevice({required PlatformInt64 deviceId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(deviceId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 69,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaRevokeDeviceConstMeta,
+        argValues: [deviceId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaRevokeDeviceConstMeta =>
+      const TaskConstMeta(debugName: "revoke_device", argNames: ["deviceId"]);
+
+  @override
+  Future<void> crateApiMediaSetCreditFilter({required CreditFilter filter}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_credit_filter(filter, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 70,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaSetCreditFilterConstMeta,
+        argValues: [filter],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaSetCreditFilterConstMeta =>
+      const TaskConstMeta(debugName: "set_credit_filter", argNames: ["filter"]);
+
+  @override
+  Future<void> crateApiMediaSetFavourite({
+    required PlatformInt64 mediaId,
+    required bool favourite,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          sse_encode_bool(favourite, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 71,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaSetFavouriteConstMeta,
+        argValues: [mediaId, favourite],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaSetFavouriteConstMeta => const TaskConstMeta(
+    debugName: "set_favourite",
+    argNames: ["mediaId", "favourite"],
+  );
+
+  @override
+  Future<void> crateApiMediaSetHomeFeedRows({
+    required List<HomeRowConfig> rows,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_home_row_config(rows, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaSetHomeFeedRowsConstMeta,
+        argValues: [rows],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaSetHomeFeedRowsConstMeta =>
+      const TaskConstMeta(debugName: "set_home_feed_rows", argNames: ["rows"]);
+
+  @override
+  Future<void> crateApiMediaSetInWatchlist({
+    required PlatformInt64 mediaId,
+    required bool inWatchlist,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          sse_encode_bool(inWatchlist, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 73,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaSetInWatchlistConstMeta,
+        argValues: [mediaId, inWatchlist],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaSetInWatchlistConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_in_watchlist",
+        argNames: ["mediaId", "inWatchlist"],
+      );
+
+  @override
+  Future<String> crateApiMediaStart({
+    required String path,
+    required String username,
+    required String password,
+    required String token,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_String(username, serializer);
+          sse_encode_String(password, serializer);
+          sse_encode_String(token, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 74,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiMediaStartConstMeta,
+        argValues: [path, username, password, token],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaStartConstMeta => const TaskConstMeta(
+    debugName: "start",
+    argNames: ["path", "username", "password", "token"],
+  );
+
+  @override
+  Future<int> crateApiMediaStartStreamServer({
+    required MediaSource source,
+    required int port,
+    required bool allowNetwork,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_media_source(source, serializer);
+          sse_encode_u_16(port, serializer);
+          sse_encode_bool(allowNetwork, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 75,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_16,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaStartStreamServerConstMeta,
+        argValues: [source, port, allowNetwork],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaStartStreamServerConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_stream_server",
+        argNames: ["source", "port", "allowNetwork"],
+      );
+
+  @override
+  Future<void> crateApiMediaStopStreamServer() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 76,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaStopStreamServerConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaStopStreamServerConstMeta =>
+      const TaskConstMeta(debugName: "stop_stream_server", argNames: []);
+
+  @override
+  Future<void> crateApiMediaStopTranscoding({required String sessionId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(sessionId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 77,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaStopTranscodingConstMeta,
+        argValues: [sessionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaStopTranscodingConstMeta =>
+      const TaskConstMeta(
+        debugName: "stop_transcoding",
+        argNames: ["sessionId"],
+      );
+
+  @override
+  Future<void> crateApiMediaSwitchProfile({
+    required PlatformInt64 profileId,
+    String? pin,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(profileId, serializer);
+          sse_encode_opt_String(pin, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 78,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaSwitchProfileConstMeta,
+        argValues: [profileId, pin],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaSwitchProfileConstMeta => const TaskConstMeta(
+    debugName: "switch_profile",
+    argNames: ["profileId", "pin"],
+  );
+
+  @override
+  Future<void> crateApiMediaTempoMountSmb() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 79,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaTempoMountSmbConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaTempoMountSmbConstMeta =>
+      const TaskConstMeta(debugName: "tempo_mount_smb", argNames: []);
+
+  @override
+  Future<void> crateApiMediaTempoUnmountSmb() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 80,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaTempoUnmountSmbConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaTempoUnmountSmbConstMeta =>
+      const TaskConstMeta(debugName: "tempo_unmount_smb", argNames: []);
+
+  @override
+  Future<void> crateApiMediaUnlockMediaField({
+    required PlatformInt64 mediaId,
+    required String field,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(mediaId, serializer);
+          sse_encode_String(field, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 81,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMediaUnlockMediaFieldConstMeta,
+        argValues: [mediaId, field],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMediaUnlockMediaFieldConstMeta =>
+      const TaskConstMeta(
+        debugName: "unlock_media_field",
+        argNames: ["mediaId", "field"],
+      );
+
+  @override
+  Stream<LibraryEvent> crateApiMediaWatchLibraryEvents() {
+    final sink = RustStreamSink<LibraryEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_library_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 82,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiMediaWatchLibraryEventsConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiMediaWatchLibraryEventsConstMeta =>
+      const TaskConstMeta(
+        debugName: "watch_library_events",
+        argNames: ["sink"],
+      );
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  RustStreamSink<LibraryEvent> dco_decode_StreamSink_library_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AllowedJob dco_decode_allowed_job(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AllowedJob(
+      department: dco_decode_String(arr[0]),
+      job: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  CreditFilter dco_decode_box_autoadd_credit_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_credit_filter(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  MediaInfo dco_decode_box_autoadd_media_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_media_info(raw);
+  }
+
+  @protected
+  MediaSource dco_decode_box_autoadd_media_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_media_source(raw);
+  }
+
+  @protected
+  PlaybackRequest dco_decode_box_autoadd_playback_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_playback_request(raw);
+  }
+
+  @protected
+  SmartQuery dco_decode_box_autoadd_smart_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_smart_query(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  ClientCapabilities dco_decode_client_capabilities(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return ClientCapabilities(
+      containers: dco_decode_list_String(arr[0]),
+      videoCodecs: dco_decode_list_String(arr[1]),
+      audioCodecs: dco_decode_list_String(arr[2]),
+      maxWidth: dco_decode_opt_box_autoadd_i_32(arr[3]),
+      maxHeight: dco_decode_opt_box_autoadd_i_32(arr[4]),
+      maxBitrate: dco_decode_opt_box_autoadd_i_64(arr[5]),
+      maxAudioChannels: dco_decode_opt_box_autoadd_i_32(arr[6]),
+      supportsHdr: dco_decode_bool(arr[7]),
+    );
+  }
+
+  @protected
+  CollectionDetail dco_decode_collection_detail(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return CollectionDetail(
+      id: dco_decode_i_64(arr[0]),
+      name: dco_decode_String(arr[1]),
+      overview: dco_decode_String(arr[2]),
+      poster: dco_decode_opt_String(arr[3]),
+      backdrop: dco_decode_opt_String(arr[4]),
+      parts: dco_decode_list_movie_snapshot(arr[5]),
+    );
+  }
+
+  @protected
+  CollectionPartSnapshot dco_decode_collection_part_snapshot(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return CollectionPartSnapshot(
+      tmdbId: dco_decode_i_64(arr[0]),
+      title: dco_decode_String(arr[1]),
+      releaseDate: dco_decode_String(arr[2]),
+      posterPath: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  CollectionSnapshot dco_decode_collection_snapshot(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return CollectionSnapshot(
+      id: dco_decode_i_64(arr[0]),
+      name: dco_decode_String(arr[1]),
+      poster: dco_decode_opt_String(arr[2]),
+      ownedCount: dco_decode_i_64(arr[3]),
+      partCount: dco_decode_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  CreditFilter dco_decode_credit_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CreditFilter(
+      allowedJobs: dco_decode_list_allowed_job(arr[0]),
+      maxCastSize: dco_decode_opt_box_autoadd_u_32(arr[1]),
+      includeUncredited: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FacetSnapshot dco_decode_facet_snapshot(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return FacetSnapshot(
+      kind: dco_decode_String(arr[0]),
+      key: dco_decode_String(arr[1]),
+      name: dco_decode_String(arr[2]),
+      mediaCount: dco_decode_i_64(arr[3]),
+    );
+  }
+
+  @protected
+  FilmographyEntry dco_decode_filmography_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return FilmographyEntry(
+      mediaId: dco_decode_opt_box_autoadd_i_64(arr[0]),
+      tmdbId: dco_decode_i_64(arr[1]),
+      title: dco_decode_String(arr[2]),
+      releaseDate: dco_decode_String(arr[3]),
+      poster: dco_decode_opt_String(arr[4]),
+      department: dco_decode_String(arr[5]),
+      character: dco_decode_String(arr[6]),
+      jobName: dco_decode_String(arr[7]),
+    );
+  }
+
+  @protected
+  FilmographyGroup dco_decode_filmography_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FilmographyGroup(
+      department: dco_decode_String(arr[0]),
+      entries: dco_decode_list_filmography_entry(arr[1]),
+    );
+  }
+
+  @protected
+  HomeRow dco_decode_home_row(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return HomeRow(
+      kind: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      items: dco_decode_list_playback_entry(arr[2]),
+    );
+  }
+
+  @protected
+  HomeRowConfig dco_decode_home_row_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return HomeRowConfig(
+      kind: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      enabled: dco_decode_bool(arr[2]),
+      itemCount: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  LibraryEvent dco_decode_library_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return LibraryEvent_MediaAdded(mediaId: dco_decode_i_64(raw[1]));
+      case 1:
+        return LibraryEvent_MediaUpdated(mediaId: dco_decode_i_64(raw[1]));
+      case 2:
+        return LibraryEvent_MediaRemoved(mediaId: dco_decode_i_64(raw[1]));
+      case 3:
+        return LibraryEvent_WatchStateChanged(
+          profileId: dco_decode_i_64(raw[1]),
+          mediaId: dco_decode_i_64(raw[2]),
+        );
+      case 4:
+        return LibraryEvent_ScanStarted();
+      case 5:
+        return LibraryEvent_ScanFinished(
+          report: dco_decode_scan_report(raw[1]),
+        );
+      case 6:
+        return LibraryEvent_EventsMissed(count: dco_decode_u_64(raw[1]));
+      case 7:
+        return LibraryEvent_KeepAlive();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<AllowedJob> dco_decode_list_allowed_job(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_allowed_job).toList();
+  }
+
+  @protected
+  List<CollectionPartSnapshot> dco_decode_list_collection_part_snapshot(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_collection_part_snapshot).toList();
+  }
+
+  @protected
+  List<CollectionSnapshot> dco_decode_list_collection_snapshot(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_collection_snapshot).toList();
+  }
+
+  @protected
+  List<FacetSnapshot> dco_decode_list_facet_snapshot(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_facet_snapshot).toList();
+  }
+
+  @protected
+  List<FilmographyEntry> dco_decode_list_filmography_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_filmography_entry).toList();
+  }
+
+  @protected
+  List<FilmographyGroup> dco_decode_list_filmography_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_filmography_group).toList();
+  }
+
+  @protected
+  List<HomeRow> dco_decode_list_home_row(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_home_row).toList();
+  }
+
+  @protected
+  List<HomeRowConfig> dco_decode_list_home_row_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_home_row_config).toList();
+  }
+
+  @protected
+  List<MediaChapter> dco_decode_list_media_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_media_chapter).toList();
+  }
+
+  @protected
+  List<MediaList> dco_decode_list_media_list(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_media_list).toList();
+  }
+
+  @protected
+  List<MediaStream> dco_decode_list_media_stream(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_media_stream).toList();
+  }
+
+  @protected
+  List<MovieSnapshot> dco_decode_list_movie_snapshot(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_movie_snapshot).toList();
+  }
+
+  @protected
+  List<PairedDevice> dco_decode_list_paired_device(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_paired_device).toList();
+  }
+
+  @protected
+  List<PersonSnapshot> dco_decode_list_person_snapshot(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_person_snapshot).toList();
+  }
+
+  @protected
+  List<PlaybackEntry> dco_decode_list_playback_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_playback_entry).toList();
+  }
+
+  @protected
+  Int64List dco_decode_list_prim_i_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeInt64List(raw);
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<Profile> dco_decode_list_profile(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_profile).toList();
+  }
+
+  @protected
+  List<SearchResult> dco_decode_list_search_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_search_result).toList();
+  }
+
+  @protected
+  List<SmartCollection> dco_decode_list_smart_collection(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_smart_collection).toList();
+  }
+
+  @protected
+  List<SubtitleFile> dco_decode_list_subtitle_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_subtitle_file).toList();
+  }
+
+  @protected
+  List<WatchRecommendation> dco_decode_list_watch_recommendation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_watch_recommendation).toList();
+  }
+
+  @protected
+  MediaChapter dco_decode_media_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return MediaChapter(
+      index: dco_decode_i_32(arr[0]),
+      title: dco_decode_opt_String(arr[1]),
+      start: dco_decode_f_64(arr[2]),
+      end: dco_decode_opt_box_autoadd_f_64(arr[3]),
+    );
+  }
+
+  @protected
+  MediaData dco_decode_media_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 18)
+      throw Exception('unexpected arr length: expect 18 but see ${arr.length}');
+    return MediaData(
+      id: dco_decode_i_64(arr[0]),
+      filePath: dco_decode_String(arr[1]),
+      fileOptionalInfo: dco_decode_String(arr[2]),
+      originalTitle: dco_decode_String(arr[3]),
+      title: dco_decode_String(arr[4]),
+      rating: dco_decode_f_32(arr[5]),
+      releaseDate: dco_decode_String(arr[6]),
+      summary: dco_decode_String(arr[7]),
+      poster: dco_decode_String(arr[8]),
+      backdrop: dco_decode_String(arr[9]),
+      runtime: dco_decode_opt_box_autoadd_i_32(arr[10]),
+      tagline: dco_decode_String(arr[11]),
+      status: dco_decode_String(arr[12]),
+      budget: dco_decode_i_64(arr[13]),
+      revenue: dco_decode_i_64(arr[14]),
+      imdbId: dco_decode_opt_String(arr[15]),
+      certification: dco_decode_String(arr[16]),
+      mediaInfo: dco_decode_opt_box_autoadd_media_info(arr[17]),
+    );
+  }
+
+  @protected
+  MediaInfo dco_decode_media_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return MediaInfo(
+      container: dco_decode_String(arr[0]),
+      duration: dco_decode_opt_box_autoadd_f_64(arr[1]),
+      width: dco_decode_opt_box_autoadd_i_32(arr[2]),
+      height: dco_decode_opt_box_autoadd_i_32(arr[3]),
+      videoCodec: dco_decode_opt_String(arr[4]),
+      hdrFormat: dco_decode_opt_String(arr[5]),
+      bitrate: dco_decode_opt_box_autoadd_i_64(arr[6]),
+      streams: dco_decode_list_media_stream(arr[7]),
+      chapters: dco_decode_list_media_chapter(arr[8]),
+    );
+  }
+
+  @protected
+  MediaList dco_decode_media_list(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return MediaList(
+      id: dco_decode_i_64(arr[0]),
+      name: dco_decode_String(arr[1]),
+      kind: dco_decode_String(arr[2]),
+      itemCount: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  MediaSource dco_decode_media_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return MediaSource_Smb(
+          path: dco_decode_String(raw[1]),
+          username: dco_decode_String(raw[2]),
+          password: dco_decode_String(raw[3]),
+        );
+      case 1:
+        return MediaSource_Local(root: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  MediaStream dco_decode_media_stream(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return MediaStream(
+      index: dco_decode_i_32(arr[0]),
+      kind: dco_decode_String(arr[1]),
+      codec: dco_decode_String(arr[2]),
+      language: dco_decode_opt_String(arr[3]),
+      title: dco_decode_opt_String(arr[4]),
+      channels: dco_decode_opt_box_autoadd_i_32(arr[5]),
+      isDefault: dco_decode_bool(arr[6]),
+      isForced: dco_decode_bool(arr[7]),
+    );
+  }
+
+  @protected
+  MovieSnapshot dco_decode_movie_snapshot(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return MovieSnapshot(
+      id: dco_decode_i_64(arr[0]),
+      filePath: dco_decode_String(arr[1]),
+      title: dco_decode_String(arr[2]),
+      rating: dco_decode_f_32(arr[3]),
+      releaseDate: dco_decode_String(arr[4]),
+      poster: dco_decode_String(arr[5]),
+      watched: dco_decode_bool(arr[6]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_32(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  MediaInfo? dco_decode_opt_box_autoadd_media_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_media_info(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  PairedDevice dco_decode_paired_device(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PairedDevice(
+      id: dco_decode_i_64(arr[0]),
+      name: dco_decode_String(arr[1]),
+      profileId: dco_decode_i_64(arr[2]),
+      createdAt: dco_decode_i_64(arr[3]),
+      lastSeen: dco_decode_opt_box_autoadd_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  PersonData dco_decode_person_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return PersonData(
+      tmdbId: dco_decode_i_64(arr[0]),
+      name: dco_decode_String(arr[1]),
+      summary: dco_decode_String(arr[2]),
+      picturePath: dco_decode_opt_String(arr[3]),
+      birthday: dco_decode_opt_String(arr[4]),
+      deathday: dco_decode_opt_String(arr[5]),
+      placeOfBirth: dco_decode_opt_String(arr[6]),
+      knownForDepartment: dco_decode_opt_String(arr[7]),
+      gender: dco_decode_i_32(arr[8]),
+      alsoKnownAs: dco_decode_list_String(arr[9]),
+    );
+  }
+
+  @protected
+  PersonFilmography dco_decode_person_filmography(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PersonFilmography(
+      person: dco_decode_person_data(arr[0]),
+      groups: dco_decode_list_filmography_group(arr[1]),
+      missing: dco_decode_list_filmography_entry(arr[2]),
+    );
+  }
+
+  @protected
+  PersonSnapshot dco_decode_person_snapshot(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return PersonSnapshot(
+      tmdbId: dco_decode_i_64(arr[0]),
+      name: dco_decode_String(arr[1]),
+      character: dco_decode_String(arr[2]),
+      jobName: dco_decode_String(arr[3]),
+      picturePath: dco_decode_String(arr[4]),
+      department: dco_decode_String(arr[5]),
+      creditOrder: dco_decode_opt_box_autoadd_i_32(arr[6]),
+      creditId: dco_decode_String(arr[7]),
+    );
+  }
+
+  @protected
+  PlaybackDecision dco_decode_playback_decision(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PlaybackDecision(
+      method: dco_decode_String(arr[0]),
+      reasons: dco_decode_list_String(arr[1]),
+      url: dco_decode_String(arr[2]),
+      sessionId: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  PlaybackEntry dco_decode_playback_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PlaybackEntry(
+      media: dco_decode_movie_snapshot(arr[0]),
+      state: dco_decode_playback_state(arr[1]),
+    );
+  }
+
+  @protected
+  PlaybackRequest dco_decode_playback_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return PlaybackRequest(
+      capabilities: dco_decode_client_capabilities(arr[0]),
+      maxHeight: dco_decode_opt_box_autoadd_i_32(arr[1]),
+      maxBitrate: dco_decode_opt_box_autoadd_i_64(arr[2]),
+      audioStream: dco_decode_opt_box_autoadd_i_32(arr[3]),
+      subtitleStream: dco_decode_opt_box_autoadd_i_32(arr[4]),
+      subtitleFile: dco_decode_opt_box_autoadd_i_64(arr[5]),
+      startPosition: dco_decode_f_64(arr[6]),
+    );
+  }
+
+  @protected
+  PlaybackState dco_decode_playback_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return PlaybackState(
+      mediaId: dco_decode_i_64(arr[0]),
+      watched: dco_decode_bool(arr[1]),
+      playCount: dco_decode_u_32(arr[2]),
+      lastPlayed: dco_decode_opt_box_autoadd_i_64(arr[3]),
+      resumePosition: dco_decode_f_64(arr[4]),
+      duration: dco_decode_f_64(arr[5]),
+      userRating: dco_decode_opt_box_autoadd_f_32(arr[6]),
+    );
+  }
+
+  @protected
+  Profile dco_decode_profile(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return Profile(
+      id: dco_decode_i_64(arr[0]),
+      name: dco_decode_String(arr[1]),
+      avatar: dco_decode_opt_String(arr[2]),
+      hasPin: dco_decode_bool(arr[3]),
+      maxCertification: dco_decode_opt_String(arr[4]),
+      hideUnrated: dco_decode_bool(arr[5]),
+      preferredLanguage: dco_decode_opt_String(arr[6]),
+    );
+  }
+
+  @protected
+  ScanReport dco_decode_scan_report(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return ScanReport(
+      moviesFound: dco_decode_u_32(arr[0]),
+      moviesMatched: dco_decode_u_32(arr[1]),
+      moviesSaved: dco_decode_u_32(arr[2]),
+      moviesFailed: dco_decode_u_32(arr[3]),
+      personsFetched: dco_decode_u_32(arr[4]),
+      personsSkipped: dco_decode_u_32(arr[5]),
+      tmdbRequests: dco_decode_u_64(arr[6]),
+      tmdbRequestsSaved: dco_decode_u_64(arr[7]),
+    );
+  }
+
+  @protected
+  SearchResult dco_decode_search_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return SearchResult(
+      kind: dco_decode_String(arr[0]),
+      id: dco_decode_i_64(arr[1]),
+      title: dco_decode_String(arr[2]),
+      subtitle: dco_decode_String(arr[3]),
+      picturePath: dco_decode_opt_String(arr[4]),
+      score: dco_decode_f_32(arr[5]),
+    );
+  }
+
+  @protected
+  SmartCollection dco_decode_smart_collection(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SmartCollection(
+      id: dco_decode_i_64(arr[0]),
+      name: dco_decode_String(arr[1]),
+      query: dco_decode_smart_query(arr[2]),
+    );
+  }
+
+  @protected
+  SmartFilter dco_decode_smart_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return SmartFilter(
+      genres: dco_decode_list_String(arr[0]),
+      yearFrom: dco_decode_opt_box_autoadd_u_32(arr[1]),
+      yearTo: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      minRating: dco_decode_opt_box_autoadd_f_32(arr[3]),
+      maxRating: dco_decode_opt_box_autoadd_f_32(arr[4]),
+      personIds: dco_decode_list_prim_i_64_strict(arr[5]),
+      watched: dco_decode_opt_box_autoadd_bool(arr[6]),
+      resolutions: dco_decode_list_String(arr[7]),
+      addedWithinDays: dco_decode_opt_box_autoadd_u_32(arr[8]),
+    );
+  }
+
+  @protected
+  SmartQuery dco_decode_smart_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SmartQuery(
+      filter: dco_decode_smart_filter(arr[0]),
+      sort: dco_decode_smart_sort(arr[1]),
+      descending: dco_decode_bool(arr[2]),
+      limit: dco_decode_opt_box_autoadd_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  SmartSort dco_decode_smart_sort(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SmartSort.values[raw as int];
+  }
+
+  @protected
+  SubtitleFile dco_decode_subtitle_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return SubtitleFile(
+      id: dco_decode_i_64(arr[0]),
+      filePath: dco_decode_String(arr[1]),
+      format: dco_decode_String(arr[2]),
+      language: dco_decode_opt_String(arr[3]),
+      title: dco_decode_opt_String(arr[4]),
+      isForced: dco_decode_bool(arr[5]),
+      isSdh: dco_decode_bool(arr[6]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  WatchRecommendation dco_decode_watch_recommendation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WatchRecommendation(
+      source: dco_decode_movie_snapshot(arr[0]),
+      items: dco_decode_list_movie_snapshot(arr[1]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  RustStreamSink<LibraryEvent> sse_decode_StreamSink_library_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AllowedJob sse_decode_allowed_job(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_department = sse_decode_String(deserializer);
+    var var_job = sse_decode_String(deserializer);
+    return AllowedJob(department: var_department, job: var_job);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  CreditFilter sse_decode_box_autoadd_credit_filter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_credit_filter(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_32(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  MediaInfo sse_decode_box_autoadd_media_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_media_info(deserializer));
+  }
+
+  @protected
+  MediaSource sse_decode_box_autoadd_media_source(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_media_source(deserializer));
+  }
+
+  @protected
+  PlaybackRequest sse_decode_box_autoadd_playback_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_playback_request(deserializer));
+  }
+
+  @protected
+  SmartQuery sse_decode_box_autoadd_smart_query(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_smart_query(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  ClientCapabilities sse_decode_client_capabilities(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_containers = sse_decode_list_String(deserializer);
+    var var_videoCodecs = sse_decode_list_String(deserializer);
+    var var_audioCodecs = sse_decode_list_String(deserializer);
+    var var_maxWidth = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_maxHeight = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_maxBitrate = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_maxAudioChannels = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_supportsHdr = sse_decode_bool(deserializer);
+    return ClientCapabilities(
+      containers: var_containers,
+      videoCodecs: var_videoCodecs,
+      audioCodecs: var_audioCodecs,
+      maxWidth: var_maxWidth,
+      maxHeight: var_maxHeight,
+      maxBitrate: var_maxBitrate,
+      maxAudioChannels: var_maxAudioChannels,
+      supportsHdr: var_supportsHdr,
+    );
+  }
+
+  @protected
+  CollectionDetail sse_decode_collection_detail(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_overview = sse_decode_String(deserializer);
+    var var_poster = sse_decode_opt_String(deserializer);
+    var var_backdrop = sse_decode_opt_String(deserializer);
+    var var_parts = sse_decode_list_movie_snapshot(deserializer);
+    return CollectionDetail(
+      id: var_id,
+      name: var_name,
+      overview: var_overview,
+      poster: var_poster,
+      backdrop: var_backdrop,
+      parts: var_parts,
+    );
+  }
+
+  @protected
+  CollectionPartSnapshot sse_decode_collection_part_snapshot(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_tmdbId = sse_decode_i_64(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_releaseDate = sse_decode_String(deserializer);
+    var var_posterPath = sse_decode_opt_String(deserializer);
+    return CollectionPartSnapshot(
+      tmdbId: var_tmdbId,
+      title: var_title,
+      releaseDate: var_releaseDate,
+      posterPath: var_posterPath,
+    );
+  }
+
+  @protected
+  CollectionSnapshot sse_decode_collection_snapshot(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_poster = sse_decode_opt_String(deserializer);
+    var var_ownedCount = sse_decode_i_64(deserializer);
+    var var_partCount = sse_decode_i_64(deserializer);
+    return CollectionSnapshot(
+      id: var_id,
+      name: var_name,
+      poster: var_poster,
+      ownedCount: var_ownedCount,
+      partCount: var_partCount,
+    );
+  }
+
+  @protected
+  CreditFilter sse_decode_credit_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_allowedJobs = sse_decode_list_allowed_job(deserializer);
+    var var_maxCastSize = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_includeUncredited = sse_decode_bool(deserializer);
+    return CreditFilter(
+      allowedJobs: var_allowedJobs,
+      maxCastSize: var_maxCastSize,
+      includeUncredited: var_includeUncredited,
+    );
+  }
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat32();
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  FacetSnapshot sse_decode_facet_snapshot(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_String(deserializer);
+    var var_key = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_mediaCount = sse_decode_i_64(deserializer);
+    return FacetSnapshot(
+      kind: var_kind,
+      key: var_key,
+      name: var_name,
+      mediaCount: var_mediaCount,
+    );
+  }
+
+  @protected
+  FilmographyEntry sse_decode_filmography_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mediaId = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_tmdbId = sse_decode_i_64(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_releaseDate = sse_decode_String(deserializer);
+    var var_poster = sse_decode_opt_String(deserializer);
+    var var_department = sse_decode_String(deserializer);
+    var var_character = sse_decode_String(deserializer);
+    var var_jobName = sse_decode_String(deserializer);
+    return FilmographyEntry(
+      mediaId: var_mediaId,
+      tmdbId: var_tmdbId,
+      title: var_title,
+      releaseDate: var_releaseDate,
+      poster: var_poster,
+      department: var_department,
+      character: var_character,
+      jobName: var_jobName,
+    );
+  }
+
+  @protected
+  FilmographyGroup sse_decode_filmography_group(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_department = sse_decode_String(deserializer);
+    var var_entries = sse_decode_list_filmography_entry(deserializer);
+    return FilmographyGroup(department: var_department, entries: var_entries);
+  }
+
+  @protected
+  HomeRow sse_decode_home_row(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_items = sse_decode_list_playback_entry(deserializer);
+    return HomeRow(kind: var_kind, title: var_title, items: var_items);
+  }
+
+  @protected
+  HomeRowConfig sse_decode_home_row_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_itemCount = sse_decode_u_32(deserializer);
+    return HomeRowConfig(
+      kind: var_kind,
+      title: var_title,
+      enabled: var_enabled,
+      itemCount: var_itemCount,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  LibraryEvent sse_decode_library_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_mediaId = sse_decode_i_64(deserializer);
+        return LibraryEvent_MediaAdded(mediaId: var_mediaId);
+      case 1:
+        var var_mediaId = sse_decode_i_64(deserializer);
+        return LibraryEvent_MediaUpdated(mediaId: var_mediaId);
+      case 2:
+        var var_mediaId = sse_decode_i_64(deserializer);
+        return LibraryEvent_MediaRemoved(mediaId: var_mediaId);
+      case 3:
+        var var_profileId = sse_decode_i_64(deserializer);
+        var var_mediaId = sse_decode_i_64(deserializer);
+        return LibraryEvent_WatchStateChanged(
+          profileId: var_profileId,
+          mediaId: var_mediaId,
+        );
+      case 4:
+        return LibraryEvent_ScanStarted();
+      case 5:
+        var var_report = sse_decode_scan_report(deserializer);
+        return LibraryEvent_ScanFinished(report: var_report);
+      case 6:
+        var var_count = sse_decode_u_64(deserializer);
+        return LibraryEvent_EventsMissed(count: var_count);
+      case 7:
+        return LibraryEvent_KeepAlive();
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AllowedJob> sse_decode_list_allowed_job(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AllowedJob>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_allowed_job(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CollectionPartSnapshot> sse_decode_list_collection_part_snapshot(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CollectionPartSnapshot>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_collection_part_snapshot(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CollectionSnapshot> sse_decode_list_collection_snapshot(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CollectionSnapshot>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_collection_snapshot(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FacetSnapshot> sse_decode_list_facet_snapshot(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FacetSnapshot>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_facet_snapshot(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FilmographyEntry> sse_decode_list_filmography_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FilmographyEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_filmography_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FilmographyGroup> sse_decode_list_filmography_group(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FilmographyGroup>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_filmography_group(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<HomeRow> sse_decode_list_home_row(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <HomeRow>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_home_row(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<HomeRowConfig> sse_decode_list_home_row_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <HomeRowConfig>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_home_row_config(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MediaChapter> sse_decode_list_media_chapter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MediaChapter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_media_chapter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MediaList> sse_decode_list_media_list(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MediaList>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_media_list(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MediaStream> sse_decode_list_media_stream(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MediaStream>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_media_stream(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MovieSnapshot> sse_decode_list_movie_snapshot(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MovieSnapshot>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_movie_snapshot(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PairedDevice> sse_decode_list_paired_device(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PairedDevice>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_paired_device(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PersonSnapshot> sse_decode_list_person_snapshot(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PersonSnapshot>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_person_snapshot(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PlaybackEntry> sse_decode_list_playback_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PlaybackEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_playback_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getInt64List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<Profile> sse_decode_list_profile(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Profile>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_profile(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SearchResult> sse_decode_list_search_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SearchResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_search_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SmartCollection> sse_decode_list_smart_collection(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SmartCollection>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_smart_collection(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SubtitleFile> sse_decode_list_subtitle_file(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SubtitleFile>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_subtitle_file(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WatchRecommendation> sse_decode_list_watch_recommendation(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WatchRecommendation>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_watch_recommendation(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  MediaChapter sse_decode_media_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_index = sse_decode_i_32(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_start = sse_decode_f_64(deserializer);
+    var var_end = sse_decode_opt_box_autoadd_f_64(deserializer);
+    return MediaChapter(
+      index: var_index,
+      title: var_title,
+      start: var_start,
+      end: var_end,
+    );
+  }
+
+  @protected
+  MediaData sse_decode_media_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_filePath = sse_decode_String(deserializer);
+    var var_fileOptionalInfo = sse_decode_String(deserializer);
+    var var_originalTitle = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_rating = sse_decode_f_32(deserializer);
+    var var_releaseDate = sse_decode_String(deserializer);
+    var var_summary = sse_decode_String(deserializer);
+    var var_poster = sse_decode_String(deserializer);
+    var var_backdrop = sse_decode_String(deserializer);
+    var var_runtime = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_tagline = sse_decode_String(deserializer);
+    var var_status = sse_decode_String(deserializer);
+    var var_budget = sse_decode_i_64(deserializer);
+    var var_revenue = sse_decode_i_64(deserializer);
+    var var_imdbId = sse_decode_opt_String(deserializer);
+    var var_certification = sse_decode_String(deserializer);
+    var var_mediaInfo = sse_decode_opt_box_autoadd_media_info(deserializer);
+    return MediaData(
+      id: var_id,
+      filePath: var_filePath,
+      fileOptionalInfo: var_fileOptionalInfo,
+      originalTitle: var_originalTitle,
+      title: var_title,
+      rating: var_rating,
+      releaseDate: var_releaseDate,
+      summary: var_summary,
+      poster: var_poster,
+      backdrop: var_backdrop,
+      runtime: var_runtime,
+      tagline: var_tagline,
+      status: var_status,
+      budget: var_budget,
+      revenue: var_revenue,
+      imdbId: var_imdbId,
+      certification: var_certification,
+      mediaInfo: var_mediaInfo,
+    );
+  }
+
+  @protected
+  MediaInfo sse_decode_media_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_container = sse_decode_String(deserializer);
+    var var_duration = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_width = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_height = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_videoCodec = sse_decode_opt_String(deserializer);
+    var var_hdrFormat = sse_decode_opt_String(deserializer);
+    var var_bitrate = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_streams = sse_decode_list_media_stream(deserializer);
+    var var_chapters = sse_decode_list_media_chapter(deserializer);
+    return MediaInfo(
+      container: var_container,
+      duration: var_duration,
+      width: var_width,
+      height: var_height,
+      videoCodec: var_videoCodec,
+      hdrFormat: var_hdrFormat,
+      bitrate: var_bitrate,
+      streams: var_streams,
+      chapters: var_chapters,
+    );
+  }
+
+  @protected
+  MediaList sse_decode_media_list(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_kind = sse_decode_String(deserializer);
+    var var_itemCount = sse_decode_u_32(deserializer);
+    return MediaList(
+      id: var_id,
+      name: var_name,
+      kind: var_kind,
+      itemCount: var_itemCount,
+    );
+  }
+
+  @protected
+  MediaSource sse_decode_media_source(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_path = sse_decode_String(deserializer);
+        var var_username = sse_decode_String(deserializer);
+        var var_password = sse_decode_String(deserializer);
+        return MediaSource_Smb(
+          path: var_path,
+          username: var_username,
+          password: var_password,
+        );
+      case 1:
+        var var_root = sse_decode_String(deserializer);
+        return MediaSource_Local(root: var_root);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  MediaStream sse_decode_media_stream(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_index = sse_decode_i_32(deserializer);
+    var var_kind = sse_decode_String(deserializer);
+    var var_codec = sse_decode_String(deserializer);
+    var var_language = sse_decode_opt_String(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_channels = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_isDefault = sse_decode_bool(deserializer);
+    var var_isForced = sse_decode_bool(deserializer);
+    return MediaStream(
+      index: var_index,
+      kind: var_kind,
+      codec: var_codec,
+      language: var_language,
+      title: var_title,
+      channels: var_channels,
+      isDefault: var_isDefault,
+      isForced: var_isForced,
+    );
+  }
+
+  @protected
+  MovieSnapshot sse_decode_movie_snapshot(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_filePath = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_rating = sse_decode_f_32(deserializer);
+    var var_releaseDate = sse_decode_String(deserializer);
+    var var_poster = sse_decode_String(deserializer);
+    var var_watched = sse_decode_bool(deserializer);
+    return MovieSnapshot(
+      id: var_id,
+      filePath: var_filePath,
+      title: var_title,
+      rating: var_rating,
+      releaseDate: var_releaseDate,
+      poster: var_poster,
+      watched: var_watched,
+    );
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  MediaInfo? sse_decode_opt_box_autoadd_media_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_media_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PairedDevice sse_decode_paired_device(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_profileId = sse_decode_i_64(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    var var_lastSeen = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return PairedDevice(
+      id: var_id,
+      name: var_name,
+      profileId: var_profileId,
+      createdAt: var_createdAt,
+      lastSeen: var_lastSeen,
+    );
+  }
+
+  @protected
+  PersonData sse_decode_person_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_tmdbId = sse_decode_i_64(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_summary = sse_decode_String(deserializer);
+    var var_picturePath = sse_decode_opt_String(deserializer);
+    var var_birthday = sse_decode_opt_String(deserializer);
+    var var_deathday = sse_decode_opt_String(deserializer);
+    var var_placeOfBirth = sse_decode_opt_String(deserializer);
+    var var_knownForDepartment = sse_decode_opt_String(deserializer);
+    var var_gender = sse_decode_i_32(deserializer);
+    var var_alsoKnownAs = sse_decode_list_String(deserializer);
+    return PersonData(
+      tmdbId: var_tmdbId,
+      name: var_name,
+      summary: var_summary,
+      picturePath: var_picturePath,
+      birthday: var_birthday,
+      deathday: var_deathday,
+      placeOfBirth: var_placeOfBirth,
+      knownForDepartment: var_knownForDepartment,
+      gender: var_gender,
+      alsoKnownAs: var_alsoKnownAs,
+    );
+  }
+
+  @protected
+  PersonFilmography sse_decode_person_filmography(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_person = sse_decode_person_data(deserializer);
+    var var_groups = sse_decode_list_filmography_group(deserializer);
+    var var_missing = sse_decode_list_filmography_entry(deserializer);
+    return PersonFilmography(
+      person: var_person,
+      groups: var_groups,
+      missing: var_missing,
+    );
+  }
+
+  @protected
+  PersonSnapshot sse_decode_person_snapshot(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_tmdbId = sse_decode_i_64(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_character = sse_decode_String(deserializer);
+    var var_jobName = sse_decode_String(deserializer);
+    var var_picturePath = sse_decode_String(deserializer);
+    var var_department = sse_decode_String(deserializer);
+    var var_creditOrder = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_creditId = sse_decode_String(deserializer);
+    return PersonSnapshot(
+      tmdbId: var_tmdbId,
+      name: var_name,
+      character: var_character,
+      jobName: var_jobName,
+      picturePath: var_picturePath,
+      department: var_department,
+      creditOrder: var_creditOrder,
+      creditId: var_creditId,
+    );
+  }
+
+  @protected
+  PlaybackDecision sse_decode_playback_decision(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_method = sse_decode_String(deserializer);
+    var var_reasons = sse_decode_list_String(deserializer);
+    var var_url = sse_decode_String(deserializer);
+    var var_sessionId = sse_decode_opt_String(deserializer);
+    return PlaybackDecision(
+      method: var_method,
+      reasons: var_reasons,
+      url: var_url,
+      sessionId: var_sessionId,
+    );
+  }
+
+  @protected
+  PlaybackEntry sse_decode_playback_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_media = sse_decode_movie_snapshot(deserializer);
+    var var_state = sse_decode_playback_state(deserializer);
+    return PlaybackEntry(media: var_media, state: var_state);
+  }
+
+  @protected
+  PlaybackRequest sse_decode_playback_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_capabilities = sse_decode_client_capabilities(deserializer);
+    var var_maxHeight = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_maxBitrate = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_audioStream = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_subtitleStream = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_subtitleFile = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_startPosition = sse_decode_f_64(deserializer);
+    return PlaybackRequest(
+      capabilities: var_capabilities,
+      maxHeight: var_maxHeight,
+      maxBitrate: var_maxBitrate,
+      audioStream: var_audioStream,
+      subtitleStream: var_subtitleStream,
+      subtitleFile: var_subtitleFile,
+      startPosition: var_startPosition,
+    );
+  }
+
+  @protected
+  PlaybackState sse_decode_playback_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mediaId = sse_decode_i_64(deserializer);
+    var var_watched = sse_decode_bool(deserializer);
+    var var_playCount = sse_decode_u_32(deserializer);
+    var var_lastPlayed = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_resumePosition = sse_decode_f_64(deserializer);
+    var var_duration = sse_decode_f_64(deserializer);
+    var var_userRating = sse_decode_opt_box_autoadd_f_32(deserializer);
+    return PlaybackState(
+      mediaId: var_mediaId,
+      watched: var_watched,
+      playCount: var_playCount,
+      lastPlayed: var_lastPlayed,
+      resumePosition: var_resumePosition,
+      duration: var_duration,
+      userRating: var_userRating,
+    );
+  }
+
+  @protected
+  Profile sse_decode_profile(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_avatar = sse_decode_opt_String(deserializer);
+    var var_hasPin = sse_decode_bool(deserializer);
+    var var_maxCertification = sse_decode_opt_String(deserializer);
+    var var_hideUnrated = sse_decode_bool(deserializer);
+    var var_preferredLanguage = sse_decode_opt_String(deserializer);
+    return Profile(
+      id: var_id,
+      name: var_name,
+      avatar: var_avatar,
+      hasPin: var_hasPin,
+      maxCertification: var_maxCertification,
+      hideUnrated: var_hideUnrated,
+      preferredLanguage: var_preferredLanguage,
+    );
+  }
+
+  @protected
+  ScanReport sse_decode_scan_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_moviesFound = sse_decode_u_32(deserializer);
+    var var_moviesMatched = sse_decode_u_32(deserializer);
+    var var_moviesSaved = sse_decode_u_32(deserializer);
+    var var_moviesFailed = sse_decode_u_32(deserializer);
+    var var_personsFetched = sse_decode_u_32(deserializer);
+    var var_personsSkipped = sse_decode_u_32(deserializer);
+    var var_tmdbRequests = sse_decode_u_64(deserializer);
+    var var_tmdbRequestsSaved = sse_decode_u_64(deserializer);
+    return ScanReport(
+      moviesFound: var_moviesFound,
+      moviesMatched: var_moviesMatched,
+      moviesSaved: var_moviesSaved,
+      moviesFailed: var_moviesFailed,
+      personsFetched: var_personsFetched,
+      personsSkipped: var_personsSkipped,
+      tmdbRequests: var_tmdbRequests,
+      tmdbRequestsSaved: var_tmdbRequestsSaved,
+    );
+  }
+
+  @protected
+  SearchResult sse_decode_search_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_String(deserializer);
+    var var_id = sse_decode_i_64(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_subtitle = sse_decode_String(deserializer);
+    var var_picturePath = sse_decode_opt_String(deserializer);
+    var var_score = sse_decode_f_32(deserializer);
+    return SearchResult(
+      kind: var_kind,
+      id: var_id,
+      title: var_title,
+      subtitle: var_subtitle,
+      picturePath: var_picturePath,
+      score: var_score,
+    );
+  }
+
+  @protected
+  SmartCollection sse_decode_smart_collection(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_query = sse_decode_smart_query(deserializer);
+    return SmartCollection(id: var_id, name: var_name, query: var_query);
+  }
+
+  @protected
+  SmartFilter sse_decode_smart_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_genres = sse_decode_list_String(deserializer);
+    var var_yearFrom = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_yearTo = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_minRating = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_maxRating = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_personIds = sse_decode_list_prim_i_64_strict(deserializer);
+    var var_watched = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_resolutions = sse_decode_list_String(deserializer);
+    var var_addedWithinDays = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return SmartFilter(
+      genres: var_genres,
+      yearFrom: var_yearFrom,
+      yearTo: var_yearTo,
+      minRating: var_minRating,
+      maxRating: var_maxRating,
+      personIds: var_personIds,
+      watched: var_watched,
+      resolutions: var_resolutions,
+      addedWithinDays: var_addedWithinDays,
+    );
+  }
+
+  @protected
+  SmartQuery sse_decode_smart_query(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_filter = sse_decode_smart_filter(deserializer);
+    var var_sort = sse_decode_smart_sort(deserializer);
+    var var_descending = sse_decode_bool(deserializer);
+    var var_limit = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return SmartQuery(
+      filter: var_filter,
+      sort: var_sort,
+      descending: var_descending,
+      limit: var_limit,
+    );
+  }
+
+  @protected
+  SmartSort sse_decode_smart_sort(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SmartSort.values[inner];
+  }
+
+  @protected
+  SubtitleFile sse_decode_subtitle_file(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_filePath = sse_decode_String(deserializer);
+    var var_format = sse_decode_String(deserializer);
+    var var_language = sse_decode_opt_String(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_isForced = sse_decode_bool(deserializer);
+    var var_isSdh = sse_decode_bool(deserializer);
+    return SubtitleFile(
+      id: var_id,
+      filePath: var_filePath,
+      format: var_format,
+      language: var_language,
+      title: var_title,
+      isForced: var_isForced,
+      isSdh: var_isSdh,
+    );
+  }
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  WatchRecommendation sse_decode_watch_recommendation(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_source = sse_decode_movie_snapshot(deserializer);
+    var var_items = sse_decode_list_movie_snapshot(deserializer);
+    return WatchRecommendation(source: var_source, items: var_items);
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_StreamSink_library_event_Sse(
+    RustStreamSink<LibraryEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_library_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_allowed_job(AllowedJob self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.department, serializer);
+    sse_encode_String(self.job, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_credit_filter(
+    CreditFilter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_credit_filter(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_media_info(
+    MediaInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_media_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_media_source(
+    MediaSource self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_media_source(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_playback_request(
+    PlaybackRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_playback_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_smart_query(
+    SmartQuery self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_smart_query(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_client_capabilities(
+    ClientCapabilities self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.containers, serializer);
+    sse_encode_list_String(self.videoCodecs, serializer);
+    sse_encode_list_String(self.audioCodecs, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.maxWidth, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.maxHeight, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.maxBitrate, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.maxAudioChannels, serializer);
+    sse_encode_bool(self.supportsHdr, serializer);
+  }
+
+  @protected
+  void sse_encode_collection_detail(
+    CollectionDetail self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.overview, serializer);
+    sse_encode_opt_String(self.poster, serializer);
+    sse_encode_opt_String(self.backdrop, serializer);
+    sse_encode_list_movie_snapshot(self.parts, serializer);
+  }
+
+  @protected
+  void sse_encode_collection_part_snapshot(
+    CollectionPartSnapshot self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.tmdbId, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.releaseDate, serializer);
+    sse_encode_opt_String(self.posterPath, serializer);
+  }
+
+  @protected
+  void sse_encode_collection_snapshot(
+    CollectionSnapshot self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.poster, serializer);
+    sse_encode_i_64(self.ownedCount, serializer);
+    sse_encode_i_64(self.partCount, serializer);
+  }
+
+  @protected
+  void sse_encode_credit_filter(CreditFilter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_allowed_job(self.allowedJobs, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.maxCastSize, serializer);
+    sse_encode_bool(self.includeUncredited, serializer);
+  }
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat32(self);
+  }
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
+  }
+
+  @protected
+  void sse_encode_facet_snapshot(FacetSnapshot self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.kind, serializer);
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_i_64(self.mediaCount, serializer);
+  }
+
+  @protected
+  void sse_encode_filmography_entry(
+    FilmographyEntry self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_i_64(self.mediaId, serializer);
+    sse_encode_i_64(self.tmdbId, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.releaseDate, serializer);
+    sse_encode_opt_String(self.poster, serializer);
+    sse_encode_String(self.department, serializer);
+    sse_encode_String(self.character, serializer);
+    sse_encode_String(self.jobName, serializer);
+  }
+
+  @protected
+  void sse_encode_filmography_group(
+    FilmographyGroup self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.department, serializer);
+    sse_encode_list_filmography_entry(self.entries, serializer);
+  }
+
+  @protected
+  void sse_encode_home_row(HomeRow self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.kind, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_list_playback_entry(self.items, serializer);
+  }
+
+  @protected
+  void sse_encode_home_row_config(
+    HomeRowConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.kind, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_u_32(self.itemCount, serializer);
+  }
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
+  }
+
+  @protected
+  void sse_encode_library_event(LibraryEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case LibraryEvent_MediaAdded(mediaId: final mediaId):
+        sse_encode_i_32(0, serializer);
+        sse_encode_i_64(mediaId, serializer);
+      case LibraryEvent_MediaUpdated(mediaId: final mediaId):
+        sse_encode_i_32(1, serializer);
+        sse_encode_i_64(mediaId, serializer);
+      case LibraryEvent_MediaRemoved(mediaId: final mediaId):
+        sse_encode_i_32(2, serializer);
+        sse_encode_i_64(mediaId, serializer);
+      case LibraryEvent_WatchStateChanged(
+        profileId: final profileId,
+        mediaId: final mediaId,
+      ):
+        sse_encode_i_32(3, serializer);
+        sse_encode_i_64(profileId, serializer);
+        sse_encode_i_64(mediaId, serializer);
+      case LibraryEvent_ScanStarted():
+        sse_encode_i_32(4, serializer);
+      case LibraryEvent_ScanFinished(report: final report):
+        sse_encode_i_32(5, serializer);
+        sse_encode_scan_report(report, serializer);
+      case LibraryEvent_EventsMissed(count: final count):
+        sse_encode_i_32(6, serializer);
+        sse_encode_u_64(count, serializer);
+      case LibraryEvent_KeepAlive():
+        sse_encode_i_32(7, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_allowed_job(
+    List<AllowedJob> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_allowed_job(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_collection_part_snapshot(
+    List<CollectionPartSnapshot> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_collection_part_snapshot(item, serializer);
+    }
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  void sse_encode_list_collection_snapshot(
+    List<CollectionSnapshot> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_collection_snapshot(item, serializer);
+    }
   }
 
   @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  void sse_encode_list_facet_snapshot(
+    List<FacetSnapshot> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_facet_snapshot(item, serializer);
+    }
   }
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
+  void sse_encode_list_filmography_entry(
+    List<FilmographyEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_filmography_entry(item, serializer);
+    }
   }
 
   @protected
-  List<MovieSnapshot> dco_decode_list_movie_snapshot(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_movie_snapshot).toList();
+  void sse_encode_list_filmography_group(
+    List<FilmographyGroup> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_filmography_group(item, serializer);
+    }
   }
 
   @protected
-  List<PersonSnapshot> dco_decode_list_person_snapshot(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_person_snapshot).toList();
+  void sse_encode_list_home_row(List<HomeRow> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_home_row(item, serializer);
+    }
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_list_home_row_config(
+    List<HomeRowConfig> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_home_row_config(item, serializer);
+    }
   }
 
   @protected
-  MediaData dco_decode_media_data(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 10)
-      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
-    return MediaData(
-      id: dco_decode_i_64(arr[0]),
-      filePath: dco_decode_String(arr[1]),
-      fileOptionalInfo: dco_decode_String(arr[2]),
-      originalTitle: dco_decode_String(arr[3]),
-      title: dco_decode_String(arr[4]),
-      rating: dco_decode_f_32(arr[5]),
-      releaseDate: dco_decode_String(arr[6]),
-      summary: dco_decode_String(arr[7]),
-      poster: dco_decode_String(arr[8]),
-      backdrop: dco_decode_String(arr[9]),
-    );
+  void sse_encode_list_media_chapter(
+    List<MediaChapter> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_media_chapter(item, serializer);
+    }
   }
 
   @protected
-  MovieSnapshot dco_decode_movie_snapshot(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return MovieSnapshot(
-      id: dco_decode_i_64(arr[0]),
-      filePath: dco_decode_String(arr[1]),
-      title: dco_decode_String(arr[2]),
-      rating: dco_decode_f_32(arr[3]),
-      releaseDate: dco_decode_String(arr[4]),
-      poster: dco_decode_String(arr[5]),
-    );
+  void sse_encode_list_media_list(
+    List<MediaList> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_media_list(item, serializer);
+    }
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void sse_encode_list_media_stream(
+    List<MediaStream> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_media_stream(item, serializer);
+    }
   }
 
   @protected
-  PersonData dco_decode_person_data(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return PersonData(
-      tmdbId: dco_decode_i_64(arr[0]),
-      name: dco_decode_String(arr[1]),
-      summary: dco_decode_String(arr[2]),
-      picturePath: dco_decode_opt_String(arr[3]),
-    );
+  void sse_encode_list_movie_snapshot(
+    List<MovieSnapshot> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_movie_snapshot(item, serializer);
+    }
   }
 
   @protected
-  PersonSnapshot dco_decode_person_snapshot(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return PersonSnapshot(
-      tmdbId: dco_decode_i_64(arr[0]),
-      name: dco_decode_String(arr[1]),
-      character: dco_decode_String(arr[2]),
-      jobName: dco_decode_String(arr[3]),
-      picturePath: dco_decode_String(arr[4]),
-    );
+  void sse_encode_list_paired_device(
+    List<PairedDevice> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_paired_device(item, serializer);
+    }
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_list_person_snapshot(
+    List<PersonSnapshot> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_person_snapshot(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_playback_entry(
+    List<PlaybackEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_playback_entry(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prim_i_64_strict(
+    Int64List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putInt64List(self);
+  }
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
+  }
+
+  @protected
+  void sse_encode_list_profile(List<Profile> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_profile(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_search_result(
+    List<SearchResult> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_search_result(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_smart_collection(
+    List<SmartCollection> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_smart_collection(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_subtitle_file(
+    List<SubtitleFile> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_subtitle_file(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_watch_recommendation(
+    List<WatchRecommendation> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_watch_recommendation(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_media_chapter(MediaChapter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_f_64(self.start, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.end, serializer);
+  }
+
+  @protected
+  void sse_encode_media_data(MediaData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.id, serializer);
+    sse_encode_String(self.filePath, serializer);
+    sse_encode_String(self.fileOptionalInfo, serializer);
+    sse_encode_String(self.originalTitle, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_f_32(self.rating, serializer);
+    sse_encode_String(self.releaseDate, serializer);
+    sse_encode_String(self.summary, serializer);
+    sse_encode_String(self.poster, serializer);
+    sse_encode_String(self.backdrop, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.runtime, serializer);
+    sse_encode_String(self.tagline, serializer);
+    sse_encode_String(self.status, serializer);
+    sse_encode_i_64(self.budget, serializer);
+    sse_encode_i_64(self.revenue, serializer);
+    sse_encode_opt_String(self.imdbId, serializer);
+    sse_encode_String(self.certification, serializer);
+    sse_encode_opt_box_autoadd_media_info(self.mediaInfo, serializer);
+  }
+
+  @protected
+  void sse_encode_media_info(MediaInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.container, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.duration, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.width, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.height, serializer);
+    sse_encode_opt_String(self.videoCodec, serializer);
+    sse_encode_opt_String(self.hdrFormat, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.bitrate, serializer);
+    sse_encode_list_media_stream(self.streams, serializer);
+    sse_encode_list_media_chapter(self.chapters, serializer);
+  }
+
+  @protected
+  void sse_encode_media_list(MediaList self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.kind, serializer);
+    sse_encode_u_32(self.itemCount, serializer);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_media_source(MediaSource self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case MediaSource_Smb(
+        path: final path,
+        username: final username,
+        password: final password,
+      ):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(path, serializer);
+        sse_encode_String(username, serializer);
+        sse_encode_String(password, serializer);
+      case MediaSource_Local(root: final root):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(root, serializer);
+    }
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_media_stream(MediaStream self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_i_32(self.index, serializer);
+    sse_encode_String(self.kind, serializer);
+    sse_encode_String(self.codec, serializer);
+    sse_encode_opt_String(self.language, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.channels, serializer);
+    sse_encode_bool(self.isDefault, serializer);
+    sse_encode_bool(self.isForced, serializer);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_movie_snapshot(MovieSnapshot self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_i_64(self.id, serializer);
+    sse_encode_String(self.filePath, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_f_32(self.rating, serializer);
+    sse_encode_String(self.releaseDate, serializer);
+    sse_encode_String(self.poster, serializer);
+    sse_encode_bool(self.watched, serializer);
   }
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
   }
 
   @protected
-  List<MovieSnapshot> sse_decode_list_movie_snapshot(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <MovieSnapshot>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_movie_snapshot(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_32(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<PersonSnapshot> sse_decode_list_person_snapshot(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <PersonSnapshot>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_person_snapshot(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_32(self, serializer);
+    }
   }
 
   @protected
-  MediaData sse_decode_media_data(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_64(deserializer);
-    var var_filePath = sse_decode_String(deserializer);
-    var var_fileOptionalInfo = sse_decode_String(deserializer);
-    var var_originalTitle = sse_decode_String(deserializer);
-    var var_title = sse_decode_String(deserializer);
-    var var_rating = sse_decode_f_32(deserializer);
-    var var_releaseDate = sse_decode_String(deserializer);
-    var var_summary = sse_decode_String(deserializer);
-    var var_poster = sse_decode_String(deserializer);
-    var var_backdrop = sse_decode_String(deserializer);
-    return MediaData(
-      id: var_id,
-      filePath: var_filePath,
-      fileOptionalInfo: var_fileOptionalInfo,
-      originalTitle: var_originalTitle,
-      title: var_title,
-      rating: var_rating,
-      releaseDate: var_releaseDate,
-      summary: var_summary,
-      poster: var_poster,
-      backdrop: var_backdrop,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
   }
 
   @protected
-  MovieSnapshot sse_decode_movie_snapshot(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_media_info(
+    MediaInfo? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_64(deserializer);
-    var var_filePath = sse_decode_String(deserializer);
-    var var_title = sse_decode_String(deserializer);
-    var var_rating = sse_decode_f_32(deserializer);
-    var var_releaseDate = sse_decode_String(deserializer);
-    var var_poster = sse_decode_String(deserializer);
-    return MovieSnapshot(
-      id: var_id,
-      filePath: var_filePath,
-      title: var_title,
-      rating: var_rating,
-      releaseDate: var_releaseDate,
-      poster: var_poster,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_media_info(self, serializer);
+    }
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
     }
   }
 
   @protected
-  PersonData sse_decode_person_data(SseDeserializer deserializer) {
+  void sse_encode_paired_device(PairedDevice self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_tmdbId = sse_decode_i_64(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_summary = sse_decode_String(deserializer);
-    var var_picturePath = sse_decode_opt_String(deserializer);
-    return PersonData(
-      tmdbId: var_tmdbId,
-      name: var_name,
-      summary: var_summary,
-      picturePath: var_picturePath,
-    );
+    sse_encode_i_64(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_i_64(self.profileId, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.lastSeen, serializer);
   }
 
   @protected
-  PersonSnapshot sse_decode_person_snapshot(SseDeserializer deserializer) {
+  void sse_encode_person_data(PersonData self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_tmdbId = sse_decode_i_64(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_character = sse_decode_String(deserializer);
-    var var_jobName = sse_decode_String(deserializer);
-    var var_picturePath = sse_decode_String(deserializer);
-    return PersonSnapshot(
-      tmdbId: var_tmdbId,
-      name: var_name,
-      character: var_character,
-      jobName: var_jobName,
-      picturePath: var_picturePath,
-    );
+    sse_encode_i_64(self.tmdbId, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.summary, serializer);
+    sse_encode_opt_String(self.picturePath, serializer);
+    sse_encode_opt_String(self.birthday, serializer);
+    sse_encode_opt_String(self.deathday, serializer);
+    sse_encode_opt_String(self.placeOfBirth, serializer);
+    sse_encode_opt_String(self.knownForDepartment, serializer);
+    sse_encode_i_32(self.gender, serializer);
+    sse_encode_list_String(self.alsoKnownAs, serializer);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_person_filmography(
+    PersonFilmography self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_person_data(self.person, serializer);
+    sse_encode_list_filmography_group(self.groups, serializer);
+    sse_encode_list_filmography_entry(self.missing, serializer);
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_person_snapshot(
+    PersonSnapshot self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.tmdbId, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.character, serializer);
+    sse_encode_String(self.jobName, serializer);
+    sse_encode_String(self.picturePath, serializer);
+    sse_encode_String(self.department, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.creditOrder, serializer);
+    sse_encode_String(self.creditId, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_playback_decision(
+    PlaybackDecision self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_String(self.method, serializer);
+    sse_encode_list_String(self.reasons, serializer);
+    sse_encode_String(self.url, serializer);
+    sse_encode_opt_String(self.sessionId, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_playback_entry(PlaybackEntry self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_movie_snapshot(self.media, serializer);
+    sse_encode_playback_state(self.state, serializer);
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_playback_request(
+    PlaybackRequest self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    sse_encode_client_capabilities(self.capabilities, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.maxHeight, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.maxBitrate, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.audioStream, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.subtitleStream, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.subtitleFile, serializer);
+    sse_encode_f_64(self.startPosition, serializer);
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_playback_state(PlaybackState self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_i_64(self.mediaId, serializer);
+    sse_encode_bool(self.watched, serializer);
+    sse_encode_u_32(self.playCount, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.lastPlayed, serializer);
+    sse_encode_f_64(self.resumePosition, serializer);
+    sse_encode_f_64(self.duration, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.userRating, serializer);
   }
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
+  void sse_encode_profile(Profile self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
+    sse_encode_i_64(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.avatar, serializer);
+    sse_encode_bool(self.hasPin, serializer);
+    sse_encode_opt_String(self.maxCertification, serializer);
+    sse_encode_bool(self.hideUnrated, serializer);
+    sse_encode_opt_String(self.preferredLanguage, serializer);
   }
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+  void sse_encode_scan_report(ScanReport self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
+    sse_encode_u_32(self.moviesFound, serializer);
+    sse_encode_u_32(self.moviesMatched, serializer);
+    sse_encode_u_32(self.moviesSaved, serializer);
+    sse_encode_u_32(self.moviesFailed, serializer);
+    sse_encode_u_32(self.personsFetched, serializer);
+    sse_encode_u_32(self.personsSkipped, serializer);
+    sse_encode_u_64(self.tmdbRequests, serializer);
+    sse_encode_u_64(self.tmdbRequestsSaved, serializer);
   }
 
   @protected
-  void sse_encode_list_movie_snapshot(
-    List<MovieSnapshot> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_search_result(SearchResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_movie_snapshot(item, serializer);
-    }
+    sse_encode_String(self.kind, serializer);
+    sse_encode_i_64(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.subtitle, serializer);
+    sse_encode_opt_String(self.picturePath, serializer);
+    sse_encode_f_32(self.score, serializer);
   }
 
   @protected
-  void sse_encode_list_person_snapshot(
-    List<PersonSnapshot> self,
+  void sse_encode_smart_collection(
+    SmartCollection self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_person_snapshot(item, serializer);
-    }
+    sse_encode_i_64(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_smart_query(self.query, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_smart_filter(SmartFilter self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_list_String(self.genres, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.yearFrom, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.yearTo, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.minRating, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.maxRating, serializer);
+    sse_encode_list_prim_i_64_strict(self.personIds, serializer);
+    sse_encode_opt_box_autoadd_bool(self.watched, serializer);
+    sse_encode_list_String(self.resolutions, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.addedWithinDays, serializer);
   }
 
   @protected
-  void sse_encode_media_data(MediaData self, SseSerializer serializer) {
+  void sse_encode_smart_query(SmartQuery self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.id, serializer);
-    sse_encode_String(self.filePath, serializer);
-    sse_encode_String(self.fileOptionalInfo, serializer);
-    sse_encode_String(self.originalTitle, serializer);
-    sse_encode_String(self.title, serializer);
-    sse_encode_f_32(self.rating, serializer);
-    sse_encode_String(self.releaseDate, serializer);
-    sse_encode_String(self.summary, serializer);
-    sse_encode_String(self.poster, serializer);
-    sse_encode_String(self.backdrop, serializer);
+    sse_encode_smart_filter(self.filter, serializer);
+    sse_encode_smart_sort(self.sort, serializer);
+    sse_encode_bool(self.descending, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.limit, serializer);
   }
 
   @protected
-  void sse_encode_movie_snapshot(MovieSnapshot self, SseSerializer serializer) {
+  void sse_encode_smart_sort(SmartSort self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_subtitle_file(SubtitleFile self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_64(self.id, serializer);
     sse_encode_String(self.filePath, serializer);
-    sse_encode_String(self.title, serializer);
-    sse_encode_f_32(self.rating, serializer);
-    sse_encode_String(self.releaseDate, serializer);
-    sse_encode_String(self.poster, serializer);
+    sse_encode_String(self.format, serializer);
+    sse_encode_opt_String(self.language, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_bool(self.isForced, serializer);
+    sse_encode_bool(self.isSdh, serializer);
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
+    serializer.buffer.putUint16(self);
   }
 
   @protected
-  void sse_encode_person_data(PersonData self, SseSerializer serializer) {
+  void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.tmdbId, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_String(self.summary, serializer);
-    sse_encode_opt_String(self.picturePath, serializer);
+    serializer.buffer.putUint32(self);
   }
 
   @protected
-  void sse_encode_person_snapshot(
-    PersonSnapshot self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(self.tmdbId, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_String(self.character, serializer);
-    sse_encode_String(self.jobName, serializer);
-    sse_encode_String(self.picturePath, serializer);
+    serializer.buffer.putBigUint64(self);
   }
 
   @protected
@@ -847,14 +6129,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_watch_recommendation(
+    WatchRecommendation self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_movie_snapshot(self.source, serializer);
+    sse_encode_list_movie_snapshot(self.items, serializer);
   }
 }
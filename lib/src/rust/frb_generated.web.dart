@@ -9,6 +9,7 @@
 import 'api/media.dart';
 import 'dart:async';
 import 'dart:convert';
+import 'directory_explorer/media_file.dart';
 import 'frb_generated.dart';
 import 'movie_data/movie_data.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
@@ -24,142 +25,926 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
+  @protected
+  RustStreamSink<LibraryEvent> dco_decode_StreamSink_library_event_Sse(
+    dynamic raw,
+  );
+
   @protected
   String dco_decode_String(dynamic raw);
 
   @protected
-  double dco_decode_f_32(dynamic raw);
+  AllowedJob dco_decode_allowed_job(dynamic raw);
+
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw);
+
+  @protected
+  CreditFilter dco_decode_box_autoadd_credit_filter(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_32(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  MediaInfo dco_decode_box_autoadd_media_info(dynamic raw);
+
+  @protected
+  MediaSource dco_decode_box_autoadd_media_source(dynamic raw);
+
+  @protected
+  PlaybackRequest dco_decode_box_autoadd_playback_request(dynamic raw);
+
+  @protected
+  SmartQuery dco_decode_box_autoadd_smart_query(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  ClientCapabilities dco_decode_client_capabilities(dynamic raw);
+
+  @protected
+  CollectionDetail dco_decode_collection_detail(dynamic raw);
+
+  @protected
+  CollectionPartSnapshot dco_decode_collection_part_snapshot(dynamic raw);
+
+  @protected
+  CollectionSnapshot dco_decode_collection_snapshot(dynamic raw);
+
+  @protected
+  CreditFilter dco_decode_credit_filter(dynamic raw);
+
+  @protected
+  double dco_decode_f_32(dynamic raw);
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
+  @protected
+  FacetSnapshot dco_decode_facet_snapshot(dynamic raw);
+
+  @protected
+  FilmographyEntry dco_decode_filmography_entry(dynamic raw);
+
+  @protected
+  FilmographyGroup dco_decode_filmography_group(dynamic raw);
+
+  @protected
+  HomeRow dco_decode_home_row(dynamic raw);
+
+  @protected
+  HomeRowConfig dco_decode_home_row_config(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw);
+
+  @protected
+  LibraryEvent dco_decode_library_event(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<AllowedJob> dco_decode_list_allowed_job(dynamic raw);
+
+  @protected
+  List<CollectionPartSnapshot> dco_decode_list_collection_part_snapshot(
+    dynamic raw,
+  );
+
+  @protected
+  List<CollectionSnapshot> dco_decode_list_collection_snapshot(dynamic raw);
+
+  @protected
+  List<FacetSnapshot> dco_decode_list_facet_snapshot(dynamic raw);
+
+  @protected
+  List<FilmographyEntry> dco_decode_list_filmography_entry(dynamic raw);
+
+  @protected
+  List<FilmographyGroup> dco_decode_list_filmography_group(dynamic raw);
+
+  @protected
+  List<HomeRow> dco_decode_list_home_row(dynamic raw);
+
+  @protected
+  List<HomeRowConfig> dco_decode_list_home_row_config(dynamic raw);
+
+  @protected
+  List<MediaChapter> dco_decode_list_media_chapter(dynamic raw);
+
+  @protected
+  List<MediaList> dco_decode_list_media_list(dynamic raw);
+
+  @protected
+  List<MediaStream> dco_decode_list_media_stream(dynamic raw);
+
+  @protected
+  List<MovieSnapshot> dco_decode_list_movie_snapshot(dynamic raw);
+
+  @protected
+  List<PairedDevice> dco_decode_list_paired_device(dynamic raw);
+
+  @protected
+  List<PersonSnapshot> dco_decode_list_person_snapshot(dynamic raw);
+
+  @protected
+  List<PlaybackEntry> dco_decode_list_playback_entry(dynamic raw);
+
+  @protected
+  Int64List dco_decode_list_prim_i_64_strict(dynamic raw);
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<Profile> dco_decode_list_profile(dynamic raw);
+
+  @protected
+  List<SearchResult> dco_decode_list_search_result(dynamic raw);
+
+  @protected
+  List<SmartCollection> dco_decode_list_smart_collection(dynamic raw);
+
+  @protected
+  List<SubtitleFile> dco_decode_list_subtitle_file(dynamic raw);
+
+  @protected
+  List<WatchRecommendation> dco_decode_list_watch_recommendation(dynamic raw);
+
+  @protected
+  MediaChapter dco_decode_media_chapter(dynamic raw);
+
+  @protected
+  MediaData dco_decode_media_data(dynamic raw);
+
+  @protected
+  MediaInfo dco_decode_media_info(dynamic raw);
+
+  @protected
+  MediaList dco_decode_media_list(dynamic raw);
+
+  @protected
+  MediaSource dco_decode_media_source(dynamic raw);
+
+  @protected
+  MediaStream dco_decode_media_stream(dynamic raw);
+
+  @protected
+  MovieSnapshot dco_decode_movie_snapshot(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw);
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  MediaInfo? dco_decode_opt_box_autoadd_media_info(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  PairedDevice dco_decode_paired_device(dynamic raw);
+
+  @protected
+  PersonData dco_decode_person_data(dynamic raw);
+
+  @protected
+  PersonFilmography dco_decode_person_filmography(dynamic raw);
+
+  @protected
+  PersonSnapshot dco_decode_person_snapshot(dynamic raw);
+
+  @protected
+  PlaybackDecision dco_decode_playback_decision(dynamic raw);
+
+  @protected
+  PlaybackEntry dco_decode_playback_entry(dynamic raw);
+
+  @protected
+  PlaybackRequest dco_decode_playback_request(dynamic raw);
+
+  @protected
+  PlaybackState dco_decode_playback_state(dynamic raw);
+
+  @protected
+  Profile dco_decode_profile(dynamic raw);
+
+  @protected
+  ScanReport dco_decode_scan_report(dynamic raw);
+
+  @protected
+  SearchResult dco_decode_search_result(dynamic raw);
+
+  @protected
+  SmartCollection dco_decode_smart_collection(dynamic raw);
+
+  @protected
+  SmartFilter dco_decode_smart_filter(dynamic raw);
+
+  @protected
+  SmartQuery dco_decode_smart_query(dynamic raw);
+
+  @protected
+  SmartSort dco_decode_smart_sort(dynamic raw);
+
+  @protected
+  SubtitleFile dco_decode_subtitle_file(dynamic raw);
+
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  WatchRecommendation dco_decode_watch_recommendation(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<LibraryEvent> sse_decode_StreamSink_library_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  AllowedJob sse_decode_allowed_job(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  CreditFilter sse_decode_box_autoadd_credit_filter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  MediaInfo sse_decode_box_autoadd_media_info(SseDeserializer deserializer);
+
+  @protected
+  MediaSource sse_decode_box_autoadd_media_source(SseDeserializer deserializer);
+
+  @protected
+  PlaybackRequest sse_decode_box_autoadd_playback_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SmartQuery sse_decode_box_autoadd_smart_query(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  ClientCapabilities sse_decode_client_capabilities(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CollectionDetail sse_decode_collection_detail(SseDeserializer deserializer);
+
+  @protected
+  CollectionPartSnapshot sse_decode_collection_part_snapshot(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CollectionSnapshot sse_decode_collection_snapshot(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CreditFilter sse_decode_credit_filter(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  FacetSnapshot sse_decode_facet_snapshot(SseDeserializer deserializer);
+
+  @protected
+  FilmographyEntry sse_decode_filmography_entry(SseDeserializer deserializer);
+
+  @protected
+  FilmographyGroup sse_decode_filmography_group(SseDeserializer deserializer);
+
+  @protected
+  HomeRow sse_decode_home_row(SseDeserializer deserializer);
+
+  @protected
+  HomeRowConfig sse_decode_home_row_config(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  LibraryEvent sse_decode_library_event(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<AllowedJob> sse_decode_list_allowed_job(SseDeserializer deserializer);
+
+  @protected
+  List<CollectionPartSnapshot> sse_decode_list_collection_part_snapshot(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CollectionSnapshot> sse_decode_list_collection_snapshot(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FacetSnapshot> sse_decode_list_facet_snapshot(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FilmographyEntry> sse_decode_list_filmography_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FilmographyGroup> sse_decode_list_filmography_group(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<HomeRow> sse_decode_list_home_row(SseDeserializer deserializer);
+
+  @protected
+  List<HomeRowConfig> sse_decode_list_home_row_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MediaChapter> sse_decode_list_media_chapter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MediaList> sse_decode_list_media_list(SseDeserializer deserializer);
+
+  @protected
+  List<MediaStream> sse_decode_list_media_stream(SseDeserializer deserializer);
+
+  @protected
+  List<MovieSnapshot> sse_decode_list_movie_snapshot(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PairedDevice> sse_decode_list_paired_device(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PersonSnapshot> sse_decode_list_person_snapshot(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PlaybackEntry> sse_decode_list_playback_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Int64List sse_decode_list_prim_i_64_strict(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<Profile> sse_decode_list_profile(SseDeserializer deserializer);
+
+  @protected
+  List<SearchResult> sse_decode_list_search_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SmartCollection> sse_decode_list_smart_collection(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SubtitleFile> sse_decode_list_subtitle_file(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<WatchRecommendation> sse_decode_list_watch_recommendation(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MediaChapter sse_decode_media_chapter(SseDeserializer deserializer);
+
+  @protected
+  MediaData sse_decode_media_data(SseDeserializer deserializer);
+
+  @protected
+  MediaInfo sse_decode_media_info(SseDeserializer deserializer);
+
+  @protected
+  MediaList sse_decode_media_list(SseDeserializer deserializer);
+
+  @protected
+  MediaSource sse_decode_media_source(SseDeserializer deserializer);
+
+  @protected
+  MediaStream sse_decode_media_stream(SseDeserializer deserializer);
+
+  @protected
+  MovieSnapshot sse_decode_movie_snapshot(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer);
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  MediaInfo? sse_decode_opt_box_autoadd_media_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  PairedDevice sse_decode_paired_device(SseDeserializer deserializer);
+
+  @protected
+  PersonData sse_decode_person_data(SseDeserializer deserializer);
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw);
+  PersonFilmography sse_decode_person_filmography(SseDeserializer deserializer);
 
   @protected
-  List<MovieSnapshot> dco_decode_list_movie_snapshot(dynamic raw);
+  PersonSnapshot sse_decode_person_snapshot(SseDeserializer deserializer);
 
   @protected
-  List<PersonSnapshot> dco_decode_list_person_snapshot(dynamic raw);
+  PlaybackDecision sse_decode_playback_decision(SseDeserializer deserializer);
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  PlaybackEntry sse_decode_playback_entry(SseDeserializer deserializer);
 
   @protected
-  MediaData dco_decode_media_data(dynamic raw);
+  PlaybackRequest sse_decode_playback_request(SseDeserializer deserializer);
 
   @protected
-  MovieSnapshot dco_decode_movie_snapshot(dynamic raw);
+  PlaybackState sse_decode_playback_state(SseDeserializer deserializer);
 
   @protected
-  String? dco_decode_opt_String(dynamic raw);
+  Profile sse_decode_profile(SseDeserializer deserializer);
 
   @protected
-  PersonData dco_decode_person_data(dynamic raw);
+  ScanReport sse_decode_scan_report(SseDeserializer deserializer);
 
   @protected
-  PersonSnapshot dco_decode_person_snapshot(dynamic raw);
+  SearchResult sse_decode_search_result(SseDeserializer deserializer);
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  SmartCollection sse_decode_smart_collection(SseDeserializer deserializer);
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  SmartFilter sse_decode_smart_filter(SseDeserializer deserializer);
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+  SmartQuery sse_decode_smart_query(SseDeserializer deserializer);
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  SmartSort sse_decode_smart_sort(SseDeserializer deserializer);
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
+  SubtitleFile sse_decode_subtitle_file(SseDeserializer deserializer);
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+  int sse_decode_u_16(SseDeserializer deserializer);
 
   @protected
-  List<MovieSnapshot> sse_decode_list_movie_snapshot(
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  WatchRecommendation sse_decode_watch_recommendation(
     SseDeserializer deserializer,
   );
 
   @protected
-  List<PersonSnapshot> sse_decode_list_person_snapshot(
-    SseDeserializer deserializer,
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
   );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  void sse_encode_StreamSink_library_event_Sse(
+    RustStreamSink<LibraryEvent> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  MediaData sse_decode_media_data(SseDeserializer deserializer);
+  void sse_encode_String(String self, SseSerializer serializer);
 
   @protected
-  MovieSnapshot sse_decode_movie_snapshot(SseDeserializer deserializer);
+  void sse_encode_allowed_job(AllowedJob self, SseSerializer serializer);
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  void sse_encode_bool(bool self, SseSerializer serializer);
 
   @protected
-  PersonData sse_decode_person_data(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
 
   @protected
-  PersonSnapshot sse_decode_person_snapshot(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_credit_filter(
+    CreditFilter self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_box_autoadd_media_info(
+    MediaInfo self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_box_autoadd_media_source(
+    MediaSource self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_playback_request(
+    PlaybackRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_smart_query(
+    SmartQuery self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_client_capabilities(
+    ClientCapabilities self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_collection_detail(
+    CollectionDetail self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_collection_part_snapshot(
+    CollectionPartSnapshot self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_collection_snapshot(
+    CollectionSnapshot self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_credit_filter(CreditFilter self, SseSerializer serializer);
 
   @protected
   void sse_encode_f_32(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_facet_snapshot(FacetSnapshot self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_filmography_entry(
+    FilmographyEntry self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_filmography_group(
+    FilmographyGroup self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_home_row(HomeRow self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_home_row_config(HomeRowConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_library_event(LibraryEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_allowed_job(
+    List<AllowedJob> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_collection_part_snapshot(
+    List<CollectionPartSnapshot> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_collection_snapshot(
+    List<CollectionSnapshot> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_facet_snapshot(
+    List<FacetSnapshot> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_filmography_entry(
+    List<FilmographyEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_filmography_group(
+    List<FilmographyGroup> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_home_row(List<HomeRow> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_home_row_config(
+    List<HomeRowConfig> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_media_chapter(
+    List<MediaChapter> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_media_list(
+    List<MediaList> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_media_stream(
+    List<MediaStream> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_movie_snapshot(
     List<MovieSnapshot> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_paired_device(
+    List<PairedDevice> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_person_snapshot(
     List<PersonSnapshot> self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_playback_entry(
+    List<PlaybackEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_i_64_strict(
+    Int64List self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_prim_u_8_strict(
     Uint8List self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_list_profile(List<Profile> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_search_result(
+    List<SearchResult> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_smart_collection(
+    List<SmartCollection> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_subtitle_file(
+    List<SubtitleFile> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_watch_recommendation(
+    List<WatchRecommendation> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_media_chapter(MediaChapter self, SseSerializer serializer);
+
   @protected
   void sse_encode_media_data(MediaData self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_media_info(MediaInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_media_list(MediaList self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_media_source(MediaSource self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_media_stream(MediaStream self, SseSerializer serializer);
+
   @protected
   void sse_encode_movie_snapshot(MovieSnapshot self, SseSerializer serializer);
 
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_media_info(
+    MediaInfo? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_paired_device(PairedDevice self, SseSerializer serializer);
+
   @protected
   void sse_encode_person_data(PersonData self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_person_filmography(
+    PersonFilmography self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_person_snapshot(
     PersonSnapshot self,
@@ -167,16 +952,70 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   );
 
   @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+  void sse_encode_playback_decision(
+    PlaybackDecision self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+  void sse_encode_playback_entry(PlaybackEntry self, SseSerializer serializer);
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_playback_request(
+    PlaybackRequest self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_playback_state(PlaybackState self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_profile(Profile self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scan_report(ScanReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_search_result(SearchResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_smart_collection(
+    SmartCollection self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_smart_filter(SmartFilter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_smart_query(SmartQuery self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_smart_sort(SmartSort self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_subtitle_file(SubtitleFile self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_8(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_unit(void self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_watch_recommendation(
+    WatchRecommendation self,
+    SseSerializer serializer,
+  );
 }
 
 // Section: wire_class
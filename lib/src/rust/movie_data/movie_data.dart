@@ -5,6 +5,414 @@
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+
+part 'movie_data.freezed.dart';
+
+class AllowedJob {
+  final String department;
+  final String job;
+
+  const AllowedJob({required this.department, required this.job});
+
+  @override
+  int get hashCode => department.hashCode ^ job.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AllowedJob &&
+          runtimeType == other.runtimeType &&
+          department == other.department &&
+          job == other.job;
+}
+
+/// What a player declares it can play as is, codecs being named as in `MediaStream`.
+class ClientCapabilities {
+  /// "mkv", "webm", "mp4" or "mov".
+  final List<String> containers;
+  final List<String> videoCodecs;
+  final List<String> audioCodecs;
+  final int? maxWidth;
+  final int? maxHeight;
+  /// In bits per second.
+  final PlatformInt64? maxBitrate;
+  final int? maxAudioChannels;
+  final bool supportsHdr;
+
+  const ClientCapabilities({
+    required this.containers,
+    required this.videoCodecs,
+    required this.audioCodecs,
+    this.maxWidth,
+    this.maxHeight,
+    this.maxBitrate,
+    this.maxAudioChannels,
+    required this.supportsHdr,
+  });
+
+  @override
+  int get hashCode =>
+      containers.hashCode ^
+      videoCodecs.hashCode ^
+      audioCodecs.hashCode ^
+      maxWidth.hashCode ^
+      maxHeight.hashCode ^
+      maxBitrate.hashCode ^
+      maxAudioChannels.hashCode ^
+      supportsHdr.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ClientCapabilities &&
+          runtimeType == other.runtimeType &&
+          containers == other.containers &&
+          videoCodecs == other.videoCodecs &&
+          audioCodecs == other.audioCodecs &&
+          maxWidth == other.maxWidth &&
+          maxHeight == other.maxHeight &&
+          maxBitrate == other.maxBitrate &&
+          maxAudioChannels == other.maxAudioChannels &&
+          supportsHdr == other.supportsHdr;
+}
+
+/// A collection with the movies of the library belonging to it, in release order.
+class CollectionDetail {
+  final PlatformInt64 id;
+  final String name;
+  final String overview;
+  final String? poster;
+  final String? backdrop;
+  final List<MovieSnapshot> parts;
+
+  const CollectionDetail({
+    required this.id,
+    required this.name,
+    required this.overview,
+    this.poster,
+    this.backdrop,
+    required this.parts,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      name.hashCode ^
+      overview.hashCode ^
+      poster.hashCode ^
+      backdrop.hashCode ^
+      parts.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CollectionDetail &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          overview == other.overview &&
+          poster == other.poster &&
+          backdrop == other.backdrop &&
+          parts == other.parts;
+}
+
+class CollectionPartSnapshot {
+  final PlatformInt64 tmdbId;
+  final String title;
+  final String releaseDate;
+  final String? posterPath;
+
+  const CollectionPartSnapshot({
+    required this.tmdbId,
+    required this.title,
+    required this.releaseDate,
+    this.posterPath,
+  });
+
+  @override
+  int get hashCode =>
+      tmdbId.hashCode ^
+      title.hashCode ^
+      releaseDate.hashCode ^
+      posterPath.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CollectionPartSnapshot &&
+          runtimeType == other.runtimeType &&
+          tmdbId == other.tmdbId &&
+          title == other.title &&
+          releaseDate == other.releaseDate &&
+          posterPath == other.posterPath;
+}
+
+class CollectionSnapshot {
+  final PlatformInt64 id;
+  final String name;
+  final String? poster;
+  final PlatformInt64 ownedCount;
+  final PlatformInt64 partCount;
+
+  const CollectionSnapshot({
+    required this.id,
+    required this.name,
+    this.poster,
+    required this.ownedCount,
+    required this.partCount,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      name.hashCode ^
+      poster.hashCode ^
+      ownedCount.hashCode ^
+      partCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CollectionSnapshot &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          poster == other.poster &&
+          ownedCount == other.ownedCount &&
+          partCount == other.partCount;
+}
+
+/// Rules deciding which credits are kept when a movie is scanned.
+class CreditFilter {
+  final List<AllowedJob> allowedJobs;
+  final int? maxCastSize;
+  final bool includeUncredited;
+
+  const CreditFilter({
+    required this.allowedJobs,
+    this.maxCastSize,
+    required this.includeUncredited,
+  });
+
+  @override
+  int get hashCode =>
+      allowedJobs.hashCode ^ maxCastSize.hashCode ^ includeUncredited.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CreditFilter &&
+          runtimeType == other.runtimeType &&
+          allowedJobs == other.allowedJobs &&
+          maxCastSize == other.maxCastSize &&
+          includeUncredited == other.includeUncredited;
+}
+
+/// A browsable value shared by several movies, such as a studio, a country or a keyword.
+class FacetSnapshot {
+  final String kind;
+  final String key;
+  final String name;
+  final PlatformInt64 mediaCount;
+
+  const FacetSnapshot({
+    required this.kind,
+    required this.key,
+    required this.name,
+    required this.mediaCount,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^ key.hashCode ^ name.hashCode ^ mediaCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FacetSnapshot &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          key == other.key &&
+          name == other.name &&
+          mediaCount == other.mediaCount;
+}
+
+class FilmographyEntry {
+  final PlatformInt64? mediaId;
+  final PlatformInt64 tmdbId;
+  final String title;
+  final String releaseDate;
+  final String? poster;
+  final String department;
+  final String character;
+  final String jobName;
+
+  const FilmographyEntry({
+    this.mediaId,
+    required this.tmdbId,
+    required this.title,
+    required this.releaseDate,
+    this.poster,
+    required this.department,
+    required this.character,
+    required this.jobName,
+  });
+
+  @override
+  int get hashCode =>
+      mediaId.hashCode ^
+      tmdbId.hashCode ^
+      title.hashCode ^
+      releaseDate.hashCode ^
+      poster.hashCode ^
+      department.hashCode ^
+      character.hashCode ^
+      jobName.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FilmographyEntry &&
+          runtimeType == other.runtimeType &&
+          mediaId == other.mediaId &&
+          tmdbId == other.tmdbId &&
+          title == other.title &&
+          releaseDate == other.releaseDate &&
+          poster == other.poster &&
+          department == other.department &&
+          character == other.character &&
+          jobName == other.jobName;
+}
+
+class FilmographyGroup {
+  final String department;
+  final List<FilmographyEntry> entries;
+
+  const FilmographyGroup({required this.department, required this.entries});
+
+  @override
+  int get hashCode => department.hashCode ^ entries.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FilmographyGroup &&
+          runtimeType == other.runtimeType &&
+          department == other.department &&
+          entries == other.entries;
+}
+
+/// A row of the home screen with its media and their playback state.
+class HomeRow {
+  final String kind;
+  final String title;
+  final List<PlaybackEntry> items;
+
+  const HomeRow({required this.kind, required this.title, required this.items});
+
+  @override
+  int get hashCode => kind.hashCode ^ title.hashCode ^ items.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HomeRow &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          title == other.title &&
+          items == other.items;
+}
+
+/// Placement of a home screen row for a profile.
+class HomeRowConfig {
+  /// One of the kinds of `HOME_ROWS`, e.g. "recently_added".
+  final String kind;
+  final String title;
+  final bool enabled;
+  /// Maximum number of media shown in the row.
+  final int itemCount;
+
+  const HomeRowConfig({
+    required this.kind,
+    required this.title,
+    required this.enabled,
+    required this.itemCount,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^ title.hashCode ^ enabled.hashCode ^ itemCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HomeRowConfig &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          title == other.title &&
+          enabled == other.enabled &&
+          itemCount == other.itemCount;
+}
+
+/// Change of the library, sent to the views and the network clients as it happens.
+/// Serialized as a JSON object whose `type` is the snake case variant name.
+@freezed
+sealed class LibraryEvent with _$LibraryEvent {
+  const LibraryEvent._();
+
+  const factory LibraryEvent.mediaAdded({required PlatformInt64 mediaId}) =
+      LibraryEvent_MediaAdded;
+  const factory LibraryEvent.mediaUpdated({required PlatformInt64 mediaId}) =
+      LibraryEvent_MediaUpdated;
+  const factory LibraryEvent.mediaRemoved({required PlatformInt64 mediaId}) =
+      LibraryEvent_MediaRemoved;
+  /// Watched flag, resume position or rating of a media changed for a profile.
+  const factory LibraryEvent.watchStateChanged({
+    required PlatformInt64 profileId,
+    required PlatformInt64 mediaId,
+  }) = LibraryEvent_WatchStateChanged;
+  const factory LibraryEvent.scanStarted() = LibraryEvent_ScanStarted;
+  const factory LibraryEvent.scanFinished({required ScanReport report}) =
+      LibraryEvent_ScanFinished;
+  /// The subscriber fell behind and lost events, its views should be reloaded.
+  const factory LibraryEvent.eventsMissed({required BigInt count}) =
+      LibraryEvent_EventsMissed;
+  /// Sent to an idle Dart stream to find out whether it is still listened to, carries no change.
+  const factory LibraryEvent.keepAlive() = LibraryEvent_KeepAlive;
+}
+
+/// A chapter marker of a media file, the player seeking to its start.
+class MediaChapter {
+  /// Position of the chapter, from 0.
+  final int index;
+  final String? title;
+  /// In seconds.
+  final double start;
+  /// In seconds, None for the last chapter of a file of unknown duration.
+  final double? end;
+
+  const MediaChapter({
+    required this.index,
+    this.title,
+    required this.start,
+    this.end,
+  });
+
+  @override
+  int get hashCode =>
+      index.hashCode ^ title.hashCode ^ start.hashCode ^ end.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MediaChapter &&
+          runtimeType == other.runtimeType &&
+          index == other.index &&
+          title == other.title &&
+          start == other.start &&
+          end == other.end;
+}
 
 class MediaData {
   final PlatformInt64 id;
@@ -17,6 +425,15 @@ class MediaData {
   final String summary;
   final String poster;
   final String backdrop;
+  final int? runtime;
+  final String tagline;
+  final String status;
+  final PlatformInt64 budget;
+  final PlatformInt64 revenue;
+  final String? imdbId;
+  final String certification;
+  /// Technical info read from the file headers, None until the file is probed.
+  final MediaInfo? mediaInfo;
 
   const MediaData({
     required this.id,
@@ -29,6 +446,14 @@ class MediaData {
     required this.summary,
     required this.poster,
     required this.backdrop,
+    this.runtime,
+    required this.tagline,
+    required this.status,
+    required this.budget,
+    required this.revenue,
+    this.imdbId,
+    required this.certification,
+    this.mediaInfo,
   });
 
   @override
@@ -42,7 +467,15 @@ class MediaData {
       releaseDate.hashCode ^
       summary.hashCode ^
       poster.hashCode ^
-      backdrop.hashCode;
+      backdrop.hashCode ^
+      runtime.hashCode ^
+      tagline.hashCode ^
+      status.hashCode ^
+      budget.hashCode ^
+      revenue.hashCode ^
+      imdbId.hashCode ^
+      certification.hashCode ^
+      mediaInfo.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -58,7 +491,151 @@ class MediaData {
           releaseDate == other.releaseDate &&
           summary == other.summary &&
           poster == other.poster &&
-          backdrop == other.backdrop;
+          backdrop == other.backdrop &&
+          runtime == other.runtime &&
+          tagline == other.tagline &&
+          status == other.status &&
+          budget == other.budget &&
+          revenue == other.revenue &&
+          imdbId == other.imdbId &&
+          certification == other.certification &&
+          mediaInfo == other.mediaInfo;
+}
+
+/// Technical info of a media file, read from its container headers.
+class MediaInfo {
+  /// "matroska", "webm", "mp4" or "mov".
+  final String container;
+  /// In seconds.
+  final double? duration;
+  final int? width;
+  final int? height;
+  final String? videoCodec;
+  /// "Dolby Vision", "HDR10" or "HLG", None for SDR.
+  final String? hdrFormat;
+  /// Overall bitrate in bits per second.
+  final PlatformInt64? bitrate;
+  final List<MediaStream> streams;
+  final List<MediaChapter> chapters;
+
+  const MediaInfo({
+    required this.container,
+    this.duration,
+    this.width,
+    this.height,
+    this.videoCodec,
+    this.hdrFormat,
+    this.bitrate,
+    required this.streams,
+    required this.chapters,
+  });
+
+  @override
+  int get hashCode =>
+      container.hashCode ^
+      duration.hashCode ^
+      width.hashCode ^
+      height.hashCode ^
+      videoCodec.hashCode ^
+      hdrFormat.hashCode ^
+      bitrate.hashCode ^
+      streams.hashCode ^
+      chapters.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MediaInfo &&
+          runtimeType == other.runtimeType &&
+          container == other.container &&
+          duration == other.duration &&
+          width == other.width &&
+          height == other.height &&
+          videoCodec == other.videoCodec &&
+          hdrFormat == other.hdrFormat &&
+          bitrate == other.bitrate &&
+          streams == other.streams &&
+          chapters == other.chapters;
+}
+
+/// A per-profile list of media: the built-in "favourites" and "watchlist", or a "custom" one.
+class MediaList {
+  final PlatformInt64 id;
+  final String name;
+  final String kind;
+  final int itemCount;
+
+  const MediaList({
+    required this.id,
+    required this.name,
+    required this.kind,
+    required this.itemCount,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^ name.hashCode ^ kind.hashCode ^ itemCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MediaList &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          kind == other.kind &&
+          itemCount == other.itemCount;
+}
+
+/// A video, audio or subtitle track of a media file.
+class MediaStream {
+  /// Track number in the container.
+  final int index;
+  /// "video", "audio" or "subtitle".
+  final String kind;
+  final String codec;
+  /// ISO 639-2 or BCP 47 language, None when undetermined.
+  final String? language;
+  final String? title;
+  final int? channels;
+  final bool isDefault;
+  final bool isForced;
+
+  const MediaStream({
+    required this.index,
+    required this.kind,
+    required this.codec,
+    this.language,
+    this.title,
+    this.channels,
+    required this.isDefault,
+    required this.isForced,
+  });
+
+  @override
+  int get hashCode =>
+      index.hashCode ^
+      kind.hashCode ^
+      codec.hashCode ^
+      language.hashCode ^
+      title.hashCode ^
+      channels.hashCode ^
+      isDefault.hashCode ^
+      isForced.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MediaStream &&
+          runtimeType == other.runtimeType &&
+          index == other.index &&
+          kind == other.kind &&
+          codec == other.codec &&
+          language == other.language &&
+          title == other.title &&
+          channels == other.channels &&
+          isDefault == other.isDefault &&
+          isForced == other.isForced;
 }
 
 class MovieSnapshot {
@@ -68,6 +645,7 @@ class MovieSnapshot {
   final double rating;
   final String releaseDate;
   final String poster;
+  final bool watched;
 
   const MovieSnapshot({
     required this.id,
@@ -76,6 +654,7 @@ class MovieSnapshot {
     required this.rating,
     required this.releaseDate,
     required this.poster,
+    required this.watched,
   });
 
   @override
@@ -85,7 +664,8 @@ class MovieSnapshot {
       title.hashCode ^
       rating.hashCode ^
       releaseDate.hashCode ^
-      poster.hashCode;
+      poster.hashCode ^
+      watched.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -97,7 +677,45 @@ class MovieSnapshot {
           title == other.title &&
           rating == other.rating &&
           releaseDate == other.releaseDate &&
-          poster == other.poster;
+          poster == other.poster &&
+          watched == other.watched;
+}
+
+/// A device paired with the network api, acting as one profile.
+class PairedDevice {
+  final PlatformInt64 id;
+  final String name;
+  final PlatformInt64 profileId;
+  /// Unix timestamps of the pairing and of the last authenticated request.
+  final PlatformInt64 createdAt;
+  final PlatformInt64? lastSeen;
+
+  const PairedDevice({
+    required this.id,
+    required this.name,
+    required this.profileId,
+    required this.createdAt,
+    this.lastSeen,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      name.hashCode ^
+      profileId.hashCode ^
+      createdAt.hashCode ^
+      lastSeen.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PairedDevice &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          profileId == other.profileId &&
+          createdAt == other.createdAt &&
+          lastSeen == other.lastSeen;
 }
 
 class PersonData {
@@ -105,17 +723,38 @@ class PersonData {
   final String name;
   final String summary;
   final String? picturePath;
+  final String? birthday;
+  final String? deathday;
+  final String? placeOfBirth;
+  final String? knownForDepartment;
+  final int gender;
+  final List<String> alsoKnownAs;
 
   const PersonData({
     required this.tmdbId,
     required this.name,
     required this.summary,
     this.picturePath,
+    this.birthday,
+    this.deathday,
+    this.placeOfBirth,
+    this.knownForDepartment,
+    required this.gender,
+    required this.alsoKnownAs,
   });
 
   @override
   int get hashCode =>
-      tmdbId.hashCode ^ name.hashCode ^ summary.hashCode ^ picturePath.hashCode;
+      tmdbId.hashCode ^
+      name.hashCode ^
+      summary.hashCode ^
+      picturePath.hashCode ^
+      birthday.hashCode ^
+      deathday.hashCode ^
+      placeOfBirth.hashCode ^
+      knownForDepartment.hashCode ^
+      gender.hashCode ^
+      alsoKnownAs.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -125,7 +764,37 @@ class PersonData {
           tmdbId == other.tmdbId &&
           name == other.name &&
           summary == other.summary &&
-          picturePath == other.picturePath;
+          picturePath == other.picturePath &&
+          birthday == other.birthday &&
+          deathday == other.deathday &&
+          placeOfBirth == other.placeOfBirth &&
+          knownForDepartment == other.knownForDepartment &&
+          gender == other.gender &&
+          alsoKnownAs == other.alsoKnownAs;
+}
+
+class PersonFilmography {
+  final PersonData person;
+  final List<FilmographyGroup> groups;
+  final List<FilmographyEntry> missing;
+
+  const PersonFilmography({
+    required this.person,
+    required this.groups,
+    required this.missing,
+  });
+
+  @override
+  int get hashCode => person.hashCode ^ groups.hashCode ^ missing.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PersonFilmography &&
+          runtimeType == other.runtimeType &&
+          person == other.person &&
+          groups == other.groups &&
+          missing == other.missing;
 }
 
 class PersonSnapshot {
@@ -134,6 +803,9 @@ class PersonSnapshot {
   final String character;
   final String jobName;
   final String picturePath;
+  final String department;
+  final int? creditOrder;
+  final String creditId;
 
   const PersonSnapshot({
     required this.tmdbId,
@@ -141,6 +813,9 @@ class PersonSnapshot {
     required this.character,
     required this.jobName,
     required this.picturePath,
+    required this.department,
+    this.creditOrder,
+    required this.creditId,
   });
 
   @override
@@ -149,7 +824,10 @@ class PersonSnapshot {
       name.hashCode ^
       character.hashCode ^
       jobName.hashCode ^
-      picturePath.hashCode;
+      picturePath.hashCode ^
+      department.hashCode ^
+      creditOrder.hashCode ^
+      creditId.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -160,5 +838,484 @@ class PersonSnapshot {
           name == other.name &&
           character == other.character &&
           jobName == other.jobName &&
-          picturePath == other.picturePath;
+          picturePath == other.picturePath &&
+          department == other.department &&
+          creditOrder == other.creditOrder &&
+          creditId == other.creditId;
+}
+
+/// How a media is played by a player and where.
+class PlaybackDecision {
+  /// "direct_play", "remux" or "transcode".
+  final String method;
+  /// Why the media can not be played as is, empty for a direct play.
+  final List<String> reasons;
+  /// The file for a direct play, a HLS playlist otherwise.
+  final String url;
+  /// Transcoding session to stop once playback ends, None for a direct play.
+  final String? sessionId;
+
+  const PlaybackDecision({
+    required this.method,
+    required this.reasons,
+    required this.url,
+    this.sessionId,
+  });
+
+  @override
+  int get hashCode =>
+      method.hashCode ^ reasons.hashCode ^ url.hashCode ^ sessionId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PlaybackDecision &&
+          runtimeType == other.runtimeType &&
+          method == other.method &&
+          reasons == other.reasons &&
+          url == other.url &&
+          sessionId == other.sessionId;
+}
+
+/// A media snapshot with its playback state, listed in "continue watching" and "recently watched".
+class PlaybackEntry {
+  final MovieSnapshot media;
+  final PlaybackState state;
+
+  const PlaybackEntry({required this.media, required this.state});
+
+  @override
+  int get hashCode => media.hashCode ^ state.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PlaybackEntry &&
+          runtimeType == other.runtimeType &&
+          media == other.media &&
+          state == other.state;
+}
+
+/// How a player asks for a media to be played.
+class PlaybackRequest {
+  final ClientCapabilities capabilities;
+  /// Quality chosen by the user, lowering the client limits.
+  final int? maxHeight;
+  final PlatformInt64? maxBitrate;
+  /// Track number of the audio stream kept when remuxed or transcoded, the default one
+  /// when not given.
+  final int? audioStream;
+  /// Track number of an embedded subtitle stream to burn into the video.
+  final int? subtitleStream;
+  /// Id of a subtitle file next to the video to burn into the video, as listed by
+  /// `get_subtitle_files`. Can not be given with `subtitle_stream`.
+  final PlatformInt64? subtitleFile;
+  /// In seconds, a transcode starting at the segment holding it. A remux always starts from
+  /// the beginning, the player seeking once its playlist reaches the position.
+  final double startPosition;
+
+  const PlaybackRequest({
+    required this.capabilities,
+    this.maxHeight,
+    this.maxBitrate,
+    this.audioStream,
+    this.subtitleStream,
+    this.subtitleFile,
+    required this.startPosition,
+  });
+
+  @override
+  int get hashCode =>
+      capabilities.hashCode ^
+      maxHeight.hashCode ^
+      maxBitrate.hashCode ^
+      audioStream.hashCode ^
+      subtitleStream.hashCode ^
+      subtitleFile.hashCode ^
+      startPosition.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PlaybackRequest &&
+          runtimeType == other.runtimeType &&
+          capabilities == other.capabilities &&
+          maxHeight == other.maxHeight &&
+          maxBitrate == other.maxBitrate &&
+          audioStream == other.audioStream &&
+          subtitleStream == other.subtitleStream &&
+          subtitleFile == other.subtitleFile &&
+          startPosition == other.startPosition;
+}
+
+/// Playback state of a media item, positions and duration being in seconds.
+class PlaybackState {
+  final PlatformInt64 mediaId;
+  final bool watched;
+  final int playCount;
+  /// Unix timestamp of the last playback, if any.
+  final PlatformInt64? lastPlayed;
+  final double resumePosition;
+  final double duration;
+  /// Rating given by the profile, from 0 to 10.
+  final double? userRating;
+
+  const PlaybackState({
+    required this.mediaId,
+    required this.watched,
+    required this.playCount,
+    this.lastPlayed,
+    required this.resumePosition,
+    required this.duration,
+    this.userRating,
+  });
+
+  @override
+  int get hashCode =>
+      mediaId.hashCode ^
+      watched.hashCode ^
+      playCount.hashCode ^
+      lastPlayed.hashCode ^
+      resumePosition.hashCode ^
+      duration.hashCode ^
+      userRating.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PlaybackState &&
+          runtimeType == other.runtimeType &&
+          mediaId == other.mediaId &&
+          watched == other.watched &&
+          playCount == other.playCount &&
+          lastPlayed == other.lastPlayed &&
+          resumePosition == other.resumePosition &&
+          duration == other.duration &&
+          userRating == other.userRating;
+}
+
+/// A household member with their own watch history and preferences.
+class Profile {
+  final PlatformInt64 id;
+  final String name;
+  final String? avatar;
+  /// Whether switching to the profile asks for its PIN.
+  final bool hasPin;
+  /// Highest content rating shown to the profile, e.g. "PG-13".
+  final String? maxCertification;
+  /// Whether the titles without a US content rating are hidden too when the profile has a
+  /// rating limit, covering the unrated titles and those only rated in other countries.
+  final bool hideUnrated;
+  /// ISO 639-1 code of the preferred audio and subtitle language.
+  final String? preferredLanguage;
+
+  const Profile({
+    required this.id,
+    required this.name,
+    this.avatar,
+    required this.hasPin,
+    this.maxCertification,
+    required this.hideUnrated,
+    this.preferredLanguage,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      name.hashCode ^
+      avatar.hashCode ^
+      hasPin.hashCode ^
+      maxCertification.hashCode ^
+      hideUnrated.hashCode ^
+      preferredLanguage.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Profile &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          avatar == other.avatar &&
+          hasPin == other.hasPin &&
+          maxCertification == other.maxCertification &&
+          hideUnrated == other.hideUnrated &&
+          preferredLanguage == other.preferredLanguage;
+}
+
+/// Summary of a library scan, including how many TMDB requests the combined fetch path avoided.
+class ScanReport {
+  final int moviesFound;
+  final int moviesMatched;
+  final int moviesSaved;
+  final int moviesFailed;
+  final int personsFetched;
+  final int personsSkipped;
+  final BigInt tmdbRequests;
+  final BigInt tmdbRequestsSaved;
+
+  const ScanReport({
+    required this.moviesFound,
+    required this.moviesMatched,
+    required this.moviesSaved,
+    required this.moviesFailed,
+    required this.personsFetched,
+    required this.personsSkipped,
+    required this.tmdbRequests,
+    required this.tmdbRequestsSaved,
+  });
+
+  @override
+  int get hashCode =>
+      moviesFound.hashCode ^
+      moviesMatched.hashCode ^
+      moviesSaved.hashCode ^
+      moviesFailed.hashCode ^
+      personsFetched.hashCode ^
+      personsSkipped.hashCode ^
+      tmdbRequests.hashCode ^
+      tmdbRequestsSaved.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScanReport &&
+          runtimeType == other.runtimeType &&
+          moviesFound == other.moviesFound &&
+          moviesMatched == other.moviesMatched &&
+          moviesSaved == other.moviesSaved &&
+          moviesFailed == other.moviesFailed &&
+          personsFetched == other.personsFetched &&
+          personsSkipped == other.personsSkipped &&
+          tmdbRequests == other.tmdbRequests &&
+          tmdbRequestsSaved == other.tmdbRequestsSaved;
+}
+
+class SearchResult {
+  final String kind;
+  final PlatformInt64 id;
+  final String title;
+  final String subtitle;
+  final String? picturePath;
+  final double score;
+
+  const SearchResult({
+    required this.kind,
+    required this.id,
+    required this.title,
+    required this.subtitle,
+    this.picturePath,
+    required this.score,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      id.hashCode ^
+      title.hashCode ^
+      subtitle.hashCode ^
+      picturePath.hashCode ^
+      score.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SearchResult &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          id == other.id &&
+          title == other.title &&
+          subtitle == other.subtitle &&
+          picturePath == other.picturePath &&
+          score == other.score;
+}
+
+/// A per-profile collection whose movies are the ones matching its query when evaluated.
+class SmartCollection {
+  final PlatformInt64 id;
+  final String name;
+  final SmartQuery query;
+
+  const SmartCollection({
+    required this.id,
+    required this.name,
+    required this.query,
+  });
+
+  @override
+  int get hashCode => id.hashCode ^ name.hashCode ^ query.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SmartCollection &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          query == other.query;
+}
+
+/// Conditions a movie must all match to be part of a smart collection, unset ones being ignored.
+class SmartFilter {
+  /// Genre names, matching movies having any of them.
+  final List<String> genres;
+  final int? yearFrom;
+  final int? yearTo;
+  final double? minRating;
+  final double? maxRating;
+  /// TMDB ids of persons, matching movies crediting any of them.
+  final Int64List personIds;
+  final bool? watched;
+  /// Resolution tags of the file name, e.g. "2160p", matching movies having any of them.
+  final List<String> resolutions;
+  /// Only keeps the movies added to the library in the last days.
+  final int? addedWithinDays;
+
+  const SmartFilter({
+    required this.genres,
+    this.yearFrom,
+    this.yearTo,
+    this.minRating,
+    this.maxRating,
+    required this.personIds,
+    this.watched,
+    required this.resolutions,
+    this.addedWithinDays,
+  });
+
+  @override
+  int get hashCode =>
+      genres.hashCode ^
+      yearFrom.hashCode ^
+      yearTo.hashCode ^
+      minRating.hashCode ^
+      maxRating.hashCode ^
+      personIds.hashCode ^
+      watched.hashCode ^
+      resolutions.hashCode ^
+      addedWithinDays.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SmartFilter &&
+          runtimeType == other.runtimeType &&
+          genres == other.genres &&
+          yearFrom == other.yearFrom &&
+          yearTo == other.yearTo &&
+          minRating == other.minRating &&
+          maxRating == other.maxRating &&
+          personIds == other.personIds &&
+          watched == other.watched &&
+          resolutions == other.resolutions &&
+          addedWithinDays == other.addedWithinDays;
+}
+
+/// Saved query of a smart collection, stored as JSON.
+class SmartQuery {
+  final SmartFilter filter;
+  final SmartSort sort;
+  final bool descending;
+  /// Maximum number of movies in the collection.
+  final int? limit;
+
+  const SmartQuery({
+    required this.filter,
+    required this.sort,
+    required this.descending,
+    this.limit,
+  });
+
+  @override
+  int get hashCode =>
+      filter.hashCode ^ sort.hashCode ^ descending.hashCode ^ limit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SmartQuery &&
+          runtimeType == other.runtimeType &&
+          filter == other.filter &&
+          sort == other.sort &&
+          descending == other.descending &&
+          limit == other.limit;
+}
+
+enum SmartSort {
+  title,
+  releaseDate,
+  rating,
+  dateAdded,
+  /// Shuffled again on every evaluation.
+  random,
+  ;
+}
+
+/// A subtitle file found next to the video of a media, its tags read from its name
+/// as in "Movie.en.forced.srt".
+class SubtitleFile {
+  /// 0 until saved.
+  final PlatformInt64 id;
+  /// Relative to the library root.
+  final String filePath;
+  /// "srt", "ass", "ssa" or "vtt".
+  final String format;
+  /// Language code as written in the file name, None when not given.
+  final String? language;
+  final String? title;
+  final bool isForced;
+  /// Subtitles for the deaf and hard of hearing.
+  final bool isSdh;
+
+  const SubtitleFile({
+    required this.id,
+    required this.filePath,
+    required this.format,
+    this.language,
+    this.title,
+    required this.isForced,
+    required this.isSdh,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      filePath.hashCode ^
+      format.hashCode ^
+      language.hashCode ^
+      title.hashCode ^
+      isForced.hashCode ^
+      isSdh.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SubtitleFile &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          filePath == other.filePath &&
+          format == other.format &&
+          language == other.language &&
+          title == other.title &&
+          isForced == other.isForced &&
+          isSdh == other.isSdh;
+}
+
+/// Unwatched media recommended because the profile watched `source`.
+class WatchRecommendation {
+  final MovieSnapshot source;
+  final List<MovieSnapshot> items;
+
+  const WatchRecommendation({required this.source, required this.items});
+
+  @override
+  int get hashCode => source.hashCode ^ items.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WatchRecommendation &&
+          runtimeType == other.runtimeType &&
+          source == other.source &&
+          items == other.items;
 }
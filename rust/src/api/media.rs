@@ -1,18 +1,60 @@
-pub use crate::movie_data::movie_data::{MediaData, MovieSnapshot, PersonData}; //expose for dart
+pub use crate::directory_explorer::media_file::MediaSource; //expose for dart
+pub use crate::movie_data::movie_data::{
+    AllowedJob, ClientCapabilities, CollectionDetail, CollectionPartSnapshot, CollectionSnapshot,
+    CreditFilter, FacetSnapshot, FilmographyEntry, FilmographyGroup, HomeRow, HomeRowConfig,
+    LibraryEvent, MediaChapter, MediaData, MediaInfo, MediaList, MediaStream, MovieSnapshot,
+    PairedDevice, PersonData, PersonFilmography, PlaybackDecision, PlaybackEntry, PlaybackRequest,
+    PlaybackState, Profile, ScanReport, SearchResult, SmartCollection, SmartFilter, SmartQuery,
+    SmartSort, SubtitleFile, WatchRecommendation,
+}; //expose for dart
 use crate::{
-    db_interface::data_getter::DataGetter,
-    media_retriever::media_retriever::retrieve_media,
+    db_interface::{data_getter::DataGetter, data_saver::DataSaver},
+    directory_explorer::media_file::FileSource,
+    event_bus::event_bus::library_events,
+    frb_generated::StreamSink,
+    media_probe::media_probe::probe_library,
+    media_retriever::media_retriever::{
+        refresh_metadata, retrieve_media, retrieve_missing_filmography, RefreshTarget,
+    },
     movie_data::movie_data::PersonSnapshot,
+    recommender::content_index::{get_because_you_watched, get_similar, refresh_content_index},
+    search_index::fuzzy_index::{refresh_search_index, search_index},
     smb_mounter::smb_mounter::{mount_smb, unmount_smb},
+    stream_server::stream_server::{self, server_url, stream_url, subtitle_url, trickplay_url},
+    subtitles::sidecar::discover_subtitles,
+    thumbnails::{thumbnails::generate_thumbnails, trickplay::has_trickplay},
+    transcoder::{
+        ffmpeg::ffmpeg_path,
+        transcoder::{prepare_playback, stop_session},
+    },
 };
-use anyhow::{Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
+use futures::StreamExt;
+use std::{path::Path, time::Duration};
 
 use tracing_subscriber::fmt::format::FmtSpan;
 
+/// Idle time after which a library event stream checks that Dart still listens.
+const EVENT_KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(30);
+
 #[flutter_rust_bridge::frb(init)]
 pub fn init_app() {
     init_tracing_subscriber();
     flutter_rust_bridge::setup_default_user_utils();
+    initiate_db()
+        .map_err(|e| tracing::error!("Failed to initiate database \n Caused by {:?}", e))
+        .ok();
+}
+
+/// Initializes the database by creating the database file and required tables.
+/// Every other call expects the schema to be there, so this runs once when the app starts.
+fn initiate_db() -> Result<()> {
+    let mut data_saver =
+        DataSaver::new("movie_db.db".to_owned()).context("Failed to create database connection")?;
+    data_saver.create_tables()?;
+
+    tracing::info!("Data base initiated");
+    Ok(())
 }
 
 // Basic function that creates a default tracing subscribe that outputs only in the console
@@ -34,36 +76,565 @@ pub async fn start(path: &str, username: &str, password: &str, token: &str) -> S
     format!("Hello, {:?}!", res)
 }
 
+/// Fetches the metadata and artwork of a stored movie again, returning the movies saved.
+#[flutter_rust_bridge::frb]
+pub async fn refresh_movie_metadata(media_id: i64, token: &str) -> Result<u32> {
+    let report = refresh_metadata(RefreshTarget::Movie(media_id), token).await?;
+    Ok(report.movies_saved)
+}
+
+/// Fetches the details and picture of a stored person again, returning the persons saved.
+#[flutter_rust_bridge::frb]
+pub async fn refresh_person_metadata(person_tmdb_id: i64, token: &str) -> Result<u32> {
+    let report = refresh_metadata(RefreshTarget::Person(person_tmdb_id), token).await?;
+    Ok(report.persons_fetched)
+}
+
+/// Fetches the metadata of every stored movie again, returning the movies saved.
+#[flutter_rust_bridge::frb]
+pub async fn refresh_library_metadata(token: &str) -> Result<u32> {
+    let report = refresh_metadata(RefreshTarget::Library, token).await?;
+    Ok(report.movies_saved)
+}
+
+/// Fetches the metadata of the movies not refreshed within `max_age_days` days again.
+#[flutter_rust_bridge::frb]
+pub async fn refresh_stale_metadata(max_age_days: u32, token: &str) -> Result<u32> {
+    let report = refresh_metadata(RefreshTarget::OlderThan(max_age_days), token).await?;
+    Ok(report.movies_saved)
+}
+
+// Opens the database for edits of the active profile, returning its id with the saver
+fn active_profile_saver() -> Result<(DataSaver, i64)> {
+    let data_saver = DataSaver::new("movie_db.db".to_owned())?;
+    let profile_id = DataGetter::new("movie_db.db".to_owned())?.get_active_profile_id()?;
+    Ok((data_saver, profile_id))
+}
+
+// Opens the database for the reads of the active profile, failing for the media it may not see
+fn media_getter(media_id: i64) -> Result<DataGetter> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let profile_id = data_getter.get_active_profile_id()?;
+    if !data_getter.is_media_allowed(profile_id, media_id)? {
+        bail!("No media found for id: {}", media_id);
+    }
+    Ok(data_getter)
+}
+
 #[flutter_rust_bridge::frb]
 pub fn get_media_snapshots(media_type: &str) -> Result<Vec<MovieSnapshot>> {
     let data_getter = DataGetter::new("movie_db.db".to_owned())?;
-    return data_getter.get_media_snapshot(media_type);
+    let profile_id = data_getter.get_active_profile_id()?;
+    return data_getter.get_media_snapshot(media_type, profile_id);
 }
 
 #[flutter_rust_bridge::frb]
 pub fn get_media(media_id: i64) -> Result<MediaData> {
-    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let data_getter = media_getter(media_id)?;
     return data_getter.get_media_data(media_id);
 }
 
+/// Returns the chapters of a media for the scene list and chapter seeking, empty until its
+/// file is probed with `probe_media_files`.
+#[flutter_rust_bridge::frb]
+pub fn get_media_chapters(media_id: i64) -> Result<Vec<MediaChapter>> {
+    let data_getter = media_getter(media_id)?;
+    return data_getter.get_media_chapters(media_id);
+}
+
 #[flutter_rust_bridge::frb]
 pub fn get_media_cast(media_id: i64) -> Result<Vec<PersonSnapshot>> {
-    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let data_getter = media_getter(media_id)?;
     return data_getter.get_media_cast(media_id);
 }
 
 #[flutter_rust_bridge::frb]
 pub fn get_media_crew(media_id: i64) -> Result<Vec<PersonSnapshot>> {
-    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let data_getter = media_getter(media_id)?;
     return data_getter.get_media_crew(media_id);
 }
 
+/// Sets a movie field by hand and locks it against scans and refreshes.
+/// Editable fields are "title", "original_title", "summary", "tagline", "release_date" and "certification".
+/// Only "release_date" can be cleared with a None value.
+#[flutter_rust_bridge::frb]
+pub fn edit_media_field(media_id: i64, field: &str, value: Option<String>) -> Result<()> {
+    if field == "poster" || field == "backdrop" {
+        return Err(anyhow!("Artwork is edited with edit_media_artwork"));
+    }
+    let mut data_saver = DataSaver::new("movie_db.db".to_owned())?;
+    data_saver.edit_movie_field(media_id, field, value)?;
+    return refresh_search_index("movie_db.db");
+}
+
+/// Replaces the genres of a movie by hand and locks them.
+/// Every genre id must be one of the stored TMDB genres.
+#[flutter_rust_bridge::frb]
+pub fn edit_media_genres(media_id: i64, genre_ids: Vec<i64>) -> Result<()> {
+    let mut data_saver = DataSaver::new("movie_db.db".to_owned())?;
+    data_saver.edit_movie_genres(media_id, &genre_ids)?;
+    return refresh_content_index("movie_db.db");
+}
+
+/// Sets the "poster" or "backdrop" of a movie to a local image and locks it.
+#[flutter_rust_bridge::frb]
+pub fn edit_media_artwork(media_id: i64, kind: &str, image_path: &str) -> Result<()> {
+    if kind != "poster" && kind != "backdrop" {
+        return Err(anyhow!("Unknown artwork kind: {}", kind));
+    }
+    if !Path::new(image_path).is_file() {
+        return Err(anyhow!("Image file not found: {}", image_path));
+    }
+    let mut data_saver = DataSaver::new("movie_db.db".to_owned())?;
+    data_saver.edit_movie_field(media_id, kind, Some(image_path.to_owned()))?;
+    return refresh_search_index("movie_db.db");
+}
+
+#[flutter_rust_bridge::frb]
+pub fn get_media_locked_fields(media_id: i64) -> Result<Vec<String>> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    return data_getter.get_media_locked_fields(media_id);
+}
+
+/// Unlocks an edited field and reverts it to the latest provider data.
+#[flutter_rust_bridge::frb]
+pub fn unlock_media_field(media_id: i64, field: &str) -> Result<()> {
+    let mut data_saver = DataSaver::new("movie_db.db".to_owned())?;
+    data_saver.unlock_movie_field(media_id, field)?;
+    return refresh_search_index("movie_db.db");
+}
+
+/// Removes a movie from the library with its watch states and list entries, e.g. once its
+/// file is deleted. A later scan finding the file again adds it back.
+#[flutter_rust_bridge::frb]
+pub fn remove_media(media_id: i64) -> Result<()> {
+    let mut data_saver = DataSaver::new("movie_db.db".to_owned())?;
+    data_saver.remove_movie(media_id)?;
+    refresh_search_index("movie_db.db")?;
+    return refresh_content_index("movie_db.db");
+}
+
+/// Sends every library change as it happens, so views refresh without polling.
+/// Ends once the Dart stream is closed, which an idle stream notices through the
+/// `KeepAlive` events sent every `EVENT_KEEP_ALIVE_INTERVAL`.
+#[flutter_rust_bridge::frb]
+pub async fn watch_library_events(sink: StreamSink<LibraryEvent>) -> Result<()> {
+    let mut events = Box::pin(library_events());
+    let mut keep_alive = tokio::time::interval(EVENT_KEEP_ALIVE_INTERVAL);
+    keep_alive.reset();
+    loop {
+        let event = tokio::select! {
+            event = events.next() => match event {
+                Some(event) => event,
+                None => break,
+            },
+            _ = keep_alive.tick() => LibraryEvent::KeepAlive,
+        };
+        if sink.add(event).is_err() {
+            // The Dart stream was closed
+            break;
+        }
+        keep_alive.reset();
+    }
+    Ok(())
+}
+
+/// Returns the media most like a movie by genres, credits, keywords and era, most similar first.
+#[flutter_rust_bridge::frb]
+pub fn get_similar_media(media_id: i64, limit: u32) -> Result<Vec<MovieSnapshot>> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let profile_id = data_getter.get_active_profile_id()?;
+    return get_similar("movie_db.db", profile_id, media_id, limit as usize);
+}
+
+/// Recommends unwatched media for each recently watched movie of the active profile,
+/// with at most `limit` media per watched movie.
+#[flutter_rust_bridge::frb]
+pub fn get_watch_recommendations(limit: u32) -> Result<Vec<WatchRecommendation>> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let profile_id = data_getter.get_active_profile_id()?;
+    return get_because_you_watched("movie_db.db", profile_id, limit as usize);
+}
+
+#[flutter_rust_bridge::frb]
+pub fn get_collections() -> Result<Vec<CollectionSnapshot>> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let profile_id = data_getter.get_active_profile_id()?;
+    return data_getter.get_collections(profile_id);
+}
+
+#[flutter_rust_bridge::frb]
+pub fn get_collection(collection_id: i64) -> Result<CollectionDetail> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let profile_id = data_getter.get_active_profile_id()?;
+    return data_getter.get_collection(collection_id, profile_id);
+}
+
+#[flutter_rust_bridge::frb]
+pub fn get_collection_missing_parts(collection_id: i64) -> Result<Vec<CollectionPartSnapshot>> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let profile_id = data_getter.get_active_profile_id()?;
+    return data_getter.get_collection_missing_parts(collection_id, profile_id);
+}
+
+/// Lists the values of a facet kind: "genre", "studio", "country", "language" or "keyword".
+#[flutter_rust_bridge::frb]
+pub fn get_facets(kind: &str) -> Result<Vec<FacetSnapshot>> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    return data_getter.get_facets(kind);
+}
+
+#[flutter_rust_bridge::frb]
+pub fn get_media_facets(media_id: i64) -> Result<Vec<FacetSnapshot>> {
+    let data_getter = media_getter(media_id)?;
+    return data_getter.get_media_facets(media_id);
+}
+
+#[flutter_rust_bridge::frb]
+pub fn get_media_snapshots_by_facet(kind: &str, key: &str) -> Result<Vec<MovieSnapshot>> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let profile_id = data_getter.get_active_profile_id()?;
+    return data_getter.get_media_snapshot_by_facet(kind, key, profile_id);
+}
+
+#[flutter_rust_bridge::frb]
+pub fn mark_media_watched(media_id: i64, watched: bool) -> Result<()> {
+    let mut data_saver = DataSaver::new("movie_db.db".to_owned())?;
+    let profile_id = DataGetter::new("movie_db.db".to_owned())?.get_active_profile_id()?;
+    return data_saver.set_watched(profile_id, media_id, watched);
+}
+
+/// Records the playback position of a media, in seconds, marking it watched near the end.
+#[flutter_rust_bridge::frb]
+pub fn report_playback_progress(media_id: i64, position: f64, duration: f64) -> Result<()> {
+    let mut data_saver = DataSaver::new("movie_db.db".to_owned())?;
+    let profile_id = DataGetter::new("movie_db.db".to_owned())?.get_active_profile_id()?;
+    return data_saver.report_progress(profile_id, media_id, position, duration);
+}
+
+#[flutter_rust_bridge::frb]
+pub fn get_playback_state(media_id: i64) -> Result<PlaybackState> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let profile_id = data_getter.get_active_profile_id()?;
+    return data_getter.get_playback_state(profile_id, media_id);
+}
+
+#[flutter_rust_bridge::frb]
+pub fn get_continue_watching(limit: u32) -> Result<Vec<PlaybackEntry>> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let profile_id = data_getter.get_active_profile_id()?;
+    return data_getter.get_continue_watching(profile_id, limit);
+}
+
+#[flutter_rust_bridge::frb]
+pub fn get_recently_watched(limit: u32) -> Result<Vec<PlaybackEntry>> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let profile_id = data_getter.get_active_profile_id()?;
+    return data_getter.get_recently_watched(profile_id, limit);
+}
+
+/// Sets or clears the rating the active profile gives to a media, from 0 to 10.
+#[flutter_rust_bridge::frb]
+pub fn rate_media(media_id: i64, rating: Option<f32>) -> Result<()> {
+    let mut data_saver = DataSaver::new("movie_db.db".to_owned())?;
+    let profile_id = DataGetter::new("movie_db.db".to_owned())?.get_active_profile_id()?;
+    return data_saver.rate_media(profile_id, media_id, rating);
+}
+
+#[flutter_rust_bridge::frb]
+pub fn get_profiles() -> Result<Vec<Profile>> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    return data_getter.get_profiles();
+}
+
+#[flutter_rust_bridge::frb]
+pub fn get_active_profile() -> Result<Profile> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    return data_getter.get_active_profile();
+}
+
+/// Creates a profile and returns it. An empty or missing PIN leaves the profile unlocked.
+/// With a `max_certification`, `hide_unrated` also hides the titles without a US rating.
+#[flutter_rust_bridge::frb]
+pub fn create_profile(
+    name: &str,
+    avatar: Option<String>,
+    pin: Option<String>,
+    max_certification: Option<String>,
+    hide_unrated: bool,
+    preferred_language: Option<String>,
+) -> Result<Profile> {
+    let mut data_saver = DataSaver::new("movie_db.db".to_owned())?;
+    let profile_id = data_saver.create_profile(
+        name,
+        avatar,
+        pin.as_deref(),
+        max_certification,
+        hide_unrated,
+        preferred_language,
+    )?;
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    return data_getter
+        .get_profiles()?
+        .into_iter()
+        .find(|profile| profile.id == profile_id)
+        .ok_or_else(|| anyhow!("Created profile not found: {}", profile_id));
+}
+
+/// Makes a profile the active one, its PIN being required when it has one.
+#[flutter_rust_bridge::frb]
+pub fn switch_profile(profile_id: i64, pin: Option<String>) -> Result<()> {
+    let mut data_saver = DataSaver::new("movie_db.db".to_owned())?;
+    return data_saver.switch_profile(profile_id, pin.as_deref());
+}
+
+/// Deletes a profile with its watch history, ratings, lists, smart collections and home screen.
+///
+/// Takes the PIN of the deleted profile, or of the active profile when only that one has a PIN.
+/// Deleting the active profile can leave no profile active, `get_active_profile` then failing
+/// until a profile is picked with `switch_profile`.
+#[flutter_rust_bridge::frb]
+pub fn delete_profile(profile_id: i64, pin: Option<String>) -> Result<()> {
+    let mut data_saver = DataSaver::new("movie_db.db".to_owned())?;
+    return data_saver.delete_profile(profile_id, pin.as_deref());
+}
+
+/// Lists the favourites, the watchlist and the custom lists of the active profile.
+#[flutter_rust_bridge::frb]
+pub fn get_lists() -> Result<Vec<MediaList>> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let profile_id = data_getter.get_active_profile_id()?;
+    return data_getter.get_lists(profile_id);
+}
+
+/// Returns a zero-based page of the media of a list, in list order.
+#[flutter_rust_bridge::frb]
+pub fn get_list_items(list_id: i64, page: u32, page_size: u32) -> Result<Vec<MovieSnapshot>> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let profile_id = data_getter.get_active_profile_id()?;
+    return data_getter.get_list_snapshots(profile_id, list_id, page, page_size);
+}
+
+#[flutter_rust_bridge::frb]
+pub fn create_list(name: &str) -> Result<i64> {
+    let (mut data_saver, profile_id) = active_profile_saver()?;
+    return data_saver.create_list(profile_id, name);
+}
+
+#[flutter_rust_bridge::frb]
+pub fn rename_list(list_id: i64, name: &str) -> Result<()> {
+    let (mut data_saver, profile_id) = active_profile_saver()?;
+    return data_saver.rename_list(profile_id, list_id, name);
+}
+
+#[flutter_rust_bridge::frb]
+pub fn delete_list(list_id: i64) -> Result<()> {
+    let (mut data_saver, profile_id) = active_profile_saver()?;
+    return data_saver.delete_list(profile_id, list_id);
+}
+
+#[flutter_rust_bridge::frb]
+pub fn add_to_list(list_id: i64, media_id: i64) -> Result<()> {
+    let (mut data_saver, profile_id) = active_profile_saver()?;
+    return data_saver.add_to_list(profile_id, list_id, media_id);
+}
+
+#[flutter_rust_bridge::frb]
+pub fn remove_from_list(list_id: i64, media_id: i64) -> Result<()> {
+    let (mut data_saver, profile_id) = active_profile_saver()?;
+    return data_saver.remove_from_list(profile_id, list_id, media_id);
+}
+
+/// Moves a media of a list to a new zero-based position.
+#[flutter_rust_bridge::frb]
+pub fn move_list_item(list_id: i64, media_id: i64, position: u32) -> Result<()> {
+    let (mut data_saver, profile_id) = active_profile_saver()?;
+    return data_saver.move_list_item(profile_id, list_id, media_id, position);
+}
+
+#[flutter_rust_bridge::frb]
+pub fn set_favourite(media_id: i64, favourite: bool) -> Result<()> {
+    set_in_builtin_list("favourites", media_id, favourite)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn set_in_watchlist(media_id: i64, in_watchlist: bool) -> Result<()> {
+    set_in_builtin_list("watchlist", media_id, in_watchlist)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn get_favourites(page: u32, page_size: u32) -> Result<Vec<MovieSnapshot>> {
+    get_builtin_list_items("favourites", page, page_size)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn get_watchlist(page: u32, page_size: u32) -> Result<Vec<MovieSnapshot>> {
+    get_builtin_list_items("watchlist", page, page_size)
+}
+
+fn set_in_builtin_list(kind: &str, media_id: i64, included: bool) -> Result<()> {
+    let (mut data_saver, profile_id) = active_profile_saver()?;
+    let list_id =
+        DataGetter::new("movie_db.db".to_owned())?.get_builtin_list_id(profile_id, kind)?;
+    if included {
+        data_saver.add_to_list(profile_id, list_id, media_id)
+    } else {
+        data_saver.remove_from_list(profile_id, list_id, media_id)
+    }
+}
+
+fn get_builtin_list_items(kind: &str, page: u32, page_size: u32) -> Result<Vec<MovieSnapshot>> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let profile_id = data_getter.get_active_profile_id()?;
+    let list_id = data_getter.get_builtin_list_id(profile_id, kind)?;
+    data_getter.get_list_snapshots(profile_id, list_id, page, page_size)
+}
+
+/// Lists the smart collections of the active profile.
+#[flutter_rust_bridge::frb]
+pub fn get_smart_collections() -> Result<Vec<SmartCollection>> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let profile_id = data_getter.get_active_profile_id()?;
+    return data_getter.get_smart_collections(profile_id);
+}
+
+#[flutter_rust_bridge::frb]
+pub fn create_smart_collection(name: &str, query: SmartQuery) -> Result<i64> {
+    let (mut data_saver, profile_id) = active_profile_saver()?;
+    return data_saver.create_smart_collection(profile_id, name, &query);
+}
+
+#[flutter_rust_bridge::frb]
+pub fn edit_smart_collection(collection_id: i64, name: &str, query: SmartQuery) -> Result<()> {
+    let (mut data_saver, profile_id) = active_profile_saver()?;
+    return data_saver.update_smart_collection(profile_id, collection_id, name, &query);
+}
+
+#[flutter_rust_bridge::frb]
+pub fn delete_smart_collection(collection_id: i64) -> Result<()> {
+    let (mut data_saver, profile_id) = active_profile_saver()?;
+    return data_saver.delete_smart_collection(profile_id, collection_id);
+}
+
+/// Evaluates a query without saving it, returning a zero-based page of the matching media.
+/// A random sort is shuffled with `seed`, to be kept while paging through the same results.
+#[flutter_rust_bridge::frb]
+pub fn preview_smart_collection(
+    query: SmartQuery,
+    page: u32,
+    page_size: u32,
+    seed: u32,
+) -> Result<Vec<MovieSnapshot>> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let profile_id = data_getter.get_active_profile_id()?;
+    return data_getter.get_smart_snapshots(profile_id, &query, page, page_size, seed);
+}
+
+/// Evaluates a saved smart collection, returning a zero-based page of the matching media.
+/// A random sort is shuffled with `seed`, to be kept while paging through the same results.
+#[flutter_rust_bridge::frb]
+pub fn get_smart_collection_items(
+    collection_id: i64,
+    page: u32,
+    page_size: u32,
+    seed: u32,
+) -> Result<Vec<MovieSnapshot>> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let profile_id = data_getter.get_active_profile_id()?;
+    let collection = data_getter.get_smart_collection(profile_id, collection_id)?;
+    return data_getter.get_smart_snapshots(profile_id, &collection.query, page, page_size, seed);
+}
+
+/// Assembles the enabled home screen rows of the active profile, leaving out the empty ones.
+#[flutter_rust_bridge::frb]
+pub fn get_home_feed() -> Result<Vec<HomeRow>> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let profile_id = data_getter.get_active_profile_id()?;
+
+    let mut feed = vec![];
+    for row in data_getter.get_home_rows(profile_id)? {
+        if !row.enabled {
+            continue;
+        }
+        let items = data_getter.get_home_row_items(profile_id, &row.kind, row.item_count)?;
+        if !items.is_empty() {
+            feed.push(HomeRow::new(row.kind, row.title, items));
+        }
+    }
+    Ok(feed)
+}
+
+/// Lists every home screen row of the active profile in order, including the disabled ones.
+#[flutter_rust_bridge::frb]
+pub fn get_home_feed_rows() -> Result<Vec<HomeRowConfig>> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let profile_id = data_getter.get_active_profile_id()?;
+    return data_getter.get_home_rows(profile_id);
+}
+
+/// Saves the order, visibility and size of the home screen rows of the active profile.
+#[flutter_rust_bridge::frb]
+pub fn set_home_feed_rows(rows: Vec<HomeRowConfig>) -> Result<()> {
+    let (mut data_saver, profile_id) = active_profile_saver()?;
+    return data_saver.set_home_rows(profile_id, &rows);
+}
+
 #[flutter_rust_bridge::frb]
 pub fn get_person(person_tmdb_id: i64) -> Result<PersonData> {
     let data_getter = DataGetter::new("movie_db.db".to_owned())?;
     return data_getter.get_person_data(person_tmdb_id);
 }
 
+/// Lists the library movies of a person grouped by department.
+/// When a TMDB token is given, the titles credited on TMDB but not owned are listed too.
+#[flutter_rust_bridge::frb]
+pub async fn get_person_filmography(
+    person_tmdb_id: i64,
+    token: Option<String>,
+) -> Result<PersonFilmography> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let person = data_getter.get_person_data(person_tmdb_id)?;
+    let profile_id = data_getter.get_active_profile_id()?;
+    let owned = data_getter.get_person_filmography(person_tmdb_id, profile_id)?;
+
+    let missing = match token {
+        Some(token) => {
+            let owned_tmdb_ids = data_getter.get_library_tmdb_ids()?;
+            retrieve_missing_filmography(person_tmdb_id, &owned_tmdb_ids, &token).await?
+        }
+        None => Vec::new(),
+    };
+
+    Ok(PersonFilmography::new(person, owned, missing))
+}
+
+#[flutter_rust_bridge::frb]
+pub fn get_credit_filter() -> Result<CreditFilter> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    return data_getter.get_credit_filter();
+}
+
+/// Saves the credit filter rules, used by every following scan.
+#[flutter_rust_bridge::frb]
+pub fn set_credit_filter(filter: CreditFilter) -> Result<()> {
+    let mut data_saver = DataSaver::new("movie_db.db".to_owned())?;
+    return data_saver.save_credit_filter(&filter);
+}
+
+/// Removes already stored credits that the current rules would filter out.
+#[flutter_rust_bridge::frb]
+pub fn reapply_credit_filter() -> Result<u32> {
+    let mut data_saver = DataSaver::new("movie_db.db".to_owned())?;
+    let removed = data_saver.reapply_credit_filter()?;
+    Ok(removed as u32)
+}
+
+#[flutter_rust_bridge::frb]
+pub fn quick_search(query: &str, limit: u32) -> Result<Vec<SearchResult>> {
+    let profile_id = DataGetter::new("movie_db.db".to_owned())?.get_active_profile_id()?;
+    return search_index("movie_db.db", profile_id, query, limit as usize);
+}
+
 #[flutter_rust_bridge::frb]
 pub async fn tempo_mount_smb() -> Result<()> {
     mount_smb("user", "passwd", "ip", "folder_path", "mount_point")?;
@@ -76,6 +647,136 @@ pub fn tempo_unmount_smb() -> Result<()> {
     Ok(())
 }
 
+/// Starts serving the library files and the REST api over HTTP, `port` 0 picking a free one,
+/// and returns the port. Players then need no mount to play an item.
+/// The server only listens on the loopback interface unless `allow_network` is set,
+/// other devices then needing to be paired with `create_pairing_code`.
+#[flutter_rust_bridge::frb]
+pub async fn start_stream_server(
+    source: MediaSource,
+    port: u16,
+    allow_network: bool,
+) -> Result<u16> {
+    return stream_server::start_stream_server(source, port, allow_network).await;
+}
+
+#[flutter_rust_bridge::frb]
+pub async fn stop_stream_server() -> Result<()> {
+    return stream_server::stop_stream_server().await;
+}
+
+/// Returns the URL of a media item on the running streaming server.
+#[flutter_rust_bridge::frb]
+pub async fn get_stream_url(media_id: i64) -> Result<String> {
+    return stream_url(media_id).await;
+}
+
+/// Reads the technical info of the library files from their container headers and returns
+/// the number of files probed. Only the files never probed are read unless `reprobe_all` is set.
+#[flutter_rust_bridge::frb]
+pub async fn probe_media_files(source: MediaSource, reprobe_all: bool) -> Result<u32> {
+    let files = FileSource::connect(source).await?;
+    return probe_library(&files, reprobe_all).await;
+}
+
+/// Finds the subtitle files lying next to the library videos, as "Movie.en.forced.srt",
+/// and returns the number of media whose subtitles changed.
+#[flutter_rust_bridge::frb]
+pub async fn discover_subtitle_files(source: MediaSource) -> Result<u32> {
+    let files = FileSource::connect(source).await?;
+    return discover_subtitles(&files).await;
+}
+
+#[flutter_rust_bridge::frb]
+pub fn get_subtitle_files(media_id: i64) -> Result<Vec<SubtitleFile>> {
+    let data_getter = media_getter(media_id)?;
+    return data_getter.get_subtitle_files(media_id);
+}
+
+/// Returns the URL a subtitle file is served at as WebVTT on the running streaming server.
+#[flutter_rust_bridge::frb]
+pub async fn get_subtitle_url(media_id: i64, subtitle_id: i64) -> Result<String> {
+    return subtitle_url(media_id, subtitle_id).await;
+}
+
+/// Generates posters and backdrops from video frames for the media without artwork, and the
+/// seek preview sprites of the probed media, with ffmpeg. Returns the number of media given
+/// new images. Generated images are only made again when `regenerate` is set.
+///
+/// A SMB library is read through the streaming server, which must be running.
+#[flutter_rust_bridge::frb]
+pub async fn generate_media_thumbnails(source: MediaSource, regenerate: bool) -> Result<u32> {
+    let files = FileSource::connect(source).await?;
+    return generate_thumbnails(&files, regenerate).await;
+}
+
+/// Returns the URL of the WebVTT index of the seek previews of a media on the running
+/// streaming server, None until they are generated.
+#[flutter_rust_bridge::frb]
+pub async fn get_trickplay_url(media_id: i64) -> Result<Option<String>> {
+    if !has_trickplay(media_id) {
+        return Ok(None);
+    }
+    return Ok(Some(trickplay_url(media_id).await?));
+}
+
+/// Whether media the player can not play as is can be remuxed or transcoded,
+/// which needs ffmpeg in the `PATH` or at `FFMPEG_PATH`.
+#[flutter_rust_bridge::frb(sync)]
+pub fn is_transcoding_available() -> bool {
+    return ffmpeg_path().is_some();
+}
+
+/// Decides whether a media is played as is, remuxed or transcoded for the player of the app,
+/// starting the HLS session it needs on the running streaming server.
+#[flutter_rust_bridge::frb]
+pub async fn get_playback_decision(
+    media_id: i64,
+    request: PlaybackRequest,
+) -> Result<PlaybackDecision> {
+    let profile_id = DataGetter::new("movie_db.db".to_owned())?.get_active_profile_id()?;
+    let mut decision = prepare_playback(profile_id, media_id, &request)
+        .await?
+        .ok_or_else(|| anyhow!("No media found for id: {}", media_id))?;
+    decision.url = format!("{}{}", server_url().await?, decision.url);
+    return Ok(decision);
+}
+
+/// Stops a transcoding session once playback ends, deleting its segments.
+#[flutter_rust_bridge::frb]
+pub async fn stop_transcoding(session_id: String) -> Result<()> {
+    let profile_id = DataGetter::new("movie_db.db".to_owned())?.get_active_profile_id()?;
+    if !stop_session(&session_id, profile_id).await {
+        return Err(anyhow!(
+            "No transcoding session found for id: {}",
+            session_id
+        ));
+    }
+    return Ok(());
+}
+
+/// Returns a one-time code pairing a device with the active profile, valid for 5 minutes.
+#[flutter_rust_bridge::frb]
+pub fn create_pairing_code() -> Result<String> {
+    let mut data_saver = DataSaver::new("movie_db.db".to_owned())?;
+    let profile_id = DataGetter::new("movie_db.db".to_owned())?.get_active_profile_id()?;
+    return data_saver.create_pairing_code(profile_id);
+}
+
+/// Lists the devices paired with the network api, of every profile.
+#[flutter_rust_bridge::frb]
+pub fn get_paired_devices() -> Result<Vec<PairedDevice>> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    return data_getter.get_devices();
+}
+
+/// Revokes the token of a paired device, which then has to be paired again.
+#[flutter_rust_bridge::frb]
+pub fn revoke_device(device_id: i64) -> Result<()> {
+    let mut data_saver = DataSaver::new("movie_db.db".to_owned())?;
+    return data_saver.revoke_device(device_id);
+}
+
 #[flutter_rust_bridge::frb]
 pub fn open_video(path: &str) -> Result<()> {
     open::that(path).with_context(|| format!("An error occurred when opening {}", path))?;
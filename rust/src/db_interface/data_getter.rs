@@ -1,44 +1,132 @@
-use crate::movie_data::movie_data::{MediaData, MovieSnapshot, PersonData, PersonSnapshot};
-use anyhow::{Context, Result};
-use rusqlite::Connection;
+use crate::db_interface::{
+    data_saver::DataSaver,
+    home_row_saver::{HOME_ROWS, HOME_ROW_ITEM_COUNT},
+};
+use crate::movie_data::movie_data::{
+    AllowedJob, CollectionDetail, CollectionPartSnapshot, CollectionSnapshot, CreditFilter,
+    FacetSnapshot, FilmographyEntry, HomeRowConfig, MediaChapter, MediaData, MediaInfo, MediaList,
+    MediaStream, MovieData, MovieSnapshot, PairedDevice, PersonData, PersonSnapshot, PlaybackEntry,
+    PlaybackState, Profile, SearchResult, SmartCollection, SmartQuery, SmartSort, SubtitleFile,
+};
+use anyhow::{anyhow, Context, Result};
+use rusqlite::{types::Value, Connection, OptionalExtension, ToSql};
+use std::collections::{HashMap, HashSet};
+
+/// Columns read into a `MovieSnapshot`, from `Movie AS m` joined with `SNAPSHOT_JOIN`.
+const SNAPSHOT_COLUMNS: &str =
+    "m.id, m.file_path, m.title, m.vote_average AS rating, m.release_date, m.poster,
+    COALESCE(p.watched, 0)";
+
+/// Join bringing the playback state of the `:profile_id` profile read by `SNAPSHOT_COLUMNS`.
+const SNAPSHOT_JOIN: &str =
+    "LEFT JOIN Playback_State AS p ON p.media_id = m.id AND p.profile_id = :profile_id";
+
+/// Condition hiding the movies rated above the content rating limit of the `:profile_id` profile.
+/// Movies without a ranked rating are only hidden when the profile hides unrated titles.
+const SNAPSHOT_ALLOWED: &str = "NOT EXISTS (SELECT 1 FROM Profile AS pr
+    INNER JOIN Certification_Rank AS lim ON lim.certification = pr.max_certification
+    LEFT JOIN Certification_Rank AS cur ON cur.certification = m.certification
+    WHERE pr.id = :profile_id
+        AND (cur.rank > lim.rank OR (cur.rank IS NULL AND pr.hide_unrated = 1)))";
+
+/// Condition hiding the collection parts `p` missing from the library from the `:profile_id`
+/// profile when it hides unrated titles, the content rating of a missing part being unknown.
+const MISSING_PART_ALLOWED: &str = "NOT EXISTS (SELECT 1 FROM Profile AS pr
+    INNER JOIN Certification_Rank AS lim ON lim.certification = pr.max_certification
+    WHERE pr.id = :profile_id AND pr.hide_unrated = 1)";
+
+/// Columns read into the `PlaybackState` of a `PlaybackEntry`, after `SNAPSHOT_COLUMNS`.
+const ENTRY_STATE_COLUMNS: &str = "m.id, COALESCE(p.watched, 0), COALESCE(p.play_count, 0),
+    p.last_played, COALESCE(p.position, 0), COALESCE(p.duration, 0), p.user_rating";
+
+/// Movies watched by the `:profile_id` profile or in its favourites, counted twice when both.
+const PROFILE_TASTE_MEDIA: &str = "SELECT media_id FROM Playback_State
+        WHERE profile_id = :profile_id AND watched = 1
+    UNION ALL
+    SELECT li.media_id FROM Media_List_Item AS li
+        INNER JOIN Media_List AS l ON l.id = li.list_id
+        WHERE l.profile_id = :profile_id AND l.kind = 'favourites'";
+
+/// Number of genres, ranked by `PROFILE_TASTE_MEDIA`, counted as favourites of a profile.
+const FAVOURITE_GENRE_COUNT: u32 = 3;
+
+/// Number of last watched movies whose directors and top-billed actors feed the home screen.
+const RECENT_WATCH_COUNT: u32 = 5;
+
+/// TMDB paths of the stored poster and backdrop of each movie, by file path.
+pub type ArtworkSources = HashMap<String, (Option<String>, Option<String>)>;
+
+/// User-locked fields of each movie, by file path.
+pub type LockedFields = HashMap<String, HashSet<String>>;
 
 pub struct DataGetter {
     conn: Connection,
 }
 
+/// Media types listed by `get_media_snapshot` and the table holding them.
+const MEDIA_TABLES: [(&str, &str); 1] = [("movie", "Movie")];
+
+/// Browsable facet kinds and the table holding their values, linked through `Movie_<table>`.
+const FACET_TABLES: [(&str, &str); 5] = [
+    ("genre", "Genre"),
+    ("studio", "Company"),
+    ("country", "Country"),
+    ("language", "Language"),
+    ("keyword", "Keyword"),
+];
+
 impl DataGetter {
     pub fn new(db_path: String) -> Result<Self> {
         let conn = Connection::open(&db_path)
             .with_context(|| format!("Failed to open database connection at : {}", &db_path))?;
-        Ok(Self { conn: conn })
+        Ok(Self { conn })
+    }
+
+    /// Builds a `MovieSnapshot` from a row selected with `SNAPSHOT_COLUMNS`.
+    fn snapshot_from_row(row: &rusqlite::Row) -> rusqlite::Result<MovieSnapshot> {
+        Ok(MovieSnapshot::new(
+            row.get(0)?,
+            row.get(1)?,
+            row.get(2)?,
+            row.get(3)?,
+            row.get(4)?,
+            row.get(5)?,
+            row.get(6)?,
+        ))
     }
 
     //TODO add filters
-    pub fn get_media_snapshot(&self, media_type: &str) -> Result<Vec<MovieSnapshot>> {
+    pub fn get_media_snapshot(
+        &self,
+        media_type: &str,
+        profile_id: i64,
+    ) -> Result<Vec<MovieSnapshot>> {
+        // The table name is formatted into the query, only known media types are accepted
+        let table = MEDIA_TABLES
+            .iter()
+            .find(|(kind, _)| kind.eq_ignore_ascii_case(media_type))
+            .map(|(_, table)| *table)
+            .ok_or_else(|| anyhow!("Unknown media type: {}", media_type))?;
         let query_str = format!(
-            "SELECT id, file_path, title, vote_average AS rating, release_date, poster
-             FROM {}
-             ORDER BY title COLLATE NOCASE ",
-            media_type
+            "SELECT {}
+             FROM {} AS m
+             {}
+             WHERE {}
+             ORDER BY m.title COLLATE NOCASE ",
+            SNAPSHOT_COLUMNS, table, SNAPSHOT_JOIN, SNAPSHOT_ALLOWED
         );
 
         let mut stmt = self
             .conn
             .prepare(&query_str)
-            .with_context(|| format!("Failed to prepare statement for data selection"))?;
+            .with_context(|| "Failed to prepare statement for data selection")?;
 
         let mapped_rows = stmt
-            .query_map([], |row| {
-                Ok(MovieSnapshot::new(
-                    row.get(0)?,
-                    row.get(1)?,
-                    row.get(2)?,
-                    row.get(3)?,
-                    row.get(4)?,
-                    row.get(5)?,
-                ))
-            })
-            .with_context(|| format!("Failed to get select result"))?;
+            .query_map(
+                rusqlite::named_params! {":profile_id": profile_id},
+                Self::snapshot_from_row,
+            )
+            .with_context(|| "Failed to get select result")?;
 
         Ok(mapped_rows
             .filter_map(|res| res.ok())
@@ -46,11 +134,13 @@ impl DataGetter {
     }
 
     pub fn get_media_data(&self, media_id: i64) -> Result<MediaData> {
+        let media_info = self.get_media_info(media_id)?;
         let mut stmt = self
             .conn
             .prepare(
                 "SELECT id, file_path, file_optional_info, original_title, title,
-                vote_average AS rating, release_date, summary, poster, backdrop
+                vote_average AS rating, release_date, summary, poster, backdrop,
+                runtime, tagline, status, budget, revenue, imdb_id, certification
          FROM Movie
          WHERE id = ?1",
             )
@@ -58,50 +148,267 @@ impl DataGetter {
 
         let media = stmt
             .query_row([media_id], |row| {
-                Ok(MediaData::new(
-                    row.get(0)?,
-                    row.get(1)?,
-                    row.get(2)?,
-                    row.get(3)?,
-                    row.get(4)?,
-                    row.get(5)?,
-                    row.get(6)?,
-                    row.get(7)?,
-                    row.get(8)?,
-                    row.get(9)?,
-                ))
+                Ok(MediaData {
+                    id: row.get(0)?,
+                    file_path: row.get(1)?,
+                    file_optional_info: row.get(2)?,
+                    original_title: row.get(3)?,
+                    title: row.get(4)?,
+                    rating: row.get(5)?,
+                    release_date: row.get(6)?,
+                    summary: row.get(7)?,
+                    poster: row.get(8)?,
+                    backdrop: row.get(9)?,
+                    runtime: row.get(10)?,
+                    tagline: row.get(11)?,
+                    status: row.get(12)?,
+                    budget: row.get(13)?,
+                    revenue: row.get(14)?,
+                    imdb_id: row.get(15)?,
+                    certification: row.get(16)?,
+                    media_info,
+                })
             })
             .with_context(|| "Failed to fetch media data")?;
 
         Ok(media)
     }
 
+    /// Returns the probed technical info of a media with its tracks, None when not probed yet.
+    pub fn get_media_info(&self, media_id: i64) -> Result<Option<MediaInfo>> {
+        let info = self
+            .conn
+            .query_row(
+                "SELECT container, duration, width, height, video_codec, hdr_format, bitrate
+             FROM Media_Info
+             WHERE movie_id = ?1",
+                [media_id],
+                |row| {
+                    Ok(MediaInfo {
+                        container: row.get(0)?,
+                        duration: row.get(1)?,
+                        width: row.get(2)?,
+                        height: row.get(3)?,
+                        video_codec: row.get(4)?,
+                        hdr_format: row.get(5)?,
+                        bitrate: row.get(6)?,
+                        streams: vec![],
+                        chapters: vec![],
+                    })
+                },
+            )
+            .optional()
+            .with_context(|| format!("Failed to get media info of media {}", media_id))?;
+        let Some(mut info) = info else {
+            return Ok(None);
+        };
+
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT stream_index, kind, codec, language, title, channels, is_default, is_forced
+             FROM Media_Stream
+             WHERE movie_id = ?1
+             ORDER BY stream_index",
+            )
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map([media_id], |row| {
+                Ok(MediaStream {
+                    index: row.get(0)?,
+                    kind: row.get(1)?,
+                    codec: row.get(2)?,
+                    language: row.get(3)?,
+                    title: row.get(4)?,
+                    channels: row.get(5)?,
+                    is_default: row.get(6)?,
+                    is_forced: row.get(7)?,
+                })
+            })
+            .with_context(|| "Failed to get select result")?;
+
+        info.streams = mapped_rows.filter_map(|res| res.ok()).collect();
+        info.chapters = self.get_media_chapters(media_id)?;
+        Ok(Some(info))
+    }
+
+    /// Returns the chapters of a media in playback order, empty when it has none or was never probed.
+    pub fn get_media_chapters(&self, media_id: i64) -> Result<Vec<MediaChapter>> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT chapter_index, title, start_time, end_time
+             FROM Media_Chapter
+             WHERE movie_id = ?1
+             ORDER BY chapter_index",
+            )
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map([media_id], |row| {
+                Ok(MediaChapter {
+                    index: row.get(0)?,
+                    title: row.get(1)?,
+                    start: row.get(2)?,
+                    end: row.get(3)?,
+                })
+            })
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows.filter_map(|res| res.ok()).collect())
+    }
+
+    /// Returns the id and file path of the media whose file was never probed, or of every media.
+    pub fn get_media_to_probe(&self, all: bool) -> Result<Vec<(i64, String)>> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT m.id, m.file_path
+             FROM Movie AS m
+             LEFT JOIN Media_Info AS i
+                ON i.movie_id = m.id
+             WHERE ?1 OR i.movie_id IS NULL
+             ORDER BY m.id",
+            )
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map([all], |row| {
+                Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+            })
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows.filter_map(|res| res.ok()).collect())
+    }
+
+    /// Returns the file path of a media item, relative to the library root, if it exists.
+    pub fn get_media_file_path(&self, media_id: i64) -> Result<Option<String>> {
+        self.conn
+            .query_row(
+                "SELECT file_path FROM Movie WHERE id = ?1",
+                [media_id],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .with_context(|| format!("Failed to get file path for media {}", media_id))
+    }
+
+    /// Returns the id and file path of every media.
+    pub fn get_media_file_paths(&self) -> Result<Vec<(i64, String)>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT id, file_path FROM Movie ORDER BY id")
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+            })
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows.filter_map(|res| res.ok()).collect())
+    }
+
+    /// Returns the id, file path, poster and backdrop of every movie, empty artwork paths
+    /// meaning none is stored.
+    pub fn get_media_artwork(&self) -> Result<Vec<(i64, String, String, String)>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT id, file_path, poster, backdrop FROM Movie ORDER BY id")
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map([], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+            })
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows.filter_map(|res| res.ok()).collect())
+    }
+
+    /// Returns the subtitle files found next to the video of a media, by file name.
+    pub fn get_subtitle_files(&self, media_id: i64) -> Result<Vec<SubtitleFile>> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT id, file_path, format, language, title, is_forced, is_sdh
+             FROM Subtitle_File
+             WHERE movie_id = ?1
+             ORDER BY file_path",
+            )
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map([media_id], Self::subtitle_file_from_row)
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows.filter_map(|res| res.ok()).collect())
+    }
+
+    /// Returns a subtitle file of a media, None when the media has no such subtitle.
+    pub fn get_subtitle_file(
+        &self,
+        media_id: i64,
+        subtitle_id: i64,
+    ) -> Result<Option<SubtitleFile>> {
+        self.conn
+            .query_row(
+                "SELECT id, file_path, format, language, title, is_forced, is_sdh
+             FROM Subtitle_File
+             WHERE id = ?1 AND movie_id = ?2",
+                [subtitle_id, media_id],
+                Self::subtitle_file_from_row,
+            )
+            .optional()
+            .with_context(|| {
+                format!(
+                    "Failed to get subtitle {} of media {}",
+                    subtitle_id, media_id
+                )
+            })
+    }
+
+    fn subtitle_file_from_row(row: &rusqlite::Row) -> rusqlite::Result<SubtitleFile> {
+        Ok(SubtitleFile {
+            id: row.get(0)?,
+            file_path: row.get(1)?,
+            format: row.get(2)?,
+            language: row.get(3)?,
+            title: row.get(4)?,
+            is_forced: row.get(5)?,
+            is_sdh: row.get(6)?,
+        })
+    }
+
     pub fn get_media_cast(&self, media_id: i64) -> Result<Vec<PersonSnapshot>> {
-        let query_str = format!(
-            "SELECT c.tmdb_id, c.name, c.character, c.job_name, p.picture_path
+        let query_str = "SELECT c.tmdb_id, c.name, c.character, c.job_name, p.picture_path,
+                COALESCE(c.department, 'Acting'), c.credit_order, COALESCE(c.credit_id, '')
              FROM Credits AS c
              INNER JOIN Person AS p
                 ON c.tmdb_id = p.tmdb_id
              WHERE c.movie_id = ?1 AND c.job_name = 'actor'
-             ORDER BY c.id ",
-        );
+             ORDER BY c.credit_order IS NULL, c.credit_order, c.id ";
 
         let mut stmt = self
             .conn
-            .prepare(&query_str)
-            .with_context(|| format!("Failed to prepare statement for data selection"))?;
+            .prepare(query_str)
+            .with_context(|| "Failed to prepare statement for data selection")?;
 
         let mapped_rows = stmt
             .query_map([media_id], |row| {
-                Ok(PersonSnapshot::new(
-                    row.get(0)?,
-                    row.get(1)?,
-                    row.get(2)?,
-                    row.get(3)?,
-                    row.get(4)?,
-                ))
+                Ok(PersonSnapshot {
+                    tmdb_id: row.get(0)?,
+                    name: row.get(1)?,
+                    character: row.get(2)?,
+                    job_name: row.get(3)?,
+                    picture_path: row.get(4)?,
+                    department: row.get(5)?,
+                    credit_order: row.get(6)?,
+                    credit_id: row.get(7)?,
+                })
             })
-            .with_context(|| format!("Failed to get select result"))?;
+            .with_context(|| "Failed to get select result")?;
 
         Ok(mapped_rows
             .filter_map(|res| res.ok())
@@ -109,31 +416,33 @@ impl DataGetter {
     }
 
     pub fn get_media_crew(&self, media_id: i64) -> Result<Vec<PersonSnapshot>> {
-        let query_str = format!(
-            "SELECT c.tmdb_id, c.name, c.character, c.job_name, p.picture_path
+        let query_str = "SELECT c.tmdb_id, c.name, c.character, c.job_name, p.picture_path,
+                COALESCE(c.department, ''), c.credit_order, COALESCE(c.credit_id, '')
              FROM Credits AS c
              INNER JOIN Person AS p
                 ON c.tmdb_id = p.tmdb_id
              WHERE c.movie_id = ?1 AND c.job_name != 'actor'
-             ORDER BY c.id",
-        );
+             ORDER BY c.department, c.id";
 
         let mut stmt = self
             .conn
-            .prepare(&query_str)
-            .with_context(|| format!("Failed to prepare statement for data selection"))?;
+            .prepare(query_str)
+            .with_context(|| "Failed to prepare statement for data selection")?;
 
         let mapped_rows = stmt
             .query_map([media_id], |row| {
-                Ok(PersonSnapshot::new(
-                    row.get(0)?,
-                    row.get(1)?,
-                    row.get(2)?,
-                    row.get(3)?,
-                    row.get(4)?,
-                ))
+                Ok(PersonSnapshot {
+                    tmdb_id: row.get(0)?,
+                    name: row.get(1)?,
+                    character: row.get(2)?,
+                    job_name: row.get(3)?,
+                    picture_path: row.get(4)?,
+                    department: row.get(5)?,
+                    credit_order: row.get(6)?,
+                    credit_id: row.get(7)?,
+                })
             })
-            .with_context(|| format!("Failed to get select result"))?;
+            .with_context(|| "Failed to get select result")?;
 
         Ok(mapped_rows
             .filter_map(|res| res.ok())
@@ -144,23 +453,1335 @@ impl DataGetter {
         let mut stmt = self
             .conn
             .prepare(
-                "SELECT tmdb_id, name, summary, picture_path
+                "SELECT tmdb_id, name, summary, picture_path, birthday, deathday, place_of_birth,
+                known_for_department, gender
          FROM Person
          WHERE tmdb_id = ?1",
             )
             .with_context(|| "Failed to prepare statement for data selection")?;
 
+        let also_known_as = self.get_person_aliases(tmdb_id)?;
+
         let person = stmt
             .query_row([tmdb_id], |row| {
-                Ok(PersonData::new(
-                    row.get(0)?,
-                    row.get(1)?,
-                    row.get(2)?,
-                    row.get(3)?,
-                ))
+                Ok(PersonData {
+                    tmdb_id: row.get(0)?,
+                    name: row.get(1)?,
+                    summary: row.get(2)?,
+                    picture_path: row.get(3)?,
+                    birthday: row.get(4)?,
+                    deathday: row.get(5)?,
+                    place_of_birth: row.get(6)?,
+                    known_for_department: row.get(7)?,
+                    gender: row.get(8)?,
+                    also_known_as,
+                })
             })
             .with_context(|| "Failed to fetch media data")?;
 
         Ok(person)
     }
+
+    fn get_person_aliases(&self, tmdb_id: i64) -> Result<Vec<String>> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT name
+         FROM Person_Alias
+         WHERE person_id = ?1
+         ORDER BY id",
+            )
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map([tmdb_id], |row| row.get::<_, String>(0))
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows
+            .filter_map(|res| res.ok())
+            .collect::<Vec<String>>())
+    }
+
+    /// Returns every movie and person as an unscored search entry, used to build the quick search index.
+    pub fn get_search_entries(&self) -> Result<Vec<SearchResult>> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT 'movie', id, title, original_title, substr(release_date, 1, 4), poster
+             FROM Movie
+             UNION ALL
+             SELECT 'person', tmdb_id, name, '', known_for_department, picture_path
+             FROM Person",
+            )
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map([], |row| {
+                let kind: String = row.get(0)?;
+                let title: String = row.get(2)?;
+                let original_title: String = row.get(3)?;
+                let detail: Option<String> = row.get(4)?;
+
+                let subtitle = if original_title.is_empty() || original_title == title {
+                    detail.unwrap_or_default()
+                } else {
+                    format!("{} ({})", original_title, detail.unwrap_or_default())
+                };
+
+                Ok(SearchResult::new(
+                    kind,
+                    row.get(1)?,
+                    title,
+                    subtitle,
+                    row.get(5)?,
+                ))
+            })
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows
+            .filter_map(|res| res.ok())
+            .collect::<Vec<SearchResult>>())
+    }
+
+    /// Returns every library movie a person is credited in, with their character or job.
+    /// Movies above the content rating limit of the profile are left out.
+    pub fn get_person_filmography(
+        &self,
+        tmdb_id: i64,
+        profile_id: i64,
+    ) -> Result<Vec<FilmographyEntry>> {
+        let query_str = format!(
+            "SELECT m.id, m.tmdb_id, m.title, m.release_date, m.poster,
+                COALESCE(c.department, CASE WHEN c.job_name = 'actor' THEN 'Acting' ELSE 'Crew' END),
+                CASE WHEN c.job_name = 'actor' THEN c.character ELSE '' END,
+                c.job_name
+             FROM Credits AS c
+             INNER JOIN Movie AS m
+                ON c.movie_id = m.id
+             WHERE c.tmdb_id = :tmdb_id AND {}
+             ORDER BY m.release_date",
+            SNAPSHOT_ALLOWED
+        );
+        let mut stmt = self
+            .conn
+            .prepare(&query_str)
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map(
+                rusqlite::named_params! {":tmdb_id": tmdb_id, ":profile_id": profile_id},
+                |row| {
+                    Ok(FilmographyEntry {
+                        media_id: row.get(0)?,
+                        tmdb_id: row.get(1)?,
+                        title: row.get(2)?,
+                        release_date: row.get::<_, Option<String>>(3)?.unwrap_or_default(),
+                        poster: row.get(4)?,
+                        department: row.get(5)?,
+                        character: row.get::<_, Option<String>>(6)?.unwrap_or_default(),
+                        job_name: row.get(7)?,
+                    })
+                },
+            )
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows
+            .filter_map(|res| res.ok())
+            .collect::<Vec<FilmographyEntry>>())
+    }
+
+    /// Returns the ids of the stored persons refreshed within the last `max_age_days` days.
+    pub fn get_fresh_person_ids(&self, max_age_days: u32) -> Result<HashSet<i64>> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT tmdb_id FROM Person
+             WHERE updated_at >= CAST(strftime('%s', 'now') AS INTEGER) - ?1 * 86400",
+            )
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map([max_age_days], |row| row.get::<_, i64>(0))
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows
+            .filter_map(|res| res.ok())
+            .collect::<HashSet<i64>>())
+    }
+
+    /// Returns the TMDB paths of the stored poster and backdrop of every movie.
+    pub fn get_artwork_sources(&self) -> Result<ArtworkSources> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT file_path, poster_source, backdrop_source FROM Movie")
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    (
+                        row.get::<_, Option<String>>(1)?,
+                        row.get::<_, Option<String>>(2)?,
+                    ),
+                ))
+            })
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows.filter_map(|res| res.ok()).collect())
+    }
+
+    /// Returns the stored movies to refresh, optionally restricted to one movie or to the
+    /// movies not refreshed within the last `max_age_days` days.
+    pub fn get_movies_to_refresh(
+        &self,
+        media_id: Option<i64>,
+        max_age_days: Option<u32>,
+    ) -> Result<Vec<MovieData>> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT id, file_path, tmdb_id FROM Movie
+             WHERE (?1 IS NULL OR id = ?1)
+             AND (?2 IS NULL OR updated_at < CAST(strftime('%s', 'now') AS INTEGER) - ?2 * 86400)",
+            )
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map((media_id, max_age_days), |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Option<i64>>(2)?,
+                ))
+            })
+            .with_context(|| "Failed to get select result")?;
+
+        let movies = mapped_rows
+            .filter_map(|res| res.ok())
+            .filter_map(|(id, file_path, tmdb_id)| {
+                let mut movie = MovieData::new(&file_path)
+                    .map_err(|e| {
+                        tracing::error!(
+                            "Failed to rebuild stored movie {} \n Caused by {:?}",
+                            file_path,
+                            e
+                        );
+                    })
+                    .ok()?;
+                movie.set_id(id).set_tmdb_id(tmdb_id.unwrap_or_default());
+                Some(movie)
+            })
+            .collect();
+
+        Ok(movies)
+    }
+
+    /// Returns the user-locked fields of every movie with at least one lock.
+    pub fn get_locked_fields(&self) -> Result<LockedFields> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT m.file_path, l.field FROM Movie_Lock l
+             JOIN Movie m ON m.id = l.movie_id",
+            )
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })
+            .with_context(|| "Failed to get select result")?;
+
+        let mut locked_fields = LockedFields::new();
+        for (file_path, field) in mapped_rows.filter_map(|res| res.ok()) {
+            locked_fields.entry(file_path).or_default().insert(field);
+        }
+        Ok(locked_fields)
+    }
+
+    /// Returns the fields of a movie edited by the user and locked against refreshes.
+    pub fn get_media_locked_fields(&self, media_id: i64) -> Result<Vec<String>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT field FROM Movie_Lock WHERE movie_id = ?1 ORDER BY field")
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map([media_id], |row| row.get::<_, String>(0))
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows.filter_map(|res| res.ok()).collect())
+    }
+
+    /// Returns the TMDB ids of every movie in the library.
+    pub fn get_library_tmdb_ids(&self) -> Result<HashSet<i64>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT tmdb_id FROM Movie WHERE tmdb_id IS NOT NULL")
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map([], |row| row.get::<_, i64>(0))
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows
+            .filter_map(|res| res.ok())
+            .collect::<HashSet<i64>>())
+    }
+
+    pub fn get_credit_filter(&self) -> Result<CreditFilter> {
+        let (max_cast_size, include_uncredited) = self
+            .conn
+            .query_row(
+                "SELECT max_cast_size, include_uncredited FROM Credit_Filter WHERE id = 1",
+                [],
+                |row| Ok((row.get::<_, Option<u32>>(0)?, row.get::<_, bool>(1)?)),
+            )
+            .with_context(|| "Failed to fetch credit filter settings")?;
+
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT department, job
+         FROM Credit_Filter_Job
+         ORDER BY department, job",
+            )
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let allowed_jobs = stmt
+            .query_map([], |row| {
+                Ok(AllowedJob {
+                    department: row.get(0)?,
+                    job: row.get(1)?,
+                })
+            })
+            .with_context(|| "Failed to get select result")?
+            .filter_map(|res| res.ok())
+            .collect::<Vec<AllowedJob>>();
+
+        Ok(CreditFilter::new(
+            allowed_jobs,
+            max_cast_size,
+            include_uncredited,
+        ))
+    }
+
+    // region: ---- COLLECTIONS ----
+    /// Returns every collection with at least one movie in the library the profile may see.
+    /// Parts are only counted when the profile may see them.
+    pub fn get_collections(&self, profile_id: i64) -> Result<Vec<CollectionSnapshot>> {
+        let query_str = format!(
+            "SELECT c.id, c.name, c.poster,
+                (SELECT COUNT(*) FROM Movie AS m WHERE m.collection_id = c.id AND {allowed}),
+                (SELECT COUNT(*) FROM Collection_Part AS p
+                WHERE p.collection_id = c.id
+                AND (EXISTS (SELECT 1 FROM Movie AS m WHERE m.tmdb_id = p.tmdb_id AND {allowed})
+                    OR (NOT EXISTS (SELECT 1 FROM Movie AS m WHERE m.tmdb_id = p.tmdb_id)
+                        AND {missing_allowed})))
+         FROM Collection AS c
+         WHERE EXISTS (SELECT 1 FROM Movie AS m WHERE m.collection_id = c.id AND {allowed})
+         ORDER BY c.name COLLATE NOCASE",
+            allowed = SNAPSHOT_ALLOWED,
+            missing_allowed = MISSING_PART_ALLOWED,
+        );
+        let mut stmt = self
+            .conn
+            .prepare(&query_str)
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map(rusqlite::named_params! {":profile_id": profile_id}, |row| {
+                Ok(CollectionSnapshot::new(
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                ))
+            })
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows
+            .filter_map(|res| res.ok())
+            .collect::<Vec<CollectionSnapshot>>())
+    }
+
+    /// Whether a collection has a movie in the library the profile may see.
+    fn is_collection_allowed(&self, profile_id: i64, collection_id: i64) -> Result<bool> {
+        let query_str = format!(
+            "SELECT EXISTS (SELECT 1 FROM Movie AS m WHERE m.collection_id = :collection_id AND {})",
+            SNAPSHOT_ALLOWED
+        );
+        self.conn
+            .query_row(
+                &query_str,
+                rusqlite::named_params! {
+                    ":collection_id": collection_id,
+                    ":profile_id": profile_id,
+                },
+                |row| row.get::<_, bool>(0),
+            )
+            .with_context(|| format!("Failed to check access to collection {}", collection_id))
+    }
+
+    /// Returns a collection with the library movies belonging to it, in release order.
+    pub fn get_collection(&self, collection_id: i64, profile_id: i64) -> Result<CollectionDetail> {
+        if !self.is_collection_allowed(profile_id, collection_id)? {
+            return Err(anyhow!("No collection found for id: {}", collection_id));
+        }
+        let (id, name, overview, poster, backdrop) = self
+            .conn
+            .query_row(
+                "SELECT id, name, COALESCE(overview, ''), poster, backdrop
+         FROM Collection
+         WHERE id = ?1",
+                [collection_id],
+                |row| {
+                    Ok((
+                        row.get(0)?,
+                        row.get(1)?,
+                        row.get(2)?,
+                        row.get(3)?,
+                        row.get(4)?,
+                    ))
+                },
+            )
+            .with_context(|| "Failed to fetch collection data")?;
+
+        let mut stmt = self
+            .conn
+            .prepare(&format!(
+                "SELECT {}
+         FROM Movie AS m
+         {}
+         WHERE m.collection_id = :collection_id AND {}
+         ORDER BY m.release_date",
+                SNAPSHOT_COLUMNS, SNAPSHOT_JOIN, SNAPSHOT_ALLOWED
+            ))
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let parts = stmt
+            .query_map(
+                rusqlite::named_params! {
+                    ":collection_id": collection_id,
+                    ":profile_id": profile_id,
+                },
+                Self::snapshot_from_row,
+            )
+            .with_context(|| "Failed to get select result")?
+            .filter_map(|res| res.ok())
+            .collect::<Vec<MovieSnapshot>>();
+
+        Ok(CollectionDetail {
+            id,
+            name,
+            overview,
+            poster,
+            backdrop,
+            parts,
+        })
+    }
+
+    /// Returns the movies of a collection that are not in the library, in release order.
+    /// Their content rating being unknown, they are hidden from profiles hiding unrated titles.
+    pub fn get_collection_missing_parts(
+        &self,
+        collection_id: i64,
+        profile_id: i64,
+    ) -> Result<Vec<CollectionPartSnapshot>> {
+        if !self.is_collection_allowed(profile_id, collection_id)? {
+            return Err(anyhow!("No collection found for id: {}", collection_id));
+        }
+
+        let query_str = format!(
+            "SELECT p.tmdb_id, p.title, COALESCE(p.release_date, ''), p.poster_path
+         FROM Collection_Part AS p
+         WHERE p.collection_id = :collection_id
+         AND NOT EXISTS (SELECT 1 FROM Movie AS m WHERE m.tmdb_id = p.tmdb_id)
+         AND {}
+         ORDER BY p.release_date = '', p.release_date",
+            MISSING_PART_ALLOWED
+        );
+        let mut stmt = self
+            .conn
+            .prepare(&query_str)
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map(
+                rusqlite::named_params! {
+                    ":collection_id": collection_id,
+                    ":profile_id": profile_id,
+                },
+                |row| {
+                    Ok(CollectionPartSnapshot::new(
+                        row.get(0)?,
+                        row.get(1)?,
+                        row.get(2)?,
+                        row.get(3)?,
+                    ))
+                },
+            )
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows
+            .filter_map(|res| res.ok())
+            .collect::<Vec<CollectionPartSnapshot>>())
+    }
+    // endregion
+
+    // region: ---- FACETS ----
+    fn facet_table(kind: &str) -> Result<&'static str> {
+        FACET_TABLES
+            .iter()
+            .find(|(facet_kind, _)| *facet_kind == kind)
+            .map(|(_, table)| *table)
+            .ok_or_else(|| anyhow!("Unknown facet kind: {}", kind))
+    }
+
+    /// Returns every value of a facet kind with the number of movies using it.
+    pub fn get_facets(&self, kind: &str) -> Result<Vec<FacetSnapshot>> {
+        let table = Self::facet_table(kind)?;
+        let query_str = format!(
+            "SELECT CAST(f.id AS TEXT), f.name, COUNT(l.movie_id)
+             FROM {table} AS f
+             INNER JOIN Movie_{table} AS l
+                ON l.{column}_id = f.id
+             GROUP BY f.id
+             ORDER BY f.name COLLATE NOCASE",
+            table = table,
+            column = table.to_lowercase(),
+        );
+
+        let mut stmt = self
+            .conn
+            .prepare(&query_str)
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map([], |row| {
+                Ok(FacetSnapshot::new(
+                    kind.to_owned(),
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                ))
+            })
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows
+            .filter_map(|res| res.ok())
+            .collect::<Vec<FacetSnapshot>>())
+    }
+
+    /// Returns the genres, studios, countries, languages and keywords of a movie.
+    pub fn get_media_facets(&self, media_id: i64) -> Result<Vec<FacetSnapshot>> {
+        let mut facets = Vec::new();
+        for (kind, table) in FACET_TABLES.iter() {
+            let query_str = format!(
+                "SELECT CAST(f.id AS TEXT), f.name
+                 FROM {table} AS f
+                 INNER JOIN Movie_{table} AS l
+                    ON l.{column}_id = f.id
+                 WHERE l.movie_id = ?1
+                 ORDER BY l.id",
+                table = table,
+                column = table.to_lowercase(),
+            );
+
+            let mut stmt = self
+                .conn
+                .prepare(&query_str)
+                .with_context(|| "Failed to prepare statement for data selection")?;
+
+            let mapped_rows = stmt
+                .query_map([media_id], |row| {
+                    Ok(FacetSnapshot::new(
+                        kind.to_string(),
+                        row.get(0)?,
+                        row.get(1)?,
+                        1,
+                    ))
+                })
+                .with_context(|| "Failed to get select result")?;
+
+            facets.extend(mapped_rows.filter_map(|res| res.ok()));
+        }
+        Ok(facets)
+    }
+
+    /// Returns the snapshots of every movie linked to the given facet value.
+    pub fn get_media_snapshot_by_facet(
+        &self,
+        kind: &str,
+        key: &str,
+        profile_id: i64,
+    ) -> Result<Vec<MovieSnapshot>> {
+        let table = Self::facet_table(kind)?;
+        let query_str = format!(
+            "SELECT {columns}
+             FROM Movie AS m
+             INNER JOIN Movie_{table} AS l
+                ON l.movie_id = m.id
+             {join}
+             WHERE CAST(l.{column}_id AS TEXT) = :key AND {allowed}
+             ORDER BY m.release_date",
+            columns = SNAPSHOT_COLUMNS,
+            join = SNAPSHOT_JOIN,
+            allowed = SNAPSHOT_ALLOWED,
+            table = table,
+            column = table.to_lowercase(),
+        );
+
+        let mut stmt = self
+            .conn
+            .prepare(&query_str)
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map(
+                rusqlite::named_params! {":key": key, ":profile_id": profile_id},
+                Self::snapshot_from_row,
+            )
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows
+            .filter_map(|res| res.ok())
+            .collect::<Vec<MovieSnapshot>>())
+    }
+    // endregion
+
+    // region: ---- PLAYBACK ----
+    /// Returns the playback state of a media item, a default one when it was never played.
+    pub fn get_playback_state(&self, profile_id: i64, media_id: i64) -> Result<PlaybackState> {
+        let state = self
+            .conn
+            .query_row(
+                "SELECT media_id, watched, play_count, last_played, position, duration, user_rating
+             FROM Playback_State
+             WHERE profile_id = ?1 AND media_id = ?2",
+                (profile_id, media_id),
+                Self::playback_state_from_row,
+            )
+            .optional()
+            .with_context(|| format!("Failed to get playback state for media {}", media_id))?;
+
+        Ok(state.unwrap_or(PlaybackState {
+            media_id,
+            ..Default::default()
+        }))
+    }
+
+    /// Returns the items started but not finished, most recently played first.
+    ///
+    /// Watched items being played again are included, finishing an item clearing its position.
+    pub fn get_continue_watching(&self, profile_id: i64, limit: u32) -> Result<Vec<PlaybackEntry>> {
+        self.get_playback_entries("p.position > 0", profile_id, limit)
+    }
+
+    /// Returns the watched items, most recently played first.
+    pub fn get_recently_watched(&self, profile_id: i64, limit: u32) -> Result<Vec<PlaybackEntry>> {
+        self.get_playback_entries(
+            "p.watched = 1 AND p.last_played IS NOT NULL",
+            profile_id,
+            limit,
+        )
+    }
+
+    /// Returns the ids of every media watched by a profile.
+    pub fn get_watched_ids(&self, profile_id: i64) -> Result<HashSet<i64>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT media_id FROM Playback_State WHERE profile_id = ?1 AND watched = 1")
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map([profile_id], |row| row.get::<_, i64>(0))
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows
+            .filter_map(|res| res.ok())
+            .collect::<HashSet<i64>>())
+    }
+
+    fn get_playback_entries(
+        &self,
+        condition: &str,
+        profile_id: i64,
+        limit: u32,
+    ) -> Result<Vec<PlaybackEntry>> {
+        self.get_entries(condition, "p.last_played DESC", profile_id, limit)
+    }
+
+    /// Returns the snapshots matching a condition with their playback state,
+    /// a default one for the media never played.
+    fn get_entries(
+        &self,
+        condition: &str,
+        order: &str,
+        profile_id: i64,
+        limit: u32,
+    ) -> Result<Vec<PlaybackEntry>> {
+        let query_str = format!(
+            "SELECT {}, {}
+             FROM Movie AS m
+             {}
+             WHERE {} AND {}
+             ORDER BY {}
+             LIMIT :limit",
+            SNAPSHOT_COLUMNS,
+            ENTRY_STATE_COLUMNS,
+            SNAPSHOT_JOIN,
+            condition,
+            SNAPSHOT_ALLOWED,
+            order
+        );
+
+        let mut stmt = self
+            .conn
+            .prepare(&query_str)
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map(
+                rusqlite::named_params! {":profile_id": profile_id, ":limit": limit},
+                |row| {
+                    Ok(PlaybackEntry::new(
+                        Self::snapshot_from_row(row)?,
+                        PlaybackState::new(
+                            row.get(7)?,
+                            row.get(8)?,
+                            row.get(9)?,
+                            row.get(10)?,
+                            row.get(11)?,
+                            row.get(12)?,
+                            row.get(13)?,
+                        ),
+                    ))
+                },
+            )
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows
+            .filter_map(|res| res.ok())
+            .collect::<Vec<PlaybackEntry>>())
+    }
+
+    fn playback_state_from_row(row: &rusqlite::Row) -> rusqlite::Result<PlaybackState> {
+        Ok(PlaybackState::new(
+            row.get(0)?,
+            row.get(1)?,
+            row.get(2)?,
+            row.get(3)?,
+            row.get(4)?,
+            row.get(5)?,
+            row.get(6)?,
+        ))
+    }
+    // endregion
+
+    // region: ---- RECOMMENDATIONS ----
+    /// Returns the content features of every matched movie as `(movie_id, "<kind>:<value>")`
+    /// pairs, kinds being genre, director, writer, cast, keyword and era.
+    pub fn get_movie_features(&self, top_cast: u32) -> Result<Vec<(i64, String)>> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT movie_id, 'genre:' || genre_id FROM Movie_Genre
+             UNION ALL
+             SELECT movie_id, 'director:' || tmdb_id FROM Credits WHERE job_name = 'Director'
+             UNION ALL
+             SELECT movie_id, 'writer:' || tmdb_id FROM Credits WHERE department = 'Writing'
+             UNION ALL
+             SELECT movie_id, 'cast:' || tmdb_id FROM Credits
+                WHERE job_name = 'actor' AND credit_order < ?1
+             UNION ALL
+             SELECT movie_id, 'keyword:' || keyword_id FROM Movie_Keyword
+             UNION ALL
+             SELECT id, 'era:' || (CAST(substr(release_date, 1, 4) AS INTEGER) / 10 * 10)
+                FROM Movie WHERE tmdb_id != 0 AND length(release_date) >= 4",
+            )
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map([top_cast], |row| {
+                Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+            })
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows
+            .filter_map(|res| res.ok())
+            .collect::<Vec<(i64, String)>>())
+    }
+
+    /// Returns the snapshots of the given movies in the order of `media_ids`,
+    /// leaving out the ones hidden from the profile.
+    pub fn get_snapshots_by_ids(
+        &self,
+        profile_id: i64,
+        media_ids: &[i64],
+    ) -> Result<Vec<MovieSnapshot>> {
+        if media_ids.is_empty() {
+            return Ok(vec![]);
+        }
+        let ids = media_ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+        let query_str = format!(
+            "SELECT {}
+             FROM Movie AS m
+             {}
+             WHERE m.id IN ({}) AND {}",
+            SNAPSHOT_COLUMNS, SNAPSHOT_JOIN, ids, SNAPSHOT_ALLOWED
+        );
+
+        let mut stmt = self
+            .conn
+            .prepare(&query_str)
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mut snapshots = stmt
+            .query_map(
+                rusqlite::named_params! {":profile_id": profile_id},
+                Self::snapshot_from_row,
+            )
+            .with_context(|| "Failed to get select result")?
+            .filter_map(|res| res.ok())
+            .map(|snapshot| (snapshot.id, snapshot))
+            .collect::<HashMap<i64, MovieSnapshot>>();
+
+        Ok(media_ids
+            .iter()
+            .filter_map(|id| snapshots.remove(id))
+            .collect::<Vec<MovieSnapshot>>())
+    }
+    // endregion
+
+    // region: ---- HOME FEED ----
+    /// Returns the home screen rows of a profile in order, the `HOME_ROWS` missing from
+    /// its stored placement being appended with their defaults.
+    pub fn get_home_rows(&self, profile_id: i64) -> Result<Vec<HomeRowConfig>> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT kind, enabled, item_count FROM Home_Row
+             WHERE profile_id = ?1
+             ORDER BY position",
+            )
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let stored = stmt
+            .query_map([profile_id], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, bool>(1)?,
+                    row.get::<_, u32>(2)?,
+                ))
+            })
+            .with_context(|| "Failed to get select result")?
+            .filter_map(|res| res.ok())
+            .collect::<Vec<(String, bool, u32)>>();
+
+        let mut rows = stored
+            .into_iter()
+            .filter_map(|(kind, enabled, item_count)| {
+                let (_, title) = HOME_ROWS.iter().find(|(known, _)| *known == kind)?;
+                Some(HomeRowConfig::new(
+                    kind,
+                    title.to_string(),
+                    enabled,
+                    item_count,
+                ))
+            })
+            .collect::<Vec<HomeRowConfig>>();
+
+        for (kind, title) in HOME_ROWS.iter() {
+            if !rows.iter().any(|row| row.kind == *kind) {
+                rows.push(HomeRowConfig::new(
+                    kind.to_string(),
+                    title.to_string(),
+                    true,
+                    HOME_ROW_ITEM_COUNT,
+                ));
+            }
+        }
+        Ok(rows)
+    }
+
+    /// Returns the media of a home screen row, computed from the library and the profile history.
+    pub fn get_home_row_items(
+        &self,
+        profile_id: i64,
+        kind: &str,
+        limit: u32,
+    ) -> Result<Vec<PlaybackEntry>> {
+        match kind {
+            "continue_watching" => self.get_continue_watching(profile_id, limit),
+            "recently_added" => {
+                self.get_entries("m.date_added > 0", "m.date_added DESC", profile_id, limit)
+            }
+            "because_you_watched" => {
+                let condition = format!(
+                    "COALESCE(p.watched, 0) = 0 AND EXISTS (SELECT 1 FROM Credits AS c
+                    WHERE c.movie_id = m.id
+                        AND (c.job_name = 'Director'
+                            OR (c.job_name = 'actor' AND c.credit_order < 5))
+                        AND c.tmdb_id IN (SELECT rc.tmdb_id FROM Credits AS rc
+                            WHERE (rc.job_name = 'Director'
+                                OR (rc.job_name = 'actor' AND rc.credit_order < 5))
+                            AND rc.movie_id IN (SELECT media_id FROM Playback_State
+                                WHERE profile_id = :profile_id AND watched = 1
+                                ORDER BY last_played DESC LIMIT {})))",
+                    RECENT_WATCH_COUNT
+                );
+                self.get_entries(&condition, "m.vote_average DESC, m.id", profile_id, limit)
+            }
+            "favourite_genres" => {
+                let condition = format!(
+                    "COALESCE(p.watched, 0) = 0 AND EXISTS (SELECT 1 FROM Movie_Genre AS mg
+                    WHERE mg.movie_id = m.id AND mg.genre_id IN (
+                        SELECT tg.genre_id FROM Movie_Genre AS tg
+                        WHERE tg.movie_id IN ({})
+                        GROUP BY tg.genre_id
+                        ORDER BY COUNT(*) DESC
+                        LIMIT {}))",
+                    PROFILE_TASTE_MEDIA, FAVOURITE_GENRE_COUNT
+                );
+                self.get_entries(&condition, "m.vote_average DESC, m.id", profile_id, limit)
+            }
+            // Shuffled with the day number, so the pick stays the same for the day
+            "pick_of_the_day" => self.get_entries(
+                "COALESCE(p.watched, 0) = 0 AND m.tmdb_id != 0",
+                "((m.id + CAST(julianday('now', 'localtime') AS INTEGER)) * 2654435761)
+                    % 4294967291, m.id",
+                profile_id,
+                limit,
+            ),
+            _ => Err(anyhow!("Unknown home row: {}", kind)),
+        }
+    }
+    // endregion
+
+    // region: ---- LISTS ----
+    /// Returns the lists of a profile, the built-in ones first.
+    pub fn get_lists(&self, profile_id: i64) -> Result<Vec<MediaList>> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT l.id, l.name, l.kind, COUNT(i.media_id)
+             FROM Media_List AS l
+             LEFT JOIN Media_List_Item AS i ON i.list_id = l.id
+             WHERE l.profile_id = ?1
+             GROUP BY l.id
+             ORDER BY l.kind = 'custom', l.created_at, l.id",
+            )
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map([profile_id], |row| {
+                Ok(MediaList::new(
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                ))
+            })
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows
+            .filter_map(|res| res.ok())
+            .collect::<Vec<MediaList>>())
+    }
+
+    /// Returns the id of a built-in list of a profile, one of the kinds of `BUILTIN_LISTS`.
+    pub fn get_builtin_list_id(&self, profile_id: i64, kind: &str) -> Result<i64> {
+        self.conn
+            .query_row(
+                "SELECT id FROM Media_List WHERE profile_id = ?1 AND kind = ?2",
+                (profile_id, kind),
+                |row| row.get::<_, i64>(0),
+            )
+            .optional()
+            .with_context(|| format!("Failed to look up list: {}", kind))?
+            .ok_or_else(|| anyhow!("Unknown built-in list: {}", kind))
+    }
+
+    /// Returns a page of the snapshots of a list, in list order.
+    pub fn get_list_snapshots(
+        &self,
+        profile_id: i64,
+        list_id: i64,
+        page: u32,
+        page_size: u32,
+    ) -> Result<Vec<MovieSnapshot>> {
+        let query_str = format!(
+            "SELECT {}
+             FROM Media_List_Item AS i
+             INNER JOIN Media_List AS l ON l.id = i.list_id
+             INNER JOIN Movie AS m ON m.id = i.media_id
+             {}
+             WHERE i.list_id = :list_id AND l.profile_id = :profile_id AND {}
+             ORDER BY i.position
+             LIMIT :limit OFFSET :offset",
+            SNAPSHOT_COLUMNS, SNAPSHOT_JOIN, SNAPSHOT_ALLOWED
+        );
+
+        let mut stmt = self
+            .conn
+            .prepare(&query_str)
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map(
+                rusqlite::named_params! {
+                    ":list_id": list_id,
+                    ":profile_id": profile_id,
+                    ":limit": page_size,
+                    ":offset": page as i64 * page_size as i64,
+                },
+                Self::snapshot_from_row,
+            )
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows
+            .filter_map(|res| res.ok())
+            .collect::<Vec<MovieSnapshot>>())
+    }
+    // endregion
+
+    // region: ---- SMART COLLECTIONS ----
+    pub fn get_smart_collections(&self, profile_id: i64) -> Result<Vec<SmartCollection>> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT id, name, query FROM Smart_Collection
+             WHERE profile_id = ?1
+             ORDER BY created_at, id",
+            )
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map([profile_id], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            })
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows
+            .filter_map(|res| res.ok())
+            .filter_map(
+                |(id, name, query)| match serde_json::from_str::<SmartQuery>(&query) {
+                    Ok(query) => Some(SmartCollection::new(id, name, query)),
+                    Err(e) => {
+                        tracing::error!(
+                            "Failed to parse query of smart collection {} \n Caused by {:?}",
+                            id,
+                            e
+                        );
+                        None
+                    }
+                },
+            )
+            .collect::<Vec<SmartCollection>>())
+    }
+
+    pub fn get_smart_collection(
+        &self,
+        profile_id: i64,
+        collection_id: i64,
+    ) -> Result<SmartCollection> {
+        let (name, query) = self
+            .conn
+            .query_row(
+                "SELECT name, query FROM Smart_Collection WHERE id = ?1 AND profile_id = ?2",
+                (collection_id, profile_id),
+                |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)),
+            )
+            .optional()
+            .with_context(|| format!("Failed to fetch smart collection: {}", collection_id))?
+            .ok_or_else(|| anyhow!("No smart collection found for id: {}", collection_id))?;
+
+        let query = serde_json::from_str::<SmartQuery>(&query).with_context(|| {
+            format!(
+                "Failed to parse query of smart collection: {}",
+                collection_id
+            )
+        })?;
+        Ok(SmartCollection::new(collection_id, name, query))
+    }
+
+    /// Evaluates a smart collection query, returning a page of the matching snapshots.
+    ///
+    /// `seed` shuffles the random sort: pages read with the same seed share one order.
+    pub fn get_smart_snapshots(
+        &self,
+        profile_id: i64,
+        query: &SmartQuery,
+        page: u32,
+        page_size: u32,
+        seed: u32,
+    ) -> Result<Vec<MovieSnapshot>> {
+        let offset = page as i64 * page_size as i64;
+        let mut limit = page_size as i64;
+        if let Some(max) = query.limit {
+            limit = limit.min(max as i64 - offset);
+        }
+        if limit <= 0 {
+            return Ok(vec![]);
+        }
+
+        let (conditions, mut params) = Self::smart_conditions(query);
+        params.push((":profile_id".to_owned(), Value::Integer(profile_id)));
+        params.push((":limit".to_owned(), Value::Integer(limit)));
+        params.push((":offset".to_owned(), Value::Integer(offset)));
+
+        let order = match query.sort {
+            SmartSort::Title => "m.title COLLATE NOCASE",
+            SmartSort::ReleaseDate => "m.release_date",
+            SmartSort::Rating => "m.vote_average",
+            SmartSort::DateAdded => "m.date_added",
+            // Shuffled with the seed like the pick of the day, RANDOM() changing every page.
+            // The seed is kept under 2^16 so that the shuffle stays within 64-bit integers.
+            SmartSort::Random => {
+                params.push((":seed".to_owned(), Value::Integer(i64::from(seed & 0xFFFF))));
+                "((m.id * 2654435761) % 4294967291 * (2 * :seed + 1) + :seed) % 4294967291"
+            }
+        };
+
+        let query_str = format!(
+            "SELECT {}
+             FROM Movie AS m
+             {}
+             WHERE {} AND {}
+             ORDER BY {} {}, m.id
+             LIMIT :limit OFFSET :offset",
+            SNAPSHOT_COLUMNS,
+            SNAPSHOT_JOIN,
+            SNAPSHOT_ALLOWED,
+            conditions.join(" AND "),
+            order,
+            if query.descending { "DESC" } else { "ASC" }
+        );
+
+        let mut stmt = self
+            .conn
+            .prepare(&query_str)
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let params = params
+            .iter()
+            .map(|(name, value)| (name.as_str(), value as &dyn ToSql))
+            .collect::<Vec<(&str, &dyn ToSql)>>();
+        let mapped_rows = stmt
+            .query_map(params.as_slice(), Self::snapshot_from_row)
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows
+            .filter_map(|res| res.ok())
+            .collect::<Vec<MovieSnapshot>>())
+    }
+
+    /// Translates the filter of a smart collection query into SQL conditions on `Movie AS m`
+    /// and the named parameters they use.
+    fn smart_conditions(query: &SmartQuery) -> (Vec<String>, Vec<(String, Value)>) {
+        let filter = &query.filter;
+        let mut conditions = vec!["m.tmdb_id != 0".to_owned()];
+        let mut params: Vec<(String, Value)> = vec![];
+
+        if !filter.genres.is_empty() {
+            let values = filter
+                .genres
+                .iter()
+                .map(|g| Value::Text(g.clone()))
+                .collect();
+            conditions.push(format!(
+                "EXISTS (SELECT 1 FROM Movie_Genre AS mg
+                INNER JOIN Genre AS g ON g.id = mg.genre_id
+                WHERE mg.movie_id = m.id AND g.name COLLATE NOCASE IN ({}))",
+                Self::bind_list(&mut params, "genre", values).join(", ")
+            ));
+        }
+        if !filter.person_ids.is_empty() {
+            let values = filter
+                .person_ids
+                .iter()
+                .map(|id| Value::Integer(*id))
+                .collect();
+            conditions.push(format!(
+                "EXISTS (SELECT 1 FROM Credits AS c
+                WHERE c.movie_id = m.id AND c.tmdb_id IN ({}))",
+                Self::bind_list(&mut params, "person", values).join(", ")
+            ));
+        }
+        if !filter.resolutions.is_empty() {
+            let values = filter
+                .resolutions
+                .iter()
+                .map(|r| Value::Text(format!("%{}%", r.to_lowercase())))
+                .collect::<Vec<Value>>();
+            let likes = Self::bind_list(&mut params, "resolution", values)
+                .iter()
+                .map(|name| format!("m.file_optional_info LIKE {}", name))
+                .collect::<Vec<String>>();
+            conditions.push(format!("({})", likes.join(" OR ")));
+        }
+        if let Some(year_from) = filter.year_from {
+            conditions
+                .push("CAST(substr(m.release_date, 1, 4) AS INTEGER) >= :year_from".to_owned());
+            params.push((":year_from".to_owned(), Value::Integer(year_from as i64)));
+        }
+        if let Some(year_to) = filter.year_to {
+            conditions.push("CAST(substr(m.release_date, 1, 4) AS INTEGER) <= :year_to".to_owned());
+            params.push((":year_to".to_owned(), Value::Integer(year_to as i64)));
+        }
+        if let Some(min_rating) = filter.min_rating {
+            conditions.push("m.vote_average >= :min_rating".to_owned());
+            params.push((":min_rating".to_owned(), Value::Real(min_rating as f64)));
+        }
+        if let Some(max_rating) = filter.max_rating {
+            conditions.push("m.vote_average <= :max_rating".to_owned());
+            params.push((":max_rating".to_owned(), Value::Real(max_rating as f64)));
+        }
+        if let Some(watched) = filter.watched {
+            conditions.push("COALESCE(p.watched, 0) = :watched".to_owned());
+            params.push((":watched".to_owned(), Value::Integer(watched as i64)));
+        }
+        if let Some(days) = filter.added_within_days {
+            conditions.push("m.date_added >= strftime('%s', 'now') - :added_within".to_owned());
+            params.push((
+                ":added_within".to_owned(),
+                Value::Integer(days as i64 * 86400),
+            ));
+        }
+        (conditions, params)
+    }
+
+    /// Adds the `:<name>_0, :<name>_1, ...` parameters of a list of values, returning their names.
+    fn bind_list(params: &mut Vec<(String, Value)>, name: &str, values: Vec<Value>) -> Vec<String> {
+        let names = (0..values.len())
+            .map(|i| format!(":{}_{}", name, i))
+            .collect::<Vec<String>>();
+        params.extend(names.iter().cloned().zip(values));
+        names
+    }
+    // endregion
+
+    // region: ---- DEVICES ----
+    pub fn get_devices(&self) -> Result<Vec<PairedDevice>> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT id, name, profile_id, created_at, last_seen FROM Device
+             ORDER BY created_at, id",
+            )
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map([], |row| {
+                Ok(PairedDevice::new(
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                ))
+            })
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows
+            .filter_map(|res| res.ok())
+            .collect::<Vec<PairedDevice>>())
+    }
+
+    /// Returns the device and profile ids a token was issued for, None when it is unknown.
+    pub fn get_device_for_token(&self, token: &str) -> Result<Option<(i64, i64)>> {
+        self.conn
+            .query_row(
+                "SELECT id, profile_id FROM Device WHERE token_hash = ?1",
+                [DataSaver::hash_secret(token)],
+                |row| Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?)),
+            )
+            .optional()
+            .context("Failed to look up device token")
+    }
+
+    /// Whether a media exists and is within the content rating limit of a profile.
+    pub fn is_media_allowed(&self, profile_id: i64, media_id: i64) -> Result<bool> {
+        let query_str = format!(
+            "SELECT EXISTS (SELECT 1 FROM Movie AS m WHERE m.id = :media_id AND {})",
+            SNAPSHOT_ALLOWED
+        );
+        self.conn
+            .query_row(
+                &query_str,
+                rusqlite::named_params! {":media_id": media_id, ":profile_id": profile_id},
+                |row| row.get::<_, bool>(0),
+            )
+            .with_context(|| format!("Failed to check access to media {}", media_id))
+    }
+    // endregion
+
+    // region: ---- PROFILES ----
+    pub fn get_profiles(&self) -> Result<Vec<Profile>> {
+        let mut stmt = self
+            .conn
+            .prepare(
+                "SELECT id, name, avatar, pin_hash IS NOT NULL, max_certification,
+                hide_unrated, preferred_language
+             FROM Profile
+             ORDER BY id",
+            )
+            .with_context(|| "Failed to prepare statement for data selection")?;
+
+        let mapped_rows = stmt
+            .query_map([], Self::profile_from_row)
+            .with_context(|| "Failed to get select result")?;
+
+        Ok(mapped_rows
+            .filter_map(|res| res.ok())
+            .collect::<Vec<Profile>>())
+    }
+
+    pub fn get_active_profile(&self) -> Result<Profile> {
+        self.conn
+            .query_row(
+                "SELECT p.id, p.name, p.avatar, p.pin_hash IS NOT NULL, p.max_certification,
+                p.hide_unrated, p.preferred_language
+             FROM Profile AS p
+             INNER JOIN Active_Profile AS a ON a.profile_id = p.id
+             WHERE a.id = 1",
+                [],
+                Self::profile_from_row,
+            )
+            .with_context(|| "Failed to get active profile")
+    }
+
+    pub fn get_active_profile_id(&self) -> Result<i64> {
+        self.conn
+            .query_row(
+                "SELECT profile_id FROM Active_Profile WHERE id = 1",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .with_context(|| "Failed to get active profile id")
+    }
+
+    fn profile_from_row(row: &rusqlite::Row) -> rusqlite::Result<Profile> {
+        Ok(Profile::new(
+            row.get(0)?,
+            row.get(1)?,
+            row.get(2)?,
+            row.get(3)?,
+            row.get(4)?,
+            row.get(5)?,
+            row.get(6)?,
+        ))
+    }
+    // endregion
 }
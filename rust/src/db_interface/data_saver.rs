@@ -1,21 +1,64 @@
-use crate::movie_data::movie_data::{CreditsMovie, MovieData, PersonData};
-use anyhow::{Context, Result};
-use rusqlite::{Connection, Transaction};
+use crate::db_interface::data_writer::MovieRecord;
+use crate::event_bus::event_bus::emit;
+use crate::movie_data::movie_data::{
+    CollectionData, CreditFilter, CreditsMovie, Genre, LibraryEvent, MovieData, PersonData,
+};
+use anyhow::{anyhow, Context, Result};
+use rusqlite::{Connection, ToSql, Transaction};
+
+/// Movie fields a user can edit, locking them against scans and metadata refreshes.
+/// Every field but `genres` is the `Movie` column of the same name.
+pub const LOCKABLE_FIELDS: [&str; 9] = [
+    "title",
+    "original_title",
+    "summary",
+    "tagline",
+    "release_date",
+    "certification",
+    "poster",
+    "backdrop",
+    "genres",
+];
+
+/// Lockable fields stored in a nullable column, the only ones an edit can clear.
+const CLEARABLE_FIELDS: [&str; 1] = ["release_date"];
 
 pub struct DataSaver {
-    conn: Connection,
+    pub(super) conn: Connection,
 }
 
 impl DataSaver {
     pub fn new(db_path: String) -> Result<Self> {
         let conn = Connection::open(&db_path)
             .with_context(|| format!("Failed to open database connection at : {}", &db_path))?;
-        Ok(Self { conn: conn })
+        Ok(Self { conn })
     }
 
     // region: ---- CREATE TABLES ---
+    /// Creates or migrates every table, seeding the default profile and credit filter.
+    pub fn create_tables(&mut self) -> Result<()> {
+        self.create_movie_table()?;
+        self.create_person_table()?;
+        self.create_genre_table()?;
+        self.create_movie_genre_table()?;
+        self.create_movie_details_tables()?;
+        self.create_collection_tables()?;
+        self.create_credits_table()?;
+        self.create_credit_filter_tables()?;
+        self.create_lock_table()?;
+        self.create_profile_tables()?;
+        self.create_playback_table()?;
+        self.create_list_tables()?;
+        self.create_smart_collection_table()?;
+        self.create_home_row_table()?;
+        self.create_device_tables()?;
+        self.create_media_info_tables()?;
+        self.create_subtitle_table()?;
+        Ok(())
+    }
+
     /// Helper to an index on the specified column of a SQLite table.
-    fn create_index(&self, table: &str, column: &str) -> Result<()> {
+    pub(super) fn create_index(&self, table: &str, column: &str) -> Result<()> {
         let index_name = format!("idx_{}_{}", table.to_lowercase(), column.to_lowercase());
 
         let query = format!(
@@ -33,7 +76,38 @@ impl DataSaver {
         Ok(())
     }
 
-    pub fn create_movie_table(&mut self) -> Result<()> {
+    /// Helper to check whether a table has a column, false when the table does not exist.
+    pub(super) fn has_column(&self, table: &str, column: &str) -> Result<bool> {
+        let mut stmt = self
+            .conn
+            .prepare(&format!("PRAGMA table_info({})", table))
+            .with_context(|| format!("Failed to read table info for table: {}", table))?;
+
+        let exists = stmt
+            .query_map([], |row| row.get::<_, String>(1))
+            .with_context(|| format!("Failed to read columns for table: {}", table))?
+            .filter_map(|res| res.ok())
+            .any(|name| name == column);
+        Ok(exists)
+    }
+
+    /// Helper to add a column to a table created by an older version of the schema.
+    pub(super) fn add_column_if_missing(
+        &self,
+        table: &str,
+        column: &str,
+        definition: &str,
+    ) -> Result<()> {
+        if !self.has_column(table, column)? {
+            let query = format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition);
+            self.conn
+                .execute(&query, [])
+                .with_context(|| format!("Failed to add column: {} to table: {}", column, table))?;
+        }
+        Ok(())
+    }
+
+    fn create_movie_table(&mut self) -> Result<()> {
         self.conn
             .execute(
                 "CREATE TABLE IF NOT EXISTS Movie (
@@ -47,20 +121,146 @@ impl DataSaver {
                 summary TEXT NOT NULL,
                 vote_average REAL NOT NULL DEFAULT 0,
                 poster TEXT NOT NULL,
-                backdrop TEXT NOT NULL
+                backdrop TEXT NOT NULL,
+                runtime INTEGER,
+                tagline TEXT NOT NULL DEFAULT '',
+                status TEXT NOT NULL DEFAULT '',
+                budget INTEGER NOT NULL DEFAULT 0,
+                revenue INTEGER NOT NULL DEFAULT 0,
+                imdb_id TEXT,
+                collection_id INTEGER,
+                certification TEXT NOT NULL DEFAULT '',
+                poster_source TEXT,
+                backdrop_source TEXT,
+                updated_at INTEGER NOT NULL DEFAULT 0,
+                date_added INTEGER NOT NULL DEFAULT 0
             )",
                 (),
             )
             .context("Failed to create movie table")?;
 
+        self.add_column_if_missing("Movie", "runtime", "INTEGER")?;
+        self.add_column_if_missing("Movie", "tagline", "TEXT NOT NULL DEFAULT ''")?;
+        self.add_column_if_missing("Movie", "status", "TEXT NOT NULL DEFAULT ''")?;
+        self.add_column_if_missing("Movie", "budget", "INTEGER NOT NULL DEFAULT 0")?;
+        self.add_column_if_missing("Movie", "revenue", "INTEGER NOT NULL DEFAULT 0")?;
+        self.add_column_if_missing("Movie", "imdb_id", "TEXT")?;
+        self.add_column_if_missing("Movie", "collection_id", "INTEGER")?;
+        self.add_column_if_missing("Movie", "certification", "TEXT NOT NULL DEFAULT ''")?;
+        self.add_column_if_missing("Movie", "poster_source", "TEXT")?;
+        self.add_column_if_missing("Movie", "backdrop_source", "TEXT")?;
+        self.add_column_if_missing("Movie", "updated_at", "INTEGER NOT NULL DEFAULT 0")?;
+        if !self.has_column("Movie", "date_added")? {
+            self.add_column_if_missing("Movie", "date_added", "INTEGER NOT NULL DEFAULT 0")?;
+            self.conn
+                .execute("UPDATE Movie SET date_added = updated_at", [])
+                .context("Failed to backfill movie date_added")?;
+        }
+
         self.create_index("Movie", "title")?;
         self.create_index("Movie", "release_date")?;
         self.create_index("Movie", "tmdb_id")?;
+        self.create_index("Movie", "collection_id")?;
+        self.create_index("Movie", "updated_at")?;
+        self.create_index("Movie", "date_added")?;
+
+        Ok(())
+    }
+
+    /// Creates the studio, country, language and keyword tables with their movie link tables.
+    fn create_movie_details_tables(&mut self) -> Result<()> {
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Company (
+                id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL,
+                logo_path TEXT,
+                origin_country TEXT
+            );",
+                (),
+            )
+            .context("Failed to create company table")?;
+
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Country (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL
+            );",
+                (),
+            )
+            .context("Failed to create country table")?;
+
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Language (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL
+            );",
+                (),
+            )
+            .context("Failed to create language table")?;
+
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Keyword (
+                id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL
+            );",
+                (),
+            )
+            .context("Failed to create keyword table")?;
+
+        for (table, key_type) in [
+            ("Company", "INTEGER"),
+            ("Country", "TEXT"),
+            ("Language", "TEXT"),
+            ("Keyword", "INTEGER"),
+        ] {
+            self.create_movie_link_table(table, key_type)?;
+            self.create_index(table, "name")?;
+        }
+        Ok(())
+    }
+
+    /// Helper to create the `Movie_<table>` many to many table linking movies to a table.
+    fn create_movie_link_table(&self, table: &str, key_type: &str) -> Result<()> {
+        let link_table = format!("Movie_{}", table);
+        let link_column = format!("{}_id", table.to_lowercase());
+
+        let query = format!(
+            "CREATE TABLE IF NOT EXISTS {} (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                movie_id INTEGER NOT NULL,
+                {} {} NOT NULL,
+                FOREIGN KEY (movie_id) REFERENCES Movie(id),
+                FOREIGN KEY ({}) REFERENCES {}(id)
+            );",
+            link_table, link_column, key_type, link_column, table
+        );
+        self.conn
+            .execute(&query, ())
+            .with_context(|| format!("Failed to create table: {}", link_table))?;
+
+        let query = format!(
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_{}
+         ON {} (movie_id, {});",
+            link_table.to_lowercase(),
+            link_table,
+            link_column
+        );
+        self.conn.execute(&query, []).with_context(|| {
+            format!(
+                "Failed to create composite index for table: {} and columns: movie_id and {}",
+                link_table, link_column
+            )
+        })?;
 
+        self.create_index(&link_table, &link_column)?;
         Ok(())
     }
 
-    pub fn create_credits_table(&mut self) -> Result<()> {
+    fn create_credits_table(&mut self) -> Result<()> {
         self.conn
             .execute(
                 "CREATE TABLE IF NOT EXISTS Credits (
@@ -70,6 +270,9 @@ impl DataSaver {
                 name TEXT NOT NULL,
                 character TEXT,
                 job_name TEXT NOT NULL,
+                department TEXT,
+                credit_order INTEGER,
+                credit_id TEXT,
                 FOREIGN KEY (movie_id) REFERENCES Movie(id)
             );
             ",
@@ -77,8 +280,21 @@ impl DataSaver {
             )
             .context("Failed to create credits table")?;
 
+        self.add_column_if_missing("Credits", "department", "TEXT")?;
+        self.add_column_if_missing("Credits", "credit_order", "INTEGER")?;
+        self.add_column_if_missing("Credits", "credit_id", "TEXT")?;
+
+        // Crew used to be stored with a placeholder character.
+        self.conn
+            .execute(
+                "UPDATE Credits SET character = '' WHERE job_name != 'actor' AND character = 'N/A'",
+                [],
+            )
+            .context("Failed to clear crew placeholder characters")?;
+
         self.create_index("Credits", "name")?;
         self.create_index("Credits", "job_name")?;
+        self.create_index("Credits", "department")?;
 
         self.conn
             .execute(
@@ -91,25 +307,59 @@ impl DataSaver {
         Ok(())
     }
 
-    pub fn create_person_table(&mut self) -> Result<()> {
+    fn create_person_table(&mut self) -> Result<()> {
         self.conn
             .execute(
                 "CREATE TABLE IF NOT EXISTS Person (
                 tmdb_id INTEGER PRIMARY KEY,
                 name TEXT NOT NULL,
                 summary TEXT,
-                picture_path TEXT
+                picture_path TEXT,
+                birthday TEXT,
+                deathday TEXT,
+                place_of_birth TEXT,
+                known_for_department TEXT,
+                gender INTEGER NOT NULL DEFAULT 0,
+                updated_at INTEGER NOT NULL DEFAULT 0
             );
             ",
                 (),
             )
             .context("Failed to create person table")?;
 
+        self.add_column_if_missing("Person", "birthday", "TEXT")?;
+        self.add_column_if_missing("Person", "deathday", "TEXT")?;
+        self.add_column_if_missing("Person", "place_of_birth", "TEXT")?;
+        self.add_column_if_missing("Person", "known_for_department", "TEXT")?;
+        self.add_column_if_missing("Person", "gender", "INTEGER NOT NULL DEFAULT 0")?;
+        self.add_column_if_missing("Person", "updated_at", "INTEGER NOT NULL DEFAULT 0")?;
+
         self.create_index("Person", "name")?;
+
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Person_Alias (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                person_id INTEGER NOT NULL,
+                name TEXT NOT NULL,
+                FOREIGN KEY (person_id) REFERENCES Person(tmdb_id)
+            );
+            ",
+                (),
+            )
+            .context("Failed to create person alias table")?;
+
+        self.conn
+            .execute(
+                "CREATE UNIQUE INDEX IF NOT EXISTS idx_person_alias
+         ON Person_Alias (person_id, name);",
+                [],
+            )
+            .context("Failed to create unique composite index for table: Person_Alias")?;
         Ok(())
     }
 
-    pub fn create_genre_table(&mut self) -> Result<()> {
+    fn create_genre_table(&mut self) -> Result<()> {
         self.conn
             .execute(
                 "CREATE TABLE IF NOT EXISTS Genre (
@@ -122,7 +372,7 @@ impl DataSaver {
         Ok(())
     }
 
-    pub fn create_movie_genre_table(&mut self) -> Result<()> {
+    fn create_movie_genre_table(&mut self) -> Result<()> {
         self.conn
             .execute(
                 "CREATE TABLE IF NOT EXISTS Movie_Genre (
@@ -142,46 +392,620 @@ impl DataSaver {
          ON Movie_Genre (movie_id, genre_id);",
             [],
         ).with_context(|| {
-            format!(
-                "Failed to create composite index for table: movie_genre and columns: movie_id and genre_id"
-            )
+            "Failed to create composite index for table: movie_genre and columns: movie_id and genre_id"
         })?;
 
         Ok(())
     }
+
+    fn create_collection_tables(&mut self) -> Result<()> {
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Collection (
+                id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL,
+                overview TEXT,
+                poster TEXT,
+                backdrop TEXT
+            );",
+                (),
+            )
+            .context("Failed to create collection table")?;
+
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Collection_Part (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                collection_id INTEGER NOT NULL,
+                tmdb_id INTEGER NOT NULL,
+                title TEXT NOT NULL,
+                release_date TEXT,
+                poster_path TEXT,
+                FOREIGN KEY (collection_id) REFERENCES Collection(id)
+            );",
+                (),
+            )
+            .context("Failed to create collection part table")?;
+
+        self.create_index("Collection", "name")?;
+        self.conn
+            .execute(
+                "CREATE UNIQUE INDEX IF NOT EXISTS idx_collection_part
+         ON Collection_Part (collection_id, tmdb_id);",
+                [],
+            )
+            .context("Failed to create unique composite index for table: Collection_Part")?;
+        Ok(())
+    }
+
+    /// Creates the table of user-locked movie fields, keeping the latest provider value of each.
+    fn create_lock_table(&mut self) -> Result<()> {
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Movie_Lock (
+                movie_id INTEGER NOT NULL,
+                field TEXT NOT NULL,
+                provider_value TEXT,
+                PRIMARY KEY (movie_id, field),
+                FOREIGN KEY (movie_id) REFERENCES Movie(id)
+            );",
+                (),
+            )
+            .context("Failed to create movie lock table")?;
+        Ok(())
+    }
+
+    /// Creates the credit filter tables, seeding them with the default rules on first use.
+    fn create_credit_filter_tables(&mut self) -> Result<()> {
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Credit_Filter (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                max_cast_size INTEGER,
+                include_uncredited INTEGER NOT NULL DEFAULT 0
+            );",
+                (),
+            )
+            .context("Failed to create credit filter table")?;
+
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Credit_Filter_Job (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                department TEXT NOT NULL,
+                job TEXT NOT NULL,
+                UNIQUE (department, job)
+            );",
+                (),
+            )
+            .context("Failed to create credit filter job table")?;
+
+        let is_seeded = self
+            .conn
+            .query_row("SELECT COUNT(*) FROM Credit_Filter", [], |row| {
+                row.get::<_, i64>(0)
+            })
+            .context("Failed to count credit filter rows")?
+            > 0;
+
+        if !is_seeded {
+            self.save_credit_filter(&CreditFilter::default())?;
+        }
+        Ok(())
+    }
     // endregion
 
-    // region: ---- INSERT DATA ----
-    /// Persists all movie-related data, including basic information, genres, and credits.
-    pub fn push_movie_data(&mut self, m: &MovieData, c: &CreditsMovie) -> Result<()> {
+    // region: ---- CREDIT FILTER ----
+    /// Replaces the persisted credit filter rules.
+    pub fn save_credit_filter(&mut self, filter: &CreditFilter) -> Result<()> {
         let tx = self
             .conn
             .transaction()
             .context("Failed to open database transaction")?;
 
-        let movie_id = match Self::push_movie(&m, &tx) {
-            Ok(id) => id,
-            Err(e) => {
-                tracing::error!(
-                    "Failed to push movie data for {} \n Caused by: {:?}",
-                    m.file_path(),
-                    e
-                );
-                return Ok(());
+        tx.execute(
+            "INSERT INTO Credit_Filter (id, max_cast_size, include_uncredited)
+            VALUES (1, ?1, ?2)
+            ON CONFLICT(id) DO UPDATE SET
+                max_cast_size = excluded.max_cast_size,
+                include_uncredited = excluded.include_uncredited;",
+            (filter.max_cast_size, filter.include_uncredited),
+        )
+        .context("Failed to save credit filter settings")?;
+
+        tx.execute("DELETE FROM Credit_Filter_Job", [])
+            .context("Failed to clear credit filter jobs")?;
+
+        for allowed in filter.allowed_jobs.iter() {
+            tx.execute(
+                "INSERT INTO Credit_Filter_Job (department, job)
+                VALUES (?1, ?2)
+                ON CONFLICT(department, job) DO NOTHING;",
+                (&allowed.department, &allowed.job),
+            )
+            .with_context(|| {
+                format!(
+                    "Failed to insert allowed job: {} / {}",
+                    allowed.department, allowed.job
+                )
+            })?;
+        }
+
+        tx.commit()
+            .context("Failed to commit credit filter update")?;
+        Ok(())
+    }
+
+    /// Removes stored credits that no longer match the persisted rules, returning the number removed.
+    ///
+    /// Credits discarded at scan time are not stored, so loosened rules only take effect on the next scan.
+    pub fn reapply_credit_filter(&mut self) -> Result<usize> {
+        let tx = self
+            .conn
+            .transaction()
+            .context("Failed to open database transaction")?;
+
+        let (max_cast_size, include_uncredited) = tx
+            .query_row(
+                "SELECT max_cast_size, include_uncredited FROM Credit_Filter WHERE id = 1",
+                [],
+                |row| Ok((row.get::<_, Option<i64>>(0)?, row.get::<_, bool>(1)?)),
+            )
+            .context("Failed to load credit filter settings")?;
+
+        let mut removed = tx
+            .execute(
+                "DELETE FROM Credits
+            WHERE job_name != 'actor'
+            AND NOT EXISTS (
+                SELECT 1 FROM Credit_Filter_Job AS f
+                WHERE f.job = Credits.job_name
+                AND (Credits.department IS NULL OR f.department = Credits.department)
+            )",
+                [],
+            )
+            .context("Failed to remove filtered crew credits")?;
+
+        if !include_uncredited {
+            removed += tx
+                .execute(
+                    "DELETE FROM Credits
+                WHERE job_name = 'actor' AND character LIKE '%uncredited%'",
+                    [],
+                )
+                .context("Failed to remove uncredited cast credits")?;
+        }
+
+        if let Some(max_cast_size) = max_cast_size {
+            removed += tx
+                .execute(
+                    "DELETE FROM Credits WHERE id IN (
+                    SELECT id FROM (
+                        SELECT id, ROW_NUMBER() OVER (
+                            PARTITION BY movie_id
+                            ORDER BY credit_order IS NULL, credit_order, id
+                        ) AS billing
+                        FROM Credits
+                        WHERE job_name = 'actor'
+                    )
+                    WHERE billing > ?1
+                )",
+                    [max_cast_size],
+                )
+                .context("Failed to remove cast credits above the maximum cast size")?;
+        }
+
+        tx.commit()
+            .context("Failed to commit credit filter application")?;
+
+        tracing::info!(removed = removed, "Credit filter re-applied");
+        Ok(removed)
+    }
+    // endregion
+
+    // region: ---- USER EDITS ----
+    /// Sets a movie field by hand and locks it, remembering the provider value for a revert.
+    pub fn edit_movie_field(
+        &mut self,
+        media_id: i64,
+        field: &str,
+        value: Option<String>,
+    ) -> Result<()> {
+        let column = Self::lockable_column(field)?;
+        if value.is_none() && !CLEARABLE_FIELDS.contains(&field) {
+            return Err(anyhow!("Field: {} can not be cleared", field));
+        }
+
+        let tx = self
+            .conn
+            .transaction()
+            .context("Failed to open database transaction")?;
+
+        let query = format!(
+            "INSERT INTO Movie_Lock (movie_id, field, provider_value)
+            SELECT id, ?2, {} FROM Movie WHERE id = ?1
+            ON CONFLICT(movie_id, field) DO NOTHING;",
+            column
+        );
+        tx.execute(&query, (media_id, field))
+            .with_context(|| format!("Failed to lock field: {} for movie {}", field, media_id))?;
+
+        let query = format!("UPDATE Movie SET {} = ?2 WHERE id = ?1", column);
+        let updated = tx
+            .execute(&query, (media_id, value))
+            .with_context(|| format!("Failed to edit field: {} for movie {}", field, media_id))?;
+        if updated == 0 {
+            return Err(anyhow!("No stored movie found for media id: {}", media_id));
+        }
+
+        tx.commit()
+            .context("Failed to commit movie field edition")?;
+        emit(LibraryEvent::MediaUpdated { media_id });
+        Ok(())
+    }
+
+    /// Replaces the genres of a movie by hand and locks them.
+    pub fn edit_movie_genres(&mut self, media_id: i64, genre_ids: &[i64]) -> Result<()> {
+        let tx = self
+            .conn
+            .transaction()
+            .context("Failed to open database transaction")?;
+
+        Self::check_movie_exists(media_id, &tx)?;
+        for genre_id in genre_ids.iter() {
+            let known = tx
+                .query_row(
+                    "SELECT COUNT(*) FROM Genre WHERE id = ?1",
+                    [genre_id],
+                    |row| row.get::<_, i64>(0),
+                )
+                .context("Failed to look up genre")?
+                > 0;
+            if !known {
+                return Err(anyhow!("Unknown genre id: {}", genre_id));
+            }
+        }
+
+        tx.execute(
+            "INSERT INTO Movie_Lock (movie_id, field, provider_value)
+            VALUES (?1, 'genres',
+                (SELECT GROUP_CONCAT(genre_id) FROM Movie_Genre WHERE movie_id = ?1))
+            ON CONFLICT(movie_id, field) DO NOTHING;",
+            [media_id],
+        )
+        .with_context(|| format!("Failed to lock genres for movie {}", media_id))?;
+
+        Self::replace_movie_genres(media_id, genre_ids, &tx)?;
+
+        tx.commit()
+            .context("Failed to commit movie genres edition")?;
+        emit(LibraryEvent::MediaUpdated { media_id });
+        Ok(())
+    }
+
+    /// Unlocks a movie field and restores the latest value received from the provider.
+    pub fn unlock_movie_field(&mut self, media_id: i64, field: &str) -> Result<()> {
+        let tx = self
+            .conn
+            .transaction()
+            .context("Failed to open database transaction")?;
+
+        let provider_value = tx
+            .query_row(
+                "SELECT provider_value FROM Movie_Lock WHERE movie_id = ?1 AND field = ?2",
+                (media_id, field),
+                |row| row.get::<_, Option<String>>(0),
+            )
+            .with_context(|| format!("Field: {} is not locked for movie {}", field, media_id))?;
+
+        if field == "genres" {
+            let genre_ids: Vec<i64> = provider_value
+                .unwrap_or_default()
+                .split(',')
+                .filter_map(|id| id.trim().parse().ok())
+                .collect();
+            Self::replace_movie_genres(media_id, &genre_ids, &tx)?;
+        } else {
+            let column = Self::lockable_column(field)?;
+            let query = format!("UPDATE Movie SET {} = ?2 WHERE id = ?1", column);
+            tx.execute(&query, (media_id, provider_value))
+                .with_context(|| {
+                    format!("Failed to revert field: {} for movie {}", field, media_id)
+                })?;
+        }
+
+        tx.execute(
+            "DELETE FROM Movie_Lock WHERE movie_id = ?1 AND field = ?2",
+            (media_id, field),
+        )
+        .with_context(|| format!("Failed to unlock field: {} for movie {}", field, media_id))?;
+
+        tx.commit().context("Failed to commit movie field unlock")?;
+        emit(LibraryEvent::MediaUpdated { media_id });
+        Ok(())
+    }
+
+    /// Fails unless a movie is stored under the media id.
+    pub(super) fn check_movie_exists(media_id: i64, conn: &Connection) -> Result<()> {
+        let exists = conn
+            .query_row(
+                "SELECT COUNT(*) FROM Movie WHERE id = ?1",
+                [media_id],
+                |row| row.get::<_, i64>(0),
+            )
+            .context("Failed to look up movie")?
+            > 0;
+        if !exists {
+            return Err(anyhow!("No stored movie found for media id: {}", media_id));
+        }
+        Ok(())
+    }
+
+    /// Removes a movie with its links, watch states and lock, e.g. once its file is deleted.
+    /// Persons, genres and collections stay stored for the other movies.
+    pub fn remove_movie(&mut self, media_id: i64) -> Result<()> {
+        let tx = self
+            .conn
+            .transaction()
+            .context("Failed to open database transaction")?;
+
+        Self::clear_movie_links(media_id, &tx)?;
+        for table in ["Movie_Genre", "Movie_Lock"].iter() {
+            let query = format!("DELETE FROM {} WHERE movie_id = ?1", table);
+            tx.execute(&query, [media_id]).with_context(|| {
+                format!(
+                    "Failed to delete entries from {} table for movie {}",
+                    table, media_id
+                )
+            })?;
+        }
+        tx.execute("DELETE FROM Playback_State WHERE media_id = ?1", [media_id])
+            .with_context(|| format!("Failed to delete watch states of movie {}", media_id))?;
+
+        let deleted = tx
+            .execute("DELETE FROM Movie WHERE id = ?1", [media_id])
+            .with_context(|| format!("Failed to delete movie {}", media_id))?;
+        if deleted == 0 {
+            return Err(anyhow!("No stored movie found for media id: {}", media_id));
+        }
+
+        tx.commit().context("Failed to commit movie removal")?;
+        emit(LibraryEvent::MediaRemoved { media_id });
+        Ok(())
+    }
+
+    /// Checks that a field can be edited as a plain `Movie` column.
+    fn lockable_column(field: &str) -> Result<&str> {
+        match LOCKABLE_FIELDS.iter().find(|f| **f == field) {
+            Some(&"genres") | None => Err(anyhow!("Field: {} can not be edited", field)),
+            Some(column) => Ok(column),
+        }
+    }
+
+    fn replace_movie_genres(movie_id: i64, genre_ids: &[i64], tx: &Connection) -> Result<()> {
+        tx.execute("DELETE FROM Movie_Genre WHERE movie_id = ?1", [movie_id])
+            .with_context(|| format!("Failed to clear genres for movie {}", movie_id))?;
+
+        for genre_id in genre_ids.iter() {
+            Self::push_movie_genre(*genre_id, movie_id, tx)?;
+        }
+        Ok(())
+    }
+
+    /// Keeps the latest provider value of the locked text fields, so an unlock reverts to it.
+    ///
+    /// Locked artwork is not downloaded during scans, so its provider value stays the one
+    /// stored when it was locked.
+    fn update_provider_values(movie_id: i64, m: &MovieData, tx: &Connection) -> Result<()> {
+        let genre_ids = m
+            .genres()
+            .iter()
+            .map(|genre| genre.id().to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let provider_values = [
+            ("title", m.title()),
+            ("original_title", m.original_title()),
+            ("summary", m.summary()),
+            ("tagline", m.tagline()),
+            ("release_date", m.release_date()),
+            ("certification", m.certification()),
+            ("genres", genre_ids.as_str()),
+        ];
+
+        let mut statement = tx
+            .prepare(
+                "UPDATE Movie_Lock SET provider_value = ?3
+            WHERE movie_id = ?1 AND field = ?2",
+            )
+            .context("Failed to prepare statement for provider value update")?;
+
+        for (field, value) in provider_values.iter() {
+            statement
+                .execute((movie_id, field, value))
+                .with_context(|| {
+                    format!(
+                        "Failed to update provider value of field: {} for movie {}",
+                        field, movie_id
+                    )
+                })?;
+        }
+        Ok(())
+    }
+    // endregion
+
+    // region: ---- INSERT DATA ----
+    /// Persists a batch of scanned movies in a single transaction.
+    ///
+    /// Each record is written inside savepoints, so a failing movie is rolled back alone and
+    /// reported in the returned list, in the same order as the records. A collection or
+    /// persons failing to be written are logged and skipped, the movie still being saved.
+    /// The added and updated media are announced once the batch is committed.
+    pub fn push_batch(&mut self, records: &[MovieRecord]) -> Result<Vec<Result<()>>> {
+        let mut tx = self
+            .conn
+            .transaction()
+            .context("Failed to open database transaction")?;
+
+        let mut results = Vec::with_capacity(records.len());
+        let mut events = vec![];
+        for record in records.iter() {
+            let file_path = record.movie.file_path();
+            if let Some(collection) = record.collection.as_ref() {
+                Self::in_savepoint(&mut tx, |sp| Self::write_collection(collection, sp))?
+                    .map_err(|e| {
+                        tracing::error!(
+                            "Failed to push collection data for {} \n Caused by {:?}",
+                            file_path,
+                            e
+                        );
+                    })
+                    .ok();
             }
+
+            Self::in_savepoint(&mut tx, |sp| Self::write_persons(&record.persons, sp))?
+                .map_err(|e| {
+                    tracing::error!(
+                        "Failed to push persons data for {} \n Caused by {:?}",
+                        file_path,
+                        e
+                    );
+                })
+                .ok();
+
+            let result = Self::in_savepoint(&mut tx, |sp| {
+                Self::write_movie_data(&record.movie, &record.credits, sp)
+            })?
+            .with_context(|| format!("Failed to push movie data for {}", file_path));
+            results.push(result.map(|event| events.extend(event)));
+        }
+
+        tx.commit()
+            .context("Failed to commit batch insertion into movie table")?;
+
+        tracing::debug!(batch_size = records.len(), "Movie batch saved");
+        events.into_iter().for_each(emit);
+        Ok(results)
+    }
+
+    /// Runs a write inside a savepoint, rolled back alone when the write fails.
+    ///
+    /// The outer error is a savepoint failure, which aborts the whole transaction.
+    fn in_savepoint<T>(
+        tx: &mut Transaction,
+        write: impl FnOnce(&Connection) -> Result<T>,
+    ) -> Result<Result<T>> {
+        let savepoint = tx
+            .savepoint()
+            .context("Failed to open database savepoint")?;
+        let result = write(&savepoint);
+        if result.is_ok() {
+            savepoint
+                .commit()
+                .context("Failed to release database savepoint")?;
+        }
+        // Dropping an uncommitted savepoint rolls the write back.
+        Ok(result)
+    }
+
+    /// Stores the full TMDB genre list, so genre names are known before any movie is saved.
+    pub fn push_genres(&mut self, genres: &[Genre]) -> Result<()> {
+        let tx = self
+            .conn
+            .transaction()
+            .context("Failed to open database transaction")?;
+
+        for genre in genres.iter() {
+            tx.execute(
+                "INSERT INTO Genre ( id, name)
+                VALUES (?1, ?2)
+                ON CONFLICT(id) DO UPDATE SET name = excluded.name;",
+                (genre.id(), genre.name()),
+            )
+            .with_context(|| {
+                format!("Failed to insert new entry into Genre table for: {}", genre)
+            })?;
+        }
+
+        tx.commit()
+            .context("Failed to commit data insertion into genre table")?;
+        Ok(())
+    }
+
+    /// Persists refreshed person details outside of a scan.
+    pub fn push_persons(&mut self, persons: &[PersonData]) -> Result<()> {
+        let tx = self
+            .conn
+            .transaction()
+            .context("Failed to open database transaction")?;
+
+        Self::write_persons(persons, &tx)?;
+
+        tx.commit()
+            .context("Failed to commit data insertion into person table")?;
+        Ok(())
+    }
+
+    /// Persists all movie-related data, including basic information, genres, details, and credits.
+    ///
+    /// A matched movie replaces the genre, facet and credit links already stored for it,
+    /// while an unmatched one leaves a previously stored match untouched.
+    /// Returns the event announcing the change, None when nothing changed.
+    fn write_movie_data(
+        m: &MovieData,
+        c: &CreditsMovie,
+        tx: &Connection,
+    ) -> Result<Option<LibraryEvent>> {
+        let is_new = tx
+            .query_row(
+                "SELECT COUNT(*) FROM Movie WHERE file_path = ?1",
+                [m.file_path()],
+                |row| row.get::<_, i64>(0),
+            )
+            .with_context(|| format!("Failed to look up movie: {}", m.file_path()))?
+            == 0;
+        let movie_id = Self::push_movie(m, tx)?;
+        let event = if is_new {
+            LibraryEvent::MediaAdded { media_id: movie_id }
+        } else {
+            LibraryEvent::MediaUpdated { media_id: movie_id }
         };
 
-        Self::push_genre(movie_id, &m, &tx)
+        if m.tmdb_id() == 0 {
+            tracing::debug!(file_path = &m.file_path(), "Unmatched movie saved");
+            return Ok(is_new.then_some(event));
+        }
+
+        Self::update_provider_values(movie_id, m, tx)
+            .with_context(|| format!("Failed to keep provider values for {}", m.file_path()))?;
+
+        Self::clear_movie_links(movie_id, tx)
+            .with_context(|| format!("Failed to clear stored links for {}", m.file_path()))?;
+
+        if !Self::is_field_locked(movie_id, "genres", tx)? {
+            Self::push_genre(movie_id, m, tx)
+                .map_err(|e| {
+                    tracing::error!(
+                        "Failed to push movie genre for {} \n Caused by {:?}",
+                        m.file_path(),
+                        e
+                    );
+                })
+                .ok();
+        }
+
+        Self::push_movie_details(movie_id, m, tx)
             .map_err(|e| {
                 tracing::error!(
-                    "Failed to push movie genre for {} \n Caused by {:?}",
+                    "Failed to push movie details for {} \n Caused by {:?}",
                     m.file_path(),
                     e
                 );
             })
             .ok();
 
-        Self::push_credits(movie_id, &c, &tx)
+        Self::push_credits(movie_id, c, tx)
             .map_err(|e| {
                 tracing::error!(
                     "Failed to push movie credits for {} \n Caused by {:?}",
@@ -191,21 +1015,62 @@ impl DataSaver {
             })
             .ok();
 
-        tx.commit()
-            .context("Failed to commit data insertion into movie table")?;
-
         tracing::debug!(file_path = &m.file_path(), "Movie data saved and ready");
-        Ok(())
+        Ok(Some(event))
     }
 
-    fn push_movie(m: &MovieData, tx: &Transaction) -> Result<i64> {
-        tx.execute(
+    /// Inserts or updates a movie, keeping the stored artwork when none was downloaded
+    /// and the user-locked fields.
+    fn push_movie(m: &MovieData, tx: &Connection) -> Result<i64> {
+        let query = format!(
             "
         INSERT INTO Movie ( tmdb_id, file_path, file_optional_info, title, original_title,
-        release_date, summary, vote_average, poster, backdrop)
-        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
-        ON CONFLICT(file_path) DO NOTHING;",
-            (
+        release_date, summary, vote_average, poster, backdrop, runtime, tagline, status,
+        budget, revenue, imdb_id, collection_id, certification, poster_source, backdrop_source,
+        updated_at, date_added)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18,
+        ?19, ?20, strftime('%s', 'now'), strftime('%s', 'now'))
+        ON CONFLICT(file_path) DO UPDATE SET
+            tmdb_id = excluded.tmdb_id,
+            file_optional_info = excluded.file_optional_info,
+            {},
+            {},
+            {},
+            {},
+            vote_average = excluded.vote_average,
+            {},
+            {},
+            runtime = excluded.runtime,
+            {},
+            status = excluded.status,
+            budget = excluded.budget,
+            revenue = excluded.revenue,
+            imdb_id = excluded.imdb_id,
+            collection_id = excluded.collection_id,
+            {},
+            poster_source = COALESCE(excluded.poster_source, Movie.poster_source),
+            backdrop_source = COALESCE(excluded.backdrop_source, Movie.backdrop_source),
+            updated_at = excluded.updated_at
+        WHERE excluded.tmdb_id != 0;",
+            Self::unless_locked("title", "excluded.title"),
+            Self::unless_locked("original_title", "excluded.original_title"),
+            Self::unless_locked("release_date", "excluded.release_date"),
+            Self::unless_locked("summary", "excluded.summary"),
+            Self::unless_locked(
+                "poster",
+                "COALESCE(NULLIF(excluded.poster, ''), Movie.poster)"
+            ),
+            Self::unless_locked(
+                "backdrop",
+                "COALESCE(NULLIF(excluded.backdrop, ''), Movie.backdrop)"
+            ),
+            Self::unless_locked("tagline", "excluded.tagline"),
+            Self::unless_locked("certification", "excluded.certification"),
+        );
+
+        tx.execute(
+            &query,
+            rusqlite::params![
                 m.tmdb_id(),
                 m.file_path(),
                 m.file_optional_info(),
@@ -214,9 +1079,20 @@ impl DataSaver {
                 m.release_date(),
                 m.summary(),
                 m.vote_average(),
-                m.poster(),
-                m.backdrop(),
-            ),
+                // Movies without artwork, unmatched ones included, are stored with none
+                m.poster().map_or("", |poster| poster.as_str()),
+                m.backdrop().map_or("", |backdrop| backdrop.as_str()),
+                m.runtime(),
+                m.tagline(),
+                m.status(),
+                m.budget(),
+                m.revenue(),
+                m.imdb_id(),
+                m.collection().map(|c| c.id()),
+                m.certification(),
+                m.poster_source(),
+                m.backdrop_source(),
+            ],
         )
         .with_context(|| {
             format!(
@@ -241,33 +1117,166 @@ impl DataSaver {
         Ok(movie_id)
     }
 
-    pub fn push_persons(&mut self, persons: Vec<PersonData>) -> Result<()> {
-        let tx = self
-            .conn
-            .transaction()
-            .context("Failed to open database transaction")?;
+    /// Builds the upsert assignment of a lockable column, keeping the stored value when locked.
+    fn unless_locked(column: &str, provider_value: &str) -> String {
+        format!(
+            "{0} = CASE WHEN EXISTS (SELECT 1 FROM Movie_Lock
+                WHERE movie_id = Movie.id AND field = '{0}')
+            THEN Movie.{0} ELSE {1} END",
+            column, provider_value
+        )
+    }
 
+    fn is_field_locked(movie_id: i64, field: &str, tx: &Connection) -> Result<bool> {
+        let locked = tx
+            .query_row(
+                "SELECT COUNT(*) FROM Movie_Lock WHERE movie_id = ?1 AND field = ?2",
+                (movie_id, field),
+                |row| row.get::<_, i64>(0),
+            )
+            .with_context(|| format!("Failed to look up lock of field: {}", field))?;
+        Ok(locked > 0)
+    }
+
+    /// Removes the facet and credit links of a movie before they are written again.
+    fn clear_movie_links(movie_id: i64, tx: &Connection) -> Result<()> {
+        let link_tables = ["Company", "Country", "Language", "Keyword"];
+        for table in link_tables.iter() {
+            let query = format!("DELETE FROM Movie_{} WHERE movie_id = ?1", table);
+            tx.execute(&query, [movie_id]).with_context(|| {
+                format!(
+                    "Failed to delete entries from Movie_{} table for movie {}",
+                    table, movie_id
+                )
+            })?;
+        }
+
+        tx.execute("DELETE FROM Credits WHERE movie_id = ?1", [movie_id])
+            .with_context(|| {
+                format!(
+                    "Failed to delete entries from Credits table for movie {}",
+                    movie_id
+                )
+            })?;
+        Ok(())
+    }
+
+    /// Persists a collection and its parts, refreshing them as TMDB adds new movies.
+    fn write_collection(collection: &CollectionData, tx: &Connection) -> Result<()> {
+        tx.execute(
+            "INSERT INTO Collection (id, name, overview, poster, backdrop)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            ON CONFLICT(id) DO UPDATE SET
+                name = excluded.name,
+                overview = excluded.overview,
+                poster = COALESCE(excluded.poster, Collection.poster),
+                backdrop = COALESCE(excluded.backdrop, Collection.backdrop);",
+            (
+                collection.id(),
+                collection.name(),
+                collection.overview(),
+                collection.poster(),
+                collection.backdrop(),
+            ),
+        )
+        .with_context(|| {
+            format!(
+                "Failed to insert new entry into collection table: {}",
+                collection.name()
+            )
+        })?;
+
+        for part in collection.parts().iter() {
+            tx.execute(
+                "INSERT INTO Collection_Part (collection_id, tmdb_id, title, release_date, poster_path)
+                VALUES (?1, ?2, ?3, ?4, ?5)
+                ON CONFLICT(collection_id, tmdb_id) DO UPDATE SET
+                    title = excluded.title,
+                    release_date = excluded.release_date,
+                    poster_path = excluded.poster_path;",
+                (
+                    collection.id(),
+                    part.id(),
+                    part.title(),
+                    part.release_date(),
+                    part.poster_path(),
+                ),
+            )
+            .with_context(|| {
+                format!(
+                    "Failed to insert new entry into collection part table: {}",
+                    part.title()
+                )
+            })?;
+        }
+
+        Ok(())
+    }
+
+    fn write_persons(persons: &[PersonData], tx: &Connection) -> Result<()> {
         for p in persons.iter() {
             tx.execute(
-                "INSERT INTO Person (tmdb_id, name, summary, picture_path)
-         VALUES (?1, ?2, ?3, ?4)
-        ON CONFLICT(tmdb_id) DO NOTHING;",
-                (p.tmdb_id(), p.name(), p.summary(), p.picture_path()),
+                "INSERT INTO Person (tmdb_id, name, summary, picture_path, birthday, deathday,
+        place_of_birth, known_for_department, gender, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, strftime('%s', 'now'))
+        ON CONFLICT(tmdb_id) DO UPDATE SET
+            name = excluded.name,
+            summary = excluded.summary,
+            picture_path = COALESCE(excluded.picture_path, Person.picture_path),
+            birthday = excluded.birthday,
+            deathday = excluded.deathday,
+            place_of_birth = excluded.place_of_birth,
+            known_for_department = excluded.known_for_department,
+            gender = excluded.gender,
+            updated_at = excluded.updated_at;",
+                (
+                    p.tmdb_id(),
+                    p.name(),
+                    p.summary(),
+                    p.picture_path(),
+                    p.birthday(),
+                    p.deathday(),
+                    p.place_of_birth(),
+                    p.known_for_department(),
+                    p.gender(),
+                ),
             )
             .with_context(|| {
                 format!("Failed to insert new entry into person table: {}", p.name())
             })?;
+
+            Self::push_person_aliases(p, tx)?;
+        }
+        Ok(())
+    }
+
+    fn push_person_aliases(p: &PersonData, tx: &Connection) -> Result<()> {
+        let mut statement = tx
+            .prepare(
+                "INSERT INTO Person_Alias (person_id, name)
+         VALUES (?1, ?2)
+         ON CONFLICT(person_id, name) DO NOTHING",
+            )
+            .context("Failed to prepare statement for alias insertion into Person_Alias table")?;
+
+        for alias in p.also_known_as().iter() {
+            statement.execute((p.tmdb_id(), alias)).with_context(|| {
+                format!(
+                    "Failed to insert alias: {} into Person_Alias table for: {}",
+                    alias,
+                    p.name()
+                )
+            })?;
         }
-        tx.commit()
-            .context("Failed to commit data insertion into person table")?;
         Ok(())
     }
 
-    fn push_credits(movie_id: i64, c: &CreditsMovie, tx: &Transaction) -> Result<()> {
+    fn push_credits(movie_id: i64, c: &CreditsMovie, tx: &Connection) -> Result<()> {
         let mut statement = tx
             .prepare(
-                "INSERT INTO Credits (tmdb_id, movie_id, name, job_name, character)
-         VALUES (?1, ?2, ?3, ?4, ?5)
+                "INSERT INTO Credits (tmdb_id, movie_id, name, job_name, character, department,
+         credit_order, credit_id)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
          ON CONFLICT(tmdb_id, movie_id, character, job_name) DO NOTHING",
             )
             .context("Failed to prepare statement for credit insertion into Credits table")?;
@@ -280,6 +1289,9 @@ impl DataSaver {
                     cast.name(),
                     "actor",
                     cast.character(),
+                    "Acting",
+                    cast.order(),
+                    cast.credit_id(),
                 ))
                 .with_context(|| {
                     format!("Failed to insert cast into Person table for: {}", cast)
@@ -288,7 +1300,16 @@ impl DataSaver {
 
         for crew in c.credits_crew().iter() {
             statement
-                .execute((crew.tmdb_id(), movie_id, crew.name(), crew.job(), "N/A"))
+                .execute((
+                    crew.tmdb_id(),
+                    movie_id,
+                    crew.name(),
+                    crew.job(),
+                    "",
+                    crew.department(),
+                    None::<i32>,
+                    crew.credit_id(),
+                ))
                 .with_context(|| {
                     format!("Failed to insert crew into Person table for: {}", crew)
                 })?;
@@ -296,24 +1317,123 @@ impl DataSaver {
         Ok(())
     }
 
-    fn push_genre(movie_id: i64, m: &MovieData, tx: &Transaction) -> Result<()> {
+    fn push_genre(movie_id: i64, m: &MovieData, tx: &Connection) -> Result<()> {
         for genre in m.genres().iter() {
             tx.execute(
                 "INSERT INTO Genre ( id, name)
                 VALUES (?1, ?2)
-                ON CONFLICT(id) DO NOTHING;",
+                ON CONFLICT(id) DO UPDATE SET name = excluded.name;",
                 (genre.id(), genre.name()),
             )
             .with_context(|| {
                 format!("Failed to insert new entry into Genre table for: {}", genre)
             })?;
+        }
+
+        let genre_ids: Vec<i64> = m.genres().iter().map(|genre| genre.id()).collect();
+        Self::replace_movie_genres(movie_id, &genre_ids, tx)
+    }
 
-            Self::push_movie_genre(genre.id(), movie_id, tx)?;
+    /// Persists the studios, countries, spoken languages and keywords of a movie.
+    fn push_movie_details(movie_id: i64, m: &MovieData, tx: &Connection) -> Result<()> {
+        for company in m.production_companies().iter() {
+            tx.execute(
+                "INSERT INTO Company (id, name, logo_path, origin_country)
+                VALUES (?1, ?2, ?3, ?4)
+                ON CONFLICT(id) DO UPDATE SET
+                    name = excluded.name,
+                    logo_path = excluded.logo_path,
+                    origin_country = excluded.origin_country;",
+                (
+                    company.id(),
+                    company.name(),
+                    company.logo_path(),
+                    company.origin_country(),
+                ),
+            )
+            .with_context(|| {
+                format!(
+                    "Failed to insert new entry into Company table for: {}",
+                    company.name()
+                )
+            })?;
+            Self::push_movie_link("Company", movie_id, company.id(), tx)?;
         }
+
+        for country in m.production_countries().iter() {
+            tx.execute(
+                "INSERT INTO Country (id, name)
+                VALUES (?1, ?2)
+                ON CONFLICT(id) DO UPDATE SET name = excluded.name;",
+                (country.iso_3166_1(), country.name()),
+            )
+            .with_context(|| {
+                format!(
+                    "Failed to insert new entry into Country table for: {}",
+                    country.name()
+                )
+            })?;
+            Self::push_movie_link("Country", movie_id, country.iso_3166_1(), tx)?;
+        }
+
+        for language in m.spoken_languages().iter() {
+            tx.execute(
+                "INSERT INTO Language (id, name)
+                VALUES (?1, ?2)
+                ON CONFLICT(id) DO UPDATE SET name = excluded.name;",
+                (language.iso_639_1(), language.english_name()),
+            )
+            .with_context(|| {
+                format!(
+                    "Failed to insert new entry into Language table for: {}",
+                    language.english_name()
+                )
+            })?;
+            Self::push_movie_link("Language", movie_id, language.iso_639_1(), tx)?;
+        }
+
+        for keyword in m.keywords().iter() {
+            tx.execute(
+                "INSERT INTO Keyword (id, name)
+                VALUES (?1, ?2)
+                ON CONFLICT(id) DO UPDATE SET name = excluded.name;",
+                (keyword.id(), keyword.name()),
+            )
+            .with_context(|| {
+                format!(
+                    "Failed to insert new entry into Keyword table for: {}",
+                    keyword.name()
+                )
+            })?;
+            Self::push_movie_link("Keyword", movie_id, keyword.id(), tx)?;
+        }
+        Ok(())
+    }
+
+    /// Links a movie to an entry of a table through its `Movie_<table>` table.
+    fn push_movie_link<K: ToSql>(
+        table: &str,
+        movie_id: i64,
+        key: K,
+        tx: &Connection,
+    ) -> Result<()> {
+        let link_column = format!("{}_id", table.to_lowercase());
+        let query = format!(
+            "INSERT INTO Movie_{} (movie_id, {})
+                VALUES (?1, ?2)
+                ON CONFLICT(movie_id, {}) DO NOTHING;",
+            table, link_column, link_column
+        );
+        tx.execute(&query, (movie_id, key)).with_context(|| {
+            format!(
+                "Failed to insert entry into Movie_{} table for movie {}",
+                table, movie_id
+            )
+        })?;
         Ok(())
     }
 
-    fn push_movie_genre(genre_id: i64, movie_id: i64, tx: &Transaction) -> Result<()> {
+    fn push_movie_genre(genre_id: i64, movie_id: i64, tx: &Connection) -> Result<()> {
         tx.execute(
             "INSERT INTO Movie_Genre ( movie_id, genre_id)
                 VALUES (?1, ?2)
@@ -0,0 +1,114 @@
+use crate::{
+    db_interface::data_saver::DataSaver,
+    movie_data::movie_data::{CollectionData, CreditsMovie, MovieData, PersonData},
+};
+use anyhow::{anyhow, Context, Result};
+use tokio::{
+    sync::{mpsc, oneshot},
+    task::JoinHandle,
+};
+
+/// Everything gathered for one scanned movie, written together by the writer task.
+pub struct MovieRecord {
+    pub movie: MovieData,
+    pub credits: CreditsMovie,
+    pub collection: Option<CollectionData>,
+    pub persons: Vec<PersonData>,
+}
+
+struct WriteRequest {
+    record: MovieRecord,
+    reply: oneshot::Sender<Result<()>>,
+}
+
+/// Write result of a queued record, answered once the batch holding it is committed.
+pub struct PendingWrite(oneshot::Receiver<Result<()>>);
+
+impl PendingWrite {
+    /// Waits for the batch holding the record and returns the record's own write result.
+    pub async fn result(self) -> Result<()> {
+        self.0
+            .await
+            .context("Database writer task dropped the request")?
+    }
+}
+
+/// Owns the database connection on a dedicated blocking task fed by a bounded channel.
+///
+/// Pending records are grouped into batches written in a single transaction. When the
+/// channel is full, `write` waits, which slows the scanner down to the database pace.
+pub struct DataWriter {
+    sender: mpsc::Sender<WriteRequest>,
+    handle: JoinHandle<()>,
+}
+
+impl DataWriter {
+    pub fn spawn(data_saver: DataSaver, capacity: usize, batch_size: usize) -> Self {
+        let (sender, receiver) = mpsc::channel(capacity);
+        let handle =
+            tokio::task::spawn_blocking(move || Self::run(data_saver, receiver, batch_size));
+
+        Self { sender, handle }
+    }
+
+    /// Queues a record, only waiting while the queue is full.
+    ///
+    /// The record's write result is read from the returned `PendingWrite`, so the caller can
+    /// keep queueing records while their batch fills up.
+    pub async fn write(&self, record: MovieRecord) -> Result<PendingWrite> {
+        let (reply, response) = oneshot::channel();
+        self.sender
+            .send(WriteRequest { record, reply })
+            .await
+            .map_err(|_| anyhow!("Database writer task stopped"))?;
+        Ok(PendingWrite(response))
+    }
+
+    /// Flushes the pending records and waits for the writer task to end.
+    pub async fn close(self) -> Result<()> {
+        drop(self.sender);
+        self.handle.await.context("Database writer task panicked")
+    }
+
+    fn run(
+        mut data_saver: DataSaver,
+        mut receiver: mpsc::Receiver<WriteRequest>,
+        batch_size: usize,
+    ) {
+        while let Some(first) = receiver.blocking_recv() {
+            let mut requests = vec![first];
+            while requests.len() < batch_size {
+                match receiver.try_recv() {
+                    Ok(request) => requests.push(request),
+                    Err(_) => break,
+                }
+            }
+
+            let (records, replies): (Vec<MovieRecord>, Vec<oneshot::Sender<Result<()>>>) = requests
+                .into_iter()
+                .map(|request| (request.record, request.reply))
+                .unzip();
+
+            match data_saver.push_batch(&records) {
+                Ok(results) => {
+                    for (reply, result) in replies.into_iter().zip(results) {
+                        reply.send(result).ok();
+                    }
+                }
+                Err(e) => {
+                    tracing::error!(
+                        "Failed to write batch of {} movies \n Caused by {:?}",
+                        records.len(),
+                        e
+                    );
+                    for reply in replies {
+                        reply
+                            .send(Err(anyhow!("Batch transaction failed: {}", e)))
+                            .ok();
+                    }
+                }
+            }
+        }
+        tracing::debug!("Database writer task ended");
+    }
+}
@@ -0,0 +1,174 @@
+use crate::db_interface::data_saver::DataSaver;
+use crate::movie_data::movie_data::PairedDevice;
+use anyhow::{anyhow, Context, Result};
+use rusqlite::OptionalExtension;
+use sha2::{Digest, Sha256};
+
+/// Seconds a device pairing code stays valid once shown.
+const PAIRING_CODE_TTL_SECS: i64 = 300;
+
+impl DataSaver {
+    // region: ---- CREATE TABLES ----
+    /// Creates the tables of the devices paired with the network api and of the pending
+    /// pairing codes, both keeping hashes only.
+    pub(super) fn create_device_tables(&mut self) -> Result<()> {
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Device (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                profile_id INTEGER NOT NULL,
+                name TEXT NOT NULL,
+                token_hash TEXT NOT NULL UNIQUE,
+                created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now')),
+                last_seen INTEGER,
+                FOREIGN KEY (profile_id) REFERENCES Profile(id)
+            );",
+                (),
+            )
+            .context("Failed to create device table")?;
+
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Pairing_Code (
+                code_hash TEXT PRIMARY KEY,
+                profile_id INTEGER NOT NULL,
+                expires_at INTEGER NOT NULL,
+                FOREIGN KEY (profile_id) REFERENCES Profile(id)
+            );",
+                (),
+            )
+            .context("Failed to create pairing code table")?;
+        Ok(())
+    }
+    // endregion
+
+    // region: ---- DEVICES ----
+    /// Creates a one-time code pairing a device with a profile, valid for a few minutes.
+    pub fn create_pairing_code(&mut self, profile_id: i64) -> Result<String> {
+        self.conn
+            .execute(
+                "DELETE FROM Pairing_Code WHERE expires_at <= strftime('%s', 'now')",
+                [],
+            )
+            .context("Failed to delete expired pairing codes")?;
+
+        let code = format!("{:06}", rand::random_range(0..1_000_000));
+        self.conn
+            .execute(
+                "INSERT INTO Pairing_Code (code_hash, profile_id, expires_at)
+            VALUES (?1, ?2, strftime('%s', 'now') + ?3)
+            ON CONFLICT(code_hash) DO UPDATE SET
+                profile_id = excluded.profile_id,
+                expires_at = excluded.expires_at",
+                (Self::hash_secret(&code), profile_id, PAIRING_CODE_TTL_SECS),
+            )
+            .with_context(|| format!("Failed to save pairing code for profile {}", profile_id))?;
+        Ok(code)
+    }
+
+    /// Consumes a pairing code and registers the device, returning it with its token.
+    /// None when the code is unknown or expired.
+    pub fn pair_device(
+        &mut self,
+        code: &str,
+        name: &str,
+    ) -> Result<Option<(PairedDevice, String)>> {
+        let tx = self
+            .conn
+            .transaction()
+            .context("Failed to open database transaction")?;
+
+        let code_hash = Self::hash_secret(code);
+        let profile_id = tx
+            .query_row(
+                "SELECT profile_id FROM Pairing_Code
+            WHERE code_hash = ?1 AND expires_at > strftime('%s', 'now')",
+                [&code_hash],
+                |row| row.get::<_, i64>(0),
+            )
+            .optional()
+            .context("Failed to look up pairing code")?;
+        let Some(profile_id) = profile_id else {
+            return Ok(None);
+        };
+
+        tx.execute(
+            "DELETE FROM Pairing_Code WHERE code_hash = ?1",
+            [&code_hash],
+        )
+        .context("Failed to consume pairing code")?;
+
+        let token: String = rand::random::<[u8; 32]>()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        tx.execute(
+            "INSERT INTO Device (profile_id, name, token_hash) VALUES (?1, ?2, ?3)",
+            (profile_id, name, Self::hash_secret(&token)),
+        )
+        .with_context(|| format!("Failed to save device: {}", name))?;
+        let device_id = tx.last_insert_rowid();
+
+        let device = tx
+            .query_row(
+                "SELECT id, name, profile_id, created_at, last_seen FROM Device WHERE id = ?1",
+                [device_id],
+                |row| {
+                    Ok(PairedDevice::new(
+                        row.get(0)?,
+                        row.get(1)?,
+                        row.get(2)?,
+                        row.get(3)?,
+                        row.get(4)?,
+                    ))
+                },
+            )
+            .with_context(|| format!("Failed to read device: {}", device_id))?;
+
+        tx.commit().context("Failed to commit device pairing")?;
+        Ok(Some((device, token)))
+    }
+
+    /// Drops every pending pairing code, after too many wrong guesses.
+    pub fn clear_pairing_codes(&mut self) -> Result<()> {
+        self.conn
+            .execute("DELETE FROM Pairing_Code", [])
+            .context("Failed to delete pairing codes")?;
+        Ok(())
+    }
+
+    pub fn touch_device(&mut self, device_id: i64) -> Result<()> {
+        self.conn
+            .execute(
+                "UPDATE Device SET last_seen = strftime('%s', 'now') WHERE id = ?1",
+                [device_id],
+            )
+            .with_context(|| format!("Failed to update device: {}", device_id))?;
+        Ok(())
+    }
+
+    /// Revokes a paired device, its token being refused from then on.
+    pub fn revoke_device(&mut self, device_id: i64) -> Result<()> {
+        let deleted = self
+            .conn
+            .execute("DELETE FROM Device WHERE id = ?1", [device_id])
+            .with_context(|| format!("Failed to revoke device: {}", device_id))?;
+        if deleted == 0 {
+            return Err(anyhow!("No device found for id: {}", device_id));
+        }
+        Ok(())
+    }
+
+    /// Hashes a pairing code or device token, to look them up without storing them.
+    /// Tokens are random enough for a fast hash, and codes expire within minutes.
+    pub fn hash_secret(secret: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(secret.as_bytes());
+        hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+    // endregion
+}
@@ -0,0 +1,81 @@
+use crate::db_interface::data_saver::DataSaver;
+use crate::movie_data::movie_data::HomeRowConfig;
+use anyhow::{anyhow, Context, Result};
+
+/// Kinds and titles of the home screen rows, in their default order.
+pub const HOME_ROWS: [(&str, &str); 5] = [
+    ("continue_watching", "Continue Watching"),
+    ("recently_added", "Recently Added"),
+    (
+        "because_you_watched",
+        "From Your Recent Directors and Actors",
+    ),
+    ("favourite_genres", "Unwatched in Your Favourite Genres"),
+    ("pick_of_the_day", "Pick of the Day"),
+];
+
+/// Number of media shown in a home screen row by default.
+pub const HOME_ROW_ITEM_COUNT: u32 = 20;
+
+impl DataSaver {
+    // region: ---- CREATE TABLES ----
+    /// Creates the table of the home screen row placement of each profile.
+    /// Profiles without any row stored use the `HOME_ROWS` defaults.
+    pub(super) fn create_home_row_table(&mut self) -> Result<()> {
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Home_Row (
+                profile_id INTEGER NOT NULL,
+                kind TEXT NOT NULL,
+                position INTEGER NOT NULL,
+                enabled INTEGER NOT NULL DEFAULT 1,
+                item_count INTEGER NOT NULL,
+                PRIMARY KEY (profile_id, kind),
+                FOREIGN KEY (profile_id) REFERENCES Profile(id)
+            );",
+                (),
+            )
+            .context("Failed to create home row table")?;
+        Ok(())
+    }
+    // endregion
+
+    // region: ---- HOME FEED ----
+    /// Replaces the home screen rows of a profile, keeping their order.
+    pub fn set_home_rows(&mut self, profile_id: i64, rows: &[HomeRowConfig]) -> Result<()> {
+        if let Some(row) = rows
+            .iter()
+            .find(|row| !HOME_ROWS.iter().any(|(kind, _)| *kind == row.kind))
+        {
+            return Err(anyhow!("Unknown home row: {}", row.kind));
+        }
+
+        let tx = self
+            .conn
+            .transaction()
+            .context("Failed to open database transaction")?;
+
+        tx.execute("DELETE FROM Home_Row WHERE profile_id = ?1", [profile_id])
+            .with_context(|| format!("Failed to clear home rows of profile {}", profile_id))?;
+
+        for (position, row) in rows.iter().enumerate() {
+            tx.execute(
+                "INSERT INTO Home_Row (profile_id, kind, position, enabled, item_count)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            ON CONFLICT(profile_id, kind) DO NOTHING",
+                (
+                    profile_id,
+                    &row.kind,
+                    position as i64,
+                    row.enabled,
+                    row.item_count,
+                ),
+            )
+            .with_context(|| format!("Failed to save home row: {}", row.kind))?;
+        }
+
+        tx.commit().context("Failed to commit home rows")?;
+        Ok(())
+    }
+    // endregion
+}
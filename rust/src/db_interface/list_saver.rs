@@ -0,0 +1,244 @@
+use crate::db_interface::data_saver::DataSaver;
+use anyhow::{anyhow, Context, Result};
+use rusqlite::Connection;
+
+/// Kinds and names of the lists every profile has, created along with the profile.
+pub const BUILTIN_LISTS: [(&str, &str); 2] =
+    [("favourites", "Favourites"), ("watchlist", "Watchlist")];
+
+impl DataSaver {
+    // region: ---- CREATE TABLES ----
+    /// Creates the per-profile media list tables.
+    ///
+    /// Triggers remove the items of deleted lists and deleted movies from every list.
+    pub(super) fn create_list_tables(&mut self) -> Result<()> {
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Media_List (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                profile_id INTEGER NOT NULL,
+                name TEXT NOT NULL,
+                kind TEXT NOT NULL DEFAULT 'custom',
+                created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now')),
+                FOREIGN KEY (profile_id) REFERENCES Profile(id)
+            );",
+                (),
+            )
+            .context("Failed to create media list table")?;
+
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Media_List_Item (
+                list_id INTEGER NOT NULL,
+                media_id INTEGER NOT NULL,
+                position INTEGER NOT NULL,
+                added_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now')),
+                PRIMARY KEY (list_id, media_id),
+                FOREIGN KEY (list_id) REFERENCES Media_List(id),
+                FOREIGN KEY (media_id) REFERENCES Movie(id)
+            );",
+                (),
+            )
+            .context("Failed to create media list item table")?;
+
+        self.create_index("Media_List", "profile_id")?;
+        self.create_index("Media_List_Item", "media_id")?;
+
+        self.conn
+            .execute(
+                "CREATE TRIGGER IF NOT EXISTS trg_media_list_delete
+            AFTER DELETE ON Media_List
+            BEGIN
+                DELETE FROM Media_List_Item WHERE list_id = OLD.id;
+            END;",
+                (),
+            )
+            .context("Failed to create media list deletion trigger")?;
+
+        self.conn
+            .execute(
+                "CREATE TRIGGER IF NOT EXISTS trg_movie_delete_list_items
+            AFTER DELETE ON Movie
+            BEGIN
+                DELETE FROM Media_List_Item WHERE media_id = OLD.id;
+            END;",
+                (),
+            )
+            .context("Failed to create movie deletion trigger for media lists")?;
+
+        self.create_builtin_lists()?;
+        Ok(())
+    }
+    // endregion
+
+    // region: ---- LISTS ----
+    /// Creates a custom list for a profile, returning its id.
+    pub fn create_list(&mut self, profile_id: i64, name: &str) -> Result<i64> {
+        self.conn
+            .execute(
+                "INSERT INTO Media_List (profile_id, name, kind) VALUES (?1, ?2, 'custom')",
+                (profile_id, name),
+            )
+            .with_context(|| format!("Failed to create list: {}", name))?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    /// Creates the built-in lists each profile is missing.
+    pub(super) fn create_builtin_lists(&mut self) -> Result<()> {
+        for (kind, name) in BUILTIN_LISTS.iter() {
+            self.conn
+                .execute(
+                    "INSERT INTO Media_List (profile_id, name, kind)
+                SELECT id, ?1, ?2 FROM Profile
+                WHERE NOT EXISTS (
+                    SELECT 1 FROM Media_List WHERE profile_id = Profile.id AND kind = ?2
+                )",
+                    (name, kind),
+                )
+                .with_context(|| format!("Failed to create list: {}", kind))?;
+        }
+        Ok(())
+    }
+
+    pub fn rename_list(&mut self, profile_id: i64, list_id: i64, name: &str) -> Result<()> {
+        let updated = self
+            .conn
+            .execute(
+                "UPDATE Media_List SET name = ?3
+            WHERE id = ?1 AND profile_id = ?2 AND kind = 'custom'",
+                (list_id, profile_id, name),
+            )
+            .with_context(|| format!("Failed to rename list: {}", list_id))?;
+        if updated == 0 {
+            return Err(anyhow!("No custom list found for id: {}", list_id));
+        }
+        Ok(())
+    }
+
+    /// Deletes a custom list of a profile; its items are removed by trigger.
+    pub fn delete_list(&mut self, profile_id: i64, list_id: i64) -> Result<()> {
+        let deleted = self
+            .conn
+            .execute(
+                "DELETE FROM Media_List WHERE id = ?1 AND profile_id = ?2 AND kind = 'custom'",
+                (list_id, profile_id),
+            )
+            .with_context(|| format!("Failed to delete list: {}", list_id))?;
+        if deleted == 0 {
+            return Err(anyhow!("No custom list found for id: {}", list_id));
+        }
+        Ok(())
+    }
+
+    /// Appends a stored movie to a list, doing nothing when it is already in it.
+    pub fn add_to_list(&mut self, profile_id: i64, list_id: i64, media_id: i64) -> Result<()> {
+        self.check_list_owner(profile_id, list_id)?;
+        Self::check_movie_exists(media_id, &self.conn)?;
+        self.conn
+            .execute(
+                "INSERT INTO Media_List_Item (list_id, media_id, position)
+            SELECT ?1, ?2, COALESCE(MAX(position), -1) + 1
+            FROM Media_List_Item WHERE list_id = ?1
+            ON CONFLICT(list_id, media_id) DO NOTHING;",
+                (list_id, media_id),
+            )
+            .with_context(|| format!("Failed to add media {} to list {}", media_id, list_id))?;
+        Ok(())
+    }
+
+    pub fn remove_from_list(&mut self, profile_id: i64, list_id: i64, media_id: i64) -> Result<()> {
+        self.check_list_owner(profile_id, list_id)?;
+        let tx = self
+            .conn
+            .transaction()
+            .context("Failed to open database transaction")?;
+
+        tx.execute(
+            "DELETE FROM Media_List_Item WHERE list_id = ?1 AND media_id = ?2",
+            (list_id, media_id),
+        )
+        .with_context(|| format!("Failed to remove media {} from list {}", media_id, list_id))?;
+
+        let mut media_ids = Self::list_media_ids(list_id, &tx)?;
+        media_ids.retain(|id| *id != media_id);
+        Self::write_list_positions(list_id, &media_ids, &tx)?;
+
+        tx.commit().context("Failed to commit list item removal")?;
+        Ok(())
+    }
+
+    /// Moves an item of a list to a new zero-based position, shifting the items in between.
+    pub fn move_list_item(
+        &mut self,
+        profile_id: i64,
+        list_id: i64,
+        media_id: i64,
+        position: u32,
+    ) -> Result<()> {
+        self.check_list_owner(profile_id, list_id)?;
+        let tx = self
+            .conn
+            .transaction()
+            .context("Failed to open database transaction")?;
+
+        let mut media_ids = Self::list_media_ids(list_id, &tx)?;
+        let current = media_ids
+            .iter()
+            .position(|id| *id == media_id)
+            .ok_or_else(|| anyhow!("Media {} is not in list {}", media_id, list_id))?;
+
+        media_ids.remove(current);
+        let position = (position as usize).min(media_ids.len());
+        media_ids.insert(position, media_id);
+        Self::write_list_positions(list_id, &media_ids, &tx)?;
+
+        tx.commit().context("Failed to commit list reordering")?;
+        Ok(())
+    }
+
+    fn check_list_owner(&self, profile_id: i64, list_id: i64) -> Result<()> {
+        let owned = self
+            .conn
+            .query_row(
+                "SELECT COUNT(*) FROM Media_List WHERE id = ?1 AND profile_id = ?2",
+                (list_id, profile_id),
+                |row| row.get::<_, i64>(0),
+            )
+            .context("Failed to look up list")?
+            > 0;
+        if !owned {
+            return Err(anyhow!("No list found for id: {}", list_id));
+        }
+        Ok(())
+    }
+
+    fn list_media_ids(list_id: i64, tx: &Connection) -> Result<Vec<i64>> {
+        let mut stmt = tx
+            .prepare("SELECT media_id FROM Media_List_Item WHERE list_id = ?1 ORDER BY position")
+            .context("Failed to prepare statement for list items selection")?;
+
+        let media_ids = stmt
+            .query_map([list_id], |row| row.get::<_, i64>(0))
+            .context("Failed to get list items")?
+            .filter_map(|res| res.ok())
+            .collect();
+        Ok(media_ids)
+    }
+
+    fn write_list_positions(list_id: i64, media_ids: &[i64], tx: &Connection) -> Result<()> {
+        let mut stmt = tx
+            .prepare(
+                "UPDATE Media_List_Item SET position = ?3 WHERE list_id = ?1 AND media_id = ?2",
+            )
+            .context("Failed to prepare statement for list positions update")?;
+
+        for (position, media_id) in media_ids.iter().enumerate() {
+            stmt.execute((list_id, media_id, position as i64))
+                .with_context(|| {
+                    format!("Failed to move media {} in list {}", media_id, list_id)
+                })?;
+        }
+        Ok(())
+    }
+    // endregion
+}
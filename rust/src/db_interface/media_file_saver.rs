@@ -0,0 +1,319 @@
+use crate::db_interface::data_saver::DataSaver;
+use crate::event_bus::event_bus::emit;
+use crate::movie_data::movie_data::{LibraryEvent, MediaInfo, SubtitleFile};
+use anyhow::{Context, Result};
+
+impl DataSaver {
+    // region: ---- CREATE TABLES ----
+    /// Creates the tables of the technical info and tracks probed from the media files.
+    pub(super) fn create_media_info_tables(&mut self) -> Result<()> {
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Media_Info (
+                movie_id INTEGER PRIMARY KEY,
+                container TEXT NOT NULL,
+                duration REAL,
+                width INTEGER,
+                height INTEGER,
+                video_codec TEXT,
+                hdr_format TEXT,
+                bitrate INTEGER,
+                probed_at INTEGER NOT NULL,
+                FOREIGN KEY (movie_id) REFERENCES Movie(id)
+            );",
+                (),
+            )
+            .context("Failed to create media info table")?;
+
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Media_Stream (
+                movie_id INTEGER NOT NULL,
+                stream_index INTEGER NOT NULL,
+                kind TEXT NOT NULL,
+                codec TEXT NOT NULL,
+                language TEXT,
+                title TEXT,
+                channels INTEGER,
+                is_default INTEGER NOT NULL DEFAULT 0,
+                is_forced INTEGER NOT NULL DEFAULT 0,
+                PRIMARY KEY (movie_id, stream_index),
+                FOREIGN KEY (movie_id) REFERENCES Movie(id)
+            );",
+                (),
+            )
+            .context("Failed to create media stream table")?;
+
+        self.conn
+            .execute(
+                "CREATE TRIGGER IF NOT EXISTS trg_movie_delete_media_info
+            AFTER DELETE ON Movie
+            BEGIN
+                DELETE FROM Media_Info WHERE movie_id = OLD.id;
+                DELETE FROM Media_Stream WHERE movie_id = OLD.id;
+            END;",
+                (),
+            )
+            .context("Failed to create movie deletion trigger for media info")?;
+
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Media_Chapter (
+                movie_id INTEGER NOT NULL,
+                chapter_index INTEGER NOT NULL,
+                title TEXT,
+                start_time REAL NOT NULL,
+                end_time REAL,
+                PRIMARY KEY (movie_id, chapter_index),
+                FOREIGN KEY (movie_id) REFERENCES Movie(id)
+            );",
+                (),
+            )
+            .context("Failed to create media chapter table")?;
+
+        self.conn
+            .execute(
+                "CREATE TRIGGER IF NOT EXISTS trg_movie_delete_media_chapter
+            AFTER DELETE ON Movie
+            BEGIN
+                DELETE FROM Media_Chapter WHERE movie_id = OLD.id;
+            END;",
+                (),
+            )
+            .context("Failed to create movie deletion trigger for media chapters")?;
+        Ok(())
+    }
+
+    pub(super) fn create_subtitle_table(&mut self) -> Result<()> {
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Subtitle_File (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                movie_id INTEGER NOT NULL,
+                file_path TEXT NOT NULL UNIQUE,
+                format TEXT NOT NULL,
+                language TEXT,
+                title TEXT,
+                is_forced INTEGER NOT NULL DEFAULT 0,
+                is_sdh INTEGER NOT NULL DEFAULT 0,
+                FOREIGN KEY (movie_id) REFERENCES Movie(id)
+            );",
+                (),
+            )
+            .context("Failed to create subtitle file table")?;
+
+        self.conn
+            .execute(
+                "CREATE INDEX IF NOT EXISTS idx_subtitle_file_movie ON Subtitle_File(movie_id);",
+                (),
+            )
+            .context("Failed to create subtitle file index")?;
+
+        self.conn
+            .execute(
+                "CREATE TRIGGER IF NOT EXISTS trg_movie_delete_subtitle_file
+            AFTER DELETE ON Movie
+            BEGIN
+                DELETE FROM Subtitle_File WHERE movie_id = OLD.id;
+            END;",
+                (),
+            )
+            .context("Failed to create movie deletion trigger for subtitle files")?;
+        Ok(())
+    }
+    // endregion
+
+    // region: ---- MEDIA INFO ----
+    /// Replaces the probed technical info, tracks and chapters of a movie.
+    pub fn save_media_info(&mut self, media_id: i64, info: &MediaInfo) -> Result<()> {
+        let tx = self
+            .conn
+            .transaction()
+            .context("Failed to open database transaction")?;
+
+        tx.execute(
+            "INSERT INTO Media_Info (movie_id, container, duration, width, height, video_codec,
+                hdr_format, bitrate, probed_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, strftime('%s', 'now'))
+            ON CONFLICT(movie_id) DO UPDATE SET
+                container = excluded.container,
+                duration = excluded.duration,
+                width = excluded.width,
+                height = excluded.height,
+                video_codec = excluded.video_codec,
+                hdr_format = excluded.hdr_format,
+                bitrate = excluded.bitrate,
+                probed_at = excluded.probed_at;",
+            rusqlite::params![
+                media_id,
+                info.container,
+                info.duration,
+                info.width,
+                info.height,
+                info.video_codec,
+                info.hdr_format,
+                info.bitrate,
+            ],
+        )
+        .with_context(|| format!("Failed to save media info of movie {}", media_id))?;
+
+        tx.execute("DELETE FROM Media_Stream WHERE movie_id = ?1", [media_id])
+            .with_context(|| format!("Failed to clear media streams of movie {}", media_id))?;
+
+        for stream in info.streams.iter() {
+            tx.execute(
+                "INSERT OR REPLACE INTO Media_Stream (movie_id, stream_index, kind, codec, language,
+                    title, channels, is_default, is_forced)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                rusqlite::params![
+                    media_id,
+                    stream.index,
+                    stream.kind,
+                    stream.codec,
+                    stream.language,
+                    stream.title,
+                    stream.channels,
+                    stream.is_default,
+                    stream.is_forced,
+                ],
+            )
+            .with_context(|| {
+                format!(
+                    "Failed to save stream {} of movie {}",
+                    stream.index, media_id
+                )
+            })?;
+        }
+
+        tx.execute("DELETE FROM Media_Chapter WHERE movie_id = ?1", [media_id])
+            .with_context(|| format!("Failed to clear media chapters of movie {}", media_id))?;
+
+        for chapter in info.chapters.iter() {
+            tx.execute(
+                "INSERT OR REPLACE INTO Media_Chapter (movie_id, chapter_index, title, start_time,
+                    end_time)
+                VALUES (?1, ?2, ?3, ?4, ?5)",
+                rusqlite::params![
+                    media_id,
+                    chapter.index,
+                    chapter.title,
+                    chapter.start,
+                    chapter.end,
+                ],
+            )
+            .with_context(|| {
+                format!(
+                    "Failed to save chapter {} of movie {}",
+                    chapter.index, media_id
+                )
+            })?;
+        }
+
+        tx.commit().context("Failed to commit media info")?;
+        emit(LibraryEvent::MediaUpdated { media_id });
+        Ok(())
+    }
+    // endregion
+
+    // region: ---- SUBTITLES ----
+    /// Replaces the subtitle files of a movie, keeping the id of those still present so their
+    /// URLs stay valid. Returns whether anything changed.
+    pub fn save_subtitle_files(&mut self, media_id: i64, files: &[SubtitleFile]) -> Result<bool> {
+        let tx = self
+            .conn
+            .transaction()
+            .context("Failed to open database transaction")?;
+
+        let stored: Vec<String> = {
+            let mut stmt = tx
+                .prepare("SELECT file_path FROM Subtitle_File WHERE movie_id = ?1")
+                .with_context(|| "Failed to prepare statement for data selection")?;
+            let mapped_rows = stmt
+                .query_map([media_id], |row| row.get::<_, String>(0))
+                .with_context(|| "Failed to get select result")?;
+            mapped_rows.filter_map(|res| res.ok()).collect()
+        };
+
+        let mut changed = false;
+        for file_path in stored.iter() {
+            if files.iter().any(|file| &file.file_path == file_path) {
+                continue;
+            }
+            tx.execute(
+                "DELETE FROM Subtitle_File WHERE file_path = ?1",
+                [file_path],
+            )
+            .with_context(|| format!("Failed to remove subtitle file {}", file_path))?;
+            changed = true;
+        }
+
+        for file in files.iter() {
+            changed |= !stored.contains(&file.file_path);
+            tx.execute(
+                "INSERT INTO Subtitle_File (movie_id, file_path, format, language, title,
+                    is_forced, is_sdh)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                ON CONFLICT(file_path) DO UPDATE SET
+                    movie_id = excluded.movie_id,
+                    format = excluded.format,
+                    language = excluded.language,
+                    title = excluded.title,
+                    is_forced = excluded.is_forced,
+                    is_sdh = excluded.is_sdh;",
+                rusqlite::params![
+                    media_id,
+                    file.file_path,
+                    file.format,
+                    file.language,
+                    file.title,
+                    file.is_forced,
+                    file.is_sdh,
+                ],
+            )
+            .with_context(|| format!("Failed to save subtitle file {}", file.file_path))?;
+        }
+
+        tx.commit().context("Failed to commit subtitle files")?;
+        if changed {
+            emit(LibraryEvent::MediaUpdated { media_id });
+        }
+        Ok(changed)
+    }
+    // endregion
+
+    // region: ---- GENERATED ARTWORK ----
+    /// Sets the artwork generated from video frames of a movie, only where it has none or
+    /// already has this one, and never over a locked field. Returns whether anything changed.
+    pub fn set_generated_artwork(
+        &mut self,
+        media_id: i64,
+        poster: Option<&str>,
+        backdrop: Option<&str>,
+    ) -> Result<bool> {
+        let mut changed = false;
+        for (column, path) in [("poster", poster), ("backdrop", backdrop)] {
+            let Some(path) = path else {
+                continue;
+            };
+            let query = format!(
+                "UPDATE Movie SET {0} = ?2
+                WHERE id = ?1 AND {0} IN ('', ?2)
+                AND NOT EXISTS (SELECT 1 FROM Movie_Lock WHERE movie_id = Movie.id AND field = '{0}')",
+                column
+            );
+            let updated = self
+                .conn
+                .execute(&query, (media_id, path))
+                .with_context(|| {
+                    format!("Failed to set generated {} of movie {}", column, media_id)
+                })?;
+            changed |= updated > 0;
+        }
+
+        if changed {
+            emit(LibraryEvent::MediaUpdated { media_id });
+        }
+        Ok(changed)
+    }
+    // endregion
+}
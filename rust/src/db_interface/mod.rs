@@ -1,2 +1,9 @@
 pub mod data_getter;
 pub mod data_saver;
+pub mod data_writer;
+pub mod device_saver;
+pub mod home_row_saver;
+pub mod list_saver;
+pub mod media_file_saver;
+pub mod profile_saver;
+pub mod smart_collection_saver;
@@ -0,0 +1,466 @@
+use crate::db_interface::data_saver::DataSaver;
+use crate::event_bus::event_bus::emit;
+use crate::movie_data::movie_data::LibraryEvent;
+use anyhow::{anyhow, Context, Result};
+use argon2::{
+    password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use rusqlite::OptionalExtension;
+
+/// Tables holding per-profile data, cleared when a profile is deleted.
+const PROFILE_TABLES: [&str; 6] = [
+    "Playback_State",
+    "Media_List",
+    "Smart_Collection",
+    "Home_Row",
+    "Device",
+    "Pairing_Code",
+];
+
+/// US content ratings from the least to the most restricted, ranked to apply profile limits.
+const CERTIFICATION_RANKS: [&str; 5] = ["G", "PG", "PG-13", "R", "NC-17"];
+
+/// Share of an item that must be played for it to count as watched.
+const WATCHED_RATIO: f64 = 0.9;
+
+impl DataSaver {
+    // region: ---- CREATE TABLES ----
+    /// Creates the profile tables, seeding a default profile made active on first use.
+    pub(super) fn create_profile_tables(&mut self) -> Result<()> {
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Profile (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL UNIQUE,
+                avatar TEXT,
+                pin_hash TEXT,
+                max_certification TEXT,
+                hide_unrated INTEGER NOT NULL DEFAULT 0,
+                preferred_language TEXT
+            );",
+                (),
+            )
+            .context("Failed to create profile table")?;
+        self.add_column_if_missing("Profile", "hide_unrated", "INTEGER NOT NULL DEFAULT 0")?;
+
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Active_Profile (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                profile_id INTEGER NOT NULL,
+                FOREIGN KEY (profile_id) REFERENCES Profile(id)
+            );",
+                (),
+            )
+            .context("Failed to create active profile table")?;
+
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Certification_Rank (
+                certification TEXT PRIMARY KEY,
+                rank INTEGER NOT NULL
+            );",
+                (),
+            )
+            .context("Failed to create certification rank table")?;
+
+        for (rank, certification) in CERTIFICATION_RANKS.iter().enumerate() {
+            self.conn
+                .execute(
+                    "INSERT INTO Certification_Rank (certification, rank)
+                VALUES (?1, ?2)
+                ON CONFLICT(certification) DO UPDATE SET rank = excluded.rank;",
+                    (certification, rank as i64),
+                )
+                .with_context(|| format!("Failed to insert certification: {}", certification))?;
+        }
+
+        let is_seeded = self
+            .conn
+            .query_row("SELECT COUNT(*) FROM Profile", [], |row| {
+                row.get::<_, i64>(0)
+            })
+            .context("Failed to count profiles")?
+            > 0;
+
+        if !is_seeded {
+            self.conn
+                .execute("INSERT INTO Profile (name) VALUES ('Default')", [])
+                .context("Failed to create default profile")?;
+            let profile_id = self.conn.last_insert_rowid();
+            self.switch_profile(profile_id, None)?;
+        }
+        Ok(())
+    }
+
+    /// Creates the per-profile playback state table, one row per played, marked or rated item.
+    ///
+    /// States stored before profiles existed are moved to the active profile.
+    pub(super) fn create_playback_table(&mut self) -> Result<()> {
+        let needs_migration = self.has_column("Playback_State", "media_id")?
+            && !self.has_column("Playback_State", "profile_id")?;
+        if needs_migration {
+            self.conn
+                .execute(
+                    "ALTER TABLE Playback_State RENAME TO Playback_State_Old",
+                    [],
+                )
+                .context("Failed to rename playback state table for migration")?;
+        }
+
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Playback_State (
+                profile_id INTEGER NOT NULL,
+                media_id INTEGER NOT NULL,
+                watched INTEGER NOT NULL DEFAULT 0,
+                play_count INTEGER NOT NULL DEFAULT 0,
+                last_played INTEGER,
+                position REAL NOT NULL DEFAULT 0,
+                duration REAL NOT NULL DEFAULT 0,
+                user_rating REAL,
+                PRIMARY KEY (profile_id, media_id),
+                FOREIGN KEY (profile_id) REFERENCES Profile(id),
+                FOREIGN KEY (media_id) REFERENCES Movie(id)
+            );",
+                (),
+            )
+            .context("Failed to create playback state table")?;
+
+        if needs_migration {
+            self.conn
+                .execute(
+                    "INSERT INTO Playback_State (profile_id, media_id, watched, play_count,
+                    last_played, position, duration)
+                SELECT (SELECT profile_id FROM Active_Profile WHERE id = 1), media_id, watched,
+                    play_count, last_played, position, duration
+                FROM Playback_State_Old;",
+                    [],
+                )
+                .context("Failed to migrate playback states to the active profile")?;
+            self.conn
+                .execute("DROP TABLE Playback_State_Old", [])
+                .context("Failed to drop migrated playback state table")?;
+        }
+
+        self.create_index("Playback_State", "last_played")?;
+        Ok(())
+    }
+    // endregion
+
+    // region: ---- PROFILES ----
+    /// Creates a profile with its built-in lists, returning its id.
+    /// The PIN is stored hashed with argon2.
+    pub fn create_profile(
+        &mut self,
+        name: &str,
+        avatar: Option<String>,
+        pin: Option<&str>,
+        max_certification: Option<String>,
+        hide_unrated: bool,
+        preferred_language: Option<String>,
+    ) -> Result<i64> {
+        let pin_hash = match pin.filter(|pin| !pin.is_empty()) {
+            Some(pin) => Some(Self::hash_pin(pin)?),
+            None => None,
+        };
+
+        self.conn
+            .execute(
+                "INSERT INTO Profile (name, avatar, pin_hash, max_certification, hide_unrated,
+                preferred_language)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                (
+                    name,
+                    avatar,
+                    pin_hash,
+                    max_certification,
+                    hide_unrated,
+                    preferred_language,
+                ),
+            )
+            .with_context(|| format!("Failed to create profile: {}", name))?;
+        let profile_id = self.conn.last_insert_rowid();
+
+        self.create_builtin_lists()?;
+        Ok(profile_id)
+    }
+
+    /// Makes a profile the active one, checking its PIN when it has one.
+    pub fn switch_profile(&mut self, profile_id: i64, pin: Option<&str>) -> Result<()> {
+        let pin_hash = self
+            .conn
+            .query_row(
+                "SELECT pin_hash FROM Profile WHERE id = ?1",
+                [profile_id],
+                |row| row.get::<_, Option<String>>(0),
+            )
+            .with_context(|| format!("No profile found for id: {}", profile_id))?;
+
+        if let Some(pin_hash) = pin_hash {
+            if !pin.is_some_and(|pin| Self::verify_pin(pin, &pin_hash)) {
+                return Err(anyhow!("Wrong PIN for profile: {}", profile_id));
+            }
+        }
+
+        self.conn
+            .execute(
+                "INSERT INTO Active_Profile (id, profile_id)
+            VALUES (1, ?1)
+            ON CONFLICT(id) DO UPDATE SET profile_id = excluded.profile_id;",
+                [profile_id],
+            )
+            .with_context(|| format!("Failed to switch to profile: {}", profile_id))?;
+        Ok(())
+    }
+
+    /// Deletes a profile and its per-profile data. The last profile can not be deleted.
+    ///
+    /// The PIN of the deleted profile is required when it has one, otherwise the PIN of the
+    /// active profile when that one has one. Deleting the active profile activates the oldest
+    /// profile without a PIN, or leaves no profile active until the next `switch_profile`.
+    pub fn delete_profile(&mut self, profile_id: i64, pin: Option<&str>) -> Result<()> {
+        let tx = self
+            .conn
+            .transaction()
+            .context("Failed to open database transaction")?;
+
+        let pin_hash = tx
+            .query_row(
+                "SELECT pin_hash FROM Profile WHERE id = ?1",
+                [profile_id],
+                |row| row.get::<_, Option<String>>(0),
+            )
+            .with_context(|| format!("No profile found for id: {}", profile_id))?;
+        let active_pin_hash = tx
+            .query_row(
+                "SELECT p.pin_hash
+                FROM Profile AS p
+                INNER JOIN Active_Profile AS a ON a.profile_id = p.id
+                WHERE a.id = 1",
+                [],
+                |row| row.get::<_, Option<String>>(0),
+            )
+            .optional()
+            .context("Failed to get active profile PIN")?
+            .flatten();
+
+        if let Some(pin_hash) = pin_hash.or(active_pin_hash) {
+            if !pin.is_some_and(|pin| Self::verify_pin(pin, &pin_hash)) {
+                return Err(anyhow!("Wrong PIN to delete profile: {}", profile_id));
+            }
+        }
+
+        let remaining = tx
+            .query_row(
+                "SELECT COUNT(*) FROM Profile WHERE id != ?1",
+                [profile_id],
+                |row| row.get::<_, i64>(0),
+            )
+            .context("Failed to count profiles")?;
+        if remaining == 0 {
+            return Err(anyhow!("The last profile can not be deleted"));
+        }
+
+        for table in PROFILE_TABLES.iter() {
+            let query = format!("DELETE FROM {} WHERE profile_id = ?1", table);
+            tx.execute(&query, [profile_id]).with_context(|| {
+                format!(
+                    "Failed to delete entries from {} table for profile {}",
+                    table, profile_id
+                )
+            })?;
+        }
+
+        let was_active = tx
+            .execute(
+                "DELETE FROM Active_Profile WHERE profile_id = ?1",
+                [profile_id],
+            )
+            .context("Failed to switch away from deleted profile")?
+            > 0;
+        if was_active {
+            tx.execute(
+                "INSERT INTO Active_Profile (id, profile_id)
+                SELECT 1, id FROM Profile
+                WHERE id != ?1 AND pin_hash IS NULL
+                ORDER BY id
+                LIMIT 1",
+                [profile_id],
+            )
+            .context("Failed to activate a profile without PIN")?;
+        }
+
+        tx.execute("DELETE FROM Profile WHERE id = ?1", [profile_id])
+            .with_context(|| format!("Failed to delete profile: {}", profile_id))?;
+
+        tx.commit().context("Failed to commit profile deletion")?;
+        Ok(())
+    }
+
+    /// Hashes a PIN with argon2 and a random salt, both kept in the returned PHC string.
+    /// The slow hash keeps the few digits of a PIN from being brute forced out of the database.
+    fn hash_pin(pin: &str) -> Result<String> {
+        let salt = SaltString::encode_b64(&rand::random::<[u8; 16]>())
+            .map_err(|err| anyhow!("Failed to encode PIN salt: {}", err))?;
+        Ok(Argon2::default()
+            .hash_password(pin.as_bytes(), &salt)
+            .map_err(|err| anyhow!("Failed to hash PIN: {}", err))?
+            .to_string())
+    }
+
+    /// Whether a PIN matches a hash made by `hash_pin`, a malformed hash matching nothing.
+    fn verify_pin(pin: &str, pin_hash: &str) -> bool {
+        PasswordHash::new(pin_hash).is_ok_and(|hash| {
+            Argon2::default()
+                .verify_password(pin.as_bytes(), &hash)
+                .is_ok()
+        })
+    }
+    // endregion
+
+    // region: ---- PLAYBACK ----
+    /// Marks an item as watched, counting a play, or as unwatched. Both clear the resume position.
+    pub fn set_watched(&mut self, profile_id: i64, media_id: i64, watched: bool) -> Result<()> {
+        self.check_media_exists(media_id)?;
+        if watched {
+            self.conn
+                .execute(
+                    "INSERT INTO Playback_State (profile_id, media_id, watched, play_count,
+                    last_played)
+                VALUES (?1, ?2, 1, 1, strftime('%s', 'now'))
+                ON CONFLICT(profile_id, media_id) DO UPDATE SET
+                    watched = 1,
+                    play_count = play_count + 1,
+                    last_played = excluded.last_played,
+                    position = 0;",
+                    (profile_id, media_id),
+                )
+                .with_context(|| format!("Failed to mark media {} as watched", media_id))?;
+        } else {
+            self.conn
+                .execute(
+                    "INSERT INTO Playback_State (profile_id, media_id, watched)
+                VALUES (?1, ?2, 0)
+                ON CONFLICT(profile_id, media_id) DO UPDATE SET
+                    watched = 0,
+                    position = 0;",
+                    (profile_id, media_id),
+                )
+                .with_context(|| format!("Failed to mark media {} as unwatched", media_id))?;
+        }
+        emit(LibraryEvent::WatchStateChanged {
+            profile_id,
+            media_id,
+        });
+        Ok(())
+    }
+
+    /// Records the playback position of an item, in seconds.
+    ///
+    /// Past `WATCHED_RATIO` of the duration the item is marked watched and its position
+    /// cleared; a play is counted once per viewing even if progress keeps being reported.
+    pub fn report_progress(
+        &mut self,
+        profile_id: i64,
+        media_id: i64,
+        position: f64,
+        duration: f64,
+    ) -> Result<()> {
+        if !position.is_finite() || !duration.is_finite() {
+            return Err(anyhow!(
+                "Invalid playback progress of media {}: {} of {}",
+                media_id,
+                position,
+                duration
+            ));
+        }
+        self.check_media_exists(media_id)?;
+
+        let completed = duration > 0.0 && position >= duration * WATCHED_RATIO;
+
+        if completed {
+            self.conn
+                .execute(
+                    "INSERT INTO Playback_State (profile_id, media_id, watched, play_count,
+                    last_played, duration)
+                VALUES (?1, ?2, 1, 1, strftime('%s', 'now'), ?3)
+                ON CONFLICT(profile_id, media_id) DO UPDATE SET
+                    play_count = play_count
+                        + CASE WHEN watched = 0 OR position > 0 THEN 1 ELSE 0 END,
+                    watched = 1,
+                    last_played = excluded.last_played,
+                    position = 0,
+                    duration = excluded.duration;",
+                    (profile_id, media_id, duration),
+                )
+                .with_context(|| format!("Failed to complete playback of media {}", media_id))?;
+        } else {
+            self.conn
+                .execute(
+                    "INSERT INTO Playback_State (profile_id, media_id, last_played, position,
+                    duration)
+                VALUES (?1, ?2, strftime('%s', 'now'), ?3, ?4)
+                ON CONFLICT(profile_id, media_id) DO UPDATE SET
+                    last_played = excluded.last_played,
+                    position = excluded.position,
+                    duration = excluded.duration;",
+                    (profile_id, media_id, position.max(0.0), duration),
+                )
+                .with_context(|| {
+                    format!("Failed to save playback progress of media {}", media_id)
+                })?;
+        }
+        emit(LibraryEvent::WatchStateChanged {
+            profile_id,
+            media_id,
+        });
+        Ok(())
+    }
+
+    /// Sets or clears the rating a profile gives to an item, from 0 to 10.
+    pub fn rate_media(
+        &mut self,
+        profile_id: i64,
+        media_id: i64,
+        rating: Option<f32>,
+    ) -> Result<()> {
+        if let Some(rating) = rating.filter(|rating| !rating.is_finite()) {
+            return Err(anyhow!("Invalid rating for media {}: {}", media_id, rating));
+        }
+        self.check_media_exists(media_id)?;
+
+        let rating = rating.map(|rating| rating.clamp(0.0, 10.0));
+        self.conn
+            .execute(
+                "INSERT INTO Playback_State (profile_id, media_id, user_rating)
+            VALUES (?1, ?2, ?3)
+            ON CONFLICT(profile_id, media_id) DO UPDATE SET user_rating = excluded.user_rating;",
+                (profile_id, media_id, rating),
+            )
+            .with_context(|| format!("Failed to rate media {}", media_id))?;
+        emit(LibraryEvent::WatchStateChanged {
+            profile_id,
+            media_id,
+        });
+        Ok(())
+    }
+
+    /// Fails for a media that is not in the library, so no playback state is kept for it.
+    fn check_media_exists(&self, media_id: i64) -> Result<()> {
+        let exists = self
+            .conn
+            .query_row(
+                "SELECT EXISTS (SELECT 1 FROM Movie WHERE id = ?1)",
+                [media_id],
+                |row| row.get::<_, bool>(0),
+            )
+            .with_context(|| format!("Failed to look up media {}", media_id))?;
+        if !exists {
+            return Err(anyhow!("No media found for id: {}", media_id));
+        }
+        Ok(())
+    }
+    // endregion
+}
@@ -0,0 +1,90 @@
+use crate::db_interface::data_saver::DataSaver;
+use crate::movie_data::movie_data::SmartQuery;
+use anyhow::{anyhow, Context, Result};
+
+impl DataSaver {
+    // region: ---- CREATE TABLES ----
+    /// Creates the table of the saved smart collection queries of each profile.
+    pub(super) fn create_smart_collection_table(&mut self) -> Result<()> {
+        self.conn
+            .execute(
+                "CREATE TABLE IF NOT EXISTS Smart_Collection (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                profile_id INTEGER NOT NULL,
+                name TEXT NOT NULL,
+                query TEXT NOT NULL,
+                created_at INTEGER NOT NULL DEFAULT (strftime('%s', 'now')),
+                FOREIGN KEY (profile_id) REFERENCES Profile(id)
+            );",
+                (),
+            )
+            .context("Failed to create smart collection table")?;
+
+        self.create_index("Smart_Collection", "profile_id")?;
+        Ok(())
+    }
+    // endregion
+
+    // region: ---- SMART COLLECTIONS ----
+    /// Saves a smart collection for a profile, returning its id.
+    pub fn create_smart_collection(
+        &mut self,
+        profile_id: i64,
+        name: &str,
+        query: &SmartQuery,
+    ) -> Result<i64> {
+        let query_json = serde_json::to_string(query)
+            .with_context(|| format!("Failed to serialize smart collection: {}", name))?;
+        self.conn
+            .execute(
+                "INSERT INTO Smart_Collection (profile_id, name, query) VALUES (?1, ?2, ?3)",
+                (profile_id, name, &query_json),
+            )
+            .with_context(|| format!("Failed to create smart collection: {}", name))?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    pub fn update_smart_collection(
+        &mut self,
+        profile_id: i64,
+        collection_id: i64,
+        name: &str,
+        query: &SmartQuery,
+    ) -> Result<()> {
+        let query_json = serde_json::to_string(query)
+            .with_context(|| format!("Failed to serialize smart collection: {}", name))?;
+        let updated = self
+            .conn
+            .execute(
+                "UPDATE Smart_Collection SET name = ?3, query = ?4
+            WHERE id = ?1 AND profile_id = ?2",
+                (collection_id, profile_id, name, &query_json),
+            )
+            .with_context(|| format!("Failed to update smart collection: {}", collection_id))?;
+        if updated == 0 {
+            return Err(anyhow!(
+                "No smart collection found for id: {}",
+                collection_id
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn delete_smart_collection(&mut self, profile_id: i64, collection_id: i64) -> Result<()> {
+        let deleted = self
+            .conn
+            .execute(
+                "DELETE FROM Smart_Collection WHERE id = ?1 AND profile_id = ?2",
+                (collection_id, profile_id),
+            )
+            .with_context(|| format!("Failed to delete smart collection: {}", collection_id))?;
+        if deleted == 0 {
+            return Err(anyhow!(
+                "No smart collection found for id: {}",
+                collection_id
+            ));
+        }
+        Ok(())
+    }
+    // endregion
+}
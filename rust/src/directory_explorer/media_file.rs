@@ -0,0 +1,123 @@
+use crate::directory_explorer::smb_explorer::SmbExplorer;
+use anyhow::{Context, Result};
+use std::{io::SeekFrom, path::PathBuf};
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+/// Where the library files are read from, the stored file paths being relative to it.
+#[derive(Debug, Clone)]
+pub enum MediaSource {
+    /// A SMB share, e.g. "\\\\server\\share", read with the given credentials.
+    Smb {
+        path: String,
+        username: String,
+        password: String,
+    },
+    /// A local or already mounted folder.
+    Local { root: String },
+}
+
+/// Source of the library files, connected once and shared by every file opened from it.
+pub enum FileSource {
+    Smb(SmbExplorer),
+    Local(PathBuf),
+}
+
+impl FileSource {
+    pub async fn connect(source: MediaSource) -> Result<Self> {
+        match source {
+            MediaSource::Smb {
+                path,
+                username,
+                password,
+            } => Ok(FileSource::Smb(
+                SmbExplorer::new(path, username, password)
+                    .await
+                    .context("Failed to connect to SMB share")?,
+            )),
+            MediaSource::Local { root } => Ok(FileSource::Local(PathBuf::from(root))),
+        }
+    }
+
+    /// Returns the names of the files of a library folder, "" being the root.
+    pub async fn list_files(&self, folder: &str) -> Result<Vec<String>> {
+        match self {
+            FileSource::Smb(explorer) => explorer.list_files(folder).await,
+            FileSource::Local(root) => {
+                let path = root.join(folder);
+                let mut entries = tokio::fs::read_dir(&path)
+                    .await
+                    .with_context(|| format!("Failed to open directory: {:?}", path))?;
+
+                let mut files = vec![];
+                while let Some(entry) = entries
+                    .next_entry()
+                    .await
+                    .with_context(|| format!("Failed to get files info in: {:?}", path))?
+                {
+                    if entry.file_type().await.is_ok_and(|kind| kind.is_file()) {
+                        files.push(entry.file_name().to_string_lossy().into_owned());
+                    }
+                }
+                Ok(files)
+            }
+        }
+    }
+}
+
+/// A library file opened for reading at arbitrary offsets.
+pub enum OpenedFile {
+    Smb(smb::File),
+    Local(tokio::fs::File),
+}
+
+impl OpenedFile {
+    /// Opens a library file, returning it with its size.
+    pub async fn open(source: &FileSource, file_path: &str) -> Result<(Self, u64)> {
+        match source {
+            FileSource::Smb(explorer) => {
+                let file = explorer.open_file(file_path).await?;
+                let len = smb::GetLen::get_len(&file)
+                    .await
+                    .with_context(|| format!("Failed to get file size: {}", file_path))?;
+                Ok((OpenedFile::Smb(file), len))
+            }
+            FileSource::Local(root) => {
+                let path = root.join(file_path);
+                let file = tokio::fs::File::open(&path)
+                    .await
+                    .with_context(|| format!("Failed to open file: {:?}", path))?;
+                let len = file
+                    .metadata()
+                    .await
+                    .with_context(|| format!("Failed to get file size: {:?}", path))?
+                    .len();
+                Ok((OpenedFile::Local(file), len))
+            }
+        }
+    }
+
+    pub async fn read_at(&mut self, buf: &mut [u8], pos: u64) -> std::io::Result<usize> {
+        match self {
+            OpenedFile::Smb(file) => file.read_block(buf, pos, None, false).await,
+            OpenedFile::Local(file) => {
+                file.seek(SeekFrom::Start(pos)).await?;
+                file.read(buf).await
+            }
+        }
+    }
+
+    /// Reads up to `buf.len()` bytes from `pos`, fewer only at the end of the file.
+    pub async fn read_full_at(&mut self, buf: &mut [u8], pos: u64) -> std::io::Result<usize> {
+        let mut filled = 0;
+        while filled < buf.len() {
+            let read = self
+                .read_at(&mut buf[filled..], pos + filled as u64)
+                .await?;
+            if read == 0 {
+                break;
+            }
+            filled += read;
+        }
+        Ok(filled)
+    }
+}
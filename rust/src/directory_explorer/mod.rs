@@ -1 +1,2 @@
+pub mod media_file;
 pub mod smb_explorer;
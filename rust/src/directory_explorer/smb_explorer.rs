@@ -2,7 +2,8 @@ use crate::movie_data::movie_data::MovieData;
 use anyhow::{anyhow, Context, Result};
 use async_stream::stream;
 use smb::{
-    Client, ClientConfig, Directory, FileAccessMask, FileDirectoryInformation, Resource, UncPath,
+    Client, ClientConfig, Directory, File, FileAccessMask, FileDirectoryInformation, Resource,
+    UncPath,
 };
 use tracing::debug_span;
 
@@ -10,6 +11,8 @@ use std::{str::FromStr, sync::Arc};
 use trpl::{Stream, StreamExt};
 
 /// Represents the state and configuration for exploring an SMB shared directory.
+/// Clones share the same connection.
+#[derive(Clone)]
 pub struct SmbExplorer {
     tree: Arc<smb::Tree>,
 }
@@ -30,7 +33,7 @@ impl SmbExplorer {
             .await
             .with_context(|| format!("Failed to retrieve directory from remote: {}", &path))?;
 
-        Ok(Self { tree: tree })
+        Ok(Self { tree })
     }
 
     /// Recursively explores an SMB path and returns a stream of discovered movies.
@@ -66,7 +69,7 @@ impl SmbExplorer {
                 } else {
 
                     let file_path = self.parse_file_path(&entry, path);
-                    if !self.is_video_file(&entry.file_name.to_string()) || !self.is_not_featurette(&path) {
+                    if !self.is_video_file(&entry.file_name.to_string()) || !self.is_not_featurette(path) {
                         continue;
                      }
 
@@ -92,14 +95,51 @@ impl SmbExplorer {
 
         let resource = self
             .tree
-            .open_existing(&path, access_mask)
+            .open_existing(path, access_mask)
             .await
             .with_context(|| format!("Failed to open ressource: {}", path))?;
 
         if let Resource::Directory(dir) = resource {
             Ok(Arc::from(dir))
         } else {
-            return Err(anyhow!("Ressource is not a directory: {}", path));
+            Err(anyhow!("Ressource is not a directory: {}", path))
+        }
+    }
+
+    /// Returns the names of the files of a folder, subfolders left out.
+    pub async fn list_files(&self, path: &str) -> Result<Vec<String>> {
+        let dir = self
+            .read_directory(path)
+            .await
+            .context("Failed to open directory")?;
+
+        let mut entries = smb::Directory::query::<FileDirectoryInformation>(&dir, "*")
+            .await
+            .with_context(|| format!("Failed to get files info in: {}", path))?;
+
+        let mut files = vec![];
+        while let Some(entry) = entries.try_next().await? {
+            if !entry.file_attributes.directory() {
+                files.push(entry.file_name.to_string());
+            }
+        }
+        Ok(files)
+    }
+
+    /// Opens the given SMB file path for reading and returns Ok(file) if it is a file, or an error otherwise.
+    pub async fn open_file(&self, path: &str) -> Result<File> {
+        let access_mask = FileAccessMask::new().with_generic_read(true);
+
+        let resource = self
+            .tree
+            .open_existing(path, access_mask)
+            .await
+            .with_context(|| format!("Failed to open ressource: {}", path))?;
+
+        if let Resource::File(file) = resource {
+            Ok(file)
+        } else {
+            Err(anyhow!("Ressource is not a file: {}", path))
         }
     }
 
@@ -115,16 +155,16 @@ impl SmbExplorer {
         } else {
             format!("{}/{}", path, dir_entry.file_name)
         };
-        return (true, sub_path);
+        (true, sub_path)
     }
 
     /// Parses a file path into its components.
     fn parse_file_path(&self, file_entry: &FileDirectoryInformation, path: &str) -> String {
         if path.is_empty() {
-            return file_entry.file_name.to_string();
+            file_entry.file_name.to_string()
         } else {
-            return format!("{}/{}", path, file_entry.file_name);
-        };
+            format!("{}/{}", path, file_entry.file_name)
+        }
     }
     // endregion
     // region: ---- FILTER VIDEOS ----
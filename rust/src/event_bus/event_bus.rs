@@ -0,0 +1,32 @@
+use crate::movie_data::movie_data::LibraryEvent;
+use async_stream::stream;
+use std::sync::LazyLock;
+use tokio::sync::broadcast::{self, error::RecvError};
+
+/// Events buffered for a subscriber falling behind before the oldest ones are dropped.
+const EVENT_CAPACITY: usize = 256;
+
+/// Process wide channel the library changes are published on.
+static EVENT_BUS: LazyLock<broadcast::Sender<LibraryEvent>> =
+    LazyLock::new(|| broadcast::channel(EVENT_CAPACITY).0);
+
+/// Publishes a library event to every subscriber, doing nothing when there is none.
+pub fn emit(event: LibraryEvent) {
+    tracing::trace!(event = ?event, "Library event");
+    EVENT_BUS.send(event).ok();
+}
+
+/// Returns the events published from now on.
+/// A subscriber too slow to keep up gets an `EventsMissed` in place of the events it lost.
+pub fn library_events() -> impl futures::Stream<Item = LibraryEvent> {
+    let mut receiver = EVENT_BUS.subscribe();
+    stream! {
+        loop {
+            match receiver.recv().await {
+                Ok(event) => yield event,
+                Err(RecvError::Lagged(count)) => yield LibraryEvent::EventsMissed { count },
+                Err(RecvError::Closed) => break,
+            }
+        }
+    }
+}
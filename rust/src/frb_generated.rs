@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1975334914;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 642637354;
 
 // Section: executor
 
@@ -45,7 +45,7 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__media__get_media_impl(
+fn wire__crate__api__media__add_to_list_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -53,7 +53,7 @@ fn wire__crate__api__media__get_media_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_media",
+            debug_name: "add_to_list",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -67,12 +67,13 @@ fn wire__crate__api__media__get_media_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_list_id = <i64>::sse_decode(&mut deserializer);
             let api_media_id = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::media::get_media(api_media_id)?;
+                        let output_ok = crate::api::media::add_to_list(api_list_id, api_media_id)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -80,7 +81,7 @@ fn wire__crate__api__media__get_media_impl(
         },
     )
 }
-fn wire__crate__api__media__get_media_cast_impl(
+fn wire__crate__api__media__create_list_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -88,7 +89,7 @@ fn wire__crate__api__media__get_media_cast_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_media_cast",
+            debug_name: "create_list",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -102,12 +103,12 @@ fn wire__crate__api__media__get_media_cast_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::media::get_media_cast(api_media_id)?;
+                        let output_ok = crate::api::media::create_list(&api_name)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -115,7 +116,7 @@ fn wire__crate__api__media__get_media_cast_impl(
         },
     )
 }
-fn wire__crate__api__media__get_media_crew_impl(
+fn wire__crate__api__media__create_pairing_code_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -123,7 +124,7 @@ fn wire__crate__api__media__get_media_crew_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_media_crew",
+            debug_name: "create_pairing_code",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -137,12 +138,11 @@ fn wire__crate__api__media__get_media_crew_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_media_id = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::media::get_media_crew(api_media_id)?;
+                        let output_ok = crate::api::media::create_pairing_code()?;
                         Ok(output_ok)
                     })(),
                 )
@@ -150,7 +150,7 @@ fn wire__crate__api__media__get_media_crew_impl(
         },
     )
 }
-fn wire__crate__api__media__get_media_snapshots_impl(
+fn wire__crate__api__media__create_profile_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -158,7 +158,7 @@ fn wire__crate__api__media__get_media_snapshots_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_media_snapshots",
+            debug_name: "create_profile",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -172,12 +172,24 @@ fn wire__crate__api__media__get_media_snapshots_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_media_type = <String>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_avatar = <Option<String>>::sse_decode(&mut deserializer);
+            let api_pin = <Option<String>>::sse_decode(&mut deserializer);
+            let api_max_certification = <Option<String>>::sse_decode(&mut deserializer);
+            let api_hide_unrated = <bool>::sse_decode(&mut deserializer);
+            let api_preferred_language = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::media::get_media_snapshots(&api_media_type)?;
+                        let output_ok = crate::api::media::create_profile(
+                            &api_name,
+                            api_avatar,
+                            api_pin,
+                            api_max_certification,
+                            api_hide_unrated,
+                            api_preferred_language,
+                        )?;
                         Ok(output_ok)
                     })(),
                 )
@@ -185,7 +197,7 @@ fn wire__crate__api__media__get_media_snapshots_impl(
         },
     )
 }
-fn wire__crate__api__media__get_person_impl(
+fn wire__crate__api__media__create_smart_collection_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -193,7 +205,7 @@ fn wire__crate__api__media__get_person_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_person",
+            debug_name: "create_smart_collection",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -207,12 +219,15 @@ fn wire__crate__api__media__get_person_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_person_tmdb_id = <i64>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_query =
+                <crate::movie_data::movie_data::SmartQuery>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::media::get_person(api_person_tmdb_id)?;
+                        let output_ok =
+                            crate::api::media::create_smart_collection(&api_name, api_query)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -220,7 +235,7 @@ fn wire__crate__api__media__get_person_impl(
         },
     )
 }
-fn wire__crate__api__media__init_app_impl(
+fn wire__crate__api__media__delete_list_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -228,7 +243,7 @@ fn wire__crate__api__media__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "delete_list",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -242,19 +257,20 @@ fn wire__crate__api__media__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_list_id = <i64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::media::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::delete_list(api_list_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
             }
         },
     )
 }
-fn wire__crate__api__media__open_video_impl(
+fn wire__crate__api__media__delete_profile_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -262,7 +278,7 @@ fn wire__crate__api__media__open_video_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "open_video",
+            debug_name: "delete_profile",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -276,12 +292,13 @@ fn wire__crate__api__media__open_video_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_profile_id = <i64>::sse_decode(&mut deserializer);
+            let api_pin = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::media::open_video(&api_path)?;
+                        let output_ok = crate::api::media::delete_profile(api_profile_id, api_pin)?;
                         Ok(output_ok)
                     })(),
                 )
@@ -289,7 +306,43 @@ fn wire__crate__api__media__open_video_impl(
         },
     )
 }
-fn wire__crate__api__media__start_impl(
+fn wire__crate__api__media__delete_smart_collection_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "delete_smart_collection",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_collection_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::media::delete_smart_collection(api_collection_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__discover_subtitle_files_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -297,7 +350,7 @@ fn wire__crate__api__media__start_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "start",
+            debug_name: "discover_subtitle_files",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -311,23 +364,14 @@ fn wire__crate__api__media__start_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_path = <String>::sse_decode(&mut deserializer);
-            let api_username = <String>::sse_decode(&mut deserializer);
-            let api_password = <String>::sse_decode(&mut deserializer);
-            let api_token = <String>::sse_decode(&mut deserializer);
+            let api_source =
+                <crate::directory_explorer::media_file::MediaSource>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ()>(
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::media::start(
-                                &api_path,
-                                &api_username,
-                                &api_password,
-                                &api_token,
-                            )
-                            .await,
-                        )?;
+                        let output_ok =
+                            crate::api::media::discover_subtitle_files(api_source).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -336,7 +380,168 @@ fn wire__crate__api__media__start_impl(
         },
     )
 }
-fn wire__crate__api__media__tempo_mount_smb_impl(
+fn wire__crate__api__media__edit_media_artwork_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "edit_media_artwork",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            let api_kind = <String>::sse_decode(&mut deserializer);
+            let api_image_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::edit_media_artwork(
+                            api_media_id,
+                            &api_kind,
+                            &api_image_path,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__edit_media_field_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "edit_media_field",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            let api_field = <String>::sse_decode(&mut deserializer);
+            let api_value = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::edit_media_field(
+                            api_media_id,
+                            &api_field,
+                            api_value,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__edit_media_genres_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "edit_media_genres",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            let api_genre_ids = <Vec<i64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::media::edit_media_genres(api_media_id, api_genre_ids)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__edit_smart_collection_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "edit_smart_collection",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_collection_id = <i64>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_query =
+                <crate::movie_data::movie_data::SmartQuery>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::edit_smart_collection(
+                            api_collection_id,
+                            &api_name,
+                            api_query,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__generate_media_thumbnails_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -344,7 +549,7 @@ fn wire__crate__api__media__tempo_mount_smb_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "tempo_mount_smb",
+            debug_name: "generate_media_thumbnails",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -358,11 +563,18 @@ fn wire__crate__api__media__tempo_mount_smb_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source =
+                <crate::directory_explorer::media_file::MediaSource>::sse_decode(&mut deserializer);
+            let api_regenerate = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || async move {
-                        let output_ok = crate::api::media::tempo_mount_smb().await?;
+                        let output_ok = crate::api::media::generate_media_thumbnails(
+                            api_source,
+                            api_regenerate,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -371,7 +583,7 @@ fn wire__crate__api__media__tempo_mount_smb_impl(
         },
     )
 }
-fn wire__crate__api__media__tempo_unmount_smb_impl(
+fn wire__crate__api__media__get_active_profile_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -379,7 +591,7 @@ fn wire__crate__api__media__tempo_unmount_smb_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "tempo_unmount_smb",
+            debug_name: "get_active_profile",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -397,7 +609,7 @@ fn wire__crate__api__media__tempo_unmount_smb_impl(
             move |context| {
                 transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
                     (move || {
-                        let output_ok = crate::api::media::tempo_unmount_smb()?;
+                        let output_ok = crate::api::media::get_active_profile()?;
                         Ok(output_ok)
                     })(),
                 )
@@ -405,395 +617,5280 @@ fn wire__crate__api__media__tempo_unmount_smb_impl(
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
-}
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
-}
-
-impl SseDecode for f32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
-    }
-}
-
-impl SseDecode for i64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
-    }
-}
-
-impl SseDecode for Vec<crate::movie_data::movie_data::MovieSnapshot> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
+fn wire__crate__api__media__get_collection_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_collection",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_collection_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_collection(api_collection_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_collection_missing_parts_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_collection_missing_parts",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_collection_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::media::get_collection_missing_parts(api_collection_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_collections_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_collections",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_collections()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_continue_watching_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_continue_watching",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_continue_watching(api_limit)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_credit_filter_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_credit_filter",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_credit_filter()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_facets_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_facets",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_kind = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_facets(&api_kind)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_favourites_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_favourites",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_page = <u32>::sse_decode(&mut deserializer);
+            let api_page_size = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_favourites(api_page, api_page_size)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_home_feed_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_home_feed",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_home_feed()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_home_feed_rows_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_home_feed_rows",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_home_feed_rows()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_list_items_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_list_items",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_list_id = <i64>::sse_decode(&mut deserializer);
+            let api_page = <u32>::sse_decode(&mut deserializer);
+            let api_page_size = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_list_items(
+                            api_list_id,
+                            api_page,
+                            api_page_size,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_lists_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_lists",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_lists()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_media_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_media",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_media(api_media_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_media_cast_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_media_cast",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_media_cast(api_media_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_media_chapters_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_media_chapters",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_media_chapters(api_media_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_media_crew_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_media_crew",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_media_crew(api_media_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_media_facets_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_media_facets",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_media_facets(api_media_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_media_locked_fields_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_media_locked_fields",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_media_locked_fields(api_media_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_media_snapshots_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_media_snapshots",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_type = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_media_snapshots(&api_media_type)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_media_snapshots_by_facet_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_media_snapshots_by_facet",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_kind = <String>::sse_decode(&mut deserializer);
+            let api_key = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::media::get_media_snapshots_by_facet(&api_kind, &api_key)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_paired_devices_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_paired_devices",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_paired_devices()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_person_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_person",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_person_tmdb_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_person(api_person_tmdb_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_person_filmography_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_person_filmography",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_person_tmdb_id = <i64>::sse_decode(&mut deserializer);
+            let api_token = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::media::get_person_filmography(
+                            api_person_tmdb_id,
+                            api_token,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_playback_decision_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_playback_decision",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            let api_request =
+                <crate::movie_data::movie_data::PlaybackRequest>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::media::get_playback_decision(api_media_id, api_request)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_playback_state_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_playback_state",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_playback_state(api_media_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_profiles_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_profiles",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_profiles()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_recently_watched_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_recently_watched",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_recently_watched(api_limit)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_similar_media_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_similar_media",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::media::get_similar_media(api_media_id, api_limit)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_smart_collection_items_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_smart_collection_items",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_collection_id = <i64>::sse_decode(&mut deserializer);
+            let api_page = <u32>::sse_decode(&mut deserializer);
+            let api_page_size = <u32>::sse_decode(&mut deserializer);
+            let api_seed = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_smart_collection_items(
+                            api_collection_id,
+                            api_page,
+                            api_page_size,
+                            api_seed,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_smart_collections_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_smart_collections",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_smart_collections()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_stream_url_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_stream_url",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::media::get_stream_url(api_media_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_subtitle_files_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_subtitle_files",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_subtitle_files(api_media_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_subtitle_url_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_subtitle_url",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            let api_subtitle_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::media::get_subtitle_url(api_media_id, api_subtitle_id)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_trickplay_url_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_trickplay_url",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::media::get_trickplay_url(api_media_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_watch_recommendations_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_watch_recommendations",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_watch_recommendations(api_limit)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__get_watchlist_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_watchlist",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_page = <u32>::sse_decode(&mut deserializer);
+            let api_page_size = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::get_watchlist(api_page, api_page_size)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::media::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__media__is_transcoding_available_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_transcoding_available",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::media::is_transcoding_available())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__media__mark_media_watched_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "mark_media_watched",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            let api_watched = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::media::mark_media_watched(api_media_id, api_watched)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__move_list_item_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "move_list_item",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_list_id = <i64>::sse_decode(&mut deserializer);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            let api_position = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::move_list_item(
+                            api_list_id,
+                            api_media_id,
+                            api_position,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__open_video_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "open_video",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::open_video(&api_path)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__preview_smart_collection_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "preview_smart_collection",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query =
+                <crate::movie_data::movie_data::SmartQuery>::sse_decode(&mut deserializer);
+            let api_page = <u32>::sse_decode(&mut deserializer);
+            let api_page_size = <u32>::sse_decode(&mut deserializer);
+            let api_seed = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::preview_smart_collection(
+                            api_query,
+                            api_page,
+                            api_page_size,
+                            api_seed,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__probe_media_files_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "probe_media_files",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source =
+                <crate::directory_explorer::media_file::MediaSource>::sse_decode(&mut deserializer);
+            let api_reprobe_all = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::media::probe_media_files(api_source, api_reprobe_all)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__quick_search_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "quick_search",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::quick_search(&api_query, api_limit)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__rate_media_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rate_media",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            let api_rating = <Option<f32>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::rate_media(api_media_id, api_rating)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__reapply_credit_filter_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reapply_credit_filter",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::reapply_credit_filter()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__refresh_library_metadata_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "refresh_library_metadata",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::media::refresh_library_metadata(&api_token).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__refresh_movie_metadata_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "refresh_movie_metadata",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::media::refresh_movie_metadata(api_media_id, &api_token)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__refresh_person_metadata_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "refresh_person_metadata",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_person_tmdb_id = <i64>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::media::refresh_person_metadata(
+                            api_person_tmdb_id,
+                            &api_token,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__refresh_stale_metadata_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "refresh_stale_metadata",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_max_age_days = <u32>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::media::refresh_stale_metadata(api_max_age_days, &api_token)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__remove_from_list_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_from_list",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_list_id = <i64>::sse_decode(&mut deserializer);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::media::remove_from_list(api_list_id, api_media_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__remove_media_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_media",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::remove_media(api_media_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__rename_list_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rename_list",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_list_id = <i64>::sse_decode(&mut deserializer);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::rename_list(api_list_id, &api_name)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__report_playback_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "report_playback_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            let api_position = <f64>::sse_decode(&mut deserializer);
+            let api_duration = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::report_playback_progress(
+                            api_media_id,
+                            api_position,
+                            api_duration,
+                        )?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__revoke_device_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "revoke_device",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_device_id = <i64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::revoke_device(api_device_id)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__set_credit_filter_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_credit_filter",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_filter =
+                <crate::movie_data::movie_data::CreditFilter>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::set_credit_filter(api_filter)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__set_favourite_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_favourite",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            let api_favourite = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::media::set_favourite(api_media_id, api_favourite)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__set_home_feed_rows_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_home_feed_rows",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_rows =
+                <Vec<crate::movie_data::movie_data::HomeRowConfig>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::set_home_feed_rows(api_rows)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__set_in_watchlist_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_in_watchlist",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            let api_in_watchlist = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::media::set_in_watchlist(api_media_id, api_in_watchlist)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__start_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_username = <String>::sse_decode(&mut deserializer);
+            let api_password = <String>::sse_decode(&mut deserializer);
+            let api_token = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::media::start(
+                                &api_path,
+                                &api_username,
+                                &api_password,
+                                &api_token,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__start_stream_server_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_stream_server",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source =
+                <crate::directory_explorer::media_file::MediaSource>::sse_decode(&mut deserializer);
+            let api_port = <u16>::sse_decode(&mut deserializer);
+            let api_allow_network = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::media::start_stream_server(
+                            api_source,
+                            api_port,
+                            api_allow_network,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__stop_stream_server_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_stream_server",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::media::stop_stream_server().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__stop_transcoding_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "stop_transcoding",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::media::stop_transcoding(api_session_id).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__switch_profile_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "switch_profile",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_profile_id = <i64>::sse_decode(&mut deserializer);
+            let api_pin = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::switch_profile(api_profile_id, api_pin)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__tempo_mount_smb_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tempo_mount_smb",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::media::tempo_mount_smb().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__tempo_unmount_smb_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tempo_unmount_smb",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok = crate::api::media::tempo_unmount_smb()?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__unlock_media_field_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unlock_media_field",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_media_id = <i64>::sse_decode(&mut deserializer);
+            let api_field = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || {
+                        let output_ok =
+                            crate::api::media::unlock_media_field(api_media_id, &api_field)?;
+                        Ok(output_ok)
+                    })(),
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__media__watch_library_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "watch_library_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::movie_data::movie_data::LibraryEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
+                    (move || async move {
+                        let output_ok = crate::api::media::watch_library_events(api_sink).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::movie_data::movie_data::LibraryEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::AllowedJob {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_department = <String>::sse_decode(deserializer);
+        let mut var_job = <String>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::AllowedJob {
+            department: var_department,
+            job: var_job,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::ClientCapabilities {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_containers = <Vec<String>>::sse_decode(deserializer);
+        let mut var_videoCodecs = <Vec<String>>::sse_decode(deserializer);
+        let mut var_audioCodecs = <Vec<String>>::sse_decode(deserializer);
+        let mut var_maxWidth = <Option<i32>>::sse_decode(deserializer);
+        let mut var_maxHeight = <Option<i32>>::sse_decode(deserializer);
+        let mut var_maxBitrate = <Option<i64>>::sse_decode(deserializer);
+        let mut var_maxAudioChannels = <Option<i32>>::sse_decode(deserializer);
+        let mut var_supportsHdr = <bool>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::ClientCapabilities {
+            containers: var_containers,
+            video_codecs: var_videoCodecs,
+            audio_codecs: var_audioCodecs,
+            max_width: var_maxWidth,
+            max_height: var_maxHeight,
+            max_bitrate: var_maxBitrate,
+            max_audio_channels: var_maxAudioChannels,
+            supports_hdr: var_supportsHdr,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::CollectionDetail {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_overview = <String>::sse_decode(deserializer);
+        let mut var_poster = <Option<String>>::sse_decode(deserializer);
+        let mut var_backdrop = <Option<String>>::sse_decode(deserializer);
+        let mut var_parts =
+            <Vec<crate::movie_data::movie_data::MovieSnapshot>>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::CollectionDetail {
+            id: var_id,
+            name: var_name,
+            overview: var_overview,
+            poster: var_poster,
+            backdrop: var_backdrop,
+            parts: var_parts,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::CollectionPartSnapshot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tmdbId = <i64>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_releaseDate = <String>::sse_decode(deserializer);
+        let mut var_posterPath = <Option<String>>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::CollectionPartSnapshot {
+            tmdb_id: var_tmdbId,
+            title: var_title,
+            release_date: var_releaseDate,
+            poster_path: var_posterPath,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::CollectionSnapshot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_poster = <Option<String>>::sse_decode(deserializer);
+        let mut var_ownedCount = <i64>::sse_decode(deserializer);
+        let mut var_partCount = <i64>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::CollectionSnapshot {
+            id: var_id,
+            name: var_name,
+            poster: var_poster,
+            owned_count: var_ownedCount,
+            part_count: var_partCount,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::CreditFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_allowedJobs =
+            <Vec<crate::movie_data::movie_data::AllowedJob>>::sse_decode(deserializer);
+        let mut var_maxCastSize = <Option<u32>>::sse_decode(deserializer);
+        let mut var_includeUncredited = <bool>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::CreditFilter {
+            allowed_jobs: var_allowedJobs,
+            max_cast_size: var_maxCastSize,
+            include_uncredited: var_includeUncredited,
+        };
+    }
+}
+
+impl SseDecode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::FacetSnapshot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_mediaCount = <i64>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::FacetSnapshot {
+            kind: var_kind,
+            key: var_key,
+            name: var_name,
+            media_count: var_mediaCount,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::FilmographyEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mediaId = <Option<i64>>::sse_decode(deserializer);
+        let mut var_tmdbId = <i64>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_releaseDate = <String>::sse_decode(deserializer);
+        let mut var_poster = <Option<String>>::sse_decode(deserializer);
+        let mut var_department = <String>::sse_decode(deserializer);
+        let mut var_character = <String>::sse_decode(deserializer);
+        let mut var_jobName = <String>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::FilmographyEntry {
+            media_id: var_mediaId,
+            tmdb_id: var_tmdbId,
+            title: var_title,
+            release_date: var_releaseDate,
+            poster: var_poster,
+            department: var_department,
+            character: var_character,
+            job_name: var_jobName,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::FilmographyGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_department = <String>::sse_decode(deserializer);
+        let mut var_entries =
+            <Vec<crate::movie_data::movie_data::FilmographyEntry>>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::FilmographyGroup {
+            department: var_department,
+            entries: var_entries,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::HomeRow {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_items =
+            <Vec<crate::movie_data::movie_data::PlaybackEntry>>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::HomeRow {
+            kind: var_kind,
+            title: var_title,
+            items: var_items,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::HomeRowConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_itemCount = <u32>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::HomeRowConfig {
+            kind: var_kind,
+            title: var_title,
+            enabled: var_enabled,
+            item_count: var_itemCount,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::LibraryEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_mediaId = <i64>::sse_decode(deserializer);
+                return crate::movie_data::movie_data::LibraryEvent::MediaAdded {
+                    media_id: var_mediaId,
+                };
+            }
+            1 => {
+                let mut var_mediaId = <i64>::sse_decode(deserializer);
+                return crate::movie_data::movie_data::LibraryEvent::MediaUpdated {
+                    media_id: var_mediaId,
+                };
+            }
+            2 => {
+                let mut var_mediaId = <i64>::sse_decode(deserializer);
+                return crate::movie_data::movie_data::LibraryEvent::MediaRemoved {
+                    media_id: var_mediaId,
+                };
+            }
+            3 => {
+                let mut var_profileId = <i64>::sse_decode(deserializer);
+                let mut var_mediaId = <i64>::sse_decode(deserializer);
+                return crate::movie_data::movie_data::LibraryEvent::WatchStateChanged {
+                    profile_id: var_profileId,
+                    media_id: var_mediaId,
+                };
+            }
+            4 => {
+                return crate::movie_data::movie_data::LibraryEvent::ScanStarted;
+            }
+            5 => {
+                let mut var_report =
+                    <crate::movie_data::movie_data::ScanReport>::sse_decode(deserializer);
+                return crate::movie_data::movie_data::LibraryEvent::ScanFinished {
+                    report: var_report,
+                };
+            }
+            6 => {
+                let mut var_count = <u64>::sse_decode(deserializer);
+                return crate::movie_data::movie_data::LibraryEvent::EventsMissed {
+                    count: var_count,
+                };
+            }
+            7 => {
+                return crate::movie_data::movie_data::LibraryEvent::KeepAlive;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::movie_data::movie_data::AllowedJob> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::movie_data::movie_data::AllowedJob>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::movie_data::movie_data::CollectionPartSnapshot> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::movie_data::movie_data::CollectionPartSnapshot>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::movie_data::movie_data::CollectionSnapshot> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::movie_data::movie_data::CollectionSnapshot>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::movie_data::movie_data::FacetSnapshot> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::movie_data::movie_data::FacetSnapshot>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::movie_data::movie_data::FilmographyEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::movie_data::movie_data::FilmographyEntry>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::movie_data::movie_data::FilmographyGroup> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::movie_data::movie_data::FilmographyGroup>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::movie_data::movie_data::HomeRow> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::movie_data::movie_data::HomeRow>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::movie_data::movie_data::HomeRowConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::movie_data::movie_data::HomeRowConfig>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::movie_data::movie_data::MediaChapter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::movie_data::movie_data::MediaChapter>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::movie_data::movie_data::MediaList> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::movie_data::movie_data::MediaList>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::movie_data::movie_data::MediaStream> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::movie_data::movie_data::MediaStream>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::movie_data::movie_data::MovieSnapshot> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::movie_data::movie_data::MovieSnapshot>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::movie_data::movie_data::PairedDevice> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::movie_data::movie_data::PairedDevice>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::movie_data::movie_data::PersonSnapshot> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::movie_data::movie_data::PersonSnapshot>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::movie_data::movie_data::PlaybackEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::movie_data::movie_data::PlaybackEntry>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<i64>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::movie_data::movie_data::Profile> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::movie_data::movie_data::Profile>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::movie_data::movie_data::SearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::movie_data::movie_data::SearchResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::movie_data::movie_data::SmartCollection> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::movie_data::movie_data::SmartCollection>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::movie_data::movie_data::SubtitleFile> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::movie_data::movie_data::SubtitleFile>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::movie_data::movie_data::WatchRecommendation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::movie_data::movie_data::WatchRecommendation>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::MediaChapter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_index = <i32>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_start = <f64>::sse_decode(deserializer);
+        let mut var_end = <Option<f64>>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::MediaChapter {
+            index: var_index,
+            title: var_title,
+            start: var_start,
+            end: var_end,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::MediaData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_filePath = <String>::sse_decode(deserializer);
+        let mut var_fileOptionalInfo = <String>::sse_decode(deserializer);
+        let mut var_originalTitle = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_rating = <f32>::sse_decode(deserializer);
+        let mut var_releaseDate = <String>::sse_decode(deserializer);
+        let mut var_summary = <String>::sse_decode(deserializer);
+        let mut var_poster = <String>::sse_decode(deserializer);
+        let mut var_backdrop = <String>::sse_decode(deserializer);
+        let mut var_runtime = <Option<i32>>::sse_decode(deserializer);
+        let mut var_tagline = <String>::sse_decode(deserializer);
+        let mut var_status = <String>::sse_decode(deserializer);
+        let mut var_budget = <i64>::sse_decode(deserializer);
+        let mut var_revenue = <i64>::sse_decode(deserializer);
+        let mut var_imdbId = <Option<String>>::sse_decode(deserializer);
+        let mut var_certification = <String>::sse_decode(deserializer);
+        let mut var_mediaInfo =
+            <Option<crate::movie_data::movie_data::MediaInfo>>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::MediaData {
+            id: var_id,
+            file_path: var_filePath,
+            file_optional_info: var_fileOptionalInfo,
+            original_title: var_originalTitle,
+            title: var_title,
+            rating: var_rating,
+            release_date: var_releaseDate,
+            summary: var_summary,
+            poster: var_poster,
+            backdrop: var_backdrop,
+            runtime: var_runtime,
+            tagline: var_tagline,
+            status: var_status,
+            budget: var_budget,
+            revenue: var_revenue,
+            imdb_id: var_imdbId,
+            certification: var_certification,
+            media_info: var_mediaInfo,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::MediaInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_container = <String>::sse_decode(deserializer);
+        let mut var_duration = <Option<f64>>::sse_decode(deserializer);
+        let mut var_width = <Option<i32>>::sse_decode(deserializer);
+        let mut var_height = <Option<i32>>::sse_decode(deserializer);
+        let mut var_videoCodec = <Option<String>>::sse_decode(deserializer);
+        let mut var_hdrFormat = <Option<String>>::sse_decode(deserializer);
+        let mut var_bitrate = <Option<i64>>::sse_decode(deserializer);
+        let mut var_streams =
+            <Vec<crate::movie_data::movie_data::MediaStream>>::sse_decode(deserializer);
+        let mut var_chapters =
+            <Vec<crate::movie_data::movie_data::MediaChapter>>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::MediaInfo {
+            container: var_container,
+            duration: var_duration,
+            width: var_width,
+            height: var_height,
+            video_codec: var_videoCodec,
+            hdr_format: var_hdrFormat,
+            bitrate: var_bitrate,
+            streams: var_streams,
+            chapters: var_chapters,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::MediaList {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_itemCount = <u32>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::MediaList {
+            id: var_id,
+            name: var_name,
+            kind: var_kind,
+            item_count: var_itemCount,
+        };
+    }
+}
+
+impl SseDecode for crate::directory_explorer::media_file::MediaSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_path = <String>::sse_decode(deserializer);
+                let mut var_username = <String>::sse_decode(deserializer);
+                let mut var_password = <String>::sse_decode(deserializer);
+                return crate::directory_explorer::media_file::MediaSource::Smb {
+                    path: var_path,
+                    username: var_username,
+                    password: var_password,
+                };
+            }
+            1 => {
+                let mut var_root = <String>::sse_decode(deserializer);
+                return crate::directory_explorer::media_file::MediaSource::Local {
+                    root: var_root,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::MediaStream {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_index = <i32>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_codec = <String>::sse_decode(deserializer);
+        let mut var_language = <Option<String>>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_channels = <Option<i32>>::sse_decode(deserializer);
+        let mut var_isDefault = <bool>::sse_decode(deserializer);
+        let mut var_isForced = <bool>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::MediaStream {
+            index: var_index,
+            kind: var_kind,
+            codec: var_codec,
+            language: var_language,
+            title: var_title,
+            channels: var_channels,
+            is_default: var_isDefault,
+            is_forced: var_isForced,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::MovieSnapshot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_filePath = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_rating = <f32>::sse_decode(deserializer);
+        let mut var_releaseDate = <String>::sse_decode(deserializer);
+        let mut var_poster = <String>::sse_decode(deserializer);
+        let mut var_watched = <bool>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::MovieSnapshot {
+            id: var_id,
+            file_path: var_filePath,
+            title: var_title,
+            rating: var_rating,
+            release_date: var_releaseDate,
+            poster: var_poster,
+            watched: var_watched,
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::movie_data::movie_data::MediaInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::movie_data::movie_data::MediaInfo>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::PairedDevice {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_profileId = <i64>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        let mut var_lastSeen = <Option<i64>>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::PairedDevice {
+            id: var_id,
+            name: var_name,
+            profile_id: var_profileId,
+            created_at: var_createdAt,
+            last_seen: var_lastSeen,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::PersonData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tmdbId = <i64>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_summary = <String>::sse_decode(deserializer);
+        let mut var_picturePath = <Option<String>>::sse_decode(deserializer);
+        let mut var_birthday = <Option<String>>::sse_decode(deserializer);
+        let mut var_deathday = <Option<String>>::sse_decode(deserializer);
+        let mut var_placeOfBirth = <Option<String>>::sse_decode(deserializer);
+        let mut var_knownForDepartment = <Option<String>>::sse_decode(deserializer);
+        let mut var_gender = <i32>::sse_decode(deserializer);
+        let mut var_alsoKnownAs = <Vec<String>>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::PersonData {
+            tmdb_id: var_tmdbId,
+            name: var_name,
+            summary: var_summary,
+            picture_path: var_picturePath,
+            birthday: var_birthday,
+            deathday: var_deathday,
+            place_of_birth: var_placeOfBirth,
+            known_for_department: var_knownForDepartment,
+            gender: var_gender,
+            also_known_as: var_alsoKnownAs,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::PersonFilmography {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_person = <crate::movie_data::movie_data::PersonData>::sse_decode(deserializer);
+        let mut var_groups =
+            <Vec<crate::movie_data::movie_data::FilmographyGroup>>::sse_decode(deserializer);
+        let mut var_missing =
+            <Vec<crate::movie_data::movie_data::FilmographyEntry>>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::PersonFilmography {
+            person: var_person,
+            groups: var_groups,
+            missing: var_missing,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::PersonSnapshot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tmdbId = <i64>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_character = <String>::sse_decode(deserializer);
+        let mut var_jobName = <String>::sse_decode(deserializer);
+        let mut var_picturePath = <String>::sse_decode(deserializer);
+        let mut var_department = <String>::sse_decode(deserializer);
+        let mut var_creditOrder = <Option<i32>>::sse_decode(deserializer);
+        let mut var_creditId = <String>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::PersonSnapshot {
+            tmdb_id: var_tmdbId,
+            name: var_name,
+            character: var_character,
+            job_name: var_jobName,
+            picture_path: var_picturePath,
+            department: var_department,
+            credit_order: var_creditOrder,
+            credit_id: var_creditId,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::PlaybackDecision {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_method = <String>::sse_decode(deserializer);
+        let mut var_reasons = <Vec<String>>::sse_decode(deserializer);
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_sessionId = <Option<String>>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::PlaybackDecision {
+            method: var_method,
+            reasons: var_reasons,
+            url: var_url,
+            session_id: var_sessionId,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::PlaybackEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_media =
+            <crate::movie_data::movie_data::MovieSnapshot>::sse_decode(deserializer);
+        let mut var_state =
+            <crate::movie_data::movie_data::PlaybackState>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::PlaybackEntry {
+            media: var_media,
+            state: var_state,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::PlaybackRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_capabilities =
+            <crate::movie_data::movie_data::ClientCapabilities>::sse_decode(deserializer);
+        let mut var_maxHeight = <Option<i32>>::sse_decode(deserializer);
+        let mut var_maxBitrate = <Option<i64>>::sse_decode(deserializer);
+        let mut var_audioStream = <Option<i32>>::sse_decode(deserializer);
+        let mut var_subtitleStream = <Option<i32>>::sse_decode(deserializer);
+        let mut var_subtitleFile = <Option<i64>>::sse_decode(deserializer);
+        let mut var_startPosition = <f64>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::PlaybackRequest {
+            capabilities: var_capabilities,
+            max_height: var_maxHeight,
+            max_bitrate: var_maxBitrate,
+            audio_stream: var_audioStream,
+            subtitle_stream: var_subtitleStream,
+            subtitle_file: var_subtitleFile,
+            start_position: var_startPosition,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::PlaybackState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mediaId = <i64>::sse_decode(deserializer);
+        let mut var_watched = <bool>::sse_decode(deserializer);
+        let mut var_playCount = <u32>::sse_decode(deserializer);
+        let mut var_lastPlayed = <Option<i64>>::sse_decode(deserializer);
+        let mut var_resumePosition = <f64>::sse_decode(deserializer);
+        let mut var_duration = <f64>::sse_decode(deserializer);
+        let mut var_userRating = <Option<f32>>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::PlaybackState {
+            media_id: var_mediaId,
+            watched: var_watched,
+            play_count: var_playCount,
+            last_played: var_lastPlayed,
+            resume_position: var_resumePosition,
+            duration: var_duration,
+            user_rating: var_userRating,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::Profile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_avatar = <Option<String>>::sse_decode(deserializer);
+        let mut var_hasPin = <bool>::sse_decode(deserializer);
+        let mut var_maxCertification = <Option<String>>::sse_decode(deserializer);
+        let mut var_hideUnrated = <bool>::sse_decode(deserializer);
+        let mut var_preferredLanguage = <Option<String>>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::Profile {
+            id: var_id,
+            name: var_name,
+            avatar: var_avatar,
+            has_pin: var_hasPin,
+            max_certification: var_maxCertification,
+            hide_unrated: var_hideUnrated,
+            preferred_language: var_preferredLanguage,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::ScanReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_moviesFound = <u32>::sse_decode(deserializer);
+        let mut var_moviesMatched = <u32>::sse_decode(deserializer);
+        let mut var_moviesSaved = <u32>::sse_decode(deserializer);
+        let mut var_moviesFailed = <u32>::sse_decode(deserializer);
+        let mut var_personsFetched = <u32>::sse_decode(deserializer);
+        let mut var_personsSkipped = <u32>::sse_decode(deserializer);
+        let mut var_tmdbRequests = <u64>::sse_decode(deserializer);
+        let mut var_tmdbRequestsSaved = <u64>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::ScanReport {
+            movies_found: var_moviesFound,
+            movies_matched: var_moviesMatched,
+            movies_saved: var_moviesSaved,
+            movies_failed: var_moviesFailed,
+            persons_fetched: var_personsFetched,
+            persons_skipped: var_personsSkipped,
+            tmdb_requests: var_tmdbRequests,
+            tmdb_requests_saved: var_tmdbRequestsSaved,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::SearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_subtitle = <String>::sse_decode(deserializer);
+        let mut var_picturePath = <Option<String>>::sse_decode(deserializer);
+        let mut var_score = <f32>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::SearchResult {
+            kind: var_kind,
+            id: var_id,
+            title: var_title,
+            subtitle: var_subtitle,
+            picture_path: var_picturePath,
+            score: var_score,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::SmartCollection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_query = <crate::movie_data::movie_data::SmartQuery>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::SmartCollection {
+            id: var_id,
+            name: var_name,
+            query: var_query,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::SmartFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_genres = <Vec<String>>::sse_decode(deserializer);
+        let mut var_yearFrom = <Option<u32>>::sse_decode(deserializer);
+        let mut var_yearTo = <Option<u32>>::sse_decode(deserializer);
+        let mut var_minRating = <Option<f32>>::sse_decode(deserializer);
+        let mut var_maxRating = <Option<f32>>::sse_decode(deserializer);
+        let mut var_personIds = <Vec<i64>>::sse_decode(deserializer);
+        let mut var_watched = <Option<bool>>::sse_decode(deserializer);
+        let mut var_resolutions = <Vec<String>>::sse_decode(deserializer);
+        let mut var_addedWithinDays = <Option<u32>>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::SmartFilter {
+            genres: var_genres,
+            year_from: var_yearFrom,
+            year_to: var_yearTo,
+            min_rating: var_minRating,
+            max_rating: var_maxRating,
+            person_ids: var_personIds,
+            watched: var_watched,
+            resolutions: var_resolutions,
+            added_within_days: var_addedWithinDays,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::SmartQuery {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_filter = <crate::movie_data::movie_data::SmartFilter>::sse_decode(deserializer);
+        let mut var_sort = <crate::movie_data::movie_data::SmartSort>::sse_decode(deserializer);
+        let mut var_descending = <bool>::sse_decode(deserializer);
+        let mut var_limit = <Option<u32>>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::SmartQuery {
+            filter: var_filter,
+            sort: var_sort,
+            descending: var_descending,
+            limit: var_limit,
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::SmartSort {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::movie_data::movie_data::SmartSort::Title,
+            1 => crate::movie_data::movie_data::SmartSort::ReleaseDate,
+            2 => crate::movie_data::movie_data::SmartSort::Rating,
+            3 => crate::movie_data::movie_data::SmartSort::DateAdded,
+            4 => crate::movie_data::movie_data::SmartSort::Random,
+            _ => unreachable!("Invalid variant for SmartSort: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::movie_data::movie_data::SubtitleFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <i64>::sse_decode(deserializer);
+        let mut var_filePath = <String>::sse_decode(deserializer);
+        let mut var_format = <String>::sse_decode(deserializer);
+        let mut var_language = <Option<String>>::sse_decode(deserializer);
+        let mut var_title = <Option<String>>::sse_decode(deserializer);
+        let mut var_isForced = <bool>::sse_decode(deserializer);
+        let mut var_isSdh = <bool>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::SubtitleFile {
+            id: var_id,
+            file_path: var_filePath,
+            format: var_format,
+            language: var_language,
+            title: var_title,
+            is_forced: var_isForced,
+            is_sdh: var_isSdh,
+        };
+    }
+}
+
+impl SseDecode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u16::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::movie_data::movie_data::WatchRecommendation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::movie_data::movie_data::MovieSnapshot>::sse_decode(
-                deserializer,
-            ));
+        let mut var_source =
+            <crate::movie_data::movie_data::MovieSnapshot>::sse_decode(deserializer);
+        let mut var_items =
+            <Vec<crate::movie_data::movie_data::MovieSnapshot>>::sse_decode(deserializer);
+        return crate::movie_data::movie_data::WatchRecommendation {
+            source: var_source,
+            items: var_items,
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__media__add_to_list_impl(port, ptr, rust_vec_len, data_len),
+        2 => wire__crate__api__media__create_list_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__media__create_pairing_code_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__media__create_profile_impl(port, ptr, rust_vec_len, data_len),
+        5 => {
+            wire__crate__api__media__create_smart_collection_impl(port, ptr, rust_vec_len, data_len)
+        }
+        6 => wire__crate__api__media__delete_list_impl(port, ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__media__delete_profile_impl(port, ptr, rust_vec_len, data_len),
+        8 => {
+            wire__crate__api__media__delete_smart_collection_impl(port, ptr, rust_vec_len, data_len)
+        }
+        9 => {
+            wire__crate__api__media__discover_subtitle_files_impl(port, ptr, rust_vec_len, data_len)
+        }
+        10 => wire__crate__api__media__edit_media_artwork_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__media__edit_media_field_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__media__edit_media_genres_impl(port, ptr, rust_vec_len, data_len),
+        13 => {
+            wire__crate__api__media__edit_smart_collection_impl(port, ptr, rust_vec_len, data_len)
+        }
+        14 => wire__crate__api__media__generate_media_thumbnails_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        15 => wire__crate__api__media__get_active_profile_impl(port, ptr, rust_vec_len, data_len),
+        16 => wire__crate__api__media__get_collection_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__media__get_collection_missing_parts_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        18 => wire__crate__api__media__get_collections_impl(port, ptr, rust_vec_len, data_len),
+        19 => {
+            wire__crate__api__media__get_continue_watching_impl(port, ptr, rust_vec_len, data_len)
+        }
+        20 => wire__crate__api__media__get_credit_filter_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__media__get_facets_impl(port, ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__media__get_favourites_impl(port, ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__media__get_home_feed_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__media__get_home_feed_rows_impl(port, ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__media__get_list_items_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__media__get_lists_impl(port, ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__media__get_media_impl(port, ptr, rust_vec_len, data_len),
+        28 => wire__crate__api__media__get_media_cast_impl(port, ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__media__get_media_chapters_impl(port, ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__media__get_media_crew_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__media__get_media_facets_impl(port, ptr, rust_vec_len, data_len),
+        32 => {
+            wire__crate__api__media__get_media_locked_fields_impl(port, ptr, rust_vec_len, data_len)
+        }
+        33 => wire__crate__api__media__get_media_snapshots_impl(port, ptr, rust_vec_len, data_len),
+        34 => wire__crate__api__media__get_media_snapshots_by_facet_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        35 => wire__crate__api__media__get_paired_devices_impl(port, ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__media__get_person_impl(port, ptr, rust_vec_len, data_len),
+        37 => {
+            wire__crate__api__media__get_person_filmography_impl(port, ptr, rust_vec_len, data_len)
+        }
+        38 => {
+            wire__crate__api__media__get_playback_decision_impl(port, ptr, rust_vec_len, data_len)
+        }
+        39 => wire__crate__api__media__get_playback_state_impl(port, ptr, rust_vec_len, data_len),
+        40 => wire__crate__api__media__get_profiles_impl(port, ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__media__get_recently_watched_impl(port, ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__media__get_similar_media_impl(port, ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__media__get_smart_collection_items_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        44 => {
+            wire__crate__api__media__get_smart_collections_impl(port, ptr, rust_vec_len, data_len)
+        }
+        45 => wire__crate__api__media__get_stream_url_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__media__get_subtitle_files_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__media__get_subtitle_url_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__media__get_trickplay_url_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__media__get_watch_recommendations_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        50 => wire__crate__api__media__get_watchlist_impl(port, ptr, rust_vec_len, data_len),
+        51 => wire__crate__api__media__init_app_impl(port, ptr, rust_vec_len, data_len),
+        53 => wire__crate__api__media__mark_media_watched_impl(port, ptr, rust_vec_len, data_len),
+        54 => wire__crate__api__media__move_list_item_impl(port, ptr, rust_vec_len, data_len),
+        55 => wire__crate__api__media__open_video_impl(port, ptr, rust_vec_len, data_len),
+        56 => wire__crate__api__media__preview_smart_collection_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        57 => wire__crate__api__media__probe_media_files_impl(port, ptr, rust_vec_len, data_len),
+        58 => wire__crate__api__media__quick_search_impl(port, ptr, rust_vec_len, data_len),
+        59 => wire__crate__api__media__rate_media_impl(port, ptr, rust_vec_len, data_len),
+        60 => {
+            wire__crate__api__media__reapply_credit_filter_impl(port, ptr, rust_vec_len, data_len)
+        }
+        61 => wire__crate__api__media__refresh_library_metadata_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        62 => {
+            wire__crate__api__media__refresh_movie_metadata_impl(port, ptr, rust_vec_len, data_len)
+        }
+        63 => {
+            wire__crate__api__media__refresh_person_metadata_impl(port, ptr, rust_vec_len, data_len)
+        }
+        64 => {
+            wire__crate__api__media__refresh_stale_metadata_impl(port, ptr, rust_vec_len, data_len)
+        }
+        65 => wire__crate__api__media__remove_from_list_impl(port, ptr, rust_vec_len, data_len),
+        66 => wire__crate__api__media__remove_media_impl(port, ptr, rust_vec_len, data_len),
+        67 => wire__crate__api__media__rename_list_impl(port, ptr, rust_vec_len, data_len),
+        68 => wire__crate__api__media__report_playback_progress_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        69 => wire__crate__api__media__revoke_device_impl(port, ptr, rust_vec_len, data_len),
+        70 => wire__crate__api__media__set_credit_filter_impl(port, ptr, rust_vec_len, data_len),
+        71 => wire__crate__api__media__set_favourite_impl(port, ptr, rust_vec_len, data_len),
+        72 => wire__crate__api__media__set_home_feed_rows_impl(port, ptr, rust_vec_len, data_len),
+        73 => wire__crate__api__media__set_in_watchlist_impl(port, ptr, rust_vec_len, data_len),
+        74 => wire__crate__api__media__start_impl(port, ptr, rust_vec_len, data_len),
+        75 => wire__crate__api__media__start_stream_server_impl(port, ptr, rust_vec_len, data_len),
+        76 => wire__crate__api__media__stop_stream_server_impl(port, ptr, rust_vec_len, data_len),
+        77 => wire__crate__api__media__stop_transcoding_impl(port, ptr, rust_vec_len, data_len),
+        78 => wire__crate__api__media__switch_profile_impl(port, ptr, rust_vec_len, data_len),
+        79 => wire__crate__api__media__tempo_mount_smb_impl(port, ptr, rust_vec_len, data_len),
+        80 => wire__crate__api__media__tempo_unmount_smb_impl(port, ptr, rust_vec_len, data_len),
+        81 => wire__crate__api__media__unlock_media_field_impl(port, ptr, rust_vec_len, data_len),
+        82 => wire__crate__api__media__watch_library_events_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        52 => wire__crate__api__media__is_transcoding_available_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::AllowedJob {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.department.into_into_dart().into_dart(),
+            self.job.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::AllowedJob
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::AllowedJob>
+    for crate::movie_data::movie_data::AllowedJob
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::AllowedJob {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::ClientCapabilities {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.containers.into_into_dart().into_dart(),
+            self.video_codecs.into_into_dart().into_dart(),
+            self.audio_codecs.into_into_dart().into_dart(),
+            self.max_width.into_into_dart().into_dart(),
+            self.max_height.into_into_dart().into_dart(),
+            self.max_bitrate.into_into_dart().into_dart(),
+            self.max_audio_channels.into_into_dart().into_dart(),
+            self.supports_hdr.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::ClientCapabilities
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::ClientCapabilities>
+    for crate::movie_data::movie_data::ClientCapabilities
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::ClientCapabilities {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::CollectionDetail {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.overview.into_into_dart().into_dart(),
+            self.poster.into_into_dart().into_dart(),
+            self.backdrop.into_into_dart().into_dart(),
+            self.parts.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::CollectionDetail
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::CollectionDetail>
+    for crate::movie_data::movie_data::CollectionDetail
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::CollectionDetail {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::CollectionPartSnapshot {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tmdb_id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.release_date.into_into_dart().into_dart(),
+            self.poster_path.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::CollectionPartSnapshot
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::CollectionPartSnapshot>
+    for crate::movie_data::movie_data::CollectionPartSnapshot
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::CollectionPartSnapshot {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::CollectionSnapshot {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.poster.into_into_dart().into_dart(),
+            self.owned_count.into_into_dart().into_dart(),
+            self.part_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::CollectionSnapshot
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::CollectionSnapshot>
+    for crate::movie_data::movie_data::CollectionSnapshot
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::CollectionSnapshot {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::CreditFilter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.allowed_jobs.into_into_dart().into_dart(),
+            self.max_cast_size.into_into_dart().into_dart(),
+            self.include_uncredited.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::CreditFilter
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::CreditFilter>
+    for crate::movie_data::movie_data::CreditFilter
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::CreditFilter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::FacetSnapshot {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.key.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.media_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::FacetSnapshot
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::FacetSnapshot>
+    for crate::movie_data::movie_data::FacetSnapshot
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::FacetSnapshot {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::FilmographyEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.media_id.into_into_dart().into_dart(),
+            self.tmdb_id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.release_date.into_into_dart().into_dart(),
+            self.poster.into_into_dart().into_dart(),
+            self.department.into_into_dart().into_dart(),
+            self.character.into_into_dart().into_dart(),
+            self.job_name.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::FilmographyEntry
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::FilmographyEntry>
+    for crate::movie_data::movie_data::FilmographyEntry
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::FilmographyEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::FilmographyGroup {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.department.into_into_dart().into_dart(),
+            self.entries.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::FilmographyGroup
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::FilmographyGroup>
+    for crate::movie_data::movie_data::FilmographyGroup
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::FilmographyGroup {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::HomeRow {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::HomeRow
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::HomeRow>
+    for crate::movie_data::movie_data::HomeRow
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::HomeRow {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::HomeRowConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.enabled.into_into_dart().into_dart(),
+            self.item_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::HomeRowConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::HomeRowConfig>
+    for crate::movie_data::movie_data::HomeRowConfig
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::HomeRowConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::LibraryEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::movie_data::movie_data::LibraryEvent::MediaAdded { media_id } => {
+                [0.into_dart(), media_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::movie_data::movie_data::LibraryEvent::MediaUpdated { media_id } => {
+                [1.into_dart(), media_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::movie_data::movie_data::LibraryEvent::MediaRemoved { media_id } => {
+                [2.into_dart(), media_id.into_into_dart().into_dart()].into_dart()
+            }
+            crate::movie_data::movie_data::LibraryEvent::WatchStateChanged {
+                profile_id,
+                media_id,
+            } => [
+                3.into_dart(),
+                profile_id.into_into_dart().into_dart(),
+                media_id.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::movie_data::movie_data::LibraryEvent::ScanStarted => [4.into_dart()].into_dart(),
+            crate::movie_data::movie_data::LibraryEvent::ScanFinished { report } => {
+                [5.into_dart(), report.into_into_dart().into_dart()].into_dart()
+            }
+            crate::movie_data::movie_data::LibraryEvent::EventsMissed { count } => {
+                [6.into_dart(), count.into_into_dart().into_dart()].into_dart()
+            }
+            crate::movie_data::movie_data::LibraryEvent::KeepAlive => [7.into_dart()].into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::LibraryEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::LibraryEvent>
+    for crate::movie_data::movie_data::LibraryEvent
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::LibraryEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::MediaChapter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.index.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.start.into_into_dart().into_dart(),
+            self.end.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::MediaChapter
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::MediaChapter>
+    for crate::movie_data::movie_data::MediaChapter
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::MediaChapter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::MediaData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.file_path.into_into_dart().into_dart(),
+            self.file_optional_info.into_into_dart().into_dart(),
+            self.original_title.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.rating.into_into_dart().into_dart(),
+            self.release_date.into_into_dart().into_dart(),
+            self.summary.into_into_dart().into_dart(),
+            self.poster.into_into_dart().into_dart(),
+            self.backdrop.into_into_dart().into_dart(),
+            self.runtime.into_into_dart().into_dart(),
+            self.tagline.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.budget.into_into_dart().into_dart(),
+            self.revenue.into_into_dart().into_dart(),
+            self.imdb_id.into_into_dart().into_dart(),
+            self.certification.into_into_dart().into_dart(),
+            self.media_info.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::MediaData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::MediaData>
+    for crate::movie_data::movie_data::MediaData
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::MediaData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::MediaInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.container.into_into_dart().into_dart(),
+            self.duration.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.video_codec.into_into_dart().into_dart(),
+            self.hdr_format.into_into_dart().into_dart(),
+            self.bitrate.into_into_dart().into_dart(),
+            self.streams.into_into_dart().into_dart(),
+            self.chapters.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::MediaInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::MediaInfo>
+    for crate::movie_data::movie_data::MediaInfo
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::MediaInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::MediaList {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.item_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::MediaList
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::MediaList>
+    for crate::movie_data::movie_data::MediaList
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::MediaList {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::directory_explorer::media_file::MediaSource {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::directory_explorer::media_file::MediaSource::Smb {
+                path,
+                username,
+                password,
+            } => [
+                0.into_dart(),
+                path.into_into_dart().into_dart(),
+                username.into_into_dart().into_dart(),
+                password.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::directory_explorer::media_file::MediaSource::Local { root } => {
+                [1.into_dart(), root.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::directory_explorer::media_file::MediaSource
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::directory_explorer::media_file::MediaSource>
+    for crate::directory_explorer::media_file::MediaSource
+{
+    fn into_into_dart(self) -> crate::directory_explorer::media_file::MediaSource {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::MediaStream {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.index.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.codec.into_into_dart().into_dart(),
+            self.language.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.channels.into_into_dart().into_dart(),
+            self.is_default.into_into_dart().into_dart(),
+            self.is_forced.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::MediaStream
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::MediaStream>
+    for crate::movie_data::movie_data::MediaStream
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::MediaStream {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::MovieSnapshot {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.file_path.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.rating.into_into_dart().into_dart(),
+            self.release_date.into_into_dart().into_dart(),
+            self.poster.into_into_dart().into_dart(),
+            self.watched.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::MovieSnapshot
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::MovieSnapshot>
+    for crate::movie_data::movie_data::MovieSnapshot
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::MovieSnapshot {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::PairedDevice {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.profile_id.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.last_seen.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::PairedDevice
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::PairedDevice>
+    for crate::movie_data::movie_data::PairedDevice
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::PairedDevice {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::PersonData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tmdb_id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.summary.into_into_dart().into_dart(),
+            self.picture_path.into_into_dart().into_dart(),
+            self.birthday.into_into_dart().into_dart(),
+            self.deathday.into_into_dart().into_dart(),
+            self.place_of_birth.into_into_dart().into_dart(),
+            self.known_for_department.into_into_dart().into_dart(),
+            self.gender.into_into_dart().into_dart(),
+            self.also_known_as.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::PersonData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::PersonData>
+    for crate::movie_data::movie_data::PersonData
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::PersonData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::PersonFilmography {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.person.into_into_dart().into_dart(),
+            self.groups.into_into_dart().into_dart(),
+            self.missing.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::PersonFilmography
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::PersonFilmography>
+    for crate::movie_data::movie_data::PersonFilmography
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::PersonFilmography {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::PersonSnapshot {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tmdb_id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.character.into_into_dart().into_dart(),
+            self.job_name.into_into_dart().into_dart(),
+            self.picture_path.into_into_dart().into_dart(),
+            self.department.into_into_dart().into_dart(),
+            self.credit_order.into_into_dart().into_dart(),
+            self.credit_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::PersonSnapshot
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::PersonSnapshot>
+    for crate::movie_data::movie_data::PersonSnapshot
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::PersonSnapshot {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::PlaybackDecision {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.method.into_into_dart().into_dart(),
+            self.reasons.into_into_dart().into_dart(),
+            self.url.into_into_dart().into_dart(),
+            self.session_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::PlaybackDecision
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::PlaybackDecision>
+    for crate::movie_data::movie_data::PlaybackDecision
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::PlaybackDecision {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::PlaybackEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.media.into_into_dart().into_dart(),
+            self.state.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::PlaybackEntry
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::PlaybackEntry>
+    for crate::movie_data::movie_data::PlaybackEntry
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::PlaybackEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::PlaybackRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.capabilities.into_into_dart().into_dart(),
+            self.max_height.into_into_dart().into_dart(),
+            self.max_bitrate.into_into_dart().into_dart(),
+            self.audio_stream.into_into_dart().into_dart(),
+            self.subtitle_stream.into_into_dart().into_dart(),
+            self.subtitle_file.into_into_dart().into_dart(),
+            self.start_position.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::PlaybackRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::PlaybackRequest>
+    for crate::movie_data::movie_data::PlaybackRequest
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::PlaybackRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::PlaybackState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.media_id.into_into_dart().into_dart(),
+            self.watched.into_into_dart().into_dart(),
+            self.play_count.into_into_dart().into_dart(),
+            self.last_played.into_into_dart().into_dart(),
+            self.resume_position.into_into_dart().into_dart(),
+            self.duration.into_into_dart().into_dart(),
+            self.user_rating.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::PlaybackState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::PlaybackState>
+    for crate::movie_data::movie_data::PlaybackState
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::PlaybackState {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::Profile {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.avatar.into_into_dart().into_dart(),
+            self.has_pin.into_into_dart().into_dart(),
+            self.max_certification.into_into_dart().into_dart(),
+            self.hide_unrated.into_into_dart().into_dart(),
+            self.preferred_language.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::Profile
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::Profile>
+    for crate::movie_data::movie_data::Profile
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::Profile {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::ScanReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.movies_found.into_into_dart().into_dart(),
+            self.movies_matched.into_into_dart().into_dart(),
+            self.movies_saved.into_into_dart().into_dart(),
+            self.movies_failed.into_into_dart().into_dart(),
+            self.persons_fetched.into_into_dart().into_dart(),
+            self.persons_skipped.into_into_dart().into_dart(),
+            self.tmdb_requests.into_into_dart().into_dart(),
+            self.tmdb_requests_saved.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::ScanReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::ScanReport>
+    for crate::movie_data::movie_data::ScanReport
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::ScanReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::SearchResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.subtitle.into_into_dart().into_dart(),
+            self.picture_path.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::SearchResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::SearchResult>
+    for crate::movie_data::movie_data::SearchResult
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::SearchResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::SmartCollection {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.query.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::SmartCollection
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::SmartCollection>
+    for crate::movie_data::movie_data::SmartCollection
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::SmartCollection {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::SmartFilter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.genres.into_into_dart().into_dart(),
+            self.year_from.into_into_dart().into_dart(),
+            self.year_to.into_into_dart().into_dart(),
+            self.min_rating.into_into_dart().into_dart(),
+            self.max_rating.into_into_dart().into_dart(),
+            self.person_ids.into_into_dart().into_dart(),
+            self.watched.into_into_dart().into_dart(),
+            self.resolutions.into_into_dart().into_dart(),
+            self.added_within_days.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::SmartFilter
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::SmartFilter>
+    for crate::movie_data::movie_data::SmartFilter
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::SmartFilter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::SmartQuery {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.filter.into_into_dart().into_dart(),
+            self.sort.into_into_dart().into_dart(),
+            self.descending.into_into_dart().into_dart(),
+            self.limit.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::SmartQuery
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::SmartQuery>
+    for crate::movie_data::movie_data::SmartQuery
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::SmartQuery {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::SmartSort {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Title => 0.into_dart(),
+            Self::ReleaseDate => 1.into_dart(),
+            Self::Rating => 2.into_dart(),
+            Self::DateAdded => 3.into_dart(),
+            Self::Random => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::SmartSort
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::SmartSort>
+    for crate::movie_data::movie_data::SmartSort
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::SmartSort {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::SubtitleFile {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.file_path.into_into_dart().into_dart(),
+            self.format.into_into_dart().into_dart(),
+            self.language.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.is_forced.into_into_dart().into_dart(),
+            self.is_sdh.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::SubtitleFile
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::SubtitleFile>
+    for crate::movie_data::movie_data::SubtitleFile
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::SubtitleFile {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::WatchRecommendation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source.into_into_dart().into_dart(),
+            self.items.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::movie_data::movie_data::WatchRecommendation
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::WatchRecommendation>
+    for crate::movie_data::movie_data::WatchRecommendation
+{
+    fn into_into_dart(self) -> crate::movie_data::movie_data::WatchRecommendation {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::movie_data::movie_data::LibraryEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::AllowedJob {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.department, serializer);
+        <String>::sse_encode(self.job, serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::ClientCapabilities {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.containers, serializer);
+        <Vec<String>>::sse_encode(self.video_codecs, serializer);
+        <Vec<String>>::sse_encode(self.audio_codecs, serializer);
+        <Option<i32>>::sse_encode(self.max_width, serializer);
+        <Option<i32>>::sse_encode(self.max_height, serializer);
+        <Option<i64>>::sse_encode(self.max_bitrate, serializer);
+        <Option<i32>>::sse_encode(self.max_audio_channels, serializer);
+        <bool>::sse_encode(self.supports_hdr, serializer);
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::CollectionDetail {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.overview, serializer);
+        <Option<String>>::sse_encode(self.poster, serializer);
+        <Option<String>>::sse_encode(self.backdrop, serializer);
+        <Vec<crate::movie_data::movie_data::MovieSnapshot>>::sse_encode(self.parts, serializer);
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::CollectionPartSnapshot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.tmdb_id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <String>::sse_encode(self.release_date, serializer);
+        <Option<String>>::sse_encode(self.poster_path, serializer);
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::CollectionSnapshot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.poster, serializer);
+        <i64>::sse_encode(self.owned_count, serializer);
+        <i64>::sse_encode(self.part_count, serializer);
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::CreditFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::movie_data::movie_data::AllowedJob>>::sse_encode(self.allowed_jobs, serializer);
+        <Option<u32>>::sse_encode(self.max_cast_size, serializer);
+        <bool>::sse_encode(self.include_uncredited, serializer);
+    }
+}
+
+impl SseEncode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::FacetSnapshot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.key, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <i64>::sse_encode(self.media_count, serializer);
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::FilmographyEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<i64>>::sse_encode(self.media_id, serializer);
+        <i64>::sse_encode(self.tmdb_id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <String>::sse_encode(self.release_date, serializer);
+        <Option<String>>::sse_encode(self.poster, serializer);
+        <String>::sse_encode(self.department, serializer);
+        <String>::sse_encode(self.character, serializer);
+        <String>::sse_encode(self.job_name, serializer);
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::FilmographyGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.department, serializer);
+        <Vec<crate::movie_data::movie_data::FilmographyEntry>>::sse_encode(
+            self.entries,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::HomeRow {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <Vec<crate::movie_data::movie_data::PlaybackEntry>>::sse_encode(self.items, serializer);
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::HomeRowConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <bool>::sse_encode(self.enabled, serializer);
+        <u32>::sse_encode(self.item_count, serializer);
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::LibraryEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::movie_data::movie_data::LibraryEvent::MediaAdded { media_id } => {
+                <i32>::sse_encode(0, serializer);
+                <i64>::sse_encode(media_id, serializer);
+            }
+            crate::movie_data::movie_data::LibraryEvent::MediaUpdated { media_id } => {
+                <i32>::sse_encode(1, serializer);
+                <i64>::sse_encode(media_id, serializer);
+            }
+            crate::movie_data::movie_data::LibraryEvent::MediaRemoved { media_id } => {
+                <i32>::sse_encode(2, serializer);
+                <i64>::sse_encode(media_id, serializer);
+            }
+            crate::movie_data::movie_data::LibraryEvent::WatchStateChanged {
+                profile_id,
+                media_id,
+            } => {
+                <i32>::sse_encode(3, serializer);
+                <i64>::sse_encode(profile_id, serializer);
+                <i64>::sse_encode(media_id, serializer);
+            }
+            crate::movie_data::movie_data::LibraryEvent::ScanStarted => {
+                <i32>::sse_encode(4, serializer);
+            }
+            crate::movie_data::movie_data::LibraryEvent::ScanFinished { report } => {
+                <i32>::sse_encode(5, serializer);
+                <crate::movie_data::movie_data::ScanReport>::sse_encode(report, serializer);
+            }
+            crate::movie_data::movie_data::LibraryEvent::EventsMissed { count } => {
+                <i32>::sse_encode(6, serializer);
+                <u64>::sse_encode(count, serializer);
+            }
+            crate::movie_data::movie_data::LibraryEvent::KeepAlive => {
+                <i32>::sse_encode(7, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::movie_data::movie_data::PersonSnapshot> {
+impl SseEncode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::movie_data::movie_data::PersonSnapshot>::sse_decode(
-                deserializer,
-            ));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for Vec<crate::movie_data::movie_data::AllowedJob> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::movie_data::movie_data::AllowedJob>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for crate::movie_data::movie_data::MediaData {
+impl SseEncode for Vec<crate::movie_data::movie_data::CollectionPartSnapshot> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <i64>::sse_decode(deserializer);
-        let mut var_filePath = <String>::sse_decode(deserializer);
-        let mut var_fileOptionalInfo = <String>::sse_decode(deserializer);
-        let mut var_originalTitle = <String>::sse_decode(deserializer);
-        let mut var_title = <String>::sse_decode(deserializer);
-        let mut var_rating = <f32>::sse_decode(deserializer);
-        let mut var_releaseDate = <String>::sse_decode(deserializer);
-        let mut var_summary = <String>::sse_decode(deserializer);
-        let mut var_poster = <String>::sse_decode(deserializer);
-        let mut var_backdrop = <String>::sse_decode(deserializer);
-        return crate::movie_data::movie_data::MediaData {
-            id: var_id,
-            file_path: var_filePath,
-            file_optional_info: var_fileOptionalInfo,
-            original_title: var_originalTitle,
-            title: var_title,
-            rating: var_rating,
-            release_date: var_releaseDate,
-            summary: var_summary,
-            poster: var_poster,
-            backdrop: var_backdrop,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::movie_data::movie_data::CollectionPartSnapshot>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::movie_data::movie_data::MovieSnapshot {
+impl SseEncode for Vec<crate::movie_data::movie_data::CollectionSnapshot> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <i64>::sse_decode(deserializer);
-        let mut var_filePath = <String>::sse_decode(deserializer);
-        let mut var_title = <String>::sse_decode(deserializer);
-        let mut var_rating = <f32>::sse_decode(deserializer);
-        let mut var_releaseDate = <String>::sse_decode(deserializer);
-        let mut var_poster = <String>::sse_decode(deserializer);
-        return crate::movie_data::movie_data::MovieSnapshot {
-            id: var_id,
-            file_path: var_filePath,
-            title: var_title,
-            rating: var_rating,
-            release_date: var_releaseDate,
-            poster: var_poster,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::movie_data::movie_data::CollectionSnapshot>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseEncode for Vec<crate::movie_data::movie_data::FacetSnapshot> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::movie_data::movie_data::FacetSnapshot>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseDecode for crate::movie_data::movie_data::PersonData {
+impl SseEncode for Vec<crate::movie_data::movie_data::FilmographyEntry> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_tmdbId = <i64>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_summary = <String>::sse_decode(deserializer);
-        let mut var_picturePath = <Option<String>>::sse_decode(deserializer);
-        return crate::movie_data::movie_data::PersonData {
-            tmdb_id: var_tmdbId,
-            name: var_name,
-            summary: var_summary,
-            picture_path: var_picturePath,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::movie_data::movie_data::FilmographyEntry>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::movie_data::movie_data::PersonSnapshot {
+impl SseEncode for Vec<crate::movie_data::movie_data::FilmographyGroup> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_tmdbId = <i64>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_character = <String>::sse_decode(deserializer);
-        let mut var_jobName = <String>::sse_decode(deserializer);
-        let mut var_picturePath = <String>::sse_decode(deserializer);
-        return crate::movie_data::movie_data::PersonSnapshot {
-            tmdb_id: var_tmdbId,
-            name: var_name,
-            character: var_character,
-            job_name: var_jobName,
-            picture_path: var_picturePath,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::movie_data::movie_data::FilmographyGroup>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for Vec<crate::movie_data::movie_data::HomeRow> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::movie_data::movie_data::HomeRow>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for Vec<crate::movie_data::movie_data::HomeRowConfig> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::movie_data::movie_data::HomeRowConfig>::sse_encode(item, serializer);
+        }
+    }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for Vec<crate::movie_data::movie_data::MediaChapter> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::movie_data::movie_data::MediaChapter>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for bool {
+impl SseEncode for Vec<crate::movie_data::movie_data::MediaList> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::movie_data::movie_data::MediaList>::sse_encode(item, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__media__get_media_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__media__get_media_cast_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__media__get_media_crew_impl(port, ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__media__get_media_snapshots_impl(port, ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__media__get_person_impl(port, ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__media__init_app_impl(port, ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__media__open_video_impl(port, ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__media__start_impl(port, ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__media__tempo_mount_smb_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__media__tempo_unmount_smb_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for Vec<crate::movie_data::movie_data::MediaStream> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::movie_data::movie_data::MediaStream>::sse_encode(item, serializer);
+        }
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+impl SseEncode for Vec<crate::movie_data::movie_data::MovieSnapshot> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::movie_data::movie_data::MovieSnapshot>::sse_encode(item, serializer);
+        }
     }
 }
 
-// Section: rust2dart
-
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::MediaData {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.file_path.into_into_dart().into_dart(),
-            self.file_optional_info.into_into_dart().into_dart(),
-            self.original_title.into_into_dart().into_dart(),
-            self.title.into_into_dart().into_dart(),
-            self.rating.into_into_dart().into_dart(),
-            self.release_date.into_into_dart().into_dart(),
-            self.summary.into_into_dart().into_dart(),
-            self.poster.into_into_dart().into_dart(),
-            self.backdrop.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::movie_data::movie_data::MediaData
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::MediaData>
-    for crate::movie_data::movie_data::MediaData
-{
-    fn into_into_dart(self) -> crate::movie_data::movie_data::MediaData {
-        self
-    }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::MovieSnapshot {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.file_path.into_into_dart().into_dart(),
-            self.title.into_into_dart().into_dart(),
-            self.rating.into_into_dart().into_dart(),
-            self.release_date.into_into_dart().into_dart(),
-            self.poster.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::movie_data::movie_data::MovieSnapshot
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::MovieSnapshot>
-    for crate::movie_data::movie_data::MovieSnapshot
-{
-    fn into_into_dart(self) -> crate::movie_data::movie_data::MovieSnapshot {
-        self
-    }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::PersonData {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.tmdb_id.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.summary.into_into_dart().into_dart(),
-            self.picture_path.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::movie_data::movie_data::PersonData
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::PersonData>
-    for crate::movie_data::movie_data::PersonData
-{
-    fn into_into_dart(self) -> crate::movie_data::movie_data::PersonData {
-        self
+impl SseEncode for Vec<crate::movie_data::movie_data::PairedDevice> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::movie_data::movie_data::PairedDevice>::sse_encode(item, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::movie_data::movie_data::PersonSnapshot {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.tmdb_id.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.character.into_into_dart().into_dart(),
-            self.job_name.into_into_dart().into_dart(),
-            self.picture_path.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Vec<crate::movie_data::movie_data::PersonSnapshot> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::movie_data::movie_data::PersonSnapshot>::sse_encode(item, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::movie_data::movie_data::PersonSnapshot
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::movie_data::movie_data::PersonSnapshot>
-    for crate::movie_data::movie_data::PersonSnapshot
-{
-    fn into_into_dart(self) -> crate::movie_data::movie_data::PersonSnapshot {
-        self
+
+impl SseEncode for Vec<crate::movie_data::movie_data::PlaybackEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::movie_data::movie_data::PlaybackEntry>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for Vec<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <i64>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for f32 {
+impl SseEncode for Vec<crate::movie_data::movie_data::Profile> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::movie_data::movie_data::Profile>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for i64 {
+impl SseEncode for Vec<crate::movie_data::movie_data::SearchResult> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::movie_data::movie_data::SearchResult>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseEncode for Vec<crate::movie_data::movie_data::MovieSnapshot> {
+impl SseEncode for Vec<crate::movie_data::movie_data::SmartCollection> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::movie_data::movie_data::MovieSnapshot>::sse_encode(item, serializer);
+            <crate::movie_data::movie_data::SmartCollection>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<crate::movie_data::movie_data::PersonSnapshot> {
+impl SseEncode for Vec<crate::movie_data::movie_data::SubtitleFile> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <crate::movie_data::movie_data::PersonSnapshot>::sse_encode(item, serializer);
+            <crate::movie_data::movie_data::SubtitleFile>::sse_encode(item, serializer);
         }
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for Vec<crate::movie_data::movie_data::WatchRecommendation> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
         for item in self {
-            <u8>::sse_encode(item, serializer);
+            <crate::movie_data::movie_data::WatchRecommendation>::sse_encode(item, serializer);
         }
     }
 }
 
+impl SseEncode for crate::movie_data::movie_data::MediaChapter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.index, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <f64>::sse_encode(self.start, serializer);
+        <Option<f64>>::sse_encode(self.end, serializer);
+    }
+}
+
 impl SseEncode for crate::movie_data::movie_data::MediaData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -807,6 +5904,78 @@ impl SseEncode for crate::movie_data::movie_data::MediaData {
         <String>::sse_encode(self.summary, serializer);
         <String>::sse_encode(self.poster, serializer);
         <String>::sse_encode(self.backdrop, serializer);
+        <Option<i32>>::sse_encode(self.runtime, serializer);
+        <String>::sse_encode(self.tagline, serializer);
+        <String>::sse_encode(self.status, serializer);
+        <i64>::sse_encode(self.budget, serializer);
+        <i64>::sse_encode(self.revenue, serializer);
+        <Option<String>>::sse_encode(self.imdb_id, serializer);
+        <String>::sse_encode(self.certification, serializer);
+        <Option<crate::movie_data::movie_data::MediaInfo>>::sse_encode(self.media_info, serializer);
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::MediaInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.container, serializer);
+        <Option<f64>>::sse_encode(self.duration, serializer);
+        <Option<i32>>::sse_encode(self.width, serializer);
+        <Option<i32>>::sse_encode(self.height, serializer);
+        <Option<String>>::sse_encode(self.video_codec, serializer);
+        <Option<String>>::sse_encode(self.hdr_format, serializer);
+        <Option<i64>>::sse_encode(self.bitrate, serializer);
+        <Vec<crate::movie_data::movie_data::MediaStream>>::sse_encode(self.streams, serializer);
+        <Vec<crate::movie_data::movie_data::MediaChapter>>::sse_encode(self.chapters, serializer);
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::MediaList {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.kind, serializer);
+        <u32>::sse_encode(self.item_count, serializer);
+    }
+}
+
+impl SseEncode for crate::directory_explorer::media_file::MediaSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::directory_explorer::media_file::MediaSource::Smb {
+                path,
+                username,
+                password,
+            } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(path, serializer);
+                <String>::sse_encode(username, serializer);
+                <String>::sse_encode(password, serializer);
+            }
+            crate::directory_explorer::media_file::MediaSource::Local { root } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(root, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::MediaStream {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.index, serializer);
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.codec, serializer);
+        <Option<String>>::sse_encode(self.language, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <Option<i32>>::sse_encode(self.channels, serializer);
+        <bool>::sse_encode(self.is_default, serializer);
+        <bool>::sse_encode(self.is_forced, serializer);
     }
 }
 
@@ -819,6 +5988,7 @@ impl SseEncode for crate::movie_data::movie_data::MovieSnapshot {
         <f32>::sse_encode(self.rating, serializer);
         <String>::sse_encode(self.release_date, serializer);
         <String>::sse_encode(self.poster, serializer);
+        <bool>::sse_encode(self.watched, serializer);
     }
 }
 
@@ -832,6 +6002,87 @@ impl SseEncode for Option<String> {
     }
 }
 
+impl SseEncode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f32>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<i32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i32>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::movie_data::movie_data::MediaInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::movie_data::movie_data::MediaInfo>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::PairedDevice {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <i64>::sse_encode(self.profile_id, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+        <Option<i64>>::sse_encode(self.last_seen, serializer);
+    }
+}
+
 impl SseEncode for crate::movie_data::movie_data::PersonData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -839,6 +6090,24 @@ impl SseEncode for crate::movie_data::movie_data::PersonData {
         <String>::sse_encode(self.name, serializer);
         <String>::sse_encode(self.summary, serializer);
         <Option<String>>::sse_encode(self.picture_path, serializer);
+        <Option<String>>::sse_encode(self.birthday, serializer);
+        <Option<String>>::sse_encode(self.deathday, serializer);
+        <Option<String>>::sse_encode(self.place_of_birth, serializer);
+        <Option<String>>::sse_encode(self.known_for_department, serializer);
+        <i32>::sse_encode(self.gender, serializer);
+        <Vec<String>>::sse_encode(self.also_known_as, serializer);
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::PersonFilmography {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::movie_data::movie_data::PersonData>::sse_encode(self.person, serializer);
+        <Vec<crate::movie_data::movie_data::FilmographyGroup>>::sse_encode(self.groups, serializer);
+        <Vec<crate::movie_data::movie_data::FilmographyEntry>>::sse_encode(
+            self.missing,
+            serializer,
+        );
     }
 }
 
@@ -850,32 +6119,202 @@ impl SseEncode for crate::movie_data::movie_data::PersonSnapshot {
         <String>::sse_encode(self.character, serializer);
         <String>::sse_encode(self.job_name, serializer);
         <String>::sse_encode(self.picture_path, serializer);
+        <String>::sse_encode(self.department, serializer);
+        <Option<i32>>::sse_encode(self.credit_order, serializer);
+        <String>::sse_encode(self.credit_id, serializer);
     }
 }
 
-impl SseEncode for u8 {
+impl SseEncode for crate::movie_data::movie_data::PlaybackDecision {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self).unwrap();
+        <String>::sse_encode(self.method, serializer);
+        <Vec<String>>::sse_encode(self.reasons, serializer);
+        <String>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.session_id, serializer);
     }
 }
 
-impl SseEncode for () {
+impl SseEncode for crate::movie_data::movie_data::PlaybackEntry {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::movie_data::movie_data::MovieSnapshot>::sse_encode(self.media, serializer);
+        <crate::movie_data::movie_data::PlaybackState>::sse_encode(self.state, serializer);
+    }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for crate::movie_data::movie_data::PlaybackRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <crate::movie_data::movie_data::ClientCapabilities>::sse_encode(
+            self.capabilities,
+            serializer,
+        );
+        <Option<i32>>::sse_encode(self.max_height, serializer);
+        <Option<i64>>::sse_encode(self.max_bitrate, serializer);
+        <Option<i32>>::sse_encode(self.audio_stream, serializer);
+        <Option<i32>>::sse_encode(self.subtitle_stream, serializer);
+        <Option<i64>>::sse_encode(self.subtitle_file, serializer);
+        <f64>::sse_encode(self.start_position, serializer);
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::movie_data::movie_data::PlaybackState {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <i64>::sse_encode(self.media_id, serializer);
+        <bool>::sse_encode(self.watched, serializer);
+        <u32>::sse_encode(self.play_count, serializer);
+        <Option<i64>>::sse_encode(self.last_played, serializer);
+        <f64>::sse_encode(self.resume_position, serializer);
+        <f64>::sse_encode(self.duration, serializer);
+        <Option<f32>>::sse_encode(self.user_rating, serializer);
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::Profile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.avatar, serializer);
+        <bool>::sse_encode(self.has_pin, serializer);
+        <Option<String>>::sse_encode(self.max_certification, serializer);
+        <bool>::sse_encode(self.hide_unrated, serializer);
+        <Option<String>>::sse_encode(self.preferred_language, serializer);
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::ScanReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.movies_found, serializer);
+        <u32>::sse_encode(self.movies_matched, serializer);
+        <u32>::sse_encode(self.movies_saved, serializer);
+        <u32>::sse_encode(self.movies_failed, serializer);
+        <u32>::sse_encode(self.persons_fetched, serializer);
+        <u32>::sse_encode(self.persons_skipped, serializer);
+        <u64>::sse_encode(self.tmdb_requests, serializer);
+        <u64>::sse_encode(self.tmdb_requests_saved, serializer);
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::SearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.kind, serializer);
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <String>::sse_encode(self.subtitle, serializer);
+        <Option<String>>::sse_encode(self.picture_path, serializer);
+        <f32>::sse_encode(self.score, serializer);
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::SmartCollection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <crate::movie_data::movie_data::SmartQuery>::sse_encode(self.query, serializer);
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::SmartFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.genres, serializer);
+        <Option<u32>>::sse_encode(self.year_from, serializer);
+        <Option<u32>>::sse_encode(self.year_to, serializer);
+        <Option<f32>>::sse_encode(self.min_rating, serializer);
+        <Option<f32>>::sse_encode(self.max_rating, serializer);
+        <Vec<i64>>::sse_encode(self.person_ids, serializer);
+        <Option<bool>>::sse_encode(self.watched, serializer);
+        <Vec<String>>::sse_encode(self.resolutions, serializer);
+        <Option<u32>>::sse_encode(self.added_within_days, serializer);
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::SmartQuery {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::movie_data::movie_data::SmartFilter>::sse_encode(self.filter, serializer);
+        <crate::movie_data::movie_data::SmartSort>::sse_encode(self.sort, serializer);
+        <bool>::sse_encode(self.descending, serializer);
+        <Option<u32>>::sse_encode(self.limit, serializer);
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::SmartSort {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::movie_data::movie_data::SmartSort::Title => 0,
+                crate::movie_data::movie_data::SmartSort::ReleaseDate => 1,
+                crate::movie_data::movie_data::SmartSort::Rating => 2,
+                crate::movie_data::movie_data::SmartSort::DateAdded => 3,
+                crate::movie_data::movie_data::SmartSort::Random => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::movie_data::movie_data::SubtitleFile {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.file_path, serializer);
+        <String>::sse_encode(self.format, serializer);
+        <Option<String>>::sse_encode(self.language, serializer);
+        <Option<String>>::sse_encode(self.title, serializer);
+        <bool>::sse_encode(self.is_forced, serializer);
+        <bool>::sse_encode(self.is_sdh, serializer);
+    }
+}
+
+impl SseEncode for u16 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u16::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self).unwrap();
+    }
+}
+
+impl SseEncode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+}
+
+impl SseEncode for crate::movie_data::movie_data::WatchRecommendation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::movie_data::movie_data::MovieSnapshot>::sse_encode(self.source, serializer);
+        <Vec<crate::movie_data::movie_data::MovieSnapshot>>::sse_encode(self.items, serializer);
     }
 }
 
@@ -1,9 +1,20 @@
+// Modules keep their main file under their own name, as in movie_data::movie_data
+#![allow(clippy::module_inception)]
+
 pub mod api;
 mod db_interface;
 mod directory_explorer;
+mod event_bus;
 mod frb_generated;
+mod media_probe;
 mod media_retriever;
 mod movie_data;
 mod os_interface;
+mod recommender;
+mod search_index;
 mod smb_mounter;
+mod stream_server;
+mod subtitles;
+mod thumbnails;
 mod tmdb_client;
+mod transcoder;
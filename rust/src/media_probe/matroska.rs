@@ -0,0 +1,669 @@
+use crate::{
+    media_probe::media_probe::{hdr_from_transfer, ProbeReader},
+    movie_data::movie_data::{MediaChapter, MediaInfo, MediaStream},
+};
+use anyhow::{anyhow, Result};
+
+/// First bytes of an EBML file, the id of its header element.
+pub const EBML_MAGIC: [u8; 4] = [0x1A, 0x45, 0xDF, 0xA3];
+
+// Element ids, marker bits included as written in the Matroska specification
+const EBML_HEADER: u32 = 0x1A45DFA3;
+const DOC_TYPE: u32 = 0x4282;
+const SEGMENT: u32 = 0x18538067;
+const SEEK_HEAD: u32 = 0x114D9B74;
+const SEEK: u32 = 0x4DBB;
+const SEEK_ID: u32 = 0x53AB;
+const SEEK_POSITION: u32 = 0x53AC;
+const INFO: u32 = 0x1549A966;
+const TIMESTAMP_SCALE: u32 = 0x2AD7B1;
+const DURATION: u32 = 0x4489;
+const TRACKS: u32 = 0x1654AE6B;
+const TRACK_ENTRY: u32 = 0xAE;
+const TRACK_NUMBER: u32 = 0xD7;
+const TRACK_TYPE: u32 = 0x83;
+const CODEC_ID: u32 = 0x86;
+const NAME: u32 = 0x536E;
+const LANGUAGE: u32 = 0x22B59C;
+const LANGUAGE_BCP47: u32 = 0x22B59D;
+const FLAG_DEFAULT: u32 = 0x88;
+const FLAG_FORCED: u32 = 0x55AA;
+const VIDEO: u32 = 0xE0;
+const PIXEL_WIDTH: u32 = 0xB0;
+const PIXEL_HEIGHT: u32 = 0xBA;
+const COLOUR: u32 = 0x55B0;
+const TRANSFER_CHARACTERISTICS: u32 = 0x55BA;
+const AUDIO: u32 = 0xE1;
+const CHANNELS: u32 = 0x9F;
+const BLOCK_ADDITION_MAPPING: u32 = 0x41E4;
+const BLOCK_ADD_ID_TYPE: u32 = 0x41E7;
+const CHAPTERS: u32 = 0x1043A770;
+const EDITION_ENTRY: u32 = 0x45B9;
+const EDITION_FLAG_HIDDEN: u32 = 0x45BD;
+const EDITION_FLAG_DEFAULT: u32 = 0x45DB;
+const CHAPTER_ATOM: u32 = 0xB6;
+const CHAPTER_TIME_START: u32 = 0x91;
+const CHAPTER_TIME_END: u32 = 0x92;
+const CHAPTER_FLAG_HIDDEN: u32 = 0x98;
+const CHAPTER_DISPLAY: u32 = 0x80;
+const CHAP_STRING: u32 = 0x85;
+const CLUSTER: u32 = 0x1F43B675;
+
+/// Block addition types of the Dolby Vision configuration, "dvcC" and "dvvC".
+const DOLBY_VISION_CONFIGS: [u64; 2] = [0x64766343, 0x64767643];
+
+/// Common name of each codec id, matched as a prefix.
+const CODECS: [(&str, &str); 27] = [
+    ("V_MPEGH/ISO/HEVC", "hevc"),
+    ("V_MPEG4/ISO/AVC", "h264"),
+    ("V_MPEG4/ISO/ASP", "mpeg4"),
+    ("V_AV1", "av1"),
+    ("V_VP9", "vp9"),
+    ("V_VP8", "vp8"),
+    ("V_MPEG2", "mpeg2video"),
+    ("V_MPEG1", "mpeg1video"),
+    ("A_AAC", "aac"),
+    ("A_AC3", "ac3"),
+    ("A_EAC3", "eac3"),
+    ("A_DTS", "dts"),
+    ("A_TRUEHD", "truehd"),
+    ("A_FLAC", "flac"),
+    ("A_OPUS", "opus"),
+    ("A_VORBIS", "vorbis"),
+    ("A_MPEG/L3", "mp3"),
+    ("A_MPEG/L2", "mp2"),
+    ("A_PCM", "pcm"),
+    ("S_TEXT/UTF8", "subrip"),
+    ("S_TEXT/ASS", "ass"),
+    ("S_TEXT/SSA", "ssa"),
+    ("S_TEXT/WEBVTT", "webvtt"),
+    ("S_HDMV/PGS", "hdmv_pgs_subtitle"),
+    ("S_VOBSUB", "dvd_subtitle"),
+    ("S_DVBSUB", "dvb_subtitle"),
+    ("S_ASS", "ass"),
+];
+
+struct ElementHeader {
+    id: u32,
+    /// None for an element of unknown size, running to the end of its parent.
+    size: Option<u64>,
+    header_len: usize,
+}
+
+/// Reads the duration, tracks and chapters of a Matroska or WebM file.
+///
+/// The top-level elements of the segment are walked up to the first cluster. Info, tracks and
+/// chapters stored after the clusters are then read from the positions given by the seek head.
+pub async fn probe(reader: &mut ProbeReader<'_>) -> Result<MediaInfo> {
+    let header = read_header(reader, 0).await?;
+    if header.id != EBML_HEADER {
+        return Err(anyhow!("Missing EBML header"));
+    }
+    let ebml = read_body(reader, 0, &header).await?;
+    let container = child(&ebml, DOC_TYPE)
+        .map(string)
+        .unwrap_or_else(|| "matroska".to_owned());
+
+    let segment_pos = header.header_len as u64 + ebml.len() as u64;
+    let segment = read_header(reader, segment_pos).await?;
+    if segment.id != SEGMENT {
+        return Err(anyhow!("Missing Matroska segment"));
+    }
+    let data_start = segment_pos + segment.header_len as u64;
+    let data_end = segment
+        .size
+        .and_then(|size| data_start.checked_add(size))
+        .map_or(reader.len(), |end| end.min(reader.len()));
+
+    let mut info = None;
+    let mut tracks = None;
+    let mut chapters = None;
+    let mut seeks = vec![];
+    let mut pos = data_start;
+    while pos < data_end {
+        let element = read_header(reader, pos).await?;
+        match element.id {
+            SEEK_HEAD => seeks.extend(seek_positions(&read_body(reader, pos, &element).await?)),
+            INFO => info = Some(read_body(reader, pos, &element).await?),
+            TRACKS => tracks = Some(read_body(reader, pos, &element).await?),
+            CHAPTERS => chapters = Some(read_body(reader, pos, &element).await?),
+            CLUSTER => break,
+            _ => {}
+        }
+        let Some(next) = element
+            .size
+            .and_then(|size| size.checked_add(element.header_len as u64))
+            .and_then(|len| pos.checked_add(len))
+        else {
+            break;
+        };
+        pos = next;
+    }
+
+    for (id, offset) in seeks {
+        let missing = match id {
+            INFO => info.is_none(),
+            TRACKS => tracks.is_none(),
+            CHAPTERS => chapters.is_none(),
+            _ => false,
+        };
+        if !missing {
+            continue;
+        }
+        let Some(pos) = data_start
+            .checked_add(offset)
+            .filter(|pos| *pos < reader.len())
+        else {
+            continue;
+        };
+        let element = read_header(reader, pos).await?;
+        if element.id != id {
+            continue;
+        }
+        let body = Some(read_body(reader, pos, &element).await?);
+        match id {
+            INFO => info = body,
+            TRACKS => tracks = body,
+            _ => chapters = body,
+        }
+    }
+
+    let tracks = tracks.ok_or_else(|| anyhow!("No Matroska tracks found"))?;
+    let mut media = MediaInfo {
+        container,
+        duration: info.as_deref().and_then(duration),
+        chapters: chapters
+            .as_deref()
+            .map(edition_chapters)
+            .unwrap_or_default(),
+        ..Default::default()
+    };
+    for (id, entry) in children(&tracks) {
+        if id == TRACK_ENTRY {
+            add_track(&mut media, entry);
+        }
+    }
+    Ok(media)
+}
+
+/// Duration of the segment in seconds, from its info element.
+fn duration(info: &[u8]) -> Option<f64> {
+    let scale = child(info, TIMESTAMP_SCALE).map_or(1_000_000, uint);
+    child(info, DURATION)
+        .and_then(float)
+        .map(|duration| duration * scale as f64 / 1e9)
+}
+
+fn add_track(media: &mut MediaInfo, entry: &[u8]) {
+    let kind = match child(entry, TRACK_TYPE).map(uint) {
+        Some(1) => "video",
+        Some(2) => "audio",
+        Some(17) => "subtitle",
+        _ => return,
+    };
+
+    // English is the default language of a track in the specification
+    let language = child(entry, LANGUAGE_BCP47)
+        .or_else(|| child(entry, LANGUAGE))
+        .map_or_else(|| "eng".to_owned(), string);
+    let mut stream = MediaStream {
+        index: child(entry, TRACK_NUMBER).map_or(0, uint) as i32,
+        kind: kind.to_owned(),
+        codec: codec_name(&child(entry, CODEC_ID).map(string).unwrap_or_default()),
+        language: Some(language).filter(|language| !language.is_empty() && language != "und"),
+        title: child(entry, NAME)
+            .map(string)
+            .filter(|title| !title.is_empty()),
+        channels: None,
+        is_default: child(entry, FLAG_DEFAULT).is_none_or(|flag| uint(flag) != 0),
+        is_forced: flag(entry, FLAG_FORCED),
+    };
+
+    match kind {
+        "video" if media.video_codec.is_none() => {
+            media.video_codec = Some(stream.codec.clone());
+            if let Some(video) = child(entry, VIDEO) {
+                media.width = child(video, PIXEL_WIDTH).map(|width| uint(width) as i32);
+                media.height = child(video, PIXEL_HEIGHT).map(|height| uint(height) as i32);
+                media.hdr_format = hdr_format(entry, video);
+            }
+        }
+        "audio" => {
+            // A track without channel count is mono in the specification
+            let channels = child(entry, AUDIO).and_then(|audio| child(audio, CHANNELS));
+            stream.channels = Some(channels.map_or(1, uint) as i32);
+        }
+        _ => {}
+    }
+    media.streams.push(stream);
+}
+
+fn hdr_format(entry: &[u8], video: &[u8]) -> Option<String> {
+    let dolby_vision = children(entry)
+        .into_iter()
+        .filter(|(id, _)| *id == BLOCK_ADDITION_MAPPING)
+        .filter_map(|(_, mapping)| child(mapping, BLOCK_ADD_ID_TYPE).map(uint))
+        .any(|kind| DOLBY_VISION_CONFIGS.contains(&kind));
+    if dolby_vision {
+        return Some("Dolby Vision".to_owned());
+    }
+
+    child(video, COLOUR)
+        .and_then(|colour| child(colour, TRANSFER_CHARACTERISTICS))
+        .and_then(|transfer| hdr_from_transfer(uint(transfer)))
+}
+
+/// Returns the visible chapters of the default edition, or of the first visible edition when
+/// none is marked default. Nested chapters are left out.
+fn edition_chapters(chapters: &[u8]) -> Vec<MediaChapter> {
+    let editions: Vec<&[u8]> = children(chapters)
+        .into_iter()
+        .filter(|(id, edition)| *id == EDITION_ENTRY && !flag(edition, EDITION_FLAG_HIDDEN))
+        .map(|(_, edition)| edition)
+        .collect();
+    let Some(edition) = editions
+        .iter()
+        .find(|edition| flag(edition, EDITION_FLAG_DEFAULT))
+        .or(editions.first())
+    else {
+        return vec![];
+    };
+
+    // Chapter timestamps are in nanoseconds whatever the timestamp scale
+    children(edition)
+        .into_iter()
+        .filter(|(id, atom)| *id == CHAPTER_ATOM && !flag(atom, CHAPTER_FLAG_HIDDEN))
+        .enumerate()
+        .map(|(index, (_, atom))| MediaChapter {
+            index: index as i32,
+            title: child(atom, CHAPTER_DISPLAY)
+                .and_then(|display| child(display, CHAP_STRING))
+                .map(string)
+                .filter(|title| !title.is_empty()),
+            start: child(atom, CHAPTER_TIME_START).map_or(0, uint) as f64 / 1e9,
+            end: child(atom, CHAPTER_TIME_END).map(|end| uint(end) as f64 / 1e9),
+        })
+        .collect()
+}
+
+/// Returns the element ids of a seek head with their position in the segment.
+fn seek_positions(seek_head: &[u8]) -> Vec<(u32, u64)> {
+    children(seek_head)
+        .into_iter()
+        .filter(|(id, _)| *id == SEEK)
+        .filter_map(|(_, seek)| {
+            let id = child(seek, SEEK_ID)?;
+            let position = child(seek, SEEK_POSITION)?;
+            Some((uint(id) as u32, uint(position)))
+        })
+        .collect()
+}
+
+fn codec_name(codec_id: &str) -> String {
+    CODECS
+        .iter()
+        .find(|(known, _)| codec_id.starts_with(known))
+        .map_or_else(|| codec_id.to_lowercase(), |(_, name)| (*name).to_owned())
+}
+
+// region: ---- EBML ----
+
+async fn read_header(reader: &mut ProbeReader<'_>, pos: u64) -> Result<ElementHeader> {
+    let data = reader.read_up_to(pos, 12).await?;
+    parse_header(&data).ok_or_else(|| anyhow!("Invalid EBML element at {}", pos))
+}
+
+async fn read_body(
+    reader: &mut ProbeReader<'_>,
+    pos: u64,
+    header: &ElementHeader,
+) -> Result<Vec<u8>> {
+    let size = header
+        .size
+        .ok_or_else(|| anyhow!("EBML element of unknown size at {}", pos))?;
+    reader.read(pos + header.header_len as u64, size).await
+}
+
+/// Reads a variable size integer, with its marker bit for an id or without for a size.
+fn read_vint(data: &[u8], keep_marker: bool) -> Option<(u64, usize)> {
+    let first = *data.first()?;
+    let len = first.leading_zeros() as usize + 1;
+    if len > 8 || data.len() < len {
+        return None;
+    }
+    let first = if keep_marker {
+        first as u64
+    } else {
+        (first as u64) & (0xFF >> len)
+    };
+    let value = data[1..len]
+        .iter()
+        .fold(first, |value, byte| (value << 8) | *byte as u64);
+    Some((value, len))
+}
+
+fn parse_header(data: &[u8]) -> Option<ElementHeader> {
+    let (id, id_len) = read_vint(data, true)?;
+    if id_len > 4 {
+        return None;
+    }
+    let (size, size_len) = read_vint(&data[id_len..], false)?;
+    let unknown_size = size == (1u64 << (7 * size_len)) - 1;
+    Some(ElementHeader {
+        id: id as u32,
+        size: (!unknown_size).then_some(size),
+        header_len: id_len + size_len,
+    })
+}
+
+/// Splits the body of a master element into its children, up to the first truncated one.
+fn children(data: &[u8]) -> Vec<(u32, &[u8])> {
+    let mut found = vec![];
+    let mut pos = 0;
+    while pos < data.len() {
+        let Some(header) = parse_header(&data[pos..]) else {
+            break;
+        };
+        let start = pos + header.header_len;
+        let end = match header.size {
+            Some(size) => match usize::try_from(size)
+                .ok()
+                .and_then(|s| start.checked_add(s))
+            {
+                Some(end) if end <= data.len() => end,
+                _ => break,
+            },
+            None => data.len(),
+        };
+        found.push((header.id, &data[start..end]));
+        pos = end;
+    }
+    found
+}
+
+fn child(data: &[u8], id: u32) -> Option<&[u8]> {
+    children(data)
+        .into_iter()
+        .find(|(known, _)| *known == id)
+        .map(|(_, body)| body)
+}
+
+fn flag(data: &[u8], id: u32) -> bool {
+    child(data, id).is_some_and(|flag| uint(flag) != 0)
+}
+
+fn uint(data: &[u8]) -> u64 {
+    data.iter()
+        .take(8)
+        .fold(0, |value, byte| (value << 8) | *byte as u64)
+}
+
+fn float(data: &[u8]) -> Option<f64> {
+    match data.len() {
+        4 => Some(f32::from_be_bytes(data.try_into().ok()?) as f64),
+        8 => Some(f64::from_be_bytes(data.try_into().ok()?)),
+        _ => None,
+    }
+}
+
+fn string(data: &[u8]) -> String {
+    String::from_utf8_lossy(data)
+        .trim_end_matches('\0')
+        .to_owned()
+}
+// endregion
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encodes an element with its id as written in the specification and a vint size.
+    fn element(id: u32, body: &[u8]) -> Vec<u8> {
+        let mut data: Vec<u8> = id
+            .to_be_bytes()
+            .into_iter()
+            .skip_while(|b| *b == 0)
+            .collect();
+        match body.len() {
+            len if len < 0x7F => data.push(0x80 | len as u8),
+            len => data.extend_from_slice(&(0x4000 | len as u16).to_be_bytes()),
+        }
+        data.extend_from_slice(body);
+        data
+    }
+
+    fn uint_element(id: u32, value: u64) -> Vec<u8> {
+        element(id, &value.to_be_bytes())
+    }
+
+    fn chapter_atom(start_ns: u64, title: &str, hidden: bool) -> Vec<u8> {
+        let display = element(CHAP_STRING, title.as_bytes());
+        let body = [
+            uint_element(CHAPTER_TIME_START, start_ns),
+            uint_element(CHAPTER_FLAG_HIDDEN, hidden as u64),
+            element(CHAPTER_DISPLAY, &display),
+        ]
+        .concat();
+        element(CHAPTER_ATOM, &body)
+    }
+
+    #[test]
+    fn read_vint_strips_the_marker_of_sizes_only() {
+        assert_eq!(read_vint(&[0x81], false), Some((1, 1)));
+        assert_eq!(read_vint(&[0x40, 0x02], false), Some((2, 2)));
+        assert_eq!(read_vint(&EBML_MAGIC, true), Some((EBML_HEADER as u64, 4)));
+    }
+
+    #[test]
+    fn read_vint_rejects_truncated_and_invalid_lengths() {
+        assert_eq!(read_vint(&[], false), None);
+        assert_eq!(read_vint(&[0x40], false), None);
+        assert_eq!(read_vint(&[0x00, 0x01, 0x02], false), None);
+    }
+
+    #[test]
+    fn parse_header_reads_unknown_sizes() {
+        let data = [
+            0x18, 0x53, 0x80, 0x67, 0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+        ];
+        let header = parse_header(&data).unwrap();
+        assert_eq!(header.id, SEGMENT);
+        assert_eq!(header.size, None);
+        assert_eq!(header.header_len, 12);
+
+        let header = parse_header(&[0xAE, 0x85]).unwrap();
+        assert_eq!(header.id, TRACK_ENTRY);
+        assert_eq!(header.size, Some(5));
+        assert_eq!(header.header_len, 2);
+    }
+
+    #[test]
+    fn parse_header_rejects_ids_longer_than_four_bytes() {
+        assert!(parse_header(&[0x08, 0x01, 0x02, 0x03, 0x04, 0x81]).is_none());
+        assert!(parse_header(&[0x1A, 0x45]).is_none());
+    }
+
+    #[test]
+    fn children_stop_at_the_first_truncated_element() {
+        let mut data = [uint_element(TRACK_NUMBER, 1), uint_element(TRACK_TYPE, 2)].concat();
+        data.extend_from_slice(&[CODEC_ID as u8, 0x8A, b'A', b'_']);
+
+        let found = children(&data);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].0, TRACK_NUMBER);
+        assert_eq!(uint(found[1].1), 2);
+    }
+
+    #[test]
+    fn children_stop_at_an_oversized_element() {
+        let mut data = uint_element(TRACK_NUMBER, 1);
+        data.extend_from_slice(&[
+            TRACK_TYPE as u8,
+            0x01,
+            0x7F,
+            0xFF,
+            0xFF,
+            0xFF,
+            0xFF,
+            0xFF,
+            0xFE,
+        ]);
+        data.extend(uint_element(TRACK_TYPE, 2));
+
+        let found = children(&data);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0, TRACK_NUMBER);
+    }
+
+    #[test]
+    fn children_run_an_element_of_unknown_size_to_the_end() {
+        let data = [0x1F, 0x43, 0xB6, 0x75, 0xFF, 1, 2, 3];
+
+        let found = children(&data);
+        assert_eq!(found, vec![(CLUSTER, &[1u8, 2, 3][..])]);
+    }
+
+    #[test]
+    fn duration_applies_the_timestamp_scale() {
+        let info = [
+            uint_element(TIMESTAMP_SCALE, 1_000_000),
+            element(DURATION, &5_400_000.0f64.to_be_bytes()),
+        ]
+        .concat();
+        assert_eq!(duration(&info), Some(5400.0));
+
+        let info = [
+            uint_element(TIMESTAMP_SCALE, 1_000),
+            element(DURATION, &2_000_000.0f32.to_be_bytes()),
+        ]
+        .concat();
+        assert_eq!(duration(&info), Some(2.0));
+
+        assert_eq!(duration(&element(DURATION, &[0, 0, 0])), None);
+    }
+
+    #[test]
+    fn seek_positions_skip_incomplete_seeks() {
+        let seek_head = [
+            element(
+                SEEK,
+                &[
+                    element(SEEK_ID, &TRACKS.to_be_bytes()),
+                    uint_element(SEEK_POSITION, 4096),
+                ]
+                .concat(),
+            ),
+            element(SEEK, &element(SEEK_ID, &INFO.to_be_bytes())),
+        ]
+        .concat();
+        assert_eq!(seek_positions(&seek_head), vec![(TRACKS, 4096)]);
+    }
+
+    #[test]
+    fn edition_chapters_read_the_default_visible_edition() {
+        let hidden = element(
+            EDITION_ENTRY,
+            &[
+                uint_element(EDITION_FLAG_HIDDEN, 1),
+                chapter_atom(0, "Hidden edition", false),
+            ]
+            .concat(),
+        );
+        let first = element(EDITION_ENTRY, &chapter_atom(0, "First edition", false));
+        let default = element(
+            EDITION_ENTRY,
+            &[
+                uint_element(EDITION_FLAG_DEFAULT, 1),
+                chapter_atom(0, "Opening", false),
+                chapter_atom(30_000_000_000, "Hidden chapter", true),
+                chapter_atom(90_500_000_000, "", false),
+            ]
+            .concat(),
+        );
+
+        let chapters = edition_chapters(&[hidden, first, default].concat());
+        assert_eq!(chapters.len(), 2);
+        assert_eq!(chapters[0].title.as_deref(), Some("Opening"));
+        assert_eq!(chapters[1].index, 1);
+        assert_eq!(chapters[1].title, None);
+        assert_eq!(chapters[1].start, 90.5);
+        assert_eq!(chapters[1].end, None);
+    }
+
+    #[test]
+    fn edition_chapters_are_empty_without_visible_edition() {
+        let hidden = element(
+            EDITION_ENTRY,
+            &[
+                uint_element(EDITION_FLAG_HIDDEN, 1),
+                chapter_atom(0, "Hidden edition", false),
+            ]
+            .concat(),
+        );
+        assert!(edition_chapters(&hidden).is_empty());
+    }
+
+    #[test]
+    fn add_track_reads_video_and_audio_entries() {
+        let colour = element(COLOUR, &uint_element(TRANSFER_CHARACTERISTICS, 16));
+        let video = element(
+            VIDEO,
+            &[
+                uint_element(PIXEL_WIDTH, 3840),
+                uint_element(PIXEL_HEIGHT, 2160),
+                colour,
+            ]
+            .concat(),
+        );
+        let video_entry = [
+            uint_element(TRACK_NUMBER, 1),
+            uint_element(TRACK_TYPE, 1),
+            element(CODEC_ID, b"V_MPEGH/ISO/HEVC"),
+            element(LANGUAGE, b"und"),
+            video,
+        ]
+        .concat();
+        let audio_entry = [
+            uint_element(TRACK_NUMBER, 2),
+            uint_element(TRACK_TYPE, 2),
+            element(CODEC_ID, b"A_EAC3"),
+            uint_element(FLAG_DEFAULT, 0),
+            element(NAME, b"Commentary"),
+        ]
+        .concat();
+
+        let mut media = MediaInfo::default();
+        add_track(&mut media, &video_entry);
+        add_track(&mut media, &audio_entry);
+        add_track(&mut media, &uint_element(TRACK_TYPE, 3));
+
+        assert_eq!(media.video_codec.as_deref(), Some("hevc"));
+        assert_eq!((media.width, media.height), (Some(3840), Some(2160)));
+        assert_eq!(media.hdr_format.as_deref(), Some("HDR10"));
+        assert_eq!(media.streams.len(), 2);
+        assert_eq!(media.streams[0].language, None);
+        assert!(media.streams[0].is_default);
+
+        let audio = &media.streams[1];
+        assert_eq!(audio.codec, "eac3");
+        assert_eq!(audio.language.as_deref(), Some("eng"));
+        assert_eq!(audio.title.as_deref(), Some("Commentary"));
+        assert_eq!(audio.channels, Some(1));
+        assert!(!audio.is_default);
+    }
+
+    #[test]
+    fn hdr_format_prefers_dolby_vision_mappings() {
+        let mapping = element(
+            BLOCK_ADDITION_MAPPING,
+            &uint_element(BLOCK_ADD_ID_TYPE, 0x64766343),
+        );
+        let video = element(COLOUR, &uint_element(TRANSFER_CHARACTERISTICS, 16));
+        assert_eq!(
+            hdr_format(&mapping, &video).as_deref(),
+            Some("Dolby Vision")
+        );
+        assert_eq!(hdr_format(&[], &video).as_deref(), Some("HDR10"));
+        assert_eq!(hdr_format(&[], &[]), None);
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{
+    db_interface::{data_getter::DataGetter, data_saver::DataSaver},
+    directory_explorer::media_file::{FileSource, OpenedFile},
+    media_probe::{matroska, mp4},
+    movie_data::movie_data::MediaInfo,
+};
+use anyhow::{anyhow, Context, Result};
+use futures::stream::{self, StreamExt};
+
+/// Size of the blocks read while probing, the headers of most files fitting in the first one.
+const PROBE_BLOCK_SIZE: usize = 64 * 1024;
+
+/// Largest header element or box read whole, a bigger one being taken for a corrupted size.
+const MAX_HEADER_SIZE: u64 = 64 * 1024 * 1024;
+
+/// Files probed at the same time, each waiting mostly on the network.
+const PROBE_CONCURRENCY: usize = 4;
+
+/// Extensions of the files whose container the probe can read.
+const PROBED_EXTENSIONS: [&str; 6] = ["mkv", "mk3d", "webm", "mp4", "m4v", "mov"];
+
+/// Reads the headers of a media file, keeping the last block read so the many small reads
+/// of a header walk cost a single request.
+pub struct ProbeReader<'a> {
+    file: &'a mut OpenedFile,
+    len: u64,
+    block_start: u64,
+    block: Vec<u8>,
+}
+
+impl<'a> ProbeReader<'a> {
+    pub fn new(file: &'a mut OpenedFile, len: u64) -> Self {
+        Self {
+            file,
+            len,
+            block_start: 0,
+            block: vec![],
+        }
+    }
+
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Reads `size` bytes at `pos`, failing when the file ends before.
+    pub async fn read(&mut self, pos: u64, size: u64) -> Result<Vec<u8>> {
+        if size > MAX_HEADER_SIZE {
+            return Err(anyhow!("Header of {} bytes at {} is too large", size, pos));
+        }
+        let end = pos
+            .checked_add(size)
+            .filter(|end| *end <= self.len)
+            .ok_or_else(|| anyhow!("Header at {} goes past the end of the file", pos))?;
+
+        let block_end = self.block_start + self.block.len() as u64;
+        if pos < self.block_start || end > block_end {
+            let wanted = (size as usize)
+                .max(PROBE_BLOCK_SIZE)
+                .min((self.len - pos) as usize);
+            let mut block = vec![0u8; wanted];
+            let read = self
+                .file
+                .read_full_at(&mut block, pos)
+                .await
+                .with_context(|| format!("Failed to read file at {}", pos))?;
+            if (read as u64) < size {
+                return Err(anyhow!("File ended while reading header at {}", pos));
+            }
+            block.truncate(read);
+            self.block_start = pos;
+            self.block = block;
+        }
+
+        let start = (pos - self.block_start) as usize;
+        Ok(self.block[start..start + size as usize].to_vec())
+    }
+
+    /// Reads up to `size` bytes at `pos`, fewer at the end of the file.
+    pub async fn read_up_to(&mut self, pos: u64, size: u64) -> Result<Vec<u8>> {
+        let size = size.min(self.len.saturating_sub(pos));
+        self.read(pos, size).await
+    }
+}
+
+/// Reads the technical info and chapters of a Matroska, WebM, MP4 or QuickTime file
+/// from its headers.
+pub async fn probe_media(source: &FileSource, file_path: &str) -> Result<MediaInfo> {
+    let (mut file, len) = OpenedFile::open(source, file_path).await?;
+    let mut reader = ProbeReader::new(&mut file, len);
+
+    let magic = reader.read_up_to(0, 12).await?;
+    let mut info = if magic.starts_with(&matroska::EBML_MAGIC) {
+        matroska::probe(&mut reader).await
+    } else if mp4::is_mp4(&magic) {
+        mp4::probe(&mut reader).await
+    } else {
+        Err(anyhow!("Unknown container"))
+    }
+    .with_context(|| format!("Failed to probe {}", file_path))?;
+
+    if let Some(duration) = info.duration.filter(|duration| *duration > 0.0) {
+        info.bitrate = Some((len as f64 * 8.0 / duration) as i64);
+    }
+
+    // A chapter without end runs up to the next one, or to the end of the file
+    let starts: Vec<f64> = info.chapters.iter().map(|chapter| chapter.start).collect();
+    for (index, chapter) in info.chapters.iter_mut().enumerate() {
+        if chapter.end.is_none() {
+            chapter.end = starts.get(index + 1).copied().or(info.duration);
+        }
+    }
+    Ok(info)
+}
+
+/// Probes the files of the media never probed, or of every media with `all`, and saves their
+/// technical info. Returns the number of files probed, the failing ones being logged and skipped.
+pub async fn probe_library(source: &FileSource, all: bool) -> Result<u32> {
+    let media: Vec<(i64, String)> = DataGetter::new("movie_db.db".to_owned())?
+        .get_media_to_probe(all)?
+        .into_iter()
+        .filter(|(_, file_path)| is_probed_container(file_path))
+        .collect();
+
+    tracing::info!(files = media.len(), "Media probing started");
+
+    let mut probed = stream::iter(media)
+        .map(|(media_id, file_path)| async move {
+            let info = probe_media(source, &file_path).await;
+            (media_id, file_path, info)
+        })
+        .buffer_unordered(PROBE_CONCURRENCY);
+
+    let mut data_saver = DataSaver::new("movie_db.db".to_owned())?;
+    let mut probed_count = 0;
+    while let Some((media_id, file_path, info)) = probed.next().await {
+        match info {
+            Ok(info) => {
+                data_saver.save_media_info(media_id, &info)?;
+                probed_count += 1;
+            }
+            Err(e) => {
+                tracing::error!("Failed to probe {} \n Caused by {:?}", file_path, e);
+            }
+        }
+    }
+
+    tracing::info!(probed = probed_count, "Media probing ended");
+    Ok(probed_count)
+}
+
+/// Names the HDR format of a video from its ITU-T H.273 transfer characteristics.
+pub fn hdr_from_transfer(transfer: u64) -> Option<String> {
+    match transfer {
+        16 => Some("HDR10".to_owned()),
+        18 => Some("HLG".to_owned()),
+        _ => None,
+    }
+}
+
+fn is_probed_container(file_path: &str) -> bool {
+    let extension = file_path
+        .rsplit('.')
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    PROBED_EXTENSIONS.contains(&extension.as_str())
+}
@@ -0,0 +1,3 @@
+pub mod matroska;
+pub mod media_probe;
+pub mod mp4;
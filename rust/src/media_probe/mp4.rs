@@ -0,0 +1,690 @@
+use crate::{
+    media_probe::media_probe::{hdr_from_transfer, ProbeReader},
+    movie_data::movie_data::{MediaChapter, MediaInfo, MediaStream},
+};
+use anyhow::{anyhow, Result};
+
+/// Box types found at the start of an MP4 or QuickTime file.
+const LEADING_BOXES: [&[u8; 4]; 7] = [
+    b"ftyp", b"moov", b"mdat", b"free", b"skip", b"wide", b"pnot",
+];
+
+/// Common name of each sample entry type.
+const CODECS: [(&[u8; 4], &str); 33] = [
+    (b"avc1", "h264"),
+    (b"avc3", "h264"),
+    (b"dvav", "h264"),
+    (b"dva1", "h264"),
+    (b"hvc1", "hevc"),
+    (b"hev1", "hevc"),
+    (b"dvh1", "hevc"),
+    (b"dvhe", "hevc"),
+    (b"av01", "av1"),
+    (b"dav1", "av1"),
+    (b"vp09", "vp9"),
+    (b"vp08", "vp8"),
+    (b"mp4v", "mpeg4"),
+    (b"mp4a", "aac"),
+    (b"ac-3", "ac3"),
+    (b"ec-3", "eac3"),
+    (b"Opus", "opus"),
+    (b"fLaC", "flac"),
+    (b"alac", "alac"),
+    (b"dtsc", "dts"),
+    (b"dtsh", "dts"),
+    (b"dtsl", "dts"),
+    (b"dtse", "dts"),
+    (b"mlpa", "truehd"),
+    (b".mp3", "mp3"),
+    (b"lpcm", "pcm"),
+    (b"sowt", "pcm"),
+    (b"twos", "pcm"),
+    (b"ipcm", "pcm"),
+    (b"tx3g", "mov_text"),
+    (b"wvtt", "webvtt"),
+    (b"stpp", "ttml"),
+    (b"c608", "eia_608"),
+];
+
+/// Sample entries and configuration boxes of Dolby Vision video.
+const DOLBY_VISION_BOXES: [&[u8; 4]; 7] = [
+    b"dvh1", b"dvhe", b"dvav", b"dva1", b"dvcC", b"dvvC", b"dvwC",
+];
+
+/// Offset of the child boxes in a visual sample entry.
+const VISUAL_ENTRY_HEADER: usize = 78;
+
+/// Most chapters read from a chapter track, a larger count being taken for a corrupted table.
+const MAX_CHAPTERS: usize = 1000;
+
+/// Nero chapter start times are in units of 100 nanoseconds.
+const NERO_TIMESCALE: f64 = 10_000_000.0;
+
+/// Tells whether the first bytes of a file are those of an MP4 or QuickTime file.
+pub fn is_mp4(magic: &[u8]) -> bool {
+    magic.len() >= 8 && LEADING_BOXES.iter().any(|kind| &magic[4..8] == *kind)
+}
+
+/// Reads the duration, tracks and chapters of an MP4 or QuickTime file from its movie box.
+///
+/// Chapters come from the QuickTime chapter track, whose titles are read from the media data,
+/// or else from the Nero chapter list of the movie user data.
+pub async fn probe(reader: &mut ProbeReader<'_>) -> Result<MediaInfo> {
+    let mut container = "mp4";
+    let mut movie = None;
+    let mut pos = 0;
+    while pos < reader.len() {
+        let header = reader.read_up_to(pos, 16).await?;
+        let (kind, header_len, size) =
+            box_header(&header).ok_or_else(|| anyhow!("Invalid box at {}", pos))?;
+        let size = size.unwrap_or(reader.len() - pos);
+        if size < header_len as u64 {
+            return Err(anyhow!("Invalid box size at {}", pos));
+        }
+        match &kind {
+            b"ftyp" if header.get(header_len..header_len + 4) == Some(b"qt  ") => {
+                container = "mov";
+            }
+            b"moov" => {
+                let body_size = size - header_len as u64;
+                movie = Some(reader.read(pos + header_len as u64, body_size).await?);
+                break;
+            }
+            _ => {}
+        }
+        pos = pos
+            .checked_add(size)
+            .ok_or_else(|| anyhow!("Invalid box size at {}", pos))?;
+    }
+
+    let movie = movie.ok_or_else(|| anyhow!("No movie box found"))?;
+    let mut media = MediaInfo {
+        container: container.to_owned(),
+        duration: duration(&movie),
+        ..Default::default()
+    };
+
+    // Text tracks referenced as chapters hold chapter titles, not subtitles
+    let chapter_tracks: Vec<u32> = boxes(&movie)
+        .into_iter()
+        .filter(|(kind, _)| kind == b"trak")
+        .filter_map(|(_, track)| find_box(track, &[b"tref", b"chap"]))
+        .flat_map(|chap| chap.chunks_exact(4).map(|id| be_u32(id, 0).unwrap_or(0)))
+        .collect();
+
+    for (kind, track) in boxes(&movie) {
+        if kind == *b"trak" {
+            add_track(&mut media, track, &chapter_tracks);
+        }
+    }
+
+    if let Some(track) = chapter_tracks
+        .first()
+        .and_then(|id| track_by_id(&movie, *id))
+    {
+        media.chapters = chapter_track(reader, track).await.unwrap_or_else(|e| {
+            tracing::warn!("Failed to read chapter track \n Caused by {:?}", e);
+            vec![]
+        });
+    }
+    if media.chapters.is_empty() {
+        media.chapters = find_box(&movie, &[b"udta", b"chpl"])
+            .map(nero_chapters)
+            .unwrap_or_default();
+    }
+    Ok(media)
+}
+
+/// Duration of the movie in seconds, from its header or for a fragmented file its extends header.
+fn duration(movie: &[u8]) -> Option<f64> {
+    let header = find_box(movie, &[b"mvhd"])?;
+    let (timescale, duration) = match header.first()? {
+        1 => (be_u32(header, 20)?, be_u64(header, 24)?),
+        _ => (be_u32(header, 12)?, be_u32(header, 16)? as u64),
+    };
+    let duration = match duration {
+        0 | u64::MAX | 0xFFFF_FFFF => {
+            let extends = find_box(movie, &[b"mvex", b"mehd"])?;
+            match extends.first()? {
+                1 => be_u64(extends, 4)?,
+                _ => be_u32(extends, 4)? as u64,
+            }
+        }
+        duration => duration,
+    };
+    (timescale > 0).then(|| duration as f64 / timescale as f64)
+}
+
+fn add_track(media: &mut MediaInfo, track: &[u8], chapter_tracks: &[u32]) {
+    let Some(header) = find_box(track, &[b"tkhd"]) else {
+        return;
+    };
+    let track_id = match header.first() {
+        Some(1) => be_u32(header, 20),
+        _ => be_u32(header, 12),
+    }
+    .unwrap_or(0);
+    let enabled = header.get(3).is_some_and(|flags| flags & 1 != 0);
+
+    let handler = find_box(track, &[b"mdia", b"hdlr"]).unwrap_or_default();
+    let kind = match handler.get(8..12) {
+        Some(b"vide") => "video",
+        Some(b"soun") => "audio",
+        Some(b"text") if chapter_tracks.contains(&track_id) => return,
+        Some(b"sbtl" | b"subt" | b"text" | b"clcp") => "subtitle",
+        _ => return,
+    };
+
+    let Some((entry_type, entry)) = find_box(track, &[b"mdia", b"minf", b"stbl", b"stsd"])
+        .and_then(|descriptions| descriptions.get(8..))
+        .and_then(|entries| boxes(entries).into_iter().next())
+    else {
+        return;
+    };
+
+    let mut stream = MediaStream {
+        index: track_id as i32,
+        kind: kind.to_owned(),
+        codec: codec_name(&entry_type),
+        language: find_box(track, &[b"mdia", b"mdhd"]).and_then(language),
+        title: title(track, handler),
+        channels: None,
+        is_default: enabled,
+        is_forced: false,
+    };
+
+    match kind {
+        "video" if media.video_codec.is_none() => {
+            media.video_codec = Some(stream.codec.clone());
+            media.width = be_u16(entry, 24).map(|width| width as i32);
+            media.height = be_u16(entry, 26).map(|height| height as i32);
+            media.hdr_format = hdr_format(&entry_type, entry);
+        }
+        "audio" => {
+            // QuickTime sound descriptions of version 2 move the channel count further
+            stream.channels = match be_u16(entry, 8) {
+                Some(2) => be_u32(entry, 40).map(|channels| channels as i32),
+                _ => be_u16(entry, 16).map(|channels| channels as i32),
+            };
+        }
+        _ => {}
+    }
+    media.streams.push(stream);
+}
+
+fn hdr_format(entry_type: &[u8; 4], entry: &[u8]) -> Option<String> {
+    let children = entry
+        .get(VISUAL_ENTRY_HEADER..)
+        .map(boxes)
+        .unwrap_or_default();
+    let dolby_vision = DOLBY_VISION_BOXES.contains(&entry_type)
+        || children
+            .iter()
+            .any(|(kind, _)| DOLBY_VISION_BOXES.contains(&kind));
+    if dolby_vision {
+        return Some("Dolby Vision".to_owned());
+    }
+
+    children
+        .iter()
+        .filter(|(kind, _)| kind == b"colr")
+        .find(|(_, colour)| matches!(colour.get(..4), Some(b"nclx" | b"nclc")))
+        .and_then(|(_, colour)| be_u16(colour, 6))
+        .and_then(|transfer| hdr_from_transfer(transfer as u64))
+}
+
+fn track_by_id(movie: &[u8], track_id: u32) -> Option<&[u8]> {
+    boxes(movie)
+        .into_iter()
+        .filter(|(kind, _)| kind == b"trak")
+        .map(|(_, track)| track)
+        .find(|track| {
+            find_box(track, &[b"tkhd"]).is_some_and(|header| {
+                let id = match header.first() {
+                    Some(1) => be_u32(header, 20),
+                    _ => be_u32(header, 12),
+                };
+                id == Some(track_id)
+            })
+        })
+}
+
+/// Reads the chapters of a QuickTime chapter track, each sample holding the title of a chapter.
+async fn chapter_track(reader: &mut ProbeReader<'_>, track: &[u8]) -> Result<Vec<MediaChapter>> {
+    let media_header = find_box(track, &[b"mdia", b"mdhd"]).unwrap_or_default();
+    let timescale = match media_header.first() {
+        Some(1) => be_u32(media_header, 20),
+        _ => be_u32(media_header, 12),
+    }
+    .filter(|timescale| *timescale > 0)
+    .ok_or_else(|| anyhow!("Chapter track without timescale"))?;
+
+    let table = find_box(track, &[b"mdia", b"minf", b"stbl"])
+        .ok_or_else(|| anyhow!("Chapter track without sample table"))?;
+    let samples = sample_locations(table);
+    let times = sample_times(table);
+
+    let mut chapters = vec![];
+    for (index, ((offset, size), (start, duration))) in samples.into_iter().zip(times).enumerate() {
+        let sample = reader.read(offset, size as u64).await?;
+        chapters.push(MediaChapter {
+            index: index as i32,
+            title: sample_text(&sample),
+            start: start as f64 / timescale as f64,
+            end: Some((start + duration) as f64 / timescale as f64),
+        });
+    }
+    Ok(chapters)
+}
+
+/// Returns the file offset and size of each sample of a track, from its sample table.
+fn sample_locations(table: &[u8]) -> Vec<(u64, u32)> {
+    let sizes = find_box(table, &[b"stsz"]).unwrap_or_default();
+    let fixed_size = be_u32(sizes, 4).unwrap_or(0);
+    let sample_count = (be_u32(sizes, 8).unwrap_or(0) as usize).min(MAX_CHAPTERS);
+    let sample_size = |sample: usize| match fixed_size {
+        0 => be_u32(sizes, 12 + sample * 4),
+        size => Some(size),
+    };
+
+    let chunk_offsets: Vec<u64> = match (find_box(table, &[b"stco"]), find_box(table, &[b"co64"])) {
+        (Some(offsets), _) => (0..be_u32(offsets, 4).unwrap_or(0) as usize)
+            .map_while(|chunk| be_u32(offsets, 8 + chunk * 4).map(u64::from))
+            .collect(),
+        (None, Some(offsets)) => (0..be_u32(offsets, 4).unwrap_or(0) as usize)
+            .map_while(|chunk| be_u64(offsets, 8 + chunk * 8))
+            .collect(),
+        (None, None) => vec![],
+    };
+
+    // Runs of chunks sharing a sample count, as (first chunk from 1, samples per chunk)
+    let sample_to_chunk = find_box(table, &[b"stsc"]).unwrap_or_default();
+    let runs: Vec<(u32, u32)> = (0..be_u32(sample_to_chunk, 4).unwrap_or(0) as usize)
+        .map_while(|run| {
+            let first_chunk = be_u32(sample_to_chunk, 8 + run * 12)?;
+            let samples = be_u32(sample_to_chunk, 12 + run * 12)?;
+            Some((first_chunk, samples))
+        })
+        .collect();
+
+    let mut locations = vec![];
+    for (chunk, chunk_offset) in chunk_offsets.into_iter().enumerate() {
+        let chunk_number = chunk as u32 + 1;
+        let samples_in_chunk = runs
+            .iter()
+            .take_while(|(first_chunk, _)| *first_chunk <= chunk_number)
+            .last()
+            .map_or(0, |(_, samples)| *samples);
+        let mut offset = chunk_offset;
+        for _ in 0..samples_in_chunk {
+            if locations.len() == sample_count {
+                return locations;
+            }
+            let Some(size) = sample_size(locations.len()) else {
+                return locations;
+            };
+            locations.push((offset, size));
+            let Some(next) = offset.checked_add(size as u64) else {
+                return locations;
+            };
+            offset = next;
+        }
+    }
+    locations
+}
+
+/// Returns the start and duration of each sample of a track in its timescale.
+fn sample_times(table: &[u8]) -> Vec<(u64, u64)> {
+    let time_to_sample = find_box(table, &[b"stts"]).unwrap_or_default();
+    let mut times = vec![];
+    let mut start = 0;
+    for run in 0..be_u32(time_to_sample, 4).unwrap_or(0) as usize {
+        let (Some(count), Some(duration)) = (
+            be_u32(time_to_sample, 8 + run * 8),
+            be_u32(time_to_sample, 12 + run * 8),
+        ) else {
+            break;
+        };
+        for _ in 0..count {
+            if times.len() == MAX_CHAPTERS {
+                return times;
+            }
+            times.push((start, duration as u64));
+            start += duration as u64;
+        }
+    }
+    times
+}
+
+/// Title held by a text sample, a length prefixed UTF-8 or UTF-16 string.
+fn sample_text(sample: &[u8]) -> Option<String> {
+    let len = be_u16(sample, 0)? as usize;
+    let text = sample.get(2..2 + len)?;
+    let title = match text {
+        [0xFE, 0xFF, utf16 @ ..] => String::from_utf16_lossy(
+            &utf16
+                .chunks_exact(2)
+                .map(|unit| u16::from_be_bytes([unit[0], unit[1]]))
+                .collect::<Vec<u16>>(),
+        ),
+        _ => String::from_utf8_lossy(text).into_owned(),
+    };
+    Some(title.trim().to_owned()).filter(|title| !title.is_empty())
+}
+
+/// Reads the chapter list written by Nero and many muxers in the movie user data.
+fn nero_chapters(list: &[u8]) -> Vec<MediaChapter> {
+    let mut pos = match list.first() {
+        Some(1) => 9,
+        _ => 5,
+    };
+    let count = list.get(pos - 1).copied().unwrap_or(0);
+
+    let mut chapters = vec![];
+    for index in 0..count {
+        let (Some(start), Some(len)) = (be_u64(list, pos), list.get(pos + 8)) else {
+            break;
+        };
+        let title_start = pos + 9;
+        let Some(title) = list.get(title_start..title_start + *len as usize) else {
+            break;
+        };
+        chapters.push(MediaChapter {
+            index: index as i32,
+            title: Some(String::from_utf8_lossy(title).trim().to_owned())
+                .filter(|title| !title.is_empty()),
+            start: start as f64 / NERO_TIMESCALE,
+            end: None,
+        });
+        pos = title_start + *len as usize;
+    }
+    chapters
+}
+
+/// Language of a track from its media header, packed as three 5 bits letters.
+fn language(media_header: &[u8]) -> Option<String> {
+    let offset = match media_header.first()? {
+        1 => 32,
+        _ => 20,
+    };
+    let packed = be_u16(media_header, offset)?;
+    // Smaller values are Macintosh language codes of old QuickTime files
+    if packed < 0x400 {
+        return None;
+    }
+    let language: String = [10, 5, 0]
+        .iter()
+        .map(|shift| (((packed >> shift) & 0x1F) as u8 + 0x60) as char)
+        .collect();
+    Some(language).filter(|language| language != "und")
+}
+
+/// Name of a track from its user data, or from its handler unless that is a muxer default.
+fn title(track: &[u8], handler: &[u8]) -> Option<String> {
+    let name = find_box(track, &[b"udta", b"name"])
+        .or_else(|| handler.get(24..))
+        .map(|name| {
+            String::from_utf8_lossy(name)
+                .trim_matches(|c: char| c.is_control())
+                .to_owned()
+        })?;
+    Some(name).filter(|name| !name.is_empty() && !name.ends_with("Handler"))
+}
+
+fn codec_name(entry_type: &[u8; 4]) -> String {
+    CODECS
+        .iter()
+        .find(|(known, _)| *known == entry_type)
+        .map_or_else(
+            || String::from_utf8_lossy(entry_type).trim().to_lowercase(),
+            |(_, name)| (*name).to_owned(),
+        )
+}
+
+// region: ---- BOXES ----
+
+/// Parses a box header, returning its type, its length and the size of the box,
+/// None for a box running to the end of the file.
+fn box_header(data: &[u8]) -> Option<([u8; 4], usize, Option<u64>)> {
+    let kind: [u8; 4] = data.get(4..8)?.try_into().ok()?;
+    match be_u32(data, 0)? {
+        0 => Some((kind, 8, None)),
+        1 => Some((kind, 16, Some(be_u64(data, 8)?))),
+        size => Some((kind, 8, Some(size as u64))),
+    }
+}
+
+/// Splits the body of a box into its children, up to the first truncated one.
+fn boxes(data: &[u8]) -> Vec<([u8; 4], &[u8])> {
+    let mut found = vec![];
+    let mut pos = 0;
+    while let Some((kind, header_len, size)) = box_header(&data[pos..]) {
+        let end = match size {
+            Some(size) => match usize::try_from(size).ok().and_then(|s| pos.checked_add(s)) {
+                Some(end) if end <= data.len() && size as usize >= header_len => end,
+                _ => break,
+            },
+            None => data.len(),
+        };
+        found.push((kind, &data[pos + header_len..end]));
+        pos = end;
+    }
+    found
+}
+
+/// Finds the first box at the end of a path of nested box types.
+fn find_box<'a>(data: &'a [u8], path: &[&[u8; 4]]) -> Option<&'a [u8]> {
+    path.iter().try_fold(data, |parent, kind| {
+        boxes(parent)
+            .into_iter()
+            .find(|(found, _)| found == *kind)
+            .map(|(_, body)| body)
+    })
+}
+
+fn be_u16(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(
+        data.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn be_u32(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+fn be_u64(data: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_be_bytes(
+        data.get(offset..offset + 8)?.try_into().ok()?,
+    ))
+}
+// endregion
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mp4_box(kind: &[u8; 4], body: &[u8]) -> Vec<u8> {
+        let mut data = ((body.len() + 8) as u32).to_be_bytes().to_vec();
+        data.extend_from_slice(kind);
+        data.extend_from_slice(body);
+        data
+    }
+
+    /// A full box body, its version and flags followed by big endian 32 bits fields.
+    fn full_box(version: u8, fields: &[u32]) -> Vec<u8> {
+        let mut data = vec![version, 0, 0, 0];
+        for field in fields {
+            data.extend_from_slice(&field.to_be_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn is_mp4_checks_the_leading_box_type() {
+        assert!(is_mp4(b"\0\0\0\x20ftypisom"));
+        assert!(is_mp4(b"\0\0\0\x08wide"));
+        assert!(!is_mp4(b"RIFF\0\0\0\0WAVE"));
+        assert!(!is_mp4(&b"\0\0\0\x20ftyp"[..6]));
+    }
+
+    #[test]
+    fn box_header_reads_compact_large_and_open_sizes() {
+        assert_eq!(box_header(b"\0\0\0\x10moov"), Some((*b"moov", 8, Some(16))));
+        assert_eq!(box_header(b"\0\0\0\0mdat"), Some((*b"mdat", 8, None)));
+
+        let mut large = b"\0\0\0\x01mdat".to_vec();
+        large.extend_from_slice(&0x1_0000_0000u64.to_be_bytes());
+        assert_eq!(
+            box_header(&large),
+            Some((*b"mdat", 16, Some(0x1_0000_0000)))
+        );
+
+        assert_eq!(box_header(b"\0\0\0\x01mdat\0\0"), None);
+        assert_eq!(box_header(b"\0\0\0\x10mo"), None);
+    }
+
+    #[test]
+    fn boxes_stop_at_the_first_truncated_box() {
+        let mut data = [mp4_box(b"free", &[1, 2]), mp4_box(b"skip", &[])].concat();
+        data.extend_from_slice(b"\0\0\0\x20moov\0\0");
+
+        let found = boxes(&data);
+        assert_eq!(found, vec![(*b"free", &[1u8, 2][..]), (*b"skip", &[][..])]);
+    }
+
+    #[test]
+    fn boxes_stop_at_an_oversized_or_undersized_box() {
+        let mut oversized = mp4_box(b"free", &[]);
+        oversized.extend_from_slice(b"\0\0\0\x01mdat");
+        oversized.extend_from_slice(&u64::MAX.to_be_bytes());
+        assert_eq!(boxes(&oversized).len(), 1);
+
+        let mut undersized = b"\0\0\0\x04free".to_vec();
+        undersized.extend(mp4_box(b"skip", &[]));
+        assert!(boxes(&undersized).is_empty());
+    }
+
+    #[test]
+    fn boxes_run_an_open_box_to_the_end() {
+        let data = b"\0\0\0\0mdat\x01\x02\x03";
+        assert_eq!(boxes(data), vec![(*b"mdat", &[1u8, 2, 3][..])]);
+    }
+
+    #[test]
+    fn find_box_follows_a_path_of_nested_boxes() {
+        let movie = [
+            mp4_box(b"mvhd", &[]),
+            mp4_box(b"udta", &mp4_box(b"chpl", &[7])),
+        ]
+        .concat();
+        assert_eq!(find_box(&movie, &[b"udta", b"chpl"]), Some(&[7u8][..]));
+        assert_eq!(find_box(&movie, &[b"mvhd", b"chpl"]), None);
+        assert_eq!(find_box(&movie, &[b"trak"]), None);
+    }
+
+    #[test]
+    fn duration_reads_the_movie_header() {
+        let movie = mp4_box(b"mvhd", &full_box(0, &[0, 0, 600, 600 * 90]));
+        assert_eq!(duration(&movie), Some(90.0));
+
+        let mut header = full_box(1, &[0, 0, 0, 0, 1000]);
+        header.extend_from_slice(&7_200_000u64.to_be_bytes());
+        assert_eq!(duration(&mp4_box(b"mvhd", &header)), Some(7200.0));
+
+        let no_timescale = mp4_box(b"mvhd", &full_box(0, &[0, 0, 0, 600]));
+        assert_eq!(duration(&no_timescale), None);
+    }
+
+    #[test]
+    fn duration_of_a_fragmented_movie_comes_from_its_extends_header() {
+        let movie = [
+            mp4_box(b"mvhd", &full_box(0, &[0, 0, 1000, 0])),
+            mp4_box(b"mvex", &mp4_box(b"mehd", &full_box(0, &[45_000]))),
+        ]
+        .concat();
+        assert_eq!(duration(&movie), Some(45.0));
+
+        let movie = mp4_box(b"mvhd", &full_box(0, &[0, 0, 1000, 0]));
+        assert_eq!(duration(&movie), None);
+    }
+
+    #[test]
+    fn nero_chapters_stop_at_a_truncated_title() {
+        let mut list = vec![0, 0, 0, 0, 3];
+        list.extend_from_slice(&0u64.to_be_bytes());
+        list.push(7);
+        list.extend_from_slice(b"Opening");
+        list.extend_from_slice(&905_000_000u64.to_be_bytes());
+        list.push(1);
+        list.push(b' ');
+        list.extend_from_slice(&1_200_000_000u64.to_be_bytes());
+        list.push(20);
+        list.extend_from_slice(b"Cut");
+
+        let chapters = nero_chapters(&list);
+        assert_eq!(chapters.len(), 2);
+        assert_eq!(chapters[0].title.as_deref(), Some("Opening"));
+        assert_eq!(chapters[1].index, 1);
+        assert_eq!(chapters[1].title, None);
+        assert_eq!(chapters[1].start, 90.5);
+    }
+
+    #[test]
+    fn sample_table_locates_chapter_samples() {
+        let table = [
+            mp4_box(b"stsz", &full_box(0, &[0, 3, 10, 12, 14])),
+            mp4_box(b"stco", &full_box(0, &[2, 1000, 5000])),
+            mp4_box(b"stsc", &full_box(0, &[2, 1, 2, 1, 2, 1, 1])),
+            mp4_box(b"stts", &full_box(0, &[2, 2, 600, 1, 300])),
+        ]
+        .concat();
+
+        assert_eq!(
+            sample_locations(&table),
+            vec![(1000, 10), (1010, 12), (5000, 14)]
+        );
+        assert_eq!(
+            sample_times(&table),
+            vec![(0, 600), (600, 600), (1200, 300)]
+        );
+    }
+
+    #[test]
+    fn sample_table_caps_corrupted_counts() {
+        let table = [
+            mp4_box(b"stsz", &full_box(0, &[4, u32::MAX])),
+            mp4_box(b"stco", &full_box(0, &[1, 0])),
+            mp4_box(b"stsc", &full_box(0, &[1, 1, u32::MAX, 1])),
+            mp4_box(b"stts", &full_box(0, &[1, u32::MAX, 1])),
+        ]
+        .concat();
+
+        assert_eq!(sample_locations(&table).len(), MAX_CHAPTERS);
+        assert_eq!(sample_times(&table).len(), MAX_CHAPTERS);
+
+        let truncated = mp4_box(b"stts", &full_box(0, &[3, 1, 600]));
+        assert_eq!(sample_times(&truncated), vec![(0, 600)]);
+    }
+
+    #[test]
+    fn sample_text_reads_utf8_and_utf16_titles() {
+        assert_eq!(sample_text(b"\0\x05Intro").as_deref(), Some("Intro"));
+        assert_eq!(sample_text(b"\0\x06\xFE\xFF\0H\0i").as_deref(), Some("Hi"));
+        assert_eq!(sample_text(b"\0\x01 "), None);
+        assert_eq!(sample_text(b"\0\x09Intro"), None);
+    }
+
+    #[test]
+    fn language_unpacks_the_media_header_code() {
+        let mut header = full_box(0, &[0, 0, 600, 0]);
+        header.extend_from_slice(&0x15C7u16.to_be_bytes());
+        assert_eq!(language(&header).as_deref(), Some("eng"));
+
+        let mut header = full_box(0, &[0, 0, 600, 0]);
+        header.extend_from_slice(&0x0000u16.to_be_bytes());
+        assert_eq!(language(&header), None);
+        assert_eq!(language(&full_box(0, &[0])), None);
+    }
+}
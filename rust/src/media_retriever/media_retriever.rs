@@ -1,24 +1,119 @@
-use std::sync::Arc;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+};
 
 /// Module that orchestrates the media retrieval pipeline.
 use crate::{
+    db_interface::data_getter::{ArtworkSources, DataGetter, LockedFields},
     db_interface::data_saver::DataSaver,
-    directory_explorer::smb_explorer::SmbExplorer,
-    movie_data::movie_data::{CreditsMovie, MovieData, PersonData},
+    db_interface::data_writer::{DataWriter, MovieRecord, PendingWrite},
+    directory_explorer::{media_file::FileSource, smb_explorer::SmbExplorer},
+    event_bus::event_bus::emit,
+    media_probe::media_probe::probe_library,
+    movie_data::movie_data::{
+        CollectionData, CreditFilter, CreditsMovie, FilmographyEntry, Genre, LibraryEvent,
+        MovieData, PersonData, ScanReport,
+    },
+    recommender::content_index::refresh_content_index,
+    search_index::fuzzy_index::refresh_search_index,
+    subtitles::sidecar::discover_subtitles,
     tmdb_client::tmdb_client::TMDBClient,
 };
-use anyhow::{Context, Error, Result};
+use anyhow::{anyhow, Context, Error, Result};
 use futures::stream::{self, StreamExt};
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, OnceCell};
 use tracing::{debug_span, instrument};
 use trpl::Stream;
 
+/// Persons refreshed more recently than this are not fetched again during a scan.
+const PERSON_MAX_AGE_DAYS: u32 = 30;
+
+/// Movies waiting for the database writer before the scanner is held back.
+const WRITER_QUEUE_SIZE: usize = 32;
+
+/// Maximum number of movies committed in one transaction.
+const WRITER_BATCH_SIZE: usize = 16;
+
+/// State shared by every movie handled during a scan.
+struct ScanState {
+    credit_filter: CreditFilter,
+    genres: HashMap<i64, Genre>,
+    artwork_sources: ArtworkSources,
+    /// Fields edited by the user, left untouched by the scan.
+    locked_fields: LockedFields,
+    /// Persons stored and fresh, fetched during this scan or being fetched for another movie.
+    /// A failed fetch drops its claim, so a later movie of the scan tries again.
+    known_persons: Mutex<HashSet<i64>>,
+    /// Collections fetched during this scan with their artwork, shared by their owned parts.
+    collections: Mutex<HashMap<i64, Arc<OnceCell<CollectionData>>>>,
+    movies_found: AtomicU32,
+    movies_matched: AtomicU32,
+    movies_saved: AtomicU32,
+    movies_failed: AtomicU32,
+    persons_credited: AtomicU32,
+    persons_fetched: AtomicU32,
+    persons_skipped: AtomicU32,
+}
+
+impl ScanState {
+    fn new(
+        credit_filter: CreditFilter,
+        genres: Vec<Genre>,
+        artwork_sources: ArtworkSources,
+        locked_fields: LockedFields,
+        known_persons: HashSet<i64>,
+    ) -> Self {
+        Self {
+            credit_filter,
+            genres: genres.into_iter().map(|g| (g.id(), g)).collect(),
+            artwork_sources,
+            locked_fields,
+            known_persons: Mutex::new(known_persons),
+            collections: Mutex::new(HashMap::new()),
+            movies_found: AtomicU32::new(0),
+            movies_matched: AtomicU32::new(0),
+            movies_saved: AtomicU32::new(0),
+            movies_failed: AtomicU32::new(0),
+            persons_credited: AtomicU32::new(0),
+            persons_fetched: AtomicU32::new(0),
+            persons_skipped: AtomicU32::new(0),
+        }
+    }
+
+    /// Builds the scan report, comparing the requests sent with the one call per
+    /// search, details, credits and credited person the previous fetch path needed.
+    fn report(&self, tmdb_requests: u64) -> ScanReport {
+        let legacy_requests = 3 * self.movies_matched.load(Ordering::Relaxed) as u64
+            + self.persons_credited.load(Ordering::Relaxed) as u64;
+
+        ScanReport {
+            movies_found: self.movies_found.load(Ordering::Relaxed),
+            movies_matched: self.movies_matched.load(Ordering::Relaxed),
+            movies_saved: self.movies_saved.load(Ordering::Relaxed),
+            movies_failed: self.movies_failed.load(Ordering::Relaxed),
+            persons_fetched: self.persons_fetched.load(Ordering::Relaxed),
+            persons_skipped: self.persons_skipped.load(Ordering::Relaxed),
+            tmdb_requests,
+            tmdb_requests_saved: legacy_requests.saturating_sub(tmdb_requests),
+        }
+    }
+}
+
 /// Runs the primary streaming pipeline for media retrieval.
 ///
 /// Discovers media paths, fetches movie metadata, credits, and posters from TMDB,
 /// then persists the collected data and associated poster assets in order.
 #[instrument(skip_all)]
-pub async fn retrieve_media(path: &str, username: &str, password: &str, token: &str) -> Result<()> {
+pub async fn retrieve_media(
+    path: &str,
+    username: &str,
+    password: &str,
+    token: &str,
+) -> Result<ScanReport> {
     let smb_explorer: SmbExplorer =
         SmbExplorer::new(path.to_owned(), username.to_owned(), password.to_owned())
             .await
@@ -30,129 +125,369 @@ pub async fn retrieve_media(path: &str, username: &str, password: &str, token: &
 
     tracing::info!("Movie retrieval stream started");
 
-    let data_saver = Arc::new(Mutex::new(
-        initiate_db().context("Failed to initiate database")?,
-    ));
-
-    handle_found_movies(movies, &client, data_saver).await;
+    let report = run_movie_pipeline(movies, &client).await?;
 
     tracing::info!("Movie retrieval stream ended");
-    Ok(())
+
+    // Failing to read a file header or a folder leaves the scanned metadata valid
+    let files = FileSource::Smb(smb_explorer);
+    probe_library(&files, false)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to probe media files \n Caused by {:?}", e);
+        })
+        .ok();
+
+    discover_subtitles(&files)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to discover subtitle files \n Caused by {:?}", e);
+        })
+        .ok();
+
+    Ok(report)
 }
 
-/// Initializes the database by creating the database file and required tables.
-fn initiate_db() -> Result<DataSaver> {
+/// Selects the stored items a metadata refresh applies to.
+#[derive(Debug, Clone, Copy)]
+pub enum RefreshTarget {
+    /// A single movie, by media id.
+    Movie(i64),
+    /// A single person, by TMDB id.
+    Person(i64),
+    /// Every movie of the library.
+    Library,
+    /// The movies not refreshed within the given number of days.
+    OlderThan(u32),
+}
+
+/// Fetches the metadata of already stored items again from TMDB.
+///
+/// Changed fields are updated, genre, facet and credit links are replaced and artwork
+/// whose TMDB source changed is downloaded again.
+#[instrument(skip(token))]
+pub async fn refresh_metadata(target: RefreshTarget, token: &str) -> Result<ScanReport> {
+    let client = TMDBClient::new(token).context("Failed to create TMDB client")?;
+
+    let (media_id, max_age_days) = match target {
+        RefreshTarget::Person(person_tmdb_id) => {
+            return refresh_person(person_tmdb_id, &client).await;
+        }
+        RefreshTarget::Movie(media_id) => (Some(media_id), None),
+        RefreshTarget::Library => (None, None),
+        RefreshTarget::OlderThan(max_age_days) => (None, Some(max_age_days)),
+    };
+
+    let data_getter = DataGetter::new("movie_db.db".to_string())?;
+    let movies = data_getter
+        .get_movies_to_refresh(media_id, max_age_days)
+        .context("Failed to load movies to refresh")?;
+
+    if let (Some(media_id), true) = (media_id, movies.is_empty()) {
+        return Err(anyhow!("No stored movie found for media id: {}", media_id));
+    }
+
+    tracing::info!(movies = movies.len(), "Metadata refresh started");
+    let movies = stream::iter(movies.into_iter().map(Ok));
+    run_movie_pipeline(movies, &client).await
+}
+
+/// Fetches the details and picture of a stored person again.
+async fn refresh_person(person_tmdb_id: i64, client: &TMDBClient) -> Result<ScanReport> {
+    let mut person = client
+        .fetch_person_details(person_tmdb_id)
+        .await
+        .with_context(|| format!("Failed to get person details for id: {}", person_tmdb_id))?;
+
+    match client.update_person_images(&mut person, true).await {
+        Ok(path) => {
+            person.set_picture_path(path);
+        }
+        Err(e) => {
+            tracing::error!(
+                "Failed to update person picture for {} \n Caused by {:?}",
+                person.name(),
+                e
+            )
+        }
+    }
+
     let mut data_saver = DataSaver::new("movie_db.db".to_string())
         .context("Failed to create database connection")?;
-    data_saver.create_movie_table()?;
-    data_saver.create_person_table()?;
-    data_saver.create_genre_table()?;
-    data_saver.create_movie_genre_table()?;
-    data_saver.create_credits_table()?;
-
-    tracing::info!("Data base initiated");
-    Ok(data_saver)
+    data_saver
+        .push_persons(&[person])
+        .with_context(|| format!("Failed to save person id: {}", person_tmdb_id))?;
+
+    refresh_search_index("movie_db.db")
+        .map_err(|e| {
+            tracing::error!("Failed to refresh quick search index \n Caused by {:?}", e);
+        })
+        .ok();
+
+    Ok(ScanReport {
+        persons_fetched: 1,
+        tmdb_requests: client.request_count(),
+        ..Default::default()
+    })
+}
+
+/// Fetches, completes and saves every movie of the stream, then reports the outcome.
+async fn run_movie_pipeline(
+    movies: impl Stream<Item = Result<MovieData, Error>>,
+    client: &TMDBClient,
+) -> Result<ScanReport> {
+    let mut data_saver = DataSaver::new("movie_db.db".to_string())
+        .context("Failed to create database connection")?;
+
+    let scan = init_scan_state(client, &mut data_saver)
+        .await
+        .context("Failed to prepare scan")?;
+
+    let data_writer = DataWriter::spawn(data_saver, WRITER_QUEUE_SIZE, WRITER_BATCH_SIZE);
+    emit(LibraryEvent::ScanStarted);
+
+    let pending_writes = handle_found_movies(movies, client, &data_writer, &scan).await;
+
+    data_writer
+        .close()
+        .await
+        .context("Failed to close database writer")?;
+    for (file_path, pending_write) in pending_writes {
+        match pending_write.result().await {
+            Ok(()) => {
+                scan.movies_saved.fetch_add(1, Ordering::Relaxed);
+            }
+            Err(e) => {
+                scan.movies_failed.fetch_add(1, Ordering::Relaxed);
+                tracing::error!(
+                    "Failed to save movie data for {} \n Caused by {:?}",
+                    file_path,
+                    e
+                );
+            }
+        }
+    }
+
+    refresh_search_index("movie_db.db")
+        .map_err(|e| {
+            tracing::error!("Failed to refresh quick search index \n Caused by {:?}", e);
+        })
+        .ok();
+
+    refresh_content_index("movie_db.db")
+        .map_err(|e| {
+            tracing::error!(
+                "Failed to refresh recommendation index \n Caused by {:?}",
+                e
+            );
+        })
+        .ok();
+
+    let report = scan.report(client.request_count());
+    tracing::info!("Scan report \n{}", report);
+    emit(LibraryEvent::ScanFinished {
+        report: report.clone(),
+    });
+    Ok(report)
+}
+
+/// Loads the credit filter, the known persons, the stored artwork sources, the locked fields
+/// and the TMDB genre list shared by the whole scan.
+async fn init_scan_state(client: &TMDBClient, data_saver: &mut DataSaver) -> Result<ScanState> {
+    let data_getter = DataGetter::new("movie_db.db".to_string())?;
+    let credit_filter = data_getter
+        .get_credit_filter()
+        .context("Failed to load credit filter")?;
+    let known_persons = data_getter
+        .get_fresh_person_ids(PERSON_MAX_AGE_DAYS)
+        .context("Failed to load stored persons")?;
+    let artwork_sources = data_getter
+        .get_artwork_sources()
+        .context("Failed to load stored artwork sources")?;
+    let locked_fields = data_getter
+        .get_locked_fields()
+        .context("Failed to load locked fields")?;
+
+    let genres = match client.fetch_genre_list().await {
+        Ok(genres) => {
+            data_saver
+                .push_genres(&genres)
+                .map_err(|e| {
+                    tracing::error!("Failed to push genre list \n Caused by {:?}", e);
+                })
+                .ok();
+            genres
+        }
+        Err(e) => {
+            tracing::error!("Failed to get genre list \n Caused by {:?}", e);
+            Vec::new()
+        }
+    };
+
+    Ok(ScanState::new(
+        credit_filter,
+        genres,
+        artwork_sources,
+        locked_fields,
+        known_persons,
+    ))
+}
+
+/// Lists the movies TMDB credits a person with that are not part of the library.
+#[instrument(skip(owned_tmdb_ids, token))]
+pub async fn retrieve_missing_filmography(
+    person_tmdb_id: i64,
+    owned_tmdb_ids: &HashSet<i64>,
+    token: &str,
+) -> Result<Vec<FilmographyEntry>> {
+    let client = TMDBClient::new(token).context("Failed to create TMDB client")?;
+    let credits = client
+        .fetch_person_movie_credits(person_tmdb_id)
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to get movie credits for person id: {}",
+                person_tmdb_id
+            )
+        })?;
+
+    let mut seen = HashSet::new();
+    let missing = credits
+        .credits()
+        .filter(|credit| !owned_tmdb_ids.contains(&credit.id()) && seen.insert(credit.id()))
+        .map(|credit| FilmographyEntry {
+            media_id: None,
+            tmdb_id: credit.id(),
+            title: credit.title().to_owned(),
+            release_date: credit.release_date().to_owned(),
+            poster: credit.poster_path().to_owned(),
+            department: credit.department().to_owned(),
+            character: credit.character().to_owned(),
+            job_name: if credit.job().is_empty() {
+                "actor".to_owned()
+            } else {
+                credit.job().to_owned()
+            },
+        })
+        .collect();
+
+    Ok(missing)
 }
 
 // region: ---- UPDATE MOVIE DATA ----
 
 /// Wrapper for the concurent movie handling pipeline
+///
+/// Returns the queued movies by file path, their write results being read once all are queued.
 async fn handle_found_movies(
     movies: impl Stream<Item = Result<MovieData, Error>>,
     client: &TMDBClient,
-    data_saver: Arc<Mutex<DataSaver>>,
-) {
+    data_writer: &DataWriter,
+    scan: &ScanState,
+) -> Vec<(String, PendingWrite)> {
+    let pending_writes = Mutex::new(Vec::new());
+    let pending_writes_ref = &pending_writes;
     movies
-        .for_each_concurrent(10, |movie| {
-            let data_saver = Arc::clone(&data_saver);
-            let client = client;
-            async move {
-                match movie {
-                    Ok(mut movie) => {
-                        let credits = fetch_movie_data(&mut movie, &client).await;
-                        update_movie_posters(&mut movie, &client).await;
-
-                        let mut persons = get_persons_details(&credits, &client).await;
-
-                        update_persons_posters(&mut persons, &client).await;
-
-                        let mut ds = data_saver.lock().await;
-
-                        ds.push_persons(persons)
-                            .map_err(|e| {
-                                tracing::error!(
-                                    "Failed to push persons data for {} \n Caused by {:?}",
-                                    movie.file_path(),
-                                    e
-                                );
-                            })
-                            .ok();
-
-                        ds.push_movie_data(&movie, &credits)
-                            .map_err(|e| {
-                                tracing::error!(
-                                    "Failed to push movie data for {} \n Caused by {:?}",
-                                    movie.file_path(),
-                                    e
-                                );
-                            })
-                            .ok();
+        .for_each_concurrent(10, |movie| async move {
+            match movie {
+                Ok(mut movie) => {
+                    scan.movies_found.fetch_add(1, Ordering::Relaxed);
+
+                    let credits = fetch_movie_data(&mut movie, client, scan).await;
+                    if let Some((poster_source, backdrop_source)) =
+                        scan.artwork_sources.get(movie.file_path())
+                    {
+                        movie
+                            .set_poster_source(poster_source.clone())
+                            .set_backdrop_source(backdrop_source.clone());
                     }
-                    Err(e) => {
-                        tracing::error!(" Error finding movie, \n Caused by {:?}", e)
+                    let locked = scan.locked_fields.get(movie.file_path());
+                    update_movie_posters(&mut movie, client, locked).await;
+                    let collection = fetch_movie_collection(&movie, client, scan).await;
+
+                    let mut persons = get_persons_details(&credits, client, scan).await;
+
+                    update_persons_posters(&mut persons, client).await;
+
+                    let file_path = movie.file_path().to_owned();
+                    let record = MovieRecord {
+                        movie,
+                        credits,
+                        collection,
+                        persons,
+                    };
+
+                    match data_writer.write(record).await {
+                        Ok(pending_write) => {
+                            pending_writes_ref
+                                .lock()
+                                .await
+                                .push((file_path, pending_write));
+                        }
+                        Err(e) => {
+                            scan.movies_failed.fetch_add(1, Ordering::Relaxed);
+                            tracing::error!(
+                                "Failed to save movie data for {} \n Caused by {:?}",
+                                file_path,
+                                e
+                            );
+                        }
                     }
                 }
+                Err(e) => {
+                    scan.movies_failed.fetch_add(1, Ordering::Relaxed);
+                    tracing::error!(" Error finding movie, \n Caused by {:?}", e)
+                }
             }
         })
         .await;
+
+    pending_writes.into_inner()
 }
 
 /// Fetches movie metadata, including basic information, genres, and credits.
-async fn fetch_movie_data(movie: &mut MovieData, client: &TMDBClient) -> CreditsMovie {
+///
+/// Movies already matched to TMDB skip the search and are refreshed from their details.
+/// Credits are filtered with the user configured rules before being returned.
+async fn fetch_movie_data(
+    movie: &mut MovieData,
+    client: &TMDBClient,
+    scan: &ScanState,
+) -> CreditsMovie {
     let span = debug_span!("fetch_movie_data", movie_path = movie.file_path());
     let _enter = span.enter();
 
-    update_movie_basics(movie, client)
-        .await
-        .map_err(|e| {
-            tracing::error!(
-                "Failed to update movie basics for {} \n Caused by {:?}",
-                movie.file_path(),
-                e
-            );
-        })
-        .ok();
-
-    update_movie_genres(movie, client)
-        .await
-        .map_err(|e| {
-            tracing::error!(
-                "Failed to update movie genre for {} \n Caused by {:?}",
-                movie.file_path(),
-                e
-            );
-        })
-        .ok();
+    if movie.tmdb_id() == 0 {
+        update_movie_basics(movie, client, &scan.genres)
+            .await
+            .map_err(|e| {
+                tracing::error!(
+                    "Failed to update movie basics for {} \n Caused by {:?}",
+                    movie.file_path(),
+                    e
+                );
+            })
+            .ok();
+    }
 
-    tracing::debug!(
-        file_path = movie.file_path(),
-        success = true,
-        "Movie data received"
-    );
+    if movie.tmdb_id() == 0 {
+        return CreditsMovie::new();
+    }
+    scan.movies_matched.fetch_add(1, Ordering::Relaxed);
 
-    match get_movie_credits(movie, client).await {
+    match update_movie_details(movie, client).await {
         Ok(mut credits) => {
-            filter_credits(&mut credits);
+            scan.credit_filter.apply(&mut credits);
             tracing::debug!(
                 file_path = movie.file_path(),
                 success = true,
-                "Movie credits received"
+                "Movie data and credits received"
             );
             credits
         }
         Err(e) => {
             tracing::error!(
-                "Failed to update movie credits for {} \n Caused by: {:?}",
+                "Failed to update movie details for {} \n Caused by: {:?}",
                 movie.file_path(),
                 e
             );
@@ -161,8 +496,12 @@ async fn fetch_movie_data(movie: &mut MovieData, client: &TMDBClient) -> Credits
     }
 }
 
-/// Retrieves and updates the basic metadata for a movie.
-async fn update_movie_basics(movie: &mut MovieData, client: &TMDBClient) -> Result<()> {
+/// Retrieves and updates the basic metadata for a movie, resolving its genres from the shared genre list.
+async fn update_movie_basics(
+    movie: &mut MovieData,
+    client: &TMDBClient,
+    genres: &HashMap<i64, Genre>,
+) -> Result<()> {
     let movie_basics = client
         .get_movie_info(movie.file_title(), movie.file_year().parse::<u32>().ok())
         .await
@@ -173,10 +512,17 @@ async fn update_movie_basics(movie: &mut MovieData, client: &TMDBClient) -> Resu
             )
         })?;
 
+    let movie_genres = movie_basics
+        .genre_ids()
+        .iter()
+        .filter_map(|id| genres.get(id).cloned())
+        .collect();
+
     movie
         .set_tmdb_id(movie_basics.id())
         .set_original_title(movie_basics.original_title())
         .set_title(movie_basics.title())
+        .set_genres(movie_genres)
         .set_vote_average(movie_basics.vote_average())
         .set_release_date(movie_basics.release_date())
         .set_summary(movie_basics.overview())
@@ -186,43 +532,126 @@ async fn update_movie_basics(movie: &mut MovieData, client: &TMDBClient) -> Resu
     Ok(())
 }
 
-/// Retrieves and updates the genres for a movie.
-async fn update_movie_genres(movie: &mut MovieData, client: &TMDBClient) -> Result<()> {
-    movie.set_genres(
-        client
-            .fetch_movie_genres(movie.tmdb_id())
-            .await
-            .with_context(|| {
-                format!(
-                    "Failed to get movie genres info for file: {}",
-                    movie.file_path()
-                )
-            })?
-            .genres(),
-    );
-    Ok(())
-}
-
-/// Retrieves and updates the credits for a movie.
-async fn get_movie_credits(movie: &mut MovieData, client: &TMDBClient) -> Result<CreditsMovie> {
-    let movie_credits = client
-        .fetch_movie_credits(movie.tmdb_id())
+/// Retrieves and updates the extended details for a movie, returning its credits from the same response.
+async fn update_movie_details(movie: &mut MovieData, client: &TMDBClient) -> Result<CreditsMovie> {
+    let movie_details = client
+        .fetch_movie_details(movie.tmdb_id())
         .await
         .with_context(|| {
             format!(
-                "Failed to get movie credits info for file: {}",
+                "Failed to get movie details info for file: {}",
                 movie.file_path()
             )
         })?;
-    Ok(movie_credits)
+
+    if movie.poster().is_none() {
+        movie.set_poster(movie_details.poster_path());
+    }
+    if movie.backdrop().is_none() {
+        movie.set_backdrop(movie_details.backdrop_path());
+    }
+    if !movie_details.genres().is_empty() {
+        movie.set_genres(movie_details.genres());
+    }
+
+    movie
+        .set_original_title(movie_details.original_title())
+        .set_title(movie_details.title())
+        .set_vote_average(movie_details.vote_average())
+        .set_release_date(movie_details.release_date())
+        .set_summary(movie_details.overview())
+        .set_runtime(movie_details.runtime())
+        .set_tagline(movie_details.tagline())
+        .set_status(movie_details.status())
+        .set_budget(movie_details.budget())
+        .set_revenue(movie_details.revenue())
+        .set_imdb_id(movie_details.imdb_id())
+        .set_spoken_languages(movie_details.spoken_languages())
+        .set_production_companies(movie_details.production_companies())
+        .set_production_countries(movie_details.production_countries())
+        .set_keywords(movie_details.keywords())
+        .set_collection(movie_details.collection())
+        .set_certification(&movie_details.certification("US"));
+    Ok(movie_details.credits())
 }
 
-async fn get_persons_details(credits: &CreditsMovie, client: &TMDBClient) -> Vec<PersonData> {
+/// Fetches the collection a movie belongs to, with its parts and artwork, if it has one.
+///
+/// Each collection is fetched once per scan, its other owned parts reusing it.
+async fn fetch_movie_collection(
+    movie: &MovieData,
+    client: &TMDBClient,
+    scan: &ScanState,
+) -> Option<CollectionData> {
+    let collection_info = movie.collection()?;
+    let cell = scan
+        .collections
+        .lock()
+        .await
+        .entry(collection_info.id())
+        .or_default()
+        .clone();
+
+    // A failed fetch leaves the cell empty, so the next part of the collection tries again
+    let collection = cell
+        .get_or_try_init(|| async {
+            let mut collection = client
+                .fetch_collection_details(collection_info.id())
+                .await
+                .map_err(|e| {
+                    tracing::error!(
+                        "Failed to get collection {} for {} \n Caused by: {:?}",
+                        collection_info.name(),
+                        movie.file_path(),
+                        e
+                    );
+                })?;
+            update_collection_posters(&mut collection, client).await;
+            Ok::<_, ()>(collection)
+        })
+        .await
+        .ok()?;
+    Some(collection.clone())
+}
+
+/// Fetches the details of credited persons, skipping those stored and fresh or already fetched during this scan.
+/// Persons whose fetch fails are left unknown, to be fetched again for the next movie crediting them.
+async fn get_persons_details(
+    credits: &CreditsMovie,
+    client: &TMDBClient,
+    scan: &ScanState,
+) -> Vec<PersonData> {
     let mut tmdb_ids: Vec<i64> = credits.credits_cast().iter().map(|c| c.tmdb_id()).collect();
     let crew_ids: Vec<i64> = credits.credits_crew().iter().map(|c| c.tmdb_id()).collect();
     tmdb_ids.extend(crew_ids);
-
-    return collect_person_details(tmdb_ids, client).await;
+    tmdb_ids.sort_unstable();
+    tmdb_ids.dedup();
+
+    let credited = tmdb_ids.len() as u32;
+    let to_fetch: Vec<i64> = {
+        let mut known_persons = scan.known_persons.lock().await;
+        tmdb_ids
+            .into_iter()
+            .filter(|id| known_persons.insert(*id))
+            .collect()
+    };
+
+    scan.persons_credited.fetch_add(credited, Ordering::Relaxed);
+    scan.persons_skipped
+        .fetch_add(credited - to_fetch.len() as u32, Ordering::Relaxed);
+
+    let persons = collect_person_details(to_fetch.iter().copied(), client).await;
+    scan.persons_fetched
+        .fetch_add(persons.len() as u32, Ordering::Relaxed);
+
+    if persons.len() < to_fetch.len() {
+        let fetched: HashSet<i64> = persons.iter().map(|p| p.tmdb_id()).collect();
+        let mut known_persons = scan.known_persons.lock().await;
+        for id in to_fetch.iter().filter(|id| !fetched.contains(id)) {
+            known_persons.remove(id);
+        }
+    }
+    persons
 }
 
 async fn collect_person_details<I>(ids: I, client: &TMDBClient) -> Vec<PersonData>
@@ -257,37 +686,97 @@ where
 // region: ---- UPDATE IMAGES ----
 
 /// Downloads movie poster, snapshot and backdrop, updating their file paths.
-async fn update_movie_posters(movie: &mut MovieData, client: &TMDBClient) {
-    match client.update_movie_backdrop(movie).await {
-        Ok(snapshot_path) => {
-            movie.set_backdrop(Some(snapshot_path));
+///
+/// Artwork whose TMDB path differs from the stored one is downloaded again, while
+/// artwork locked by the user is skipped.
+async fn update_movie_posters(
+    movie: &mut MovieData,
+    client: &TMDBClient,
+    locked: Option<&HashSet<String>>,
+) {
+    let is_locked = |field: &str| locked.is_some_and(|fields| fields.contains(field));
+
+    if is_locked("backdrop") {
+        movie.set_backdrop(None);
+    } else {
+        let backdrop_source = movie.backdrop().cloned();
+        let backdrop_changed = backdrop_source.as_ref() != movie.backdrop_source();
+        match client.update_movie_backdrop(movie, backdrop_changed).await {
+            Ok(snapshot_path) => {
+                movie
+                    .set_backdrop(Some(snapshot_path))
+                    .set_backdrop_source(backdrop_source);
+            }
+            Err(e) => {
+                tracing::error!(
+                    "Failed to update movie backdrop for {} \n Casued by {:?}",
+                    movie.file_path(),
+                    e
+                )
+            }
+        }
+    }
+
+    if is_locked("poster") {
+        movie.set_poster(None);
+    } else {
+        let poster_source = movie.poster().cloned();
+        let poster_changed = poster_source.as_ref() != movie.poster_source();
+        match client.update_movie_poster(movie, poster_changed).await {
+            Ok(snapshot_path) => {
+                movie
+                    .set_poster(Some(snapshot_path))
+                    .set_poster_source(poster_source);
+            }
+            Err(e) => {
+                tracing::error!(
+                    "Failed to update movie poster for {} \n Caused by {:?}",
+                    movie.file_path(),
+                    e
+                )
+            }
+        }
+    }
+    tracing::debug!(file_path = &movie.file_path(), "Movie posters downloaded")
+}
+
+/// Downloads collection poster and backdrop, updating their file paths.
+async fn update_collection_posters(collection: &mut CollectionData, client: &TMDBClient) {
+    match client.update_collection_backdrop(collection).await {
+        Ok(backdrop_path) => {
+            collection.set_backdrop(Some(backdrop_path));
         }
         Err(e) => {
             tracing::error!(
-                "Failed to update movie backdrop for {} \n Casued by {:?}",
-                movie.file_path(),
+                "Failed to update collection backdrop for {} \n Caused by {:?}",
+                collection.name(),
                 e
             )
         }
     }
 
-    match client.update_movie_poster(movie).await {
-        Ok(snapshot_path) => {
-            movie.set_poster(Some(snapshot_path));
+    match client.update_collection_poster(collection).await {
+        Ok(poster_path) => {
+            collection.set_poster(Some(poster_path));
         }
         Err(e) => {
             tracing::error!(
-                "Failed to update movie poster for {} \n Caused by {:?}",
-                movie.file_path(),
+                "Failed to update collection poster for {} \n Caused by {:?}",
+                collection.name(),
                 e
             )
         }
     }
-    tracing::debug!(file_path = &movie.file_path(), "Movie posters downloaded")
+    tracing::debug!(
+        collection = collection.name(),
+        "Collection posters downloaded"
+    )
 }
 
 /// Downloads credit profile picture,and set their file paths.
-async fn update_persons_posters(persons: &mut Vec<PersonData>, client: &TMDBClient) {
+///
+/// Persons are only fetched when missing or stale, so their picture is always downloaded again.
+async fn update_persons_posters(persons: &mut [PersonData], client: &TMDBClient) {
     let batch_size = 20;
 
     let tasks = persons
@@ -295,7 +784,7 @@ async fn update_persons_posters(persons: &mut Vec<PersonData>, client: &TMDBClie
         .cloned() // clone for frb_generated
         .enumerate()
         .map(|(index, mut person)| async move {
-            let path = client.update_person_images(&mut person).await;
+            let path = client.update_person_images(&mut person, true).await;
             (index, person, path)
         })
         .collect::<Vec<_>>();
@@ -315,96 +804,3 @@ async fn update_persons_posters(persons: &mut Vec<PersonData>, client: &TMDBClie
     tracing::debug!("Credits posters downloaded");
 }
 // endregion
-
-// region: ---- FILTER CREDITS ----
-
-/// Filters movie credits to retain only credited cast members and principal crew.
-fn filter_credits(credits: &mut CreditsMovie) {
-    let casts = credits.credits_cast_mut();
-    casts.retain(|cast| !cast.character().contains("uncredited"));
-
-    let crew = credits.credits_crew_mut();
-    crew.retain(|crew| match crew.department() {
-        "Directing" => is_important_directing(crew.job()),
-        "Production" => is_important_production(crew.job()),
-        "Camera" => is_important_camera(crew.job()),
-        "Sound" => is_important_sound(crew.job()),
-        "Visual Effects" => is_important_vfx(crew.job()),
-        "Writing" => is_important_writing(crew.job()),
-        "Art" => is_important_art(crew.job()),
-        "Costume & Make-Up" => is_important_costumes_makeup(crew.job()),
-        _ => false,
-    });
-}
-
-fn is_important_directing(job: &str) -> bool {
-    match job {
-        "Director" => true,
-        "Co-Director" => true,
-        _ => false,
-    }
-}
-
-fn is_important_production(job: &str) -> bool {
-    match job {
-        "Producer" => true,
-        _ => false,
-    }
-}
-
-fn is_important_camera(job: &str) -> bool {
-    match job {
-        "Director of Photography" => true,
-        _ => false,
-    }
-}
-
-fn is_important_sound(job: &str) -> bool {
-    match job {
-        "Original Music Composer" => true,
-        "Sound Designer" => true,
-        _ => false,
-    }
-}
-
-fn is_important_vfx(job: &str) -> bool {
-    match job {
-        "VFX Supervisor" => true,
-        "Visual Effects Supervisor" => true,
-        "Visual Effects Art Director" => true,
-        _ => false,
-    }
-}
-
-fn is_important_writing(job: &str) -> bool {
-    match job {
-        "Writer" => true,
-        "Original Film Writer" => true,
-        "Co-Writer" => true,
-        "Scenario Writer" => true,
-        "Teleplay" => true,
-        "Screenplay" => true,
-        _ => false,
-    }
-}
-
-fn is_important_art(job: &str) -> bool {
-    match job {
-        "Art Direction" => true,
-        "Co-Art Director" => true,
-        "Production Design" => true,
-        "Art Designer" => true,
-        "Set Designer" => true,
-        "Property Master" => true,
-        _ => false,
-    }
-}
-
-fn is_important_costumes_makeup(job: &str) -> bool {
-    match job {
-        "Costume Designer" => true,
-        "Makeup Designer" => true,
-        _ => false,
-    }
-}
-// endregion
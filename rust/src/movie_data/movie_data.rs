@@ -1,6 +1,7 @@
 use anyhow::{anyhow, Context, Result};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::fmt;
+use utoipa::ToSchema;
 
 // region: ---- GENRE ----
 #[derive(Deserialize, Debug, Clone)]
@@ -28,6 +29,154 @@ impl Genre {
 }
 // endregion
 
+// region: ---- DETAILS ----
+#[derive(Deserialize, Debug, Clone)]
+pub struct Company {
+    id: i64,
+    name: String,
+    logo_path: Option<String>,
+    #[serde(default)]
+    origin_country: String,
+}
+impl Company {
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn logo_path(&self) -> Option<&String> {
+        self.logo_path.as_ref()
+    }
+    pub fn origin_country(&self) -> &str {
+        &self.origin_country
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Country {
+    iso_3166_1: String,
+    name: String,
+}
+impl Country {
+    pub fn iso_3166_1(&self) -> &str {
+        &self.iso_3166_1
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Language {
+    iso_639_1: String,
+    english_name: String,
+}
+impl Language {
+    pub fn iso_639_1(&self) -> &str {
+        &self.iso_639_1
+    }
+    pub fn english_name(&self) -> &str {
+        &self.english_name
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Keyword {
+    id: i64,
+    name: String,
+}
+impl Keyword {
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CollectionInfo {
+    id: i64,
+    name: String,
+}
+impl CollectionInfo {
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+// endregion
+
+// region: ---- COLLECTION ----
+#[derive(Deserialize, Debug, Clone)]
+pub struct CollectionPart {
+    id: i64,
+    title: String,
+    #[serde(default)]
+    release_date: String,
+    poster_path: Option<String>,
+}
+impl CollectionPart {
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+    pub fn release_date(&self) -> &str {
+        &self.release_date
+    }
+    pub fn poster_path(&self) -> Option<&String> {
+        self.poster_path.as_ref()
+    }
+}
+
+/// A TMDB collection (franchise) with every movie it contains.
+#[derive(Deserialize, Debug, Clone)]
+pub struct CollectionData {
+    id: i64,
+    name: String,
+    #[serde(default)]
+    overview: String,
+    poster_path: Option<String>,
+    backdrop_path: Option<String>,
+    #[serde(default)]
+    parts: Vec<CollectionPart>,
+}
+impl CollectionData {
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    pub fn overview(&self) -> &str {
+        &self.overview
+    }
+    pub fn poster(&self) -> Option<&String> {
+        self.poster_path.as_ref()
+    }
+    pub fn backdrop(&self) -> Option<&String> {
+        self.backdrop_path.as_ref()
+    }
+    pub fn parts(&self) -> &[CollectionPart] {
+        &self.parts
+    }
+
+    pub fn set_poster(&mut self, new_poster: Option<String>) -> &mut Self {
+        self.poster_path = new_poster;
+        self
+    }
+    pub fn set_backdrop(&mut self, new_backdrop: Option<String>) -> &mut Self {
+        self.backdrop_path = new_backdrop;
+        self
+    }
+}
+// endregion
+
 // region: ---- CREDITS ----
 #[derive(Deserialize, Debug, Clone)]
 pub struct CreditsMovie {
@@ -69,6 +218,8 @@ pub struct Cast {
     name: String,
     character: String,
     order: i32,
+    #[serde(default)]
+    credit_id: String,
 }
 impl fmt::Display for Cast {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -85,9 +236,6 @@ impl fmt::Display for Cast {
 }
 
 impl Cast {
-    pub fn id(&self) -> i64 {
-        self.id
-    }
     pub fn tmdb_id(&self) -> i64 {
         self.tmdb_id
     }
@@ -100,6 +248,9 @@ impl Cast {
     pub fn order(&self) -> i32 {
         self.order
     }
+    pub fn credit_id(&self) -> &str {
+        &self.credit_id
+    }
 }
 // endregion
 
@@ -114,6 +265,8 @@ pub struct Crew {
 
     department: String,
     job: String,
+    #[serde(default)]
+    credit_id: String,
 }
 impl fmt::Display for Crew {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -130,9 +283,6 @@ impl fmt::Display for Crew {
 }
 
 impl Crew {
-    pub fn id(&self) -> i64 {
-        self.id
-    }
     pub fn tmdb_id(&self) -> i64 {
         self.tmdb_id
     }
@@ -145,11 +295,111 @@ impl Crew {
     pub fn job(&self) -> &str {
         &self.job
     }
+    pub fn credit_id(&self) -> &str {
+        &self.credit_id
+    }
 }
 // endregion
 
-// region: ---- SNAPSHOT ----
+// region: ---- CREDIT FILTER ----
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllowedJob {
+    pub department: String,
+    pub job: String,
+}
+
+impl AllowedJob {
+    pub fn new(department: &str, job: &str) -> Self {
+        Self {
+            department: department.to_owned(),
+            job: job.to_owned(),
+        }
+    }
+}
+
+/// Rules deciding which credits are kept when a movie is scanned.
 #[derive(Debug, Clone)]
+pub struct CreditFilter {
+    pub allowed_jobs: Vec<AllowedJob>,
+    pub max_cast_size: Option<u32>,
+    pub include_uncredited: bool,
+}
+
+impl CreditFilter {
+    pub fn new(
+        allowed_jobs: Vec<AllowedJob>,
+        max_cast_size: Option<u32>,
+        include_uncredited: bool,
+    ) -> Self {
+        Self {
+            allowed_jobs,
+            max_cast_size,
+            include_uncredited,
+        }
+    }
+
+    pub fn is_allowed_job(&self, department: &str, job: &str) -> bool {
+        self.allowed_jobs
+            .iter()
+            .any(|allowed| allowed.department == department && allowed.job == job)
+    }
+
+    /// Keeps the billed cast within the limits, and the crew whose job is allowed.
+    pub fn apply(&self, credits: &mut CreditsMovie) {
+        let cast = credits.credits_cast_mut();
+        if !self.include_uncredited {
+            cast.retain(|cast| !cast.character().contains("uncredited"));
+        }
+        if let Some(max_cast_size) = self.max_cast_size {
+            cast.sort_by_key(|cast| cast.order());
+            cast.truncate(max_cast_size as usize);
+        }
+
+        credits
+            .credits_crew_mut()
+            .retain(|crew| self.is_allowed_job(crew.department(), crew.job()));
+    }
+}
+
+impl Default for CreditFilter {
+    /// Credited cast members and principal crew.
+    fn default() -> Self {
+        let allowed_jobs = [
+            ("Directing", "Director"),
+            ("Directing", "Co-Director"),
+            ("Production", "Producer"),
+            ("Camera", "Director of Photography"),
+            ("Sound", "Original Music Composer"),
+            ("Sound", "Sound Designer"),
+            ("Visual Effects", "VFX Supervisor"),
+            ("Visual Effects", "Visual Effects Supervisor"),
+            ("Visual Effects", "Visual Effects Art Director"),
+            ("Writing", "Writer"),
+            ("Writing", "Original Film Writer"),
+            ("Writing", "Co-Writer"),
+            ("Writing", "Scenario Writer"),
+            ("Writing", "Teleplay"),
+            ("Writing", "Screenplay"),
+            ("Art", "Art Direction"),
+            ("Art", "Co-Art Director"),
+            ("Art", "Production Design"),
+            ("Art", "Art Designer"),
+            ("Art", "Set Designer"),
+            ("Art", "Property Master"),
+            ("Costume & Make-Up", "Costume Designer"),
+            ("Costume & Make-Up", "Makeup Designer"),
+        ]
+        .iter()
+        .map(|(department, job)| AllowedJob::new(department, job))
+        .collect();
+
+        Self::new(allowed_jobs, None, false)
+    }
+}
+// endregion
+
+// region: ---- SNAPSHOT ----
+#[derive(Serialize, Debug, Clone, ToSchema)]
 pub struct MovieSnapshot {
     pub id: i64,
     pub file_path: String,
@@ -157,6 +407,7 @@ pub struct MovieSnapshot {
     pub rating: f32,
     pub release_date: String,
     pub poster: String,
+    pub watched: bool,
 }
 
 impl MovieSnapshot {
@@ -167,6 +418,7 @@ impl MovieSnapshot {
         rating: f32,
         release_date: String,
         poster: String,
+        watched: bool,
     ) -> Self {
         Self {
             id,
@@ -175,13 +427,279 @@ impl MovieSnapshot {
             rating,
             release_date,
             poster,
+            watched,
         }
     }
 }
 // endregion
 
-// region: ---- MediaData ----
+// region: ---- PROFILE ----
+/// A household member with their own watch history and preferences.
+#[derive(Debug, Clone)]
+pub struct Profile {
+    pub id: i64,
+    pub name: String,
+    pub avatar: Option<String>,
+    /// Whether switching to the profile asks for its PIN.
+    pub has_pin: bool,
+    /// Highest content rating shown to the profile, e.g. "PG-13".
+    pub max_certification: Option<String>,
+    /// Whether the titles without a US content rating are hidden too when the profile has a
+    /// rating limit, covering the unrated titles and those only rated in other countries.
+    pub hide_unrated: bool,
+    /// ISO 639-1 code of the preferred audio and subtitle language.
+    pub preferred_language: Option<String>,
+}
+
+impl Profile {
+    pub fn new(
+        id: i64,
+        name: String,
+        avatar: Option<String>,
+        has_pin: bool,
+        max_certification: Option<String>,
+        hide_unrated: bool,
+        preferred_language: Option<String>,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            avatar,
+            has_pin,
+            max_certification,
+            hide_unrated,
+            preferred_language,
+        }
+    }
+}
+// endregion
+
+// region: ---- DEVICES ----
+/// A device paired with the network api, acting as one profile.
+#[derive(Debug, Clone)]
+pub struct PairedDevice {
+    pub id: i64,
+    pub name: String,
+    pub profile_id: i64,
+    /// Unix timestamps of the pairing and of the last authenticated request.
+    pub created_at: i64,
+    pub last_seen: Option<i64>,
+}
+
+impl PairedDevice {
+    pub fn new(
+        id: i64,
+        name: String,
+        profile_id: i64,
+        created_at: i64,
+        last_seen: Option<i64>,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            profile_id,
+            created_at,
+            last_seen,
+        }
+    }
+}
+// endregion
+
+// region: ---- LISTS ----
+/// A per-profile list of media: the built-in "favourites" and "watchlist", or a "custom" one.
 #[derive(Debug, Clone)]
+pub struct MediaList {
+    pub id: i64,
+    pub name: String,
+    pub kind: String,
+    pub item_count: u32,
+}
+
+impl MediaList {
+    pub fn new(id: i64, name: String, kind: String, item_count: u32) -> Self {
+        Self {
+            id,
+            name,
+            kind,
+            item_count,
+        }
+    }
+}
+// endregion
+
+// region: ---- SMART COLLECTIONS ----
+/// Conditions a movie must all match to be part of a smart collection, unset ones being ignored.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct SmartFilter {
+    /// Genre names, matching movies having any of them.
+    pub genres: Vec<String>,
+    pub year_from: Option<u32>,
+    pub year_to: Option<u32>,
+    pub min_rating: Option<f32>,
+    pub max_rating: Option<f32>,
+    /// TMDB ids of persons, matching movies crediting any of them.
+    pub person_ids: Vec<i64>,
+    pub watched: Option<bool>,
+    /// Resolution tags of the file name, e.g. "2160p", matching movies having any of them.
+    pub resolutions: Vec<String>,
+    /// Only keeps the movies added to the library in the last days.
+    pub added_within_days: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SmartSort {
+    #[default]
+    Title,
+    ReleaseDate,
+    Rating,
+    DateAdded,
+    /// Shuffled again on every evaluation.
+    Random,
+}
+
+/// Saved query of a smart collection, stored as JSON.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct SmartQuery {
+    pub filter: SmartFilter,
+    pub sort: SmartSort,
+    pub descending: bool,
+    /// Maximum number of movies in the collection.
+    pub limit: Option<u32>,
+}
+
+/// A per-profile collection whose movies are the ones matching its query when evaluated.
+#[derive(Debug, Clone)]
+pub struct SmartCollection {
+    pub id: i64,
+    pub name: String,
+    pub query: SmartQuery,
+}
+
+impl SmartCollection {
+    pub fn new(id: i64, name: String, query: SmartQuery) -> Self {
+        Self { id, name, query }
+    }
+}
+// endregion
+
+// region: ---- PLAYBACK ----
+/// Playback state of a media item, positions and duration being in seconds.
+#[derive(Serialize, Debug, Clone, Default, ToSchema)]
+pub struct PlaybackState {
+    pub media_id: i64,
+    pub watched: bool,
+    pub play_count: u32,
+    /// Unix timestamp of the last playback, if any.
+    pub last_played: Option<i64>,
+    pub resume_position: f64,
+    pub duration: f64,
+    /// Rating given by the profile, from 0 to 10.
+    pub user_rating: Option<f32>,
+}
+
+impl PlaybackState {
+    pub fn new(
+        media_id: i64,
+        watched: bool,
+        play_count: u32,
+        last_played: Option<i64>,
+        resume_position: f64,
+        duration: f64,
+        user_rating: Option<f32>,
+    ) -> Self {
+        Self {
+            media_id,
+            watched,
+            play_count,
+            last_played,
+            resume_position,
+            duration,
+            user_rating,
+        }
+    }
+
+    /// Share of the item already played, from 0 to 1.
+    pub fn progress(&self) -> f64 {
+        if self.duration > 0.0 {
+            (self.resume_position / self.duration).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// A media snapshot with its playback state, listed in "continue watching" and "recently watched".
+#[derive(Debug, Clone)]
+pub struct PlaybackEntry {
+    pub media: MovieSnapshot,
+    pub state: PlaybackState,
+}
+
+impl PlaybackEntry {
+    pub fn new(media: MovieSnapshot, state: PlaybackState) -> Self {
+        Self { media, state }
+    }
+}
+// endregion
+
+// region: ---- RECOMMENDATIONS ----
+/// Unwatched media recommended because the profile watched `source`.
+#[derive(Debug, Clone)]
+pub struct WatchRecommendation {
+    pub source: MovieSnapshot,
+    pub items: Vec<MovieSnapshot>,
+}
+
+impl WatchRecommendation {
+    pub fn new(source: MovieSnapshot, items: Vec<MovieSnapshot>) -> Self {
+        Self { source, items }
+    }
+}
+// endregion
+
+// region: ---- HOME FEED ----
+/// Placement of a home screen row for a profile.
+#[derive(Debug, Clone)]
+pub struct HomeRowConfig {
+    /// One of the kinds of `HOME_ROWS`, e.g. "recently_added".
+    pub kind: String,
+    pub title: String,
+    pub enabled: bool,
+    /// Maximum number of media shown in the row.
+    pub item_count: u32,
+}
+
+impl HomeRowConfig {
+    pub fn new(kind: String, title: String, enabled: bool, item_count: u32) -> Self {
+        Self {
+            kind,
+            title,
+            enabled,
+            item_count,
+        }
+    }
+}
+
+/// A row of the home screen with its media and their playback state.
+#[derive(Debug, Clone)]
+pub struct HomeRow {
+    pub kind: String,
+    pub title: String,
+    pub items: Vec<PlaybackEntry>,
+}
+
+impl HomeRow {
+    pub fn new(kind: String, title: String, items: Vec<PlaybackEntry>) -> Self {
+        Self { kind, title, items }
+    }
+}
+// endregion
+
+// region: ---- MediaData ----
+#[derive(Serialize, Debug, Clone, ToSchema)]
 pub struct MediaData {
     pub id: i64,
     pub file_path: String,
@@ -193,88 +711,432 @@ pub struct MediaData {
     pub summary: String,
     pub poster: String,
     pub backdrop: String,
+    pub runtime: Option<i32>,
+    pub tagline: String,
+    pub status: String,
+    pub budget: i64,
+    pub revenue: i64,
+    pub imdb_id: Option<String>,
+    pub certification: String,
+    /// Technical info read from the file headers, None until the file is probed.
+    pub media_info: Option<MediaInfo>,
+}
+// endregion
+
+// region: ---- MediaInfo ----
+/// Technical info of a media file, read from its container headers.
+#[derive(Serialize, Debug, Clone, Default, ToSchema)]
+pub struct MediaInfo {
+    /// "matroska", "webm", "mp4" or "mov".
+    pub container: String,
+    /// In seconds.
+    pub duration: Option<f64>,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub video_codec: Option<String>,
+    /// "Dolby Vision", "HDR10" or "HLG", None for SDR.
+    pub hdr_format: Option<String>,
+    /// Overall bitrate in bits per second.
+    pub bitrate: Option<i64>,
+    pub streams: Vec<MediaStream>,
+    pub chapters: Vec<MediaChapter>,
 }
 
-impl MediaData {
+/// A video, audio or subtitle track of a media file.
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct MediaStream {
+    /// Track number in the container.
+    pub index: i32,
+    /// "video", "audio" or "subtitle".
+    pub kind: String,
+    pub codec: String,
+    /// ISO 639-2 or BCP 47 language, None when undetermined.
+    pub language: Option<String>,
+    pub title: Option<String>,
+    pub channels: Option<i32>,
+    pub is_default: bool,
+    pub is_forced: bool,
+}
+
+/// A chapter marker of a media file, the player seeking to its start.
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct MediaChapter {
+    /// Position of the chapter, from 0.
+    pub index: i32,
+    pub title: Option<String>,
+    /// In seconds.
+    pub start: f64,
+    /// In seconds, None for the last chapter of a file of unknown duration.
+    pub end: Option<f64>,
+}
+// endregion
+
+// region: ---- SubtitleFile ----
+/// A subtitle file found next to the video of a media, its tags read from its name
+/// as in "Movie.en.forced.srt".
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct SubtitleFile {
+    /// 0 until saved.
+    pub id: i64,
+    /// Relative to the library root.
+    pub file_path: String,
+    /// "srt", "ass", "ssa" or "vtt".
+    pub format: String,
+    /// Language code as written in the file name, None when not given.
+    pub language: Option<String>,
+    pub title: Option<String>,
+    pub is_forced: bool,
+    /// Subtitles for the deaf and hard of hearing.
+    pub is_sdh: bool,
+}
+// endregion
+
+// region: ---- PLAYBACK DECISION ----
+/// What a player declares it can play as is, codecs being named as in `MediaStream`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, ToSchema)]
+#[serde(default)]
+pub struct ClientCapabilities {
+    /// "mkv", "webm", "mp4" or "mov".
+    pub containers: Vec<String>,
+    pub video_codecs: Vec<String>,
+    pub audio_codecs: Vec<String>,
+    pub max_width: Option<i32>,
+    pub max_height: Option<i32>,
+    /// In bits per second.
+    pub max_bitrate: Option<i64>,
+    pub max_audio_channels: Option<i32>,
+    pub supports_hdr: bool,
+}
+
+/// How a player asks for a media to be played.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, ToSchema)]
+#[serde(default)]
+pub struct PlaybackRequest {
+    pub capabilities: ClientCapabilities,
+    /// Quality chosen by the user, lowering the client limits.
+    pub max_height: Option<i32>,
+    pub max_bitrate: Option<i64>,
+    /// Track number of the audio stream kept when remuxed or transcoded, the default one
+    /// when not given.
+    pub audio_stream: Option<i32>,
+    /// Track number of an embedded subtitle stream to burn into the video.
+    pub subtitle_stream: Option<i32>,
+    /// Id of a subtitle file next to the video to burn into the video, as listed by
+    /// `get_subtitle_files`. Can not be given with `subtitle_stream`.
+    pub subtitle_file: Option<i64>,
+    /// In seconds, a transcode starting at the segment holding it. A remux always starts from
+    /// the beginning, the player seeking once its playlist reaches the position.
+    pub start_position: f64,
+}
+
+/// How a media is played by a player and where.
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct PlaybackDecision {
+    /// "direct_play", "remux" or "transcode".
+    pub method: String,
+    /// Why the media can not be played as is, empty for a direct play.
+    pub reasons: Vec<String>,
+    /// The file for a direct play, a HLS playlist otherwise.
+    pub url: String,
+    /// Transcoding session to stop once playback ends, None for a direct play.
+    pub session_id: Option<String>,
+}
+// endregion
+
+// region: ---- PersonSnapshot ----
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct PersonSnapshot {
+    pub tmdb_id: i64,
+    pub name: String,
+    pub character: String,
+    pub job_name: String,
+    pub picture_path: String,
+    pub department: String,
+    pub credit_order: Option<i32>,
+    pub credit_id: String,
+}
+// endregion
+
+// region: ---- ScanReport ----
+/// Summary of a library scan, including how many TMDB requests the combined fetch path avoided.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ScanReport {
+    pub movies_found: u32,
+    pub movies_matched: u32,
+    pub movies_saved: u32,
+    pub movies_failed: u32,
+    pub persons_fetched: u32,
+    pub persons_skipped: u32,
+    pub tmdb_requests: u64,
+    pub tmdb_requests_saved: u64,
+}
+impl fmt::Display for ScanReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Movies found:        {}\n\
+             Movies matched:      {}\n\
+             Movies saved:        {}\n\
+             Movies failed:       {}\n\
+             Persons fetched:     {}\n\
+             Persons skipped:     {}\n\
+             TMDB requests:       {}\n\
+             Requests saved:      {}",
+            self.movies_found,
+            self.movies_matched,
+            self.movies_saved,
+            self.movies_failed,
+            self.persons_fetched,
+            self.persons_skipped,
+            self.tmdb_requests,
+            self.tmdb_requests_saved,
+        )
+    }
+}
+// endregion
+
+// region: ---- LibraryEvent ----
+/// Change of the library, sent to the views and the network clients as it happens.
+/// Serialized as a JSON object whose `type` is the snake case variant name.
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LibraryEvent {
+    MediaAdded {
+        media_id: i64,
+    },
+    MediaUpdated {
+        media_id: i64,
+    },
+    MediaRemoved {
+        media_id: i64,
+    },
+    /// Watched flag, resume position or rating of a media changed for a profile.
+    WatchStateChanged {
+        profile_id: i64,
+        media_id: i64,
+    },
+    ScanStarted,
+    ScanFinished {
+        report: ScanReport,
+    },
+    /// The subscriber fell behind and lost events, its views should be reloaded.
+    EventsMissed {
+        count: u64,
+    },
+    /// Sent to an idle Dart stream to find out whether it is still listened to, carries no change.
+    KeepAlive,
+}
+// endregion
+
+// region: ---- SearchResult ----
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct SearchResult {
+    pub kind: String,
+    pub id: i64,
+    pub title: String,
+    pub subtitle: String,
+    pub picture_path: Option<String>,
+    pub score: f32,
+}
+
+impl SearchResult {
     pub fn new(
+        kind: String,
         id: i64,
-        file_path: String,
-        file_optional_info: String,
-        original_title: String,
         title: String,
-        rating: f32,
-        release_date: String,
-        summary: String,
-        poster: String,
-        backdrop: String,
+        subtitle: String,
+        picture_path: Option<String>,
     ) -> Self {
         Self {
+            kind,
             id,
-            file_path,
-            file_optional_info,
-            original_title,
             title,
-            rating,
-            release_date,
-            summary,
+            subtitle,
+            picture_path,
+            score: 0.0,
+        }
+    }
+}
+// endregion
+
+// region: ---- FacetSnapshot ----
+/// A browsable value shared by several movies, such as a studio, a country or a keyword.
+#[derive(Debug, Clone)]
+pub struct FacetSnapshot {
+    pub kind: String,
+    pub key: String,
+    pub name: String,
+    pub media_count: i64,
+}
+
+impl FacetSnapshot {
+    pub fn new(kind: String, key: String, name: String, media_count: i64) -> Self {
+        Self {
+            kind,
+            key,
+            name,
+            media_count,
+        }
+    }
+}
+// endregion
+
+// region: ---- CollectionSnapshot ----
+#[derive(Debug, Clone)]
+pub struct CollectionSnapshot {
+    pub id: i64,
+    pub name: String,
+    pub poster: Option<String>,
+    pub owned_count: i64,
+    pub part_count: i64,
+}
+
+impl CollectionSnapshot {
+    pub fn new(
+        id: i64,
+        name: String,
+        poster: Option<String>,
+        owned_count: i64,
+        part_count: i64,
+    ) -> Self {
+        Self {
+            id,
+            name,
             poster,
-            backdrop,
+            owned_count,
+            part_count,
+        }
+    }
+}
+
+/// A collection with the movies of the library belonging to it, in release order.
+#[derive(Debug, Clone)]
+pub struct CollectionDetail {
+    pub id: i64,
+    pub name: String,
+    pub overview: String,
+    pub poster: Option<String>,
+    pub backdrop: Option<String>,
+    pub parts: Vec<MovieSnapshot>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CollectionPartSnapshot {
+    pub tmdb_id: i64,
+    pub title: String,
+    pub release_date: String,
+    pub poster_path: Option<String>,
+}
+
+impl CollectionPartSnapshot {
+    pub fn new(
+        tmdb_id: i64,
+        title: String,
+        release_date: String,
+        poster_path: Option<String>,
+    ) -> Self {
+        Self {
+            tmdb_id,
+            title,
+            release_date,
+            poster_path,
         }
     }
 }
 // endregion
 
-// region: ---- PersonSnapshot ----
+// region: ---- Filmography ----
 #[derive(Debug, Clone)]
-pub struct PersonSnapshot {
+pub struct FilmographyEntry {
+    pub media_id: Option<i64>,
     pub tmdb_id: i64,
-    pub name: String,
+    pub title: String,
+    pub release_date: String,
+    pub poster: Option<String>,
+    pub department: String,
     pub character: String,
     pub job_name: String,
-    pub picture_path: String,
 }
 
-impl PersonSnapshot {
+#[derive(Debug, Clone)]
+pub struct FilmographyGroup {
+    pub department: String,
+    pub entries: Vec<FilmographyEntry>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PersonFilmography {
+    pub person: PersonData,
+    pub groups: Vec<FilmographyGroup>,
+    pub missing: Vec<FilmographyEntry>,
+}
+
+impl PersonFilmography {
+    /// Groups owned entries by department, acting first, each sorted by release date.
     pub fn new(
-        tmdb_id: i64,
-        name: String,
-        character: String,
-        job_name: String,
-        picture_path: String,
+        person: PersonData,
+        owned: Vec<FilmographyEntry>,
+        mut missing: Vec<FilmographyEntry>,
     ) -> Self {
+        let mut groups: Vec<FilmographyGroup> = Vec::new();
+        for entry in owned {
+            match groups.iter_mut().find(|g| g.department == entry.department) {
+                Some(group) => group.entries.push(entry),
+                None => groups.push(FilmographyGroup {
+                    department: entry.department.clone(),
+                    entries: vec![entry],
+                }),
+            }
+        }
+
+        groups.sort_by(|a, b| {
+            (a.department != "Acting", &a.department)
+                .cmp(&(b.department != "Acting", &b.department))
+        });
+        for group in groups.iter_mut() {
+            group.entries.sort_by(Self::by_release_date);
+        }
+        missing.sort_by(Self::by_release_date);
+
         Self {
-            tmdb_id,
-            name,
-            character,
-            job_name,
-            picture_path,
+            person,
+            groups,
+            missing,
         }
     }
+
+    /// Orders entries by release date, undated entries last.
+    fn by_release_date(a: &FilmographyEntry, b: &FilmographyEntry) -> std::cmp::Ordering {
+        (a.release_date.is_empty(), &a.release_date, &a.title).cmp(&(
+            b.release_date.is_empty(),
+            &b.release_date,
+            &b.title,
+        ))
+    }
 }
 // endregion
 
 // region: ---- PersonData ----
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, ToSchema)]
 pub struct PersonData {
-    #[serde(rename = "id")]
+    #[serde(rename(deserialize = "id"))]
     pub tmdb_id: i64,
     pub name: String,
-    #[serde(rename = "biography")]
+    #[serde(rename(deserialize = "biography"))]
     pub summary: String,
-    #[serde(rename = "profile_path")]
+    #[serde(rename(deserialize = "profile_path"))]
     pub picture_path: Option<String>,
+    pub birthday: Option<String>,
+    pub deathday: Option<String>,
+    pub place_of_birth: Option<String>,
+    pub known_for_department: Option<String>,
+    #[serde(default)]
+    pub gender: i32,
+    #[serde(default)]
+    pub also_known_as: Vec<String>,
 }
 
 impl PersonData {
-    pub fn new(tmdb_id: i64, name: String, summary: String, picture_path: Option<String>) -> Self {
-        Self {
-            tmdb_id,
-            name,
-            summary,
-            picture_path,
-        }
-    }
-
     pub fn tmdb_id(&self) -> i64 {
         self.tmdb_id
     }
@@ -291,6 +1153,30 @@ impl PersonData {
         self.picture_path.as_ref()
     }
 
+    pub fn birthday(&self) -> Option<&String> {
+        self.birthday.as_ref()
+    }
+
+    pub fn deathday(&self) -> Option<&String> {
+        self.deathday.as_ref()
+    }
+
+    pub fn place_of_birth(&self) -> Option<&String> {
+        self.place_of_birth.as_ref()
+    }
+
+    pub fn known_for_department(&self) -> Option<&String> {
+        self.known_for_department.as_ref()
+    }
+
+    pub fn gender(&self) -> i32 {
+        self.gender
+    }
+
+    pub fn also_known_as(&self) -> &[String] {
+        &self.also_known_as
+    }
+
     pub fn set_picture_path(&mut self, path: String) {
         self.picture_path = Some(path)
     }
@@ -313,6 +1199,21 @@ pub struct MovieData {
     summary: String,
     poster: Option<String>,
     backdrop: Option<String>,
+    runtime: Option<i32>,
+    tagline: String,
+    status: String,
+    budget: i64,
+    revenue: i64,
+    imdb_id: Option<String>,
+    spoken_languages: Vec<Language>,
+    production_companies: Vec<Company>,
+    production_countries: Vec<Country>,
+    keywords: Vec<Keyword>,
+    collection: Option<CollectionInfo>,
+    certification: String,
+    /// TMDB paths of the stored poster and backdrop, compared to detect changed artwork.
+    poster_source: Option<String>,
+    backdrop_source: Option<String>,
 }
 // region: ---- DISPLAY ----
 impl fmt::Display for MovieData {
@@ -332,7 +1233,9 @@ impl fmt::Display for MovieData {
              Release date:        {}\n\
              Summary:             {}\n\
              Poster large:        {:?}\n\
-             Backdrop:            {:?}",
+             Backdrop:            {:?}\n\
+             Runtime:             {:?}\n\
+             Imdb id:             {:?}",
             self.id,
             self.file_path,
             self.file_title,
@@ -347,6 +1250,8 @@ impl fmt::Display for MovieData {
             self.summary,
             self.poster,
             self.backdrop,
+            self.runtime,
+            self.imdb_id,
         )
     }
 }
@@ -354,7 +1259,7 @@ impl fmt::Display for MovieData {
 
 impl MovieData {
     pub fn new(path: &str) -> Result<Self> {
-        let file_name = path.rsplit('/').next().unwrap_or(&path);
+        let file_name = path.rsplit('/').next().unwrap_or(path);
         let (file_title, file_year, file_optional_info) = Self::parse_file_name(file_name)
             .with_context(|| format!("Failed to parse file: {}", &path))?;
 
@@ -373,6 +1278,20 @@ impl MovieData {
             summary: "".to_owned(),
             poster: None,
             backdrop: None,
+            runtime: None,
+            tagline: "".to_owned(),
+            status: "".to_owned(),
+            budget: 0,
+            revenue: 0,
+            imdb_id: None,
+            spoken_languages: vec![],
+            production_companies: vec![],
+            production_countries: vec![],
+            keywords: vec![],
+            collection: None,
+            certification: "".to_owned(),
+            poster_source: None,
+            backdrop_source: None,
         })
     }
 
@@ -391,19 +1310,15 @@ impl MovieData {
         if let (Some(start), Some(end)) = (name.find('['), name.find(']')) {
             file_optional_info = &name[start + 1..end];
         }
-        return Ok((
+        Ok((
             file_title.to_string(),
             file_year.to_string(),
             file_optional_info.to_string(),
-        ));
+        ))
     }
 
     // region: ----- GETTERS -----
 
-    pub fn id(&self) -> i64 {
-        self.id
-    }
-
     pub fn file_path(&self) -> &str {
         &self.file_path
     }
@@ -455,32 +1370,68 @@ impl MovieData {
     pub fn backdrop(&self) -> Option<&String> {
         self.backdrop.as_ref()
     }
-    // endregion
 
-    // region: ------ SETTERS -----
+    pub fn runtime(&self) -> Option<i32> {
+        self.runtime
+    }
 
-    pub fn set_id(&mut self, new_id: i64) -> &mut Self {
-        self.id = new_id;
-        self
+    pub fn tagline(&self) -> &str {
+        &self.tagline
     }
 
-    pub fn set_file_path(&mut self, new_file_path: &str) -> &mut Self {
-        self.file_path = new_file_path.to_owned();
-        self
+    pub fn status(&self) -> &str {
+        &self.status
     }
 
-    pub fn set_file_title(&mut self, new_file_title: &str) -> &mut Self {
-        self.file_title = new_file_title.to_owned();
-        self
+    pub fn budget(&self) -> i64 {
+        self.budget
     }
 
-    pub fn set_file_year(&mut self, new_file_year: &str) -> &mut Self {
-        self.file_year = new_file_year.to_owned();
-        self
+    pub fn revenue(&self) -> i64 {
+        self.revenue
+    }
+
+    pub fn imdb_id(&self) -> Option<&String> {
+        self.imdb_id.as_ref()
+    }
+
+    pub fn spoken_languages(&self) -> &[Language] {
+        &self.spoken_languages
+    }
+
+    pub fn production_companies(&self) -> &[Company] {
+        &self.production_companies
+    }
+
+    pub fn production_countries(&self) -> &[Country] {
+        &self.production_countries
     }
 
-    pub fn set_file_optional_info(&mut self, new_file_optional_info: &str) -> &mut Self {
-        self.file_optional_info = new_file_optional_info.to_owned();
+    pub fn keywords(&self) -> &[Keyword] {
+        &self.keywords
+    }
+
+    pub fn collection(&self) -> Option<&CollectionInfo> {
+        self.collection.as_ref()
+    }
+
+    pub fn certification(&self) -> &str {
+        &self.certification
+    }
+
+    pub fn poster_source(&self) -> Option<&String> {
+        self.poster_source.as_ref()
+    }
+
+    pub fn backdrop_source(&self) -> Option<&String> {
+        self.backdrop_source.as_ref()
+    }
+    // endregion
+
+    // region: ------ SETTERS -----
+
+    pub fn set_id(&mut self, new_id: i64) -> &mut Self {
+        self.id = new_id;
         self
     }
 
@@ -528,5 +1479,157 @@ impl MovieData {
         self.backdrop = new_backdrop;
         self
     }
+
+    pub fn set_runtime(&mut self, new_runtime: Option<i32>) -> &mut Self {
+        self.runtime = new_runtime;
+        self
+    }
+
+    pub fn set_tagline(&mut self, new_tagline: &str) -> &mut Self {
+        self.tagline = new_tagline.to_owned();
+        self
+    }
+
+    pub fn set_status(&mut self, new_status: &str) -> &mut Self {
+        self.status = new_status.to_owned();
+        self
+    }
+
+    pub fn set_budget(&mut self, new_budget: i64) -> &mut Self {
+        self.budget = new_budget;
+        self
+    }
+
+    pub fn set_revenue(&mut self, new_revenue: i64) -> &mut Self {
+        self.revenue = new_revenue;
+        self
+    }
+
+    pub fn set_imdb_id(&mut self, new_imdb_id: Option<String>) -> &mut Self {
+        self.imdb_id = new_imdb_id;
+        self
+    }
+
+    pub fn set_spoken_languages(&mut self, new_spoken_languages: Vec<Language>) -> &mut Self {
+        self.spoken_languages = new_spoken_languages;
+        self
+    }
+
+    pub fn set_production_companies(
+        &mut self,
+        new_production_companies: Vec<Company>,
+    ) -> &mut Self {
+        self.production_companies = new_production_companies;
+        self
+    }
+
+    pub fn set_production_countries(
+        &mut self,
+        new_production_countries: Vec<Country>,
+    ) -> &mut Self {
+        self.production_countries = new_production_countries;
+        self
+    }
+
+    pub fn set_keywords(&mut self, new_keywords: Vec<Keyword>) -> &mut Self {
+        self.keywords = new_keywords;
+        self
+    }
+
+    pub fn set_collection(&mut self, new_collection: Option<CollectionInfo>) -> &mut Self {
+        self.collection = new_collection;
+        self
+    }
+
+    pub fn set_certification(&mut self, new_certification: &str) -> &mut Self {
+        self.certification = new_certification.to_owned();
+        self
+    }
+
+    pub fn set_poster_source(&mut self, new_poster_source: Option<String>) -> &mut Self {
+        self.poster_source = new_poster_source;
+        self
+    }
+
+    pub fn set_backdrop_source(&mut self, new_backdrop_source: Option<String>) -> &mut Self {
+        self.backdrop_source = new_backdrop_source;
+        self
+    }
     // endregion
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn credits() -> CreditsMovie {
+        serde_json::from_value(json!({
+            "cast": [
+                { "id": 3, "name": "Third", "character": "Guard", "order": 2 },
+                { "id": 1, "name": "Lead", "character": "Hero", "order": 0 },
+                { "id": 4, "name": "Extra", "character": "Diner (uncredited)", "order": 3 },
+                { "id": 2, "name": "Second", "character": "Villain", "order": 1 },
+            ],
+            "crew": [
+                { "id": 10, "name": "Director", "department": "Directing", "job": "Director" },
+                { "id": 11, "name": "Grip", "department": "Camera", "job": "Key Grip" },
+                { "id": 12, "name": "Writer", "department": "Writing", "job": "Screenplay" },
+                { "id": 13, "name": "Misfiled", "department": "Crew", "job": "Director" },
+            ],
+        }))
+        .unwrap()
+    }
+
+    fn cast_names(credits: &CreditsMovie) -> Vec<&str> {
+        credits
+            .credits_cast()
+            .iter()
+            .map(|cast| cast.name())
+            .collect()
+    }
+
+    fn crew_names(credits: &CreditsMovie) -> Vec<&str> {
+        credits
+            .credits_crew()
+            .iter()
+            .map(|crew| crew.name())
+            .collect()
+    }
+
+    #[test]
+    fn default_filter_drops_uncredited_cast_and_minor_crew() {
+        let mut credits = credits();
+        CreditFilter::default().apply(&mut credits);
+
+        assert_eq!(cast_names(&credits), vec!["Third", "Lead", "Second"]);
+        assert_eq!(crew_names(&credits), vec!["Director", "Writer"]);
+    }
+
+    #[test]
+    fn max_cast_size_keeps_the_top_billed_cast() {
+        let mut credits = credits();
+        CreditFilter::new(Vec::new(), Some(2), true).apply(&mut credits);
+
+        assert_eq!(cast_names(&credits), vec!["Lead", "Second"]);
+        assert!(credits.credits_crew().is_empty());
+    }
+
+    #[test]
+    fn uncredited_cast_is_kept_when_included() {
+        let mut credits = credits();
+        CreditFilter::new(Vec::new(), None, true).apply(&mut credits);
+
+        assert_eq!(credits.credits_cast().len(), 4);
+    }
+
+    #[test]
+    fn allowed_jobs_match_department_and_job() {
+        let mut credits = credits();
+        CreditFilter::new(vec![AllowedJob::new("Camera", "Key Grip")], Some(0), false)
+            .apply(&mut credits);
+
+        assert!(credits.credits_cast().is_empty());
+        assert_eq!(crew_names(&credits), vec!["Grip"]);
+    }
+}
@@ -0,0 +1,268 @@
+use crate::{
+    db_interface::data_getter::DataGetter,
+    movie_data::movie_data::{MovieSnapshot, WatchRecommendation},
+};
+use anyhow::{anyhow, Context, Result};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{LazyLock, RwLock},
+};
+
+/// Number of top-billed actors counted as features of a movie.
+const TOP_CAST: u32 = 5;
+
+/// Weight of each feature kind, before the rarity weighting.
+const FEATURE_WEIGHTS: [(&str, f32); 6] = [
+    ("director", 3.0),
+    ("writer", 2.0),
+    ("cast", 1.5),
+    ("keyword", 1.0),
+    ("genre", 1.0),
+    ("era", 0.5),
+];
+
+/// Number of last watched movies the "because you watched" recommendations start from.
+const HISTORY_SEEDS: u32 = 10;
+
+/// Share of its weight a watched movie keeps against the one watched right after it.
+const HISTORY_DECAY: f32 = 0.85;
+
+/// Process wide index shared by the recommendation api, rebuilt after each scan.
+static CONTENT_INDEX: LazyLock<RwLock<ContentIndex>> =
+    LazyLock::new(|| RwLock::new(ContentIndex::default()));
+
+/// In-memory content-based similarity index over the movies of the library.
+///
+/// Each movie is a sparse vector of its features, weighted by kind and by rarity in the library,
+/// then normalized so the similarity of two movies is the cosine of their vectors.
+#[derive(Default)]
+pub struct ContentIndex {
+    vectors: HashMap<i64, Vec<(usize, f32)>>,
+    postings: Vec<Vec<(i64, f32)>>,
+}
+
+impl ContentIndex {
+    pub fn build(features: Vec<(i64, String)>) -> Self {
+        let mut feature_ids: HashMap<String, usize> = HashMap::new();
+        let mut kind_weights: Vec<f32> = vec![];
+        let mut movie_features: HashMap<i64, Vec<usize>> = HashMap::new();
+
+        for (movie_id, feature) in features {
+            let kind = feature.split(':').next().unwrap_or_default();
+            let Some((_, weight)) = FEATURE_WEIGHTS.iter().find(|(known, _)| *known == kind) else {
+                continue;
+            };
+            let next_id = feature_ids.len();
+            let feature_id = *feature_ids.entry(feature).or_insert_with(|| {
+                kind_weights.push(*weight);
+                next_id
+            });
+            movie_features.entry(movie_id).or_default().push(feature_id);
+        }
+
+        let mut movie_counts = vec![0u32; kind_weights.len()];
+        for feature_ids in movie_features.values_mut() {
+            feature_ids.sort_unstable();
+            feature_ids.dedup();
+            for feature_id in feature_ids.iter() {
+                movie_counts[*feature_id] += 1;
+            }
+        }
+
+        let library_size = movie_features.len() as f32;
+        let mut index = Self {
+            vectors: HashMap::new(),
+            postings: vec![vec![]; kind_weights.len()],
+        };
+
+        for (movie_id, feature_ids) in movie_features {
+            let mut vector: Vec<(usize, f32)> = feature_ids
+                .into_iter()
+                .map(|id| {
+                    let rarity = 1.0 + (library_size / movie_counts[id] as f32).ln();
+                    (id, kind_weights[id] * rarity)
+                })
+                .collect();
+
+            let norm = vector.iter().map(|(_, w)| w * w).sum::<f32>().sqrt();
+            if norm == 0.0 {
+                continue;
+            }
+            for (feature_id, weight) in vector.iter_mut() {
+                *weight /= norm;
+                index.postings[*feature_id].push((movie_id, *weight));
+            }
+            index.vectors.insert(movie_id, vector);
+        }
+        index
+    }
+
+    pub fn len(&self) -> usize {
+        self.vectors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.vectors.is_empty()
+    }
+
+    /// Returns the movies most similar to `media_id` with their similarity, most similar first.
+    pub fn similar(&self, media_id: i64, limit: usize) -> Vec<(i64, f32)> {
+        let mut ranked: Vec<(i64, f32)> = self.similarities(media_id).into_iter().collect();
+        sort_ranked(&mut ranked);
+        ranked.truncate(limit);
+        ranked
+    }
+
+    /// Ranks the unwatched movies against a weighted watch history.
+    ///
+    /// A movie scores the weighted sum of its similarities to the history and is recommended
+    /// because of the watched movie contributing the most, so each seed of `history` is
+    /// returned with its own candidates, best first. Seeds without candidates are left out.
+    pub fn because_you_watched(
+        &self,
+        history: &[(i64, f32)],
+        watched: &HashSet<i64>,
+    ) -> Vec<(i64, Vec<(i64, f32)>)> {
+        // Candidate to its total score, best seed and the contribution of that seed
+        let mut candidates: HashMap<i64, (f32, i64, f32)> = HashMap::new();
+        for (seed, seed_weight) in history {
+            for (other, similarity) in self.similarities(*seed) {
+                if watched.contains(&other) {
+                    continue;
+                }
+                let contribution = seed_weight * similarity;
+                let candidate = candidates.entry(other).or_insert((0.0, *seed, 0.0));
+                candidate.0 += contribution;
+                if contribution > candidate.2 {
+                    candidate.1 = *seed;
+                    candidate.2 = contribution;
+                }
+            }
+        }
+
+        let mut groups: Vec<(i64, Vec<(i64, f32)>)> =
+            history.iter().map(|(seed, _)| (*seed, vec![])).collect();
+        for (candidate, (score, seed, _)) in candidates {
+            if let Some((_, items)) = groups.iter_mut().find(|(known, _)| *known == seed) {
+                items.push((candidate, score));
+            }
+        }
+        groups.retain(|(_, items)| !items.is_empty());
+        for (_, items) in groups.iter_mut() {
+            sort_ranked(items);
+        }
+        groups
+    }
+
+    /// Returns the cosine similarity of every movie sharing a feature with `media_id`, itself left out.
+    fn similarities(&self, media_id: i64) -> HashMap<i64, f32> {
+        let mut scores: HashMap<i64, f32> = HashMap::new();
+        if let Some(vector) = self.vectors.get(&media_id) {
+            for (feature_id, weight) in vector {
+                for (other, other_weight) in self.postings[*feature_id].iter() {
+                    if *other != media_id {
+                        *scores.entry(*other).or_default() += weight * other_weight;
+                    }
+                }
+            }
+        }
+        scores
+    }
+}
+
+/// Sorts scored movies by decreasing score, ties broken by id to keep the order stable.
+fn sort_ranked(ranked: &mut [(i64, f32)]) {
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+}
+
+// region: ---- GLOBAL INDEX ----
+
+/// Rebuilds the shared recommendation index from the genres, credits, keywords and release dates.
+pub fn refresh_content_index(db_path: &str) -> Result<()> {
+    let data_getter = DataGetter::new(db_path.to_owned())?;
+    let features = data_getter
+        .get_movie_features(TOP_CAST)
+        .context("Failed to load movie features")?;
+    let index = ContentIndex::build(features);
+
+    tracing::info!(movies = index.len(), "Recommendation index refreshed");
+
+    *CONTENT_INDEX
+        .write()
+        .map_err(|_| anyhow!("Recommendation index lock poisoned"))? = index;
+    Ok(())
+}
+
+/// Returns the media most similar to `media_id` the profile may see, most similar first.
+pub fn get_similar(
+    db_path: &str,
+    profile_id: i64,
+    media_id: i64,
+    limit: usize,
+) -> Result<Vec<MovieSnapshot>> {
+    // Some of the candidates may be hidden from the profile, so a few extra are fetched
+    let ranked = with_index(db_path, |index| index.similar(media_id, limit * 2))?;
+    let media_ids: Vec<i64> = ranked.iter().map(|(id, _)| *id).collect();
+
+    let data_getter = DataGetter::new(db_path.to_owned())?;
+    let mut snapshots = data_getter.get_snapshots_by_ids(profile_id, &media_ids)?;
+    snapshots.truncate(limit);
+    Ok(snapshots)
+}
+
+/// Recommends unwatched media from the last watched ones of a profile.
+/// Recent watches weigh more, and rated ones weigh by their rating, 5 out of 10 being neutral.
+pub fn get_because_you_watched(
+    db_path: &str,
+    profile_id: i64,
+    limit: usize,
+) -> Result<Vec<WatchRecommendation>> {
+    let data_getter = DataGetter::new(db_path.to_owned())?;
+    let history: Vec<(i64, f32)> = data_getter
+        .get_recently_watched(profile_id, HISTORY_SEEDS)?
+        .iter()
+        .enumerate()
+        .map(|(rank, entry)| {
+            let recency = HISTORY_DECAY.powi(rank as i32);
+            let rating = entry.state.user_rating.map_or(1.0, |rating| rating / 5.0);
+            (entry.media.id, recency * rating)
+        })
+        .filter(|(_, weight)| *weight > 0.0)
+        .collect();
+    let watched = data_getter.get_watched_ids(profile_id)?;
+
+    let groups = with_index(db_path, |index| {
+        index.because_you_watched(&history, &watched)
+    })?;
+
+    let mut recommendations = vec![];
+    for (seed, items) in groups {
+        let Some(source) = data_getter.get_snapshots_by_ids(profile_id, &[seed])?.pop() else {
+            continue;
+        };
+        let media_ids: Vec<i64> = items.iter().map(|(id, _)| *id).take(limit * 2).collect();
+        let mut snapshots = data_getter.get_snapshots_by_ids(profile_id, &media_ids)?;
+        snapshots.truncate(limit);
+        if !snapshots.is_empty() {
+            recommendations.push(WatchRecommendation::new(source, snapshots));
+        }
+    }
+    Ok(recommendations)
+}
+
+/// Runs a query on the shared index, building it first if no scan refreshed it yet.
+fn with_index<T>(db_path: &str, query: impl FnOnce(&ContentIndex) -> T) -> Result<T> {
+    let is_empty = CONTENT_INDEX
+        .read()
+        .map_err(|_| anyhow!("Recommendation index lock poisoned"))?
+        .is_empty();
+    if is_empty {
+        refresh_content_index(db_path)?;
+    }
+
+    let index = CONTENT_INDEX
+        .read()
+        .map_err(|_| anyhow!("Recommendation index lock poisoned"))?;
+    Ok(query(&index))
+}
+// endregion
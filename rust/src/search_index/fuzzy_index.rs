@@ -0,0 +1,388 @@
+use crate::{db_interface::data_getter::DataGetter, movie_data::movie_data::SearchResult};
+use anyhow::{anyhow, Context, Result};
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, RwLock},
+};
+
+/// Minimum score a result needs to be returned by a quick search.
+const MIN_SCORE: f32 = 0.35;
+
+/// Process wide index shared by the quick search api, rebuilt after each scan.
+/// `None` until first built, an empty library still yielding a built empty index.
+static QUICK_SEARCH_INDEX: LazyLock<RwLock<Option<FuzzyIndex>>> =
+    LazyLock::new(|| RwLock::new(None));
+
+type Trigram = [char; 3];
+
+struct IndexEntry {
+    result: SearchResult,
+    words: Vec<Vec<char>>,
+    normalized: String,
+    trigram_count: usize,
+}
+
+/// In-memory typo tolerant index over movie titles and person names.
+///
+/// Candidates are gathered through a trigram posting list, then ranked by combining
+/// trigram coverage with a per word edit distance, so "matirx" still finds "The Matrix".
+#[derive(Default)]
+pub struct FuzzyIndex {
+    entries: Vec<IndexEntry>,
+    trigrams: HashMap<Trigram, Vec<usize>>,
+}
+
+impl FuzzyIndex {
+    pub fn build(results: Vec<SearchResult>) -> Self {
+        let mut index = Self::default();
+
+        for result in results {
+            let normalized = normalize(&format!("{} {}", result.title, result.subtitle));
+            let trigrams = trigrams(&normalized);
+            let entry_id = index.entries.len();
+
+            for trigram in trigrams.iter() {
+                let postings = index.trigrams.entry(*trigram).or_default();
+                if postings.last() != Some(&entry_id) {
+                    postings.push(entry_id);
+                }
+            }
+
+            index.entries.push(IndexEntry {
+                words: normalized.split(' ').map(|w| w.chars().collect()).collect(),
+                trigram_count: trigrams.len(),
+                normalized,
+                result,
+            });
+        }
+        index
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the best matches for an as-you-type query, highest score first.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SearchResult> {
+        let normalized_query = normalize(query);
+        if normalized_query.is_empty() {
+            return Vec::new();
+        }
+
+        let query_trigrams = trigrams(&normalized_query);
+        let mut hits = vec![0u32; self.entries.len()];
+        for trigram in query_trigrams.iter() {
+            if let Some(postings) = self.trigrams.get(trigram) {
+                for entry_id in postings {
+                    hits[*entry_id] += 1;
+                }
+            }
+        }
+
+        let query_words: Vec<Vec<char>> = normalized_query
+            .split(' ')
+            .map(|w| w.chars().collect())
+            .collect();
+
+        let mut results: Vec<SearchResult> = hits
+            .iter()
+            .enumerate()
+            .filter(|(_, hit)| **hit > 0)
+            .filter_map(|(entry_id, hit)| {
+                let entry = &self.entries[entry_id];
+                let score = self.score(
+                    entry,
+                    *hit,
+                    &query_trigrams,
+                    &query_words,
+                    &normalized_query,
+                );
+                if score < MIN_SCORE {
+                    return None;
+                }
+                let mut result = entry.result.clone();
+                result.score = score;
+                Some(result)
+            })
+            .collect();
+
+        results.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.title.len().cmp(&b.title.len()))
+                .then_with(|| a.title.cmp(&b.title))
+        });
+        results.truncate(limit);
+        results
+    }
+
+    fn score(
+        &self,
+        entry: &IndexEntry,
+        hits: u32,
+        query_trigrams: &[Trigram],
+        query_words: &[Vec<char>],
+        normalized_query: &str,
+    ) -> f32 {
+        let coverage = hits as f32 / query_trigrams.len().max(1) as f32;
+        let density = hits as f32 / entry.trigram_count.max(1) as f32;
+
+        // The last word is being typed, so it is only compared against word prefixes.
+        let last = query_words.len() - 1;
+        let word_score = query_words
+            .iter()
+            .enumerate()
+            .map(|(position, query_word)| {
+                entry
+                    .words
+                    .iter()
+                    .map(|word| word_similarity(query_word, word, position == last))
+                    .fold(0.0, f32::max)
+            })
+            .sum::<f32>()
+            / query_words.len() as f32;
+
+        let prefix_bonus = if entry.normalized.starts_with(normalized_query) {
+            0.15
+        } else {
+            0.0
+        };
+
+        (0.35 * coverage + 0.5 * word_score + 0.15 * density + prefix_bonus).min(1.0)
+    }
+}
+
+// region: ---- GLOBAL INDEX ----
+
+/// Rebuilds the shared quick search index from the `Movie` and `Person` tables.
+pub fn refresh_search_index(db_path: &str) -> Result<()> {
+    let data_getter = DataGetter::new(db_path.to_owned())?;
+    let entries = data_getter
+        .get_search_entries()
+        .context("Failed to load quick search entries")?;
+    let index = FuzzyIndex::build(entries);
+
+    tracing::info!(entries = index.len(), "Quick search index refreshed");
+
+    *QUICK_SEARCH_INDEX
+        .write()
+        .map_err(|_| anyhow!("Quick search index lock poisoned"))? = Some(index);
+    Ok(())
+}
+
+/// Queries the shared index, building it first if no scan refreshed it yet.
+/// Movies above the content rating limit of the profile are left out.
+pub fn search_index(
+    db_path: &str,
+    profile_id: i64,
+    query: &str,
+    limit: usize,
+) -> Result<Vec<SearchResult>> {
+    let is_built = QUICK_SEARCH_INDEX
+        .read()
+        .map_err(|_| anyhow!("Quick search index lock poisoned"))?
+        .is_some();
+    if !is_built {
+        refresh_search_index(db_path)?;
+    }
+
+    let results = QUICK_SEARCH_INDEX
+        .read()
+        .map_err(|_| anyhow!("Quick search index lock poisoned"))?
+        .as_ref()
+        .map(|index| index.search(query, limit))
+        .unwrap_or_default();
+
+    let data_getter = DataGetter::new(db_path.to_owned())?;
+    let mut allowed = Vec::with_capacity(results.len());
+    for result in results {
+        if result.kind != "movie" || data_getter.is_media_allowed(profile_id, result.id)? {
+            allowed.push(result);
+        }
+    }
+    Ok(allowed)
+}
+// endregion
+
+// region: ---- TEXT HELPERS ----
+
+/// Lowercases the text and collapses everything that is not alphanumeric into single spaces.
+fn normalize(text: &str) -> String {
+    text.chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .flat_map(char::to_lowercase)
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Word level trigrams, padded so that short and leading fragments still produce matches.
+fn trigrams(normalized: &str) -> Vec<Trigram> {
+    let mut result = Vec::new();
+    for word in normalized.split(' ').filter(|w| !w.is_empty()) {
+        let padded: Vec<char> = "  "
+            .chars()
+            .chain(word.chars())
+            .chain(" ".chars())
+            .collect();
+        for window in padded.windows(3) {
+            let trigram = [window[0], window[1], window[2]];
+            if !result.contains(&trigram) {
+                result.push(trigram);
+            }
+        }
+    }
+    result
+}
+
+/// Similarity in [0, 1] between a query word and an indexed word, based on edit distance.
+fn word_similarity(query_word: &[char], word: &[char], as_prefix: bool) -> f32 {
+    let target = if as_prefix && word.len() > query_word.len() {
+        &word[..query_word.len()]
+    } else {
+        word
+    };
+
+    let distance = edit_distance(query_word, target);
+    let length = query_word.len().max(target.len()).max(1);
+    1.0 - distance as f32 / length as f32
+}
+
+/// Optimal string alignment distance, counting adjacent transpositions as a single edit.
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut rows = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in rows[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            let mut best = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = best;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+// endregion
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+
+    fn movie(id: i64, title: &str) -> SearchResult {
+        SearchResult::new(
+            "movie".to_owned(),
+            id,
+            title.to_owned(),
+            String::new(),
+            None,
+        )
+    }
+
+    fn titles(results: &[SearchResult]) -> Vec<&str> {
+        results.iter().map(|result| result.title.as_str()).collect()
+    }
+
+    #[test]
+    fn edit_distance_counts_single_edits() {
+        assert_eq!(edit_distance(&chars("matrix"), &chars("matrix")), 0);
+        assert_eq!(edit_distance(&chars("matrix"), &chars("matrex")), 1);
+        assert_eq!(edit_distance(&chars("matrix"), &chars("matrx")), 1);
+        assert_eq!(edit_distance(&chars("matrix"), &chars("the matrix")), 4);
+        assert_eq!(edit_distance(&chars(""), &chars("alien")), 5);
+    }
+
+    #[test]
+    fn edit_distance_counts_transposition_once() {
+        assert_eq!(edit_distance(&chars("matirx"), &chars("matrix")), 1);
+        assert_eq!(edit_distance(&chars("ca"), &chars("ac")), 1);
+    }
+
+    #[test]
+    fn word_similarity_compares_last_word_as_prefix() {
+        assert_eq!(
+            word_similarity(&chars("term"), &chars("terminator"), true),
+            1.0
+        );
+        assert!(word_similarity(&chars("term"), &chars("terminator"), false) < 0.5);
+    }
+
+    #[test]
+    fn normalize_lowercases_and_collapses_punctuation() {
+        assert_eq!(
+            normalize("  Amélie: Le Fabuleux-Destin! "),
+            "amélie le fabuleux destin"
+        );
+        assert_eq!(normalize("?!"), "");
+    }
+
+    #[test]
+    fn trigrams_are_padded_per_word_without_duplicates() {
+        assert_eq!(
+            trigrams("up"),
+            vec![[' ', ' ', 'u'], [' ', 'u', 'p'], ['u', 'p', ' ']]
+        );
+        assert_eq!(trigrams("aa aa").len(), 3);
+    }
+
+    #[test]
+    fn search_tolerates_typos() {
+        let index = FuzzyIndex::build(vec![
+            movie(1, "The Matrix"),
+            movie(2, "Mad Max"),
+            movie(3, "Amadeus"),
+        ]);
+        let results = index.search("matirx", 10);
+        assert_eq!(titles(&results).first(), Some(&"The Matrix"));
+        assert!(!titles(&results).contains(&"Amadeus"));
+    }
+
+    #[test]
+    fn search_matches_the_word_being_typed_as_prefix() {
+        let index = FuzzyIndex::build(vec![
+            movie(1, "Star Wars"),
+            movie(2, "Starship Troopers"),
+            movie(3, "Jaws"),
+        ]);
+        let results = index.search("star w", 10);
+        assert_eq!(titles(&results).first(), Some(&"Star Wars"));
+        assert!(!titles(&results).contains(&"Jaws"));
+    }
+
+    #[test]
+    fn search_ranks_best_score_first_and_respects_limit() {
+        let index = FuzzyIndex::build(vec![
+            movie(1, "Alien"),
+            movie(2, "Aliens"),
+            movie(3, "Alien 3"),
+        ]);
+        let results = index.search("alien", 2);
+        assert_eq!(results.len(), 2);
+        assert!(results[0].score >= results[1].score);
+        assert_eq!(results[0].title, "Alien");
+    }
+
+    #[test]
+    fn search_returns_nothing_for_blank_or_unrelated_queries() {
+        let index = FuzzyIndex::build(vec![movie(1, "The Matrix")]);
+        assert!(index.search("  ", 10).is_empty());
+        assert!(index.search("zzqx", 10).is_empty());
+        assert!(FuzzyIndex::build(Vec::new())
+            .search("matrix", 10)
+            .is_empty());
+    }
+}
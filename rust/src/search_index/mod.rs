@@ -0,0 +1 @@
+pub mod fuzzy_index;
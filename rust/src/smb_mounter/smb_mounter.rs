@@ -9,35 +9,33 @@ pub fn mount_smb(
     folder_path: &str,
     mount_point: &str,
 ) -> Result<()> {
-    #[cfg(target_os = "linux")]
-    {
-        fs::create_dir_all(mount_point)?;
-
-        Command::new("sudo")
-            .arg("mount")
-            .arg("-t")
-            .arg("cifs")
-            .arg(format!("//{}/{}", ip, folder_path))
-            .arg(mount_point)
-            .arg("-o")
-            .arg(format!("username={},password={}", user, password))
-            .status()
-            .map(|_| ())?;
-        return Ok(());
+    if !cfg!(target_os = "linux") {
+        return Err(anyhow!("Target os not supported: "));
     }
-    return Err(anyhow!("Target os not supported: "));
+    fs::create_dir_all(mount_point)?;
+
+    Command::new("sudo")
+        .arg("mount")
+        .arg("-t")
+        .arg("cifs")
+        .arg(format!("//{}/{}", ip, folder_path))
+        .arg(mount_point)
+        .arg("-o")
+        .arg(format!("username={},password={}", user, password))
+        .status()
+        .map(|_| ())?;
+    Ok(())
 }
 
 pub fn unmount_smb(mount_point: &str) -> Result<()> {
-    #[cfg(target_os = "linux")]
-    {
-        Command::new("sudo")
-            .arg("umount")
-            .arg("-l")
-            .arg(mount_point)
-            .status()
-            .map(|_| ())?;
-        return Ok(());
+    if !cfg!(target_os = "linux") {
+        return Err(anyhow!("Target os not supported: "));
     }
-    return Err(anyhow!("Target os not supported: "));
+    Command::new("sudo")
+        .arg("umount")
+        .arg("-l")
+        .arg(mount_point)
+        .status()
+        .map(|_| ())?;
+    Ok(())
 }
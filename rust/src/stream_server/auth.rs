@@ -0,0 +1,104 @@
+use crate::{
+    db_interface::{data_getter::DataGetter, data_saver::DataSaver},
+    stream_server::rest_api::ApiError,
+};
+use axum::{
+    extract::{ConnectInfo, Request},
+    http::header,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::{
+    net::SocketAddr,
+    sync::atomic::{AtomicU32, Ordering},
+};
+
+/// Wrong pairing codes accepted before every pending code is dropped.
+pub const MAX_PAIRING_ATTEMPTS: u32 = 5;
+
+/// Wrong pairing codes received since the last successful pairing.
+static FAILED_PAIRINGS: AtomicU32 = AtomicU32::new(0);
+
+/// Profile a request acts as, set by `authenticate` for the handlers.
+#[derive(Debug, Clone, Copy)]
+pub struct RequestProfile(pub i64);
+
+/// Resolves the profile of a request, layered on every route but `POST /api/<version>/pair`.
+///
+/// Requests from the loopback interface come from the app itself and act as the active profile.
+/// Any other request needs the token of a paired device, sent as a bearer `Authorization`
+/// header or, for players that can not set headers, as a `token` query parameter.
+pub async fn authenticate(
+    ConnectInfo(address): ConnectInfo<SocketAddr>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let profile_id = if address.ip().is_loopback() {
+        DataGetter::new("movie_db.db".to_owned())
+            .and_then(|data_getter| data_getter.get_active_profile_id())
+    } else {
+        let Some(token) = request_token(&request) else {
+            return ApiError::Unauthorized("Missing device token".to_owned()).into_response();
+        };
+        match device_profile(&token) {
+            Ok(Some(profile_id)) => Ok(profile_id),
+            Ok(None) => {
+                tracing::warn!(address = %address, "Request with an unknown device token");
+                return ApiError::Unauthorized("Unknown device token".to_owned()).into_response();
+            }
+            Err(e) => Err(e),
+        }
+    };
+
+    match profile_id {
+        Ok(profile_id) => {
+            request.extensions_mut().insert(RequestProfile(profile_id));
+            next.run(request).await
+        }
+        Err(e) => ApiError::Internal(e).into_response(),
+    }
+}
+
+/// Counts a wrong pairing code, dropping the pending codes once too many were guessed.
+pub fn record_failed_pairing() -> anyhow::Result<()> {
+    if FAILED_PAIRINGS.fetch_add(1, Ordering::SeqCst) + 1 >= MAX_PAIRING_ATTEMPTS {
+        FAILED_PAIRINGS.store(0, Ordering::SeqCst);
+        tracing::warn!("Too many wrong pairing codes, pending codes dropped");
+        DataSaver::new("movie_db.db".to_owned())?.clear_pairing_codes()?;
+    }
+    Ok(())
+}
+
+pub fn record_successful_pairing() {
+    FAILED_PAIRINGS.store(0, Ordering::SeqCst);
+}
+
+/// Returns the profile of a device token, marking the device as seen.
+fn device_profile(token: &str) -> anyhow::Result<Option<i64>> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let Some((device_id, profile_id)) = data_getter.get_device_for_token(token)? else {
+        return Ok(None);
+    };
+    DataSaver::new("movie_db.db".to_owned())?.touch_device(device_id)?;
+    Ok(Some(profile_id))
+}
+
+fn request_token(request: &Request) -> Option<String> {
+    let bearer = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(|token| token.trim().to_owned());
+    if bearer.is_some() {
+        return bearer;
+    }
+
+    request.uri().query().and_then(|query| {
+        query
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .find(|(name, _)| *name == "token")
+            .map(|(_, token)| urlencoding::decode(token).map(|t| t.into_owned()).ok())?
+    })
+}
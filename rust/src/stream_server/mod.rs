@@ -0,0 +1,4 @@
+pub mod auth;
+pub mod openapi;
+pub mod rest_api;
+pub mod stream_server;
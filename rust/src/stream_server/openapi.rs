@@ -0,0 +1,99 @@
+use crate::stream_server::rest_api::{self, API_VERSION};
+use utoipa::{
+    openapi::{
+        schema::{KnownFormat, ObjectBuilder, SchemaFormat, Type},
+        security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme},
+        OpenApi as OpenApiDocument, RefOr, Server,
+    },
+    Modify, OpenApi,
+};
+
+/// OpenAPI description of the REST api, derived from its handlers and the types they answer.
+///
+/// The schemas of the bodies and answers are collected from the routes, their descriptions
+/// coming from the doc comments of the handlers and of the fields.
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Fluster Media Center"),
+    paths(
+        rest_api::get_openapi,
+        rest_api::pair,
+        rest_api::list_media,
+        rest_api::get_media,
+        rest_api::get_media_cast,
+        rest_api::get_media_crew,
+        rest_api::get_media_subtitles,
+        rest_api::get_media_poster,
+        rest_api::get_media_backdrop,
+        rest_api::get_playback,
+        rest_api::put_playback,
+        rest_api::put_watched,
+        rest_api::post_playback_decision,
+        rest_api::get_person,
+        rest_api::get_person_picture,
+        rest_api::search,
+        rest_api::get_events,
+    ),
+    security(("bearerToken" = []), ("queryToken" = [])),
+    modifiers(&ServerInfo)
+)]
+struct ApiDoc;
+
+/// Sets the version, the server prefix, the token schemes and the image bodies, which depend
+/// on `API_VERSION` or can not be declared in the derive.
+struct ServerInfo;
+
+impl Modify for ServerInfo {
+    fn modify(&self, openapi: &mut OpenApiDocument) {
+        openapi.info.version = API_VERSION.to_owned();
+        openapi.info.description = None;
+        openapi.info.license = None;
+        openapi.servers = Some(vec![Server::new(format!("/api/{}", API_VERSION))]);
+
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearerToken",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .description(Some(
+                        "Token of a paired device, not needed from the host itself",
+                    ))
+                    .build(),
+            ),
+        );
+        components.add_security_scheme(
+            "queryToken",
+            SecurityScheme::ApiKey(ApiKey::Query(ApiKeyValue::with_description(
+                "token",
+                "Token of a paired device, for players that can not set headers",
+            ))),
+        );
+
+        // `[u8]` is described as an array of integers, images are sent as raw bytes
+        let operations = openapi
+            .paths
+            .paths
+            .values_mut()
+            .flat_map(|item| item.get.iter_mut());
+        for operation in operations {
+            for response in operation.responses.responses.values_mut() {
+                if let RefOr::T(response) = response {
+                    if let Some(image) = response.content.get_mut("image/*") {
+                        image.schema = Some(
+                            ObjectBuilder::new()
+                                .schema_type(Type::String)
+                                .format(Some(SchemaFormat::KnownFormat(KnownFormat::Binary)))
+                                .into(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Builds the OpenAPI description of the REST api.
+pub fn openapi_document() -> OpenApiDocument {
+    ApiDoc::openapi()
+}
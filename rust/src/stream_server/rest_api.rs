@@ -0,0 +1,686 @@
+use crate::{
+    db_interface::{data_getter::DataGetter, data_saver::DataSaver},
+    event_bus::event_bus::library_events,
+    movie_data::movie_data::{
+        LibraryEvent, MediaData, MovieSnapshot, PersonData, PersonSnapshot, PlaybackDecision,
+        PlaybackRequest, PlaybackState, SearchResult, SmartFilter, SmartQuery, SmartSort,
+        SubtitleFile,
+    },
+    search_index::fuzzy_index::search_index,
+    stream_server::{
+        auth::{authenticate, record_failed_pairing, record_successful_pairing, RequestProfile},
+        openapi::openapi_document,
+    },
+    transcoder::transcoder::prepare_playback,
+};
+use async_stream::stream;
+use axum::{
+    extract::{Path, Query},
+    http::{header, StatusCode},
+    middleware,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
+    routing::{get, post},
+    Extension, Json, Router,
+};
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, convert::Infallible};
+use utoipa::{
+    openapi::{ContentBuilder, RefOr, Response as ApiResponse, ResponseBuilder},
+    IntoParams, IntoResponses, PartialSchema, ToSchema,
+};
+
+/// Version of the REST api, prefixing every route as `/api/<version>`.
+pub const API_VERSION: &str = "v1";
+
+/// Number of media returned by a page of `GET /media` when not given.
+const DEFAULT_PAGE_SIZE: u32 = 50;
+
+/// Largest page of `GET /media` a client may ask for.
+const MAX_PAGE_SIZE: u32 = 500;
+
+/// Content type of each image extension saved by the artwork download.
+const IMAGE_TYPES: [(&str, &str); 4] = [
+    ("jpg", "image/jpeg"),
+    ("jpeg", "image/jpeg"),
+    ("png", "image/png"),
+    ("webp", "image/webp"),
+];
+
+/// Error answered as `{"error": "<message>"}` with the matching status code.
+pub enum ApiError {
+    NotFound(String),
+    BadRequest(String),
+    Unauthorized(String),
+    Internal(anyhow::Error),
+}
+
+impl From<anyhow::Error> for ApiError {
+    fn from(e: anyhow::Error) -> Self {
+        match e.downcast_ref::<rusqlite::Error>() {
+            Some(rusqlite::Error::QueryReturnedNoRows) => ApiError::NotFound(e.to_string()),
+            _ => ApiError::Internal(e),
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, message) = match self {
+            ApiError::NotFound(message) => (StatusCode::NOT_FOUND, message),
+            ApiError::BadRequest(message) => (StatusCode::BAD_REQUEST, message),
+            ApiError::Unauthorized(message) => (StatusCode::UNAUTHORIZED, message),
+            // The cause may hold paths or queries, it is only logged
+            ApiError::Internal(e) => {
+                tracing::error!("REST api request failed \n Caused by {:?}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Internal server error".to_owned(),
+                )
+            }
+        };
+        (status, Json(ErrorBody { error: message })).into_response()
+    }
+}
+
+/// Describes the error statuses every route may answer with in the OpenAPI document.
+impl IntoResponses for ApiError {
+    fn responses() -> BTreeMap<String, RefOr<ApiResponse>> {
+        [
+            ("400", "Invalid request"),
+            ("401", "Missing or unknown device token"),
+            ("404", "Not found"),
+            ("500", "Internal error"),
+        ]
+        .into_iter()
+        .map(|(status, description)| {
+            let content = ContentBuilder::new().schema(Some(ErrorBody::schema())).build();
+            let response = ResponseBuilder::new()
+                .description(description)
+                .content("application/json", content)
+                .build();
+            (status.to_owned(), response.into())
+        })
+        .collect()
+    }
+}
+
+/// Body of an error answer.
+#[derive(Serialize, Debug, ToSchema)]
+pub struct ErrorBody {
+    pub error: String,
+}
+
+type ApiResult<T> = Result<Json<T>, ApiError>;
+
+// region: ---- REQUESTS ----
+
+/// Filters, sort and page of `GET /media`, lists being comma-separated.
+#[derive(Deserialize, Debug, Default, IntoParams)]
+#[serde(default)]
+#[into_params(parameter_in = Query)]
+pub struct MediaListQuery {
+    /// Comma-separated genre names, any of which matches.
+    pub genres: Option<String>,
+    /// Comma-separated TMDB ids of credited persons, any of which matches.
+    pub persons: Option<String>,
+    /// Comma-separated resolution tags of the file name, e.g. 2160p.
+    pub resolutions: Option<String>,
+    /// Earliest release year.
+    pub year_from: Option<u32>,
+    /// Latest release year.
+    pub year_to: Option<u32>,
+    /// Lowest TMDB rating, from 0 to 10.
+    pub min_rating: Option<f32>,
+    /// Highest TMDB rating, from 0 to 10.
+    pub max_rating: Option<f32>,
+    /// Only the watched or the unwatched media.
+    pub watched: Option<bool>,
+    /// Only the media added in the last days.
+    pub added_within_days: Option<u32>,
+    #[param(inline)]
+    pub sort: SmartSort,
+    /// Reverses the sort order.
+    pub descending: bool,
+    /// Zero-based page.
+    pub page: u32,
+    /// Media per page, 50 by default and 500 at most.
+    pub page_size: Option<u32>,
+    /// Shuffle of the random sort, to be kept while paging.
+    pub seed: u32,
+}
+
+#[derive(Deserialize, Debug, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SearchQuery {
+    /// Text to search.
+    pub q: String,
+    /// Maximum number of results.
+    pub limit: Option<u32>,
+}
+
+/// Body of `PUT /media/{media_id}/playback`, in seconds.
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct ProgressReport {
+    pub position: f64,
+    pub duration: f64,
+}
+
+/// Body of `PUT /media/{media_id}/watched`.
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct WatchedUpdate {
+    pub watched: bool,
+}
+
+/// Body of `POST /pair`, the code being shown by the app.
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct PairingRequest {
+    pub code: String,
+    pub device_name: String,
+}
+
+/// Answer of `POST /pair`, the token being sent with every later request.
+#[derive(Serialize, Debug, ToSchema)]
+pub struct PairingResponse {
+    pub device_id: i64,
+    pub profile_id: i64,
+    pub token: String,
+}
+// endregion
+
+/// Routes of the REST api, to be nested under `/api/<API_VERSION>`.
+/// Every route but `POST /pair` goes through `authenticate`.
+pub fn router<S: Clone + Send + Sync + 'static>() -> Router<S> {
+    Router::new()
+        .route("/openapi.json", get(get_openapi))
+        .route("/media", get(list_media))
+        .route("/media/{media_id}", get(get_media))
+        .route("/media/{media_id}/cast", get(get_media_cast))
+        .route("/media/{media_id}/crew", get(get_media_crew))
+        .route("/media/{media_id}/subtitles", get(get_media_subtitles))
+        .route("/media/{media_id}/poster", get(get_media_poster))
+        .route("/media/{media_id}/backdrop", get(get_media_backdrop))
+        .route(
+            "/media/{media_id}/playback",
+            get(get_playback).put(put_playback),
+        )
+        .route("/media/{media_id}/watched", axum::routing::put(put_watched))
+        .route(
+            "/media/{media_id}/playback-decision",
+            post(post_playback_decision),
+        )
+        .route("/persons/{tmdb_id}", get(get_person))
+        .route("/persons/{tmdb_id}/picture", get(get_person_picture))
+        .route("/search", get(search))
+        .route("/events", get(get_events))
+        .route_layer(middleware::from_fn(authenticate))
+        .route("/pair", post(pair))
+}
+
+// Opens the database for the reads of a profile, answering not found for the media it may not see
+fn media_getter(profile_id: i64, media_id: i64) -> Result<DataGetter, ApiError> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    if !data_getter.is_media_allowed(profile_id, media_id)? {
+        return Err(ApiError::NotFound(format!(
+            "No media found for id: {}",
+            media_id
+        )));
+    }
+    Ok(data_getter)
+}
+
+// region: ---- HANDLERS ----
+
+/// Returns this OpenAPI document.
+#[utoipa::path(
+    get,
+    path = "/openapi.json",
+    responses(
+        (status = 200, description = "OpenAPI document", content_type = "application/json"),
+        ApiError
+    )
+)]
+async fn get_openapi() -> Json<utoipa::openapi::OpenApi> {
+    Json(openapi_document())
+}
+
+/// Pairs a device with the one-time code shown by the app, returning its token.
+#[utoipa::path(
+    post,
+    path = "/pair",
+    request_body = PairingRequest,
+    security(()),
+    responses(
+        (status = 200, body = PairingResponse),
+        ApiError
+    )
+)]
+async fn pair(Json(request): Json<PairingRequest>) -> ApiResult<PairingResponse> {
+    let device_name = request.device_name.trim();
+    if device_name.is_empty() {
+        return Err(ApiError::BadRequest(
+            "device_name must not be empty".to_owned(),
+        ));
+    }
+
+    let mut data_saver = DataSaver::new("movie_db.db".to_owned())?;
+    match data_saver.pair_device(request.code.trim(), device_name)? {
+        Some((device, token)) => {
+            record_successful_pairing();
+            tracing::info!(device_id = device.id, name = device_name, "Device paired");
+            Ok(Json(PairingResponse {
+                device_id: device.id,
+                profile_id: device.profile_id,
+                token,
+            }))
+        }
+        None => {
+            record_failed_pairing()?;
+            Err(ApiError::Unauthorized(
+                "Unknown or expired pairing code".to_owned(),
+            ))
+        }
+    }
+}
+
+/// Lists a page of the library, filtered and sorted.
+#[utoipa::path(
+    get,
+    path = "/media",
+    params(MediaListQuery),
+    responses(
+        (status = 200, body = Vec<MovieSnapshot>),
+        ApiError
+    )
+)]
+async fn list_media(
+    Extension(RequestProfile(profile_id)): Extension<RequestProfile>,
+    Query(query): Query<MediaListQuery>,
+) -> ApiResult<Vec<MovieSnapshot>> {
+    let page_size = query.page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+    if page_size == 0 || page_size > MAX_PAGE_SIZE {
+        return Err(ApiError::BadRequest(format!(
+            "page_size must be between 1 and {}",
+            MAX_PAGE_SIZE
+        )));
+    }
+
+    let person_ids = split_list(&query.persons)
+        .iter()
+        .map(|id| id.parse::<i64>())
+        .collect::<Result<Vec<i64>, _>>()
+        .map_err(|_| ApiError::BadRequest("persons must be TMDB ids".to_owned()))?;
+
+    let smart_query = SmartQuery {
+        filter: SmartFilter {
+            genres: split_list(&query.genres),
+            year_from: query.year_from,
+            year_to: query.year_to,
+            min_rating: query.min_rating,
+            max_rating: query.max_rating,
+            person_ids,
+            watched: query.watched,
+            resolutions: split_list(&query.resolutions),
+            added_within_days: query.added_within_days,
+        },
+        sort: query.sort,
+        descending: query.descending,
+        limit: None,
+    };
+
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let snapshots = data_getter.get_smart_snapshots(
+        profile_id,
+        &smart_query,
+        query.page,
+        page_size,
+        query.seed,
+    )?;
+    Ok(Json(snapshots))
+}
+
+/// Returns the details of a media, its seek previews being indexed as WebVTT at
+/// `/media/{media_id}/trickplay/index.vtt` from the server root once generated.
+#[utoipa::path(
+    get,
+    path = "/media/{media_id}",
+    params(("media_id" = i64, Path, description = "Id of the media in the library")),
+    responses(
+        (status = 200, body = MediaData),
+        ApiError
+    )
+)]
+async fn get_media(
+    Extension(RequestProfile(profile_id)): Extension<RequestProfile>,
+    Path(media_id): Path<i64>,
+) -> ApiResult<MediaData> {
+    let data_getter = media_getter(profile_id, media_id)?;
+    Ok(Json(data_getter.get_media_data(media_id)?))
+}
+
+/// Lists the cast of a media in billing order.
+#[utoipa::path(
+    get,
+    path = "/media/{media_id}/cast",
+    params(("media_id" = i64, Path, description = "Id of the media in the library")),
+    responses(
+        (status = 200, body = Vec<PersonSnapshot>),
+        ApiError
+    )
+)]
+async fn get_media_cast(
+    Extension(RequestProfile(profile_id)): Extension<RequestProfile>,
+    Path(media_id): Path<i64>,
+) -> ApiResult<Vec<PersonSnapshot>> {
+    let data_getter = media_getter(profile_id, media_id)?;
+    Ok(Json(data_getter.get_media_cast(media_id)?))
+}
+
+/// Lists the crew of a media by department.
+#[utoipa::path(
+    get,
+    path = "/media/{media_id}/crew",
+    params(("media_id" = i64, Path, description = "Id of the media in the library")),
+    responses(
+        (status = 200, body = Vec<PersonSnapshot>),
+        ApiError
+    )
+)]
+async fn get_media_crew(
+    Extension(RequestProfile(profile_id)): Extension<RequestProfile>,
+    Path(media_id): Path<i64>,
+) -> ApiResult<Vec<PersonSnapshot>> {
+    let data_getter = media_getter(profile_id, media_id)?;
+    Ok(Json(data_getter.get_media_crew(media_id)?))
+}
+
+/// Lists the subtitle files of a media, each served as WebVTT at
+/// `/media/{media_id}/subtitles/{id}` from the server root.
+#[utoipa::path(
+    get,
+    path = "/media/{media_id}/subtitles",
+    params(("media_id" = i64, Path, description = "Id of the media in the library")),
+    responses(
+        (status = 200, body = Vec<SubtitleFile>),
+        ApiError
+    )
+)]
+async fn get_media_subtitles(
+    Extension(RequestProfile(profile_id)): Extension<RequestProfile>,
+    Path(media_id): Path<i64>,
+) -> ApiResult<Vec<SubtitleFile>> {
+    let data_getter = media_getter(profile_id, media_id)?;
+    Ok(Json(data_getter.get_subtitle_files(media_id)?))
+}
+
+/// Returns the poster image of a media.
+#[utoipa::path(
+    get,
+    path = "/media/{media_id}/poster",
+    params(("media_id" = i64, Path, description = "Id of the media in the library")),
+    responses(
+        (status = 200, description = "Image file", content_type = "image/*", body = [u8]),
+        ApiError
+    )
+)]
+async fn get_media_poster(
+    Extension(RequestProfile(profile_id)): Extension<RequestProfile>,
+    Path(media_id): Path<i64>,
+) -> Result<Response, ApiError> {
+    let data_getter = media_getter(profile_id, media_id)?;
+    let media = data_getter.get_media_data(media_id)?;
+    image_response(&media.poster).await
+}
+
+/// Returns the backdrop image of a media.
+#[utoipa::path(
+    get,
+    path = "/media/{media_id}/backdrop",
+    params(("media_id" = i64, Path, description = "Id of the media in the library")),
+    responses(
+        (status = 200, description = "Image file", content_type = "image/*", body = [u8]),
+        ApiError
+    )
+)]
+async fn get_media_backdrop(
+    Extension(RequestProfile(profile_id)): Extension<RequestProfile>,
+    Path(media_id): Path<i64>,
+) -> Result<Response, ApiError> {
+    let data_getter = media_getter(profile_id, media_id)?;
+    let media = data_getter.get_media_data(media_id)?;
+    image_response(&media.backdrop).await
+}
+
+/// Returns the playback state of a media for the requesting profile.
+#[utoipa::path(
+    get,
+    path = "/media/{media_id}/playback",
+    params(("media_id" = i64, Path, description = "Id of the media in the library")),
+    responses(
+        (status = 200, body = PlaybackState),
+        ApiError
+    )
+)]
+async fn get_playback(
+    Extension(RequestProfile(profile_id)): Extension<RequestProfile>,
+    Path(media_id): Path<i64>,
+) -> ApiResult<PlaybackState> {
+    let data_getter = media_getter(profile_id, media_id)?;
+    Ok(Json(data_getter.get_playback_state(profile_id, media_id)?))
+}
+
+/// Records the playback position of a media, marking it watched near the end.
+#[utoipa::path(
+    put,
+    path = "/media/{media_id}/playback",
+    params(("media_id" = i64, Path, description = "Id of the media in the library")),
+    request_body = ProgressReport,
+    responses(
+        (status = 200, body = PlaybackState),
+        ApiError
+    )
+)]
+async fn put_playback(
+    Extension(RequestProfile(profile_id)): Extension<RequestProfile>,
+    Path(media_id): Path<i64>,
+    Json(report): Json<ProgressReport>,
+) -> ApiResult<PlaybackState> {
+    let data_getter = media_getter(profile_id, media_id)?;
+    let mut data_saver = DataSaver::new("movie_db.db".to_owned())?;
+    data_saver.report_progress(profile_id, media_id, report.position, report.duration)?;
+    Ok(Json(data_getter.get_playback_state(profile_id, media_id)?))
+}
+
+/// Marks a media watched or unwatched.
+#[utoipa::path(
+    put,
+    path = "/media/{media_id}/watched",
+    params(("media_id" = i64, Path, description = "Id of the media in the library")),
+    request_body = WatchedUpdate,
+    responses(
+        (status = 200, body = PlaybackState),
+        ApiError
+    )
+)]
+async fn put_watched(
+    Extension(RequestProfile(profile_id)): Extension<RequestProfile>,
+    Path(media_id): Path<i64>,
+    Json(update): Json<WatchedUpdate>,
+) -> ApiResult<PlaybackState> {
+    let data_getter = media_getter(profile_id, media_id)?;
+    let mut data_saver = DataSaver::new("movie_db.db".to_owned())?;
+    data_saver.set_watched(profile_id, media_id, update.watched)?;
+    Ok(Json(data_getter.get_playback_state(profile_id, media_id)?))
+}
+
+/// Decides whether a media is played as is, remuxed or transcoded for the declared client
+/// capabilities, starting the HLS session it needs. The returned url is relative to the server
+/// root, the session being stopped with `DELETE /transcode/{session_id}` from there.
+#[utoipa::path(
+    post,
+    path = "/media/{media_id}/playback-decision",
+    params(("media_id" = i64, Path, description = "Id of the media in the library")),
+    request_body = PlaybackRequest,
+    responses(
+        (status = 200, body = PlaybackDecision),
+        ApiError
+    )
+)]
+async fn post_playback_decision(
+    Extension(RequestProfile(profile_id)): Extension<RequestProfile>,
+    Path(media_id): Path<i64>,
+    Json(request): Json<PlaybackRequest>,
+) -> ApiResult<PlaybackDecision> {
+    match prepare_playback(profile_id, media_id, &request).await? {
+        Some(decision) => Ok(Json(decision)),
+        None => Err(ApiError::NotFound(format!(
+            "No media found for id: {}",
+            media_id
+        ))),
+    }
+}
+
+/// Returns the details of a person.
+#[utoipa::path(
+    get,
+    path = "/persons/{tmdb_id}",
+    params(("tmdb_id" = i64, Path, description = "TMDB id of the person")),
+    responses(
+        (status = 200, body = PersonData),
+        ApiError
+    )
+)]
+async fn get_person(Path(tmdb_id): Path<i64>) -> ApiResult<PersonData> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    Ok(Json(data_getter.get_person_data(tmdb_id)?))
+}
+
+/// Returns the picture of a person.
+#[utoipa::path(
+    get,
+    path = "/persons/{tmdb_id}/picture",
+    params(("tmdb_id" = i64, Path, description = "TMDB id of the person")),
+    responses(
+        (status = 200, description = "Image file", content_type = "image/*", body = [u8]),
+        ApiError
+    )
+)]
+async fn get_person_picture(Path(tmdb_id): Path<i64>) -> Result<Response, ApiError> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let person = data_getter.get_person_data(tmdb_id)?;
+    image_response(person.picture_path.as_deref().unwrap_or_default()).await
+}
+
+/// Searches movie titles and person names, typos included.
+#[utoipa::path(
+    get,
+    path = "/search",
+    params(SearchQuery),
+    responses(
+        (status = 200, body = Vec<SearchResult>),
+        ApiError
+    )
+)]
+async fn search(
+    Extension(RequestProfile(profile_id)): Extension<RequestProfile>,
+    Query(query): Query<SearchQuery>,
+) -> ApiResult<Vec<SearchResult>> {
+    let limit = query.limit.unwrap_or(20).min(MAX_PAGE_SIZE);
+    Ok(Json(search_index(
+        "movie_db.db",
+        profile_id,
+        &query.q,
+        limit as usize,
+    )?))
+}
+
+/// Sends the library changes the requesting profile may see as server-sent events,
+/// each one being the JSON of a `LibraryEvent`.
+#[utoipa::path(
+    get,
+    path = "/events",
+    responses(
+        (
+            status = 200,
+            description = "One JSON object per event, its `type` being media_added, \
+                media_updated, media_removed, watch_state_changed, scan_started, scan_finished \
+                or events_missed",
+            content_type = "text/event-stream",
+            body = String
+        ),
+        ApiError
+    )
+)]
+async fn get_events(
+    Extension(RequestProfile(profile_id)): Extension<RequestProfile>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let events = stream! {
+        let mut events = Box::pin(library_events());
+        while let Some(event) = events.next().await {
+            if !is_event_visible(profile_id, &event) {
+                continue;
+            }
+            match Event::default().json_data(&event) {
+                Ok(sse_event) => yield Ok(sse_event),
+                Err(e) => tracing::error!("Failed to encode library event \n Caused by {:?}", e),
+            }
+        }
+    };
+    Sse::new(events).keep_alive(KeepAlive::default())
+}
+// endregion
+
+// region: ---- HELPERS ----
+
+/// Answers with a saved image file, 404 when there is none.
+async fn image_response(image_path: &str) -> Result<Response, ApiError> {
+    if image_path.is_empty() {
+        return Err(ApiError::NotFound("No image saved".to_owned()));
+    }
+    let bytes = tokio::fs::read(image_path)
+        .await
+        .map_err(|_| ApiError::NotFound(format!("Image not found: {}", image_path)))?;
+
+    let extension = image_path
+        .rsplit('.')
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    let content_type = IMAGE_TYPES
+        .iter()
+        .find(|(known, _)| *known == extension)
+        .map(|(_, content_type)| *content_type)
+        .unwrap_or("application/octet-stream");
+
+    Ok(([(header::CONTENT_TYPE, content_type)], bytes).into_response())
+}
+
+/// Whether a profile may see an event: its own watch states and the media within its limit.
+fn is_event_visible(profile_id: i64, event: &LibraryEvent) -> bool {
+    match event {
+        LibraryEvent::WatchStateChanged {
+            profile_id: owner, ..
+        } => *owner == profile_id,
+        LibraryEvent::MediaAdded { media_id } | LibraryEvent::MediaUpdated { media_id } => {
+            DataGetter::new("movie_db.db".to_owned())
+                .and_then(|data_getter| data_getter.is_media_allowed(profile_id, *media_id))
+                .unwrap_or(false)
+        }
+        _ => true,
+    }
+}
+
+/// Splits a comma-separated query value, leaving out the blank items.
+fn split_list(value: &Option<String>) -> Vec<String> {
+    value
+        .as_deref()
+        .unwrap_or_default()
+        .split(',')
+        .map(|item| item.trim().to_owned())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+// endregion
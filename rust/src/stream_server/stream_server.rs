@@ -0,0 +1,495 @@
+use crate::{
+    db_interface::data_getter::DataGetter,
+    directory_explorer::media_file::{FileSource, MediaSource, OpenedFile},
+    stream_server::{
+        auth::{authenticate, RequestProfile},
+        rest_api::{self, API_VERSION},
+    },
+    subtitles::webvtt::read_as_webvtt,
+    thumbnails::trickplay::{is_trickplay_file, trickplay_folder, INDEX_FILE},
+    transcoder::transcoder::{
+        session_playlist, session_segment, spawn_cache_cleanup, stop_all_sessions, stop_session,
+    },
+};
+use anyhow::{anyhow, Context, Result};
+use async_stream::stream;
+use axum::{
+    body::{Body, Bytes},
+    extract::{Path, RawQuery, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    middleware,
+    response::{IntoResponse, Response},
+    routing::{delete, get},
+    Extension, Router,
+};
+use std::{
+    net::SocketAddr,
+    sync::{Arc, LazyLock},
+};
+use tokio::{
+    sync::{oneshot, Mutex},
+    task::JoinHandle,
+};
+
+/// Size of the blocks a file is read and sent in.
+const CHUNK_SIZE: usize = 512 * 1024;
+
+/// Content type of each playable video extension.
+const CONTENT_TYPES: [(&str, &str); 7] = [
+    ("mp4", "video/mp4"),
+    ("mkv", "video/x-matroska"),
+    ("avi", "video/x-msvideo"),
+    ("mov", "video/quicktime"),
+    ("flv", "video/x-flv"),
+    ("wmv", "video/x-ms-wmv"),
+    ("webm", "video/webm"),
+];
+
+/// The streaming server of the process, if started.
+static STREAM_SERVER: LazyLock<Mutex<Option<RunningServer>>> = LazyLock::new(|| Mutex::new(None));
+
+struct RunningServer {
+    port: u16,
+    shutdown: oneshot::Sender<()>,
+    handle: JoinHandle<()>,
+    cache_cleanup: JoinHandle<()>,
+}
+
+/// Starts the streaming server, `port` 0 picking a free one, and returns the port it listens on.
+/// It only listens on the loopback interface unless `allow_network` is set, other hosts then
+/// needing a paired device token. A running server is stopped first.
+pub async fn start_stream_server(
+    source: MediaSource,
+    port: u16,
+    allow_network: bool,
+) -> Result<u16> {
+    stop_stream_server().await?;
+
+    let file_source = FileSource::connect(source).await?;
+
+    let host = if allow_network {
+        "0.0.0.0"
+    } else {
+        "127.0.0.1"
+    };
+    let listener = tokio::net::TcpListener::bind((host, port))
+        .await
+        .with_context(|| format!("Failed to bind streaming server to port {}", port))?;
+    let port = listener
+        .local_addr()
+        .context("Failed to read streaming server address")?
+        .port();
+
+    let app = Router::new()
+        .route("/media/{media_id}", get(stream_media))
+        .route(
+            "/media/{media_id}/subtitles/{subtitle_id}",
+            get(stream_subtitle),
+        )
+        .route("/media/{media_id}/trickplay/{file}", get(stream_trickplay))
+        .route("/transcode/{session_id}", delete(delete_transcode_session))
+        .route("/transcode/{session_id}/index.m3u8", get(stream_playlist))
+        .route("/transcode/{session_id}/{segment}", get(stream_segment))
+        .route_layer(middleware::from_fn(authenticate))
+        .nest(&format!("/api/{}", API_VERSION), rest_api::router())
+        .with_state(Arc::new(file_source));
+
+    let (shutdown, shutdown_signal) = oneshot::channel::<()>();
+    let handle = tokio::spawn(async move {
+        let server = axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .with_graceful_shutdown(async {
+            shutdown_signal.await.ok();
+        });
+        if let Err(e) = server.await {
+            tracing::error!("Streaming server stopped \n Caused by {:?}", e);
+        }
+    });
+
+    tracing::info!(
+        port = port,
+        allow_network = allow_network,
+        "Streaming server started"
+    );
+
+    *STREAM_SERVER.lock().await = Some(RunningServer {
+        port,
+        shutdown,
+        handle,
+        cache_cleanup: spawn_cache_cleanup(),
+    });
+    Ok(port)
+}
+
+/// Stops the streaming server with its transcoding sessions, doing nothing else when it is
+/// not running. The segment cache is emptied either way.
+pub async fn stop_stream_server() -> Result<()> {
+    let Some(server) = STREAM_SERVER.lock().await.take() else {
+        stop_all_sessions().await;
+        return Ok(());
+    };
+    server.cache_cleanup.abort();
+    stop_all_sessions().await;
+    server.shutdown.send(()).ok();
+    server
+        .handle
+        .await
+        .context("Failed to wait for the streaming server to stop")?;
+
+    tracing::info!(port = server.port, "Streaming server stopped");
+    Ok(())
+}
+
+/// Returns the root URL of the streaming server on the loopback interface.
+pub async fn server_url() -> Result<String> {
+    let server = STREAM_SERVER.lock().await;
+    let server = server
+        .as_ref()
+        .ok_or_else(|| anyhow!("Streaming server is not running"))?;
+    Ok(format!("http://127.0.0.1:{}", server.port))
+}
+
+/// Returns the URL a media item is streamed at.
+pub async fn stream_url(media_id: i64) -> Result<String> {
+    Ok(format!("{}/media/{}", server_url().await?, media_id))
+}
+
+/// Returns the URL the seek preview index of a media item is served at.
+pub async fn trickplay_url(media_id: i64) -> Result<String> {
+    Ok(format!(
+        "{}/trickplay/{}",
+        stream_url(media_id).await?,
+        INDEX_FILE
+    ))
+}
+
+/// Returns the URL a subtitle file of a media item is served at as WebVTT.
+pub async fn subtitle_url(media_id: i64, subtitle_id: i64) -> Result<String> {
+    Ok(format!(
+        "{}/subtitles/{}",
+        stream_url(media_id).await?,
+        subtitle_id
+    ))
+}
+
+// region: ---- HANDLERS ----
+
+/// Streams a library file, answering a `Range` request with the requested bytes only.
+/// Media above the content rating limit of the requesting profile are answered as not found.
+async fn stream_media(
+    State(source): State<Arc<FileSource>>,
+    Extension(RequestProfile(profile_id)): Extension<RequestProfile>,
+    Path(media_id): Path<i64>,
+    headers: HeaderMap,
+) -> Response {
+    let file_path = match DataGetter::new("movie_db.db".to_owned()).and_then(|data_getter| {
+        if !data_getter.is_media_allowed(profile_id, media_id)? {
+            return Ok(None);
+        }
+        data_getter.get_media_file_path(media_id)
+    }) {
+        Ok(Some(file_path)) => file_path,
+        Ok(None) => return StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            tracing::error!("Failed to find media {} \n Caused by {:?}", media_id, e);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let (file, len) = match OpenedFile::open(&source, &file_path).await {
+        Ok(opened) => opened,
+        Err(e) => {
+            tracing::error!("Failed to open {} \n Caused by {:?}", file_path, e);
+            return StatusCode::NOT_FOUND.into_response();
+        }
+    };
+
+    let range = headers
+        .get(header::RANGE)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| parse_range(value, len));
+
+    let (status, start, end) = match range {
+        None => (StatusCode::OK, 0, len.saturating_sub(1)),
+        Some(Some((start, end))) => (StatusCode::PARTIAL_CONTENT, start, end),
+        Some(None) => {
+            return (
+                StatusCode::RANGE_NOT_SATISFIABLE,
+                [(header::CONTENT_RANGE, format!("bytes */{}", len))],
+            )
+                .into_response();
+        }
+    };
+    let content_length = if len == 0 { 0 } else { end - start + 1 };
+
+    let mut response = Response::new(Body::from_stream(read_range(file, start, content_length)));
+    *response.status_mut() = status;
+    let response_headers = response.headers_mut();
+    response_headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static(content_type(&file_path)),
+    );
+    response_headers.insert(header::ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+    response_headers.insert(header::CONTENT_LENGTH, HeaderValue::from(content_length));
+    if status == StatusCode::PARTIAL_CONTENT {
+        if let Ok(value) = HeaderValue::from_str(&format!("bytes {}-{}/{}", start, end, len)) {
+            response_headers.insert(header::CONTENT_RANGE, value);
+        }
+    }
+    response
+}
+
+/// Serves a subtitle file of a media converted to WebVTT, decoded from its legacy codepage
+/// if needed. Subtitles of media the requesting profile may not see are answered as not found.
+async fn stream_subtitle(
+    State(source): State<Arc<FileSource>>,
+    Extension(RequestProfile(profile_id)): Extension<RequestProfile>,
+    Path((media_id, subtitle_id)): Path<(i64, i64)>,
+) -> Response {
+    let subtitle = match DataGetter::new("movie_db.db".to_owned()).and_then(|data_getter| {
+        if !data_getter.is_media_allowed(profile_id, media_id)? {
+            return Ok(None);
+        }
+        data_getter.get_subtitle_file(media_id, subtitle_id)
+    }) {
+        Ok(Some(subtitle)) => subtitle,
+        Ok(None) => return StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            tracing::error!(
+                "Failed to find subtitle {} \n Caused by {:?}",
+                subtitle_id,
+                e
+            );
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    match read_as_webvtt(&source, &subtitle).await {
+        Ok(vtt) => ([(header::CONTENT_TYPE, "text/vtt; charset=utf-8")], vtt).into_response(),
+        Err(e) => {
+            tracing::error!(
+                "Failed to serve subtitle {} \n Caused by {:?}",
+                subtitle.file_path,
+                e
+            );
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Serves the seek preview index of a media or one of its sprite sheets. `query` is appended
+/// to the sprite URLs of the index, carrying the device token of the index request.
+async fn stream_trickplay(
+    Extension(RequestProfile(profile_id)): Extension<RequestProfile>,
+    Path((media_id, file)): Path<(i64, String)>,
+    RawQuery(query): RawQuery,
+) -> Response {
+    let allowed = DataGetter::new("movie_db.db".to_owned())
+        .and_then(|data_getter| data_getter.is_media_allowed(profile_id, media_id));
+    match allowed {
+        Ok(true) if is_trickplay_file(&file) => {}
+        Ok(_) => return StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            tracing::error!("Failed to find media {} \n Caused by {:?}", media_id, e);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    }
+
+    let Ok(path) = trickplay_folder(media_id).map(|folder| folder.join(&file)) else {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    };
+    let Ok(bytes) = tokio::fs::read(&path).await else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    if file != INDEX_FILE {
+        return ([(header::CONTENT_TYPE, "image/jpeg")], bytes).into_response();
+    }
+    let mut index = String::from_utf8_lossy(&bytes).into_owned();
+    if let Some(query) = query {
+        index = index.replace(".jpg#", &format!(".jpg?{}#", query));
+    }
+    ([(header::CONTENT_TYPE, "text/vtt; charset=utf-8")], index).into_response()
+}
+
+/// Serves the HLS playlist of a transcoding session started by the requesting profile.
+async fn stream_playlist(
+    Extension(RequestProfile(profile_id)): Extension<RequestProfile>,
+    Path(session_id): Path<String>,
+    RawQuery(query): RawQuery,
+) -> Response {
+    match session_playlist(&session_id, profile_id, query.as_deref()).await {
+        Ok(Some(playlist)) => (
+            [(header::CONTENT_TYPE, "application/vnd.apple.mpegurl")],
+            playlist,
+        )
+            .into_response(),
+        Ok(None) => StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            tracing::error!(
+                "Failed to build the playlist of session {} \n Caused by {:?}",
+                session_id,
+                e
+            );
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Serves a segment of a transcoding session, waiting for it to be encoded.
+async fn stream_segment(
+    Extension(RequestProfile(profile_id)): Extension<RequestProfile>,
+    Path((session_id, segment)): Path<(String, String)>,
+) -> Response {
+    let Some(index) = segment
+        .strip_prefix("segment_")
+        .and_then(|name| name.strip_suffix(".ts"))
+        .and_then(|index| index.parse::<u32>().ok())
+    else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    let path = match session_segment(&session_id, profile_id, index).await {
+        Ok(Some(path)) => path,
+        Ok(None) => return StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            tracing::error!(
+                "Failed to encode segment {} of session {} \n Caused by {:?}",
+                index,
+                session_id,
+                e
+            );
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    match tokio::fs::read(&path).await {
+        Ok(segment) => ([(header::CONTENT_TYPE, "video/mp2t")], segment).into_response(),
+        Err(e) => {
+            tracing::error!("Failed to read {:?} \n Caused by {:?}", path, e);
+            StatusCode::NOT_FOUND.into_response()
+        }
+    }
+}
+
+/// Stops a transcoding session once its player is done with it.
+async fn delete_transcode_session(
+    Extension(RequestProfile(profile_id)): Extension<RequestProfile>,
+    Path(session_id): Path<String>,
+) -> StatusCode {
+    if stop_session(&session_id, profile_id).await {
+        StatusCode::NO_CONTENT
+    } else {
+        StatusCode::NOT_FOUND
+    }
+}
+
+/// Reads `length` bytes of a file from `start`, block by block.
+fn read_range(
+    mut file: OpenedFile,
+    start: u64,
+    length: u64,
+) -> impl futures::Stream<Item = std::io::Result<Bytes>> {
+    stream! {
+        let mut buf = vec![0u8; CHUNK_SIZE];
+        let mut pos = start;
+        let end = start + length;
+        while pos < end {
+            let wanted = ((end - pos) as usize).min(CHUNK_SIZE);
+            let read = file.read_at(&mut buf[..wanted], pos).await?;
+            if read == 0 {
+                break;
+            }
+            pos += read as u64;
+            yield Ok(Bytes::copy_from_slice(&buf[..read]));
+        }
+    }
+}
+// endregion
+
+// region: ---- HELPERS ----
+
+/// Parses a single `bytes=` range into inclusive bounds within a file of `len` bytes,
+/// None when it cannot be satisfied. Only the first range of a multi-range request is served.
+fn parse_range(value: &str, len: u64) -> Option<(u64, u64)> {
+    let spec = value.strip_prefix("bytes=")?.split(',').next()?.trim();
+    let (start, end) = spec.split_once('-')?;
+    if len == 0 {
+        return None;
+    }
+
+    let (start, end) = if start.is_empty() {
+        // Suffix range: the last `end` bytes
+        let suffix = end.parse::<u64>().ok()?.min(len);
+        if suffix == 0 {
+            return None;
+        }
+        (len - suffix, len - 1)
+    } else {
+        let start = start.parse::<u64>().ok()?;
+        let end = if end.is_empty() {
+            len - 1
+        } else {
+            end.parse::<u64>().ok()?.min(len - 1)
+        };
+        (start, end)
+    };
+
+    if start > end || start >= len {
+        return None;
+    }
+    Some((start, end))
+}
+
+fn content_type(file_path: &str) -> &'static str {
+    let extension = file_path
+        .rsplit('.')
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    CONTENT_TYPES
+        .iter()
+        .find(|(known, _)| *known == extension)
+        .map(|(_, content_type)| *content_type)
+        .unwrap_or("application/octet-stream")
+}
+// endregion
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_range_reads_bounded_and_open_ranges() {
+        assert_eq!(parse_range("bytes=0-499", 1000), Some((0, 499)));
+        assert_eq!(parse_range("bytes=500-", 1000), Some((500, 999)));
+        assert_eq!(parse_range("bytes=900-5000", 1000), Some((900, 999)));
+        assert_eq!(parse_range("bytes=999-999", 1000), Some((999, 999)));
+    }
+
+    #[test]
+    fn parse_range_reads_suffix_ranges() {
+        assert_eq!(parse_range("bytes=-200", 1000), Some((800, 999)));
+        assert_eq!(parse_range("bytes=-5000", 1000), Some((0, 999)));
+        assert_eq!(parse_range("bytes=-0", 1000), None);
+    }
+
+    #[test]
+    fn parse_range_serves_the_first_of_several_ranges() {
+        assert_eq!(parse_range("bytes= 0-9 , 20-29", 1000), Some((0, 9)));
+    }
+
+    #[test]
+    fn parse_range_rejects_unsatisfiable_and_malformed_ranges() {
+        assert_eq!(parse_range("bytes=1000-", 1000), None);
+        assert_eq!(parse_range("bytes=500-100", 1000), None);
+        assert_eq!(parse_range("bytes=0-", 0), None);
+        assert_eq!(parse_range("bytes=-1", 0), None);
+        assert_eq!(parse_range("items=0-9", 1000), None);
+        assert_eq!(parse_range("bytes=abc-9", 1000), None);
+        assert_eq!(parse_range("bytes=0-18446744073709551616", 1000), None);
+        assert_eq!(parse_range("bytes=10", 1000), None);
+    }
+}
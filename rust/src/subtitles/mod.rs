@@ -0,0 +1,2 @@
+pub mod sidecar;
+pub mod webvtt;
@@ -0,0 +1,274 @@
+use crate::{
+    db_interface::{data_getter::DataGetter, data_saver::DataSaver},
+    directory_explorer::media_file::FileSource,
+    movie_data::movie_data::SubtitleFile,
+};
+use anyhow::Result;
+use std::collections::{BTreeMap, HashMap};
+
+/// Extensions of the subtitle files that can be served as WebVTT.
+pub const SUBTITLE_EXTENSIONS: [&str; 4] = ["srt", "ass", "ssa", "vtt"];
+
+/// Name tags marking forced subtitles, which only translate foreign dialogue.
+const FORCED_TAGS: [&str; 2] = ["forced", "foreign"];
+
+/// Name tags marking subtitles for the deaf and hard of hearing.
+const SDH_TAGS: [&str; 3] = ["sdh", "hi", "cc"];
+
+/// Language names sometimes written in place of a code, with their ISO 639-1 code.
+const LANGUAGE_NAMES: [(&str, &str); 16] = [
+    ("english", "en"),
+    ("french", "fr"),
+    ("german", "de"),
+    ("spanish", "es"),
+    ("italian", "it"),
+    ("portuguese", "pt"),
+    ("dutch", "nl"),
+    ("russian", "ru"),
+    ("polish", "pl"),
+    ("swedish", "sv"),
+    ("danish", "da"),
+    ("norwegian", "no"),
+    ("finnish", "fi"),
+    ("japanese", "ja"),
+    ("chinese", "zh"),
+    ("korean", "ko"),
+];
+
+/// ISO 639-1 language codes.
+const ISO_639_1: [&str; 184] = [
+    "aa", "ab", "ae", "af", "ak", "am", "an", "ar", "as", "av", "ay", "az", "ba", "be", "bg", "bh",
+    "bi", "bm", "bn", "bo", "br", "bs", "ca", "ce", "ch", "co", "cr", "cs", "cu", "cv", "cy", "da",
+    "de", "dv", "dz", "ee", "el", "en", "eo", "es", "et", "eu", "fa", "ff", "fi", "fj", "fo", "fr",
+    "fy", "ga", "gd", "gl", "gn", "gu", "gv", "ha", "he", "hi", "ho", "hr", "ht", "hu", "hy", "hz",
+    "ia", "id", "ie", "ig", "ii", "ik", "io", "is", "it", "iu", "ja", "jv", "ka", "kg", "ki", "kj",
+    "kk", "kl", "km", "kn", "ko", "kr", "ks", "ku", "kv", "kw", "ky", "la", "lb", "lg", "li", "ln",
+    "lo", "lt", "lu", "lv", "mg", "mh", "mi", "mk", "ml", "mn", "mr", "ms", "mt", "my", "na", "nb",
+    "nd", "ne", "ng", "nl", "nn", "no", "nr", "nv", "ny", "oc", "oj", "om", "or", "os", "pa", "pi",
+    "pl", "ps", "pt", "qu", "rm", "rn", "ro", "ru", "rw", "sa", "sc", "sd", "se", "sg", "si", "sk",
+    "sl", "sm", "sn", "so", "sq", "sr", "ss", "st", "su", "sv", "sw", "ta", "te", "tg", "th", "ti",
+    "tk", "tl", "tn", "to", "tr", "ts", "tt", "tw", "ty", "ug", "uk", "ur", "uz", "ve", "vi", "vo",
+    "wa", "wo", "xh", "yi", "yo", "za", "zh", "zu",
+];
+
+/// ISO 639-2 language codes of the ISO 639-1 languages, bibliographic and terminologic,
+/// with Filipino and Cantonese often found in subtitle names.
+const ISO_639_2: [&str; 206] = [
+    "aar", "abk", "afr", "aka", "alb", "amh", "ara", "arg", "arm", "asm", "ava", "ave", "aym",
+    "aze", "bak", "bam", "baq", "bel", "ben", "bih", "bis", "bod", "bos", "bre", "bul", "bur",
+    "cat", "ces", "cha", "che", "chi", "chu", "chv", "cor", "cos", "cre", "cym", "cze", "dan",
+    "deu", "div", "dut", "dzo", "ell", "eng", "epo", "est", "eus", "ewe", "fao", "fas", "fij",
+    "fil", "fin", "fra", "fre", "fry", "ful", "geo", "ger", "gla", "gle", "glg", "glv", "gre",
+    "grn", "guj", "hat", "hau", "heb", "her", "hin", "hmo", "hrv", "hun", "hye", "ibo", "ice",
+    "ido", "iii", "iku", "ile", "ina", "ind", "ipk", "isl", "ita", "jav", "jpn", "kal", "kan",
+    "kas", "kat", "kau", "kaz", "khm", "kik", "kin", "kir", "kom", "kon", "kor", "kua", "kur",
+    "lao", "lat", "lav", "lim", "lin", "lit", "ltz", "lub", "lug", "mac", "mah", "mal", "mao",
+    "mar", "may", "mkd", "mlg", "mlt", "mon", "mri", "msa", "mya", "nau", "nav", "nbl", "nde",
+    "ndo", "nep", "nld", "nno", "nob", "nor", "nya", "oci", "oji", "ori", "orm", "oss", "pan",
+    "per", "pli", "pol", "por", "pus", "que", "roh", "ron", "rum", "run", "rus", "sag", "san",
+    "sin", "slk", "slo", "slv", "sme", "smo", "sna", "snd", "som", "sot", "spa", "sqi", "srd",
+    "srp", "ssw", "sun", "swa", "swe", "tah", "tam", "tat", "tel", "tgk", "tgl", "tha", "tib",
+    "tir", "ton", "tsn", "tso", "tuk", "tur", "twi", "uig", "ukr", "urd", "uzb", "ven", "vie",
+    "vol", "wel", "wln", "wol", "xho", "yid", "yor", "yue", "zha", "zho", "zul",
+];
+
+/// Finds the subtitle files lying next to the video of every media and saves them.
+/// Returns the number of media whose subtitles changed.
+///
+/// A sidecar shares the name of its video, followed by optional tags as in
+/// "Movie.en.forced.srt". Each folder is listed once, a failing one being logged and skipped.
+pub async fn discover_subtitles(source: &FileSource) -> Result<u32> {
+    let media = DataGetter::new("movie_db.db".to_owned())?.get_media_file_paths()?;
+
+    let mut folders: BTreeMap<String, Vec<(i64, String)>> = BTreeMap::new();
+    for (media_id, file_path) in media {
+        let (folder, file_name) = file_path.rsplit_once('/').unwrap_or(("", &file_path));
+        folders
+            .entry(folder.to_owned())
+            .or_default()
+            .push((media_id, file_name.to_owned()));
+    }
+
+    let mut data_saver = DataSaver::new("movie_db.db".to_owned())?;
+    let mut changed_count = 0;
+    for (folder, videos) in folders.iter() {
+        let files = match source.list_files(folder).await {
+            Ok(files) => files,
+            Err(e) => {
+                tracing::error!(
+                    "Failed to list subtitles of {} \n Caused by {:?}",
+                    folder,
+                    e
+                );
+                continue;
+            }
+        };
+
+        // A sidecar goes to the video with the longest matching name, so that
+        // "Movie.Part2.en.srt" is not taken for a subtitle of "Movie.mkv"
+        let mut subtitles: HashMap<i64, Vec<SubtitleFile>> = videos
+            .iter()
+            .map(|(media_id, _)| (*media_id, vec![]))
+            .collect();
+        for file_name in files.iter() {
+            let best = videos
+                .iter()
+                .filter_map(|(media_id, video_name)| {
+                    let video_stem = video_name
+                        .rsplit_once('.')
+                        .map_or(video_name.as_str(), |(stem, _)| stem);
+                    let subtitle = parse_sidecar(video_stem, file_name)?;
+                    Some((video_stem.len(), *media_id, subtitle))
+                })
+                .max_by_key(|(stem_len, _, _)| *stem_len);
+
+            if let Some((_, media_id, mut subtitle)) = best {
+                if !folder.is_empty() {
+                    subtitle.file_path = format!("{}/{}", folder, subtitle.file_path);
+                }
+                subtitles.entry(media_id).or_default().push(subtitle);
+            }
+        }
+
+        for (media_id, files) in subtitles.iter() {
+            if data_saver.save_subtitle_files(*media_id, files)? {
+                changed_count += 1;
+            }
+        }
+    }
+
+    tracing::info!(media = changed_count, "Subtitle discovery ended");
+    Ok(changed_count)
+}
+
+/// Reads a file name as a subtitle of the video named `video_stem`, None when it is not one.
+///
+/// The tags between the video name and the extension give the language, the forced and SDH
+/// flags, the remaining ones making the title. The file path is the bare file name.
+pub fn parse_sidecar(video_stem: &str, file_name: &str) -> Option<SubtitleFile> {
+    let (name, extension) = file_name.rsplit_once('.')?;
+    let format = extension.to_lowercase();
+    if !SUBTITLE_EXTENSIONS.contains(&format.as_str()) {
+        return None;
+    }
+
+    let tags = if name.eq_ignore_ascii_case(video_stem) {
+        ""
+    } else {
+        let prefix = name.get(..video_stem.len())?;
+        if !prefix.eq_ignore_ascii_case(video_stem) {
+            return None;
+        }
+        name[video_stem.len()..].strip_prefix('.')?
+    };
+
+    let mut subtitle = SubtitleFile {
+        id: 0,
+        file_path: file_name.to_owned(),
+        format,
+        language: None,
+        title: None,
+        is_forced: false,
+        is_sdh: false,
+    };
+    let mut title = vec![];
+    for tag in tags.split('.').filter(|tag| !tag.is_empty()) {
+        let lower = tag.to_lowercase();
+        let language = subtitle
+            .language
+            .is_none()
+            .then(|| language_code(tag))
+            .flatten();
+        if FORCED_TAGS.contains(&lower.as_str()) {
+            subtitle.is_forced = true;
+        } else if SDH_TAGS.contains(&lower.as_str()) {
+            subtitle.is_sdh = true;
+        } else if language.is_some() {
+            subtitle.language = language;
+        } else {
+            title.push(tag);
+        }
+    }
+    subtitle.title = Some(title.join(" ")).filter(|title| !title.is_empty());
+    Some(subtitle)
+}
+
+/// Reads a tag as a language, either an ISO 639-1 or 639-2 code with an optional region or
+/// script as in "pt-BR", or a language name. The primary code is returned lowercase.
+fn language_code(tag: &str) -> Option<String> {
+    let lower = tag.to_lowercase();
+    if let Some((_, code)) = LANGUAGE_NAMES.iter().find(|(name, _)| *name == lower) {
+        return Some((*code).to_owned());
+    }
+
+    let (primary, subtag) = match tag.split_once(['-', '_']) {
+        Some((primary, subtag)) => (primary, Some(subtag)),
+        None => (tag, None),
+    };
+    let primary = primary.to_lowercase();
+    let is_primary = ISO_639_1.contains(&primary.as_str()) || ISO_639_2.contains(&primary.as_str());
+    let is_subtag = subtag.is_none_or(|subtag| {
+        (2..=4).contains(&subtag.len()) && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+    });
+    if !is_primary || !is_subtag {
+        return None;
+    }
+
+    Some(match subtag {
+        Some(subtag) => format!("{}-{}", primary, subtag),
+        None => primary,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_sidecar_reads_the_tags_after_the_video_name() {
+        let subtitle = parse_sidecar("Alien (1979)", "Alien (1979).en.forced.SRT").unwrap();
+        assert_eq!(subtitle.file_path, "Alien (1979).en.forced.SRT");
+        assert_eq!(subtitle.format, "srt");
+        assert_eq!(subtitle.language.as_deref(), Some("en"));
+        assert_eq!(subtitle.title, None);
+        assert!(subtitle.is_forced);
+        assert!(!subtitle.is_sdh);
+
+        let subtitle = parse_sidecar("Alien", "alien.Director's Cut.pt-BR.sdh.ass").unwrap();
+        assert_eq!(subtitle.language.as_deref(), Some("pt-BR"));
+        assert_eq!(subtitle.title.as_deref(), Some("Director's Cut"));
+        assert!(subtitle.is_sdh);
+    }
+
+    #[test]
+    fn parse_sidecar_keeps_the_first_language_only() {
+        let subtitle = parse_sidecar("Alien", "Alien.French.ger.vtt").unwrap();
+        assert_eq!(subtitle.language.as_deref(), Some("fr"));
+        assert_eq!(subtitle.title.as_deref(), Some("ger"));
+
+        let subtitle = parse_sidecar("Alien", "Alien.srt").unwrap();
+        assert_eq!(subtitle.language, None);
+        assert_eq!(subtitle.title, None);
+    }
+
+    #[test]
+    fn parse_sidecar_rejects_words_that_are_not_iso_639_codes() {
+        for word in ["the", "dir", "ext"] {
+            let subtitle = parse_sidecar("Alien", &format!("Alien.{}.srt", word)).unwrap();
+            assert_eq!(subtitle.language, None, "{}", word);
+            assert_eq!(subtitle.title.as_deref(), Some(word));
+        }
+        let subtitle = parse_sidecar("Alien", "Alien.en-Toolong.srt").unwrap();
+        assert_eq!(subtitle.language, None);
+    }
+
+    #[test]
+    fn parse_sidecar_ignores_other_files() {
+        assert!(parse_sidecar("Alien", "Alien.en.nfo").is_none());
+        assert!(parse_sidecar("Alien", "Aliens.en.srt").is_none());
+        assert!(parse_sidecar("Alien", "Predator.srt").is_none());
+        assert!(parse_sidecar("Alien", "srt").is_none());
+        assert!(parse_sidecar("Amélie", "Am.srt").is_none());
+        assert!(parse_sidecar("Am", "Amélie.srt").is_none());
+    }
+}
@@ -0,0 +1,537 @@
+use crate::{
+    directory_explorer::media_file::{FileSource, OpenedFile},
+    movie_data::movie_data::SubtitleFile,
+};
+use anyhow::{anyhow, Context, Result};
+use encoding_rs::{
+    Encoding, BIG5, EUC_KR, GBK, SHIFT_JIS, UTF_16BE, UTF_16LE, WINDOWS_1250, WINDOWS_1251,
+    WINDOWS_1252, WINDOWS_1253, WINDOWS_1254, WINDOWS_1255, WINDOWS_1256, WINDOWS_1257,
+    WINDOWS_1258, WINDOWS_874,
+};
+
+/// Largest subtitle file converted, a bigger one being taken for a wrong file.
+const MAX_SUBTITLE_SIZE: u64 = 16 * 1024 * 1024;
+
+/// Markup tags WebVTT shares with SRT, the others being dropped.
+const KEPT_TAGS: [&str; 6] = ["i", "/i", "b", "/b", "u", "/u"];
+
+/// Fields of an ASS or SSA event when its section gives no format line.
+const DEFAULT_EVENT_FORMAT: [&str; 10] = [
+    "layer", "start", "end", "style", "name", "marginl", "marginr", "marginv", "effect", "text",
+];
+
+/// Override tags of an ASS event kept as WebVTT markup.
+const ASS_STYLE_TAGS: [&str; 3] = ["i", "b", "u"];
+
+/// Cue settings moving a cue to the top or the middle of the video.
+const TOP_SETTINGS: &str = " line:0";
+const MIDDLE_SETTINGS: &str = " line:50%";
+
+struct Cue {
+    /// In milliseconds.
+    start: u64,
+    end: u64,
+    settings: &'static str,
+    text: String,
+}
+
+/// Reads a subtitle file of the library and converts it to WebVTT.
+pub async fn read_as_webvtt(source: &FileSource, subtitle: &SubtitleFile) -> Result<String> {
+    let (mut file, len) = OpenedFile::open(source, &subtitle.file_path).await?;
+    if len > MAX_SUBTITLE_SIZE {
+        return Err(anyhow!(
+            "Subtitle file {} is too large: {} bytes",
+            subtitle.file_path,
+            len
+        ));
+    }
+
+    let mut bytes = vec![0u8; len as usize];
+    let read = file
+        .read_full_at(&mut bytes, 0)
+        .await
+        .with_context(|| format!("Failed to read subtitle file {}", subtitle.file_path))?;
+    bytes.truncate(read);
+
+    let text = decode_subtitle(&bytes, subtitle.language.as_deref());
+    to_webvtt(&subtitle.format, &text)
+        .with_context(|| format!("Failed to convert subtitle file {}", subtitle.file_path))
+}
+
+/// Converts the text of an SRT, ASS, SSA or WebVTT subtitle to WebVTT.
+///
+/// Italic, bold and underline are kept, other styling is dropped, and cues placed at the top
+/// or middle of the video keep their place.
+pub fn to_webvtt(format: &str, text: &str) -> Result<String> {
+    let text = text
+        .trim_start_matches('\u{FEFF}')
+        .replace("\r\n", "\n")
+        .replace('\r', "\n");
+
+    let mut cues = match format {
+        "srt" => srt_cues(&text),
+        "ass" | "ssa" => ass_cues(&text),
+        "vtt" if text.starts_with("WEBVTT") => return Ok(text),
+        "vtt" => return Ok(format!("WEBVTT\n\n{}", text)),
+        _ => return Err(anyhow!("Unsupported subtitle format: {}", format)),
+    };
+    // Events of an ASS script may come in any order, WebVTT cues may not
+    cues.sort_by_key(|cue| cue.start);
+
+    let mut vtt = String::from("WEBVTT\n");
+    for cue in cues.iter().filter(|cue| !cue.text.trim().is_empty()) {
+        vtt.push_str(&format!(
+            "\n{} --> {}{}\n{}\n",
+            timestamp(cue.start),
+            timestamp(cue.end),
+            cue.settings,
+            cue.text
+        ));
+    }
+    Ok(vtt)
+}
+
+// region: ---- ENCODING ----
+
+/// Decodes a subtitle file whatever its encoding.
+///
+/// A byte order mark or UTF-16 text is recognized first, then valid UTF-8. Other files are
+/// taken for a legacy codepage: the one used for their language, or without language either
+/// Windows-1251 or Windows-1252, told apart by their byte frequencies.
+pub fn decode_subtitle(bytes: &[u8], language: Option<&str>) -> String {
+    if let Some((encoding, bom_len)) = Encoding::for_bom(bytes) {
+        return encoding
+            .decode_without_bom_handling(&bytes[bom_len..])
+            .0
+            .into_owned();
+    }
+    if let Some(encoding) = utf16_without_bom(bytes) {
+        return encoding.decode_without_bom_handling(bytes).0.into_owned();
+    }
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        return text.to_owned();
+    }
+
+    let encoding = language
+        .map(legacy_encoding)
+        .unwrap_or_else(|| guess_single_byte(bytes));
+    encoding.decode_without_bom_handling(bytes).0.into_owned()
+}
+
+/// Recognizes UTF-16 text without byte order mark from the zero high byte of most of its
+/// code units, subtitles being mostly Latin letters, digits and punctuation.
+fn utf16_without_bom(bytes: &[u8]) -> Option<&'static Encoding> {
+    let units = bytes.len() / 2;
+    if units < 2 {
+        return None;
+    }
+    let zeros_at = |offset: usize| {
+        bytes
+            .iter()
+            .skip(offset)
+            .step_by(2)
+            .filter(|byte| **byte == 0)
+            .count()
+    };
+    if zeros_at(1) * 2 > units {
+        Some(UTF_16LE)
+    } else if zeros_at(0) * 2 > units {
+        Some(UTF_16BE)
+    } else {
+        None
+    }
+}
+
+/// Codepage subtitles of a language were written in before UTF-8, by ISO 639 code.
+fn legacy_encoding(language: &str) -> &'static Encoding {
+    let language = language.to_lowercase();
+    let (primary, subtag) = language.split_once('-').unwrap_or((&language, ""));
+    match primary {
+        "ru" | "rus" | "uk" | "ukr" | "bg" | "bul" | "be" | "bel" | "sr" | "srp" | "mk" | "mkd"
+        | "mac" => WINDOWS_1251,
+        "pl" | "pol" | "cs" | "ces" | "cze" | "sk" | "slk" | "slo" | "hu" | "hun" | "ro"
+        | "ron" | "rum" | "hr" | "hrv" | "sl" | "slv" | "bs" | "bos" => WINDOWS_1250,
+        "el" | "ell" | "gre" => WINDOWS_1253,
+        "tr" | "tur" => WINDOWS_1254,
+        "he" | "heb" => WINDOWS_1255,
+        "ar" | "ara" | "fa" | "fas" | "per" => WINDOWS_1256,
+        "lt" | "lit" | "lv" | "lav" | "et" | "est" => WINDOWS_1257,
+        "vi" | "vie" => WINDOWS_1258,
+        "th" | "tha" => WINDOWS_874,
+        "ja" | "jpn" => SHIFT_JIS,
+        "ko" | "kor" => EUC_KR,
+        "zh" | "zho" | "chi" if matches!(subtag, "tw" | "hk" | "hant") => BIG5,
+        "zh" | "zho" | "chi" => GBK,
+        _ => WINDOWS_1252,
+    }
+}
+
+/// Cyrillic letters all lie above 0xC0 in Windows-1251 and make most of the letters of a
+/// Russian text, when only the accented letters of a Western text do.
+fn guess_single_byte(bytes: &[u8]) -> &'static Encoding {
+    let high = bytes.iter().filter(|byte| **byte >= 0xC0).count();
+    let ascii_letters = bytes
+        .iter()
+        .filter(|byte| byte.is_ascii_alphabetic())
+        .count();
+    if high > ascii_letters {
+        WINDOWS_1251
+    } else {
+        WINDOWS_1252
+    }
+}
+// endregion
+
+// region: ---- SRT ----
+
+fn srt_cues(text: &str) -> Vec<Cue> {
+    let mut cues = vec![];
+    let mut current: Option<(u64, u64, Vec<&str>)> = None;
+    for line in text.lines().map(str::trim_end) {
+        if let Some((start, end)) = srt_timing(line) {
+            if let Some((start, end, mut lines)) = current.take() {
+                // The counter of this cue when no blank line ended the previous one
+                if lines
+                    .last()
+                    .is_some_and(|last| last.chars().all(|c| c.is_ascii_digit()))
+                {
+                    lines.pop();
+                }
+                cues.push(srt_cue(start, end, &lines));
+            }
+            current = Some((start, end, vec![]));
+        } else if line.trim().is_empty() {
+            if let Some((start, end, lines)) = current.take() {
+                cues.push(srt_cue(start, end, &lines));
+            }
+        } else if let Some((_, _, lines)) = current.as_mut() {
+            lines.push(line);
+        }
+    }
+    if let Some((start, end, lines)) = current {
+        cues.push(srt_cue(start, end, &lines));
+    }
+    cues
+}
+
+/// Parses a timing line as "00:01:02,500 --> 00:01:04,000", ignoring the coordinates
+/// some files add after it.
+fn srt_timing(line: &str) -> Option<(u64, u64)> {
+    let (start, end) = line.split_once("-->")?;
+    let start = parse_timestamp(start)?;
+    let end = parse_timestamp(end.split_whitespace().next()?)?;
+    Some((start, end))
+}
+
+fn srt_cue(start: u64, end: u64, lines: &[&str]) -> Cue {
+    let mut text = String::new();
+    let mut settings = "";
+    let joined = lines.join("\n");
+    let mut rest = joined.as_str();
+    while let Some(c) = rest.chars().next() {
+        // ASS style positioning that many SRT files carry, as in "{\an8}"
+        if c == '{' && rest[1..].starts_with('\\') {
+            if let Some(end) = rest.find('}') {
+                settings = rest[1..end]
+                    .split('\\')
+                    .find_map(alignment)
+                    .unwrap_or(settings);
+                rest = &rest[end + 1..];
+                continue;
+            }
+        }
+        if c == '<' {
+            if let Some(end) = rest.find('>') {
+                let tag = rest[1..end].trim().to_lowercase();
+                let is_markup = tag.starts_with(|c: char| c == '/' || c.is_ascii_alphabetic());
+                if KEPT_TAGS.contains(&tag.as_str()) {
+                    text.push_str(&format!("<{}>", tag));
+                }
+                if is_markup {
+                    rest = &rest[end + 1..];
+                    continue;
+                }
+            }
+        }
+        push_escaped(&mut text, c);
+        rest = &rest[c.len_utf8()..];
+    }
+    Cue {
+        start,
+        end,
+        settings,
+        text,
+    }
+}
+// endregion
+
+// region: ---- ASS ----
+
+fn ass_cues(text: &str) -> Vec<Cue> {
+    let mut cues = vec![];
+    let mut in_events = false;
+    let mut fields: Vec<String> = DEFAULT_EVENT_FORMAT.iter().map(|f| f.to_string()).collect();
+    for line in text.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_events = line.eq_ignore_ascii_case("[events]");
+            continue;
+        }
+        if !in_events {
+            continue;
+        }
+        if let Some(format) = line.strip_prefix("Format:") {
+            fields = format.split(',').map(|f| f.trim().to_lowercase()).collect();
+            continue;
+        }
+        let Some(dialogue) = line.strip_prefix("Dialogue:") else {
+            continue;
+        };
+
+        // The text is the last field and may hold commas
+        let values: Vec<&str> = dialogue.splitn(fields.len(), ',').collect();
+        let field = |name: &str| {
+            fields
+                .iter()
+                .position(|field| field == name)
+                .and_then(|index| values.get(index))
+        };
+        let (Some(start), Some(end), Some(text)) = (
+            field("start").and_then(|start| parse_timestamp(start)),
+            field("end").and_then(|end| parse_timestamp(end)),
+            field("text"),
+        ) else {
+            continue;
+        };
+
+        let (text, settings) = ass_text(text);
+        cues.push(Cue {
+            start,
+            end,
+            settings,
+            text,
+        });
+    }
+    cues
+}
+
+/// Converts the text of an ASS event, reading its override blocks for italic, bold,
+/// underline, alignment and drawings, which are left out.
+fn ass_text(text: &str) -> (String, &'static str) {
+    let mut converted = String::new();
+    let mut settings = "";
+    let mut drawing = false;
+    let mut open = [false; ASS_STYLE_TAGS.len()];
+
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => {
+                let block: String = chars.by_ref().take_while(|c| *c != '}').collect();
+                for tag in block.split('\\').filter(|tag| !tag.is_empty()) {
+                    if let Some(alignment) = alignment(tag) {
+                        settings = alignment;
+                    } else if let Some(scale) =
+                        tag.strip_prefix('p').and_then(|v| v.parse::<u32>().ok())
+                    {
+                        drawing = scale > 0;
+                    } else if let Some((index, value)) =
+                        ASS_STYLE_TAGS.iter().enumerate().find_map(|(index, name)| {
+                            let value = tag.strip_prefix(name)?;
+                            value
+                                .chars()
+                                .all(|c| c.is_ascii_digit())
+                                .then_some((index, value))
+                        })
+                    {
+                        let enabled = !value.is_empty() && value != "0";
+                        if enabled != open[index] {
+                            let slash = if enabled { "" } else { "/" };
+                            converted.push_str(&format!("<{}{}>", slash, ASS_STYLE_TAGS[index]));
+                            open[index] = enabled;
+                        }
+                    }
+                }
+            }
+            '\\' if matches!(chars.peek(), Some('N' | 'n')) => {
+                chars.next();
+                converted.push('\n');
+            }
+            '\\' if chars.peek() == Some(&'h') => {
+                chars.next();
+                converted.push('\u{00A0}');
+            }
+            _ if drawing => {}
+            _ => push_escaped(&mut converted, c),
+        }
+    }
+
+    for (index, name) in ASS_STYLE_TAGS.iter().enumerate().rev() {
+        if open[index] {
+            converted.push_str(&format!("</{}>", name));
+        }
+    }
+    (converted, settings)
+}
+
+/// Reads an alignment override, "an" giving the numpad position and the legacy "a"
+/// of SSA giving 1 to 3 for the bottom, 5 to 7 for the top and 9 to 11 for the middle.
+fn alignment(tag: &str) -> Option<&'static str> {
+    if let Some(position) = tag.strip_prefix("an") {
+        return match position.parse::<u32>().ok()? {
+            7..=9 => Some(TOP_SETTINGS),
+            4..=6 => Some(MIDDLE_SETTINGS),
+            _ => Some(""),
+        };
+    }
+    match tag.strip_prefix('a')?.parse::<u32>().ok()? {
+        5..=7 => Some(TOP_SETTINGS),
+        9..=11 => Some(MIDDLE_SETTINGS),
+        _ => Some(""),
+    }
+}
+// endregion
+
+// region: ---- HELPERS ----
+
+/// Parses "H:MM:SS.cc", "HH:MM:SS,mmm" or "MM:SS.mmm" into milliseconds.
+fn parse_timestamp(value: &str) -> Option<u64> {
+    let value = value.trim().replace(',', ".");
+    let mut parts = value.rsplit(':');
+    let seconds: f64 = parts.next()?.parse().ok()?;
+    let minutes: u64 = parts.next()?.parse().ok()?;
+    let hours: u64 = match parts.next() {
+        Some(hours) => hours.parse().ok()?,
+        None => 0,
+    };
+    if parts.next().is_some() || !seconds.is_finite() || seconds < 0.0 {
+        return None;
+    }
+    // Garbage such as a huge hour count is rejected rather than overflowing
+    hours
+        .checked_mul(3600)?
+        .checked_add(minutes.checked_mul(60)?)?
+        .checked_mul(1000)?
+        .checked_add((seconds * 1000.0).round() as u64)
+}
+
+/// Formats milliseconds as a WebVTT timestamp.
+pub fn timestamp(ms: u64) -> String {
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        ms / 3_600_000,
+        ms / 60_000 % 60,
+        ms / 1000 % 60,
+        ms % 1000
+    )
+}
+
+fn push_escaped(text: &mut String, c: char) {
+    match c {
+        '&' => text.push_str("&amp;"),
+        '<' => text.push_str("&lt;"),
+        '>' => text.push_str("&gt;"),
+        _ => text.push(c),
+    }
+}
+// endregion
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_timestamp_reads_srt_ass_and_short_forms() {
+        assert_eq!(parse_timestamp("00:01:02,500"), Some(62_500));
+        assert_eq!(parse_timestamp(" 1:02:03.45 "), Some(3_723_450));
+        assert_eq!(parse_timestamp("02:03.004"), Some(123_004));
+    }
+
+    #[test]
+    fn parse_timestamp_rejects_garbage_and_overflow() {
+        assert_eq!(parse_timestamp("12.5"), None);
+        assert_eq!(parse_timestamp("aa:00:01.000"), None);
+        assert_eq!(parse_timestamp("1:2:3:4.000"), None);
+        assert_eq!(parse_timestamp("00:00:-1.000"), None);
+        assert_eq!(parse_timestamp("00:00:NaN"), None);
+        assert_eq!(parse_timestamp("18446744073709551615:00:00.000"), None);
+    }
+
+    #[test]
+    fn timestamp_pads_every_field() {
+        assert_eq!(timestamp(3_723_045), "01:02:03.045");
+        assert_eq!(timestamp(0), "00:00:00.000");
+    }
+
+    #[test]
+    fn to_webvtt_converts_srt_cues() {
+        let srt = "\u{FEFF}1\r\n00:00:01,000 --> 00:00:02,500 X1:10 X2:20\r\n\
+                   <i>Hello</i> <font color=\"red\">you</font> & me\r\n\r\n\
+                   2\r\n00:00:03,000 --> 00:00:04,000\r\n{\\an8}Top 1 < 2\r\n\
+                   3\r\n00:00:05,000 --> 00:00:06,000\r\n\r\n";
+
+        assert_eq!(
+            to_webvtt("srt", srt).unwrap(),
+            "WEBVTT\n\n\
+             00:00:01.000 --> 00:00:02.500\n<i>Hello</i> you &amp; me\n\n\
+             00:00:03.000 --> 00:00:04.000 line:0\nTop 1 &lt; 2\n"
+        );
+    }
+
+    #[test]
+    fn to_webvtt_converts_and_sorts_ass_events() {
+        let ass = "[Script Info]\nTitle: Test\n\n[Events]\n\
+                   Format: Layer, Start, End, Style, Text\n\
+                   Dialogue: 0,0:00:05.00,0:00:06.00,Default,{\\i1}Later, then{\\i0} done\n\
+                   Dialogue: 0,0:00:01.00,0:00:02.00,Default,{\\an5}First\\Nline\n\
+                   Dialogue: 0,0:00:03.00,0:00:04.00,Default,{\\p1}m 0 0 l 10 10{\\p0}\n\
+                   Dialogue: 0,0:00:03.50,0:00:04.00,Default,{\\b1}Open bold\n\
+                   Comment: 0,0:00:00.00,0:00:09.00,Default,Ignored\n";
+
+        assert_eq!(
+            to_webvtt("ass", ass).unwrap(),
+            "WEBVTT\n\n\
+             00:00:01.000 --> 00:00:02.000 line:50%\nFirst\nline\n\n\
+             00:00:03.500 --> 00:00:04.000\n<b>Open bold</b>\n\n\
+             00:00:05.000 --> 00:00:06.000\n<i>Later, then</i> done\n"
+        );
+    }
+
+    #[test]
+    fn to_webvtt_passes_webvtt_through() {
+        let cue = "00:00:01.000 --> 00:00:02.000\nHi\n";
+        assert_eq!(
+            to_webvtt("vtt", &format!("WEBVTT\r\n\r\n{}", cue)).unwrap(),
+            format!("WEBVTT\n\n{}", cue)
+        );
+        assert_eq!(to_webvtt("vtt", cue).unwrap(), format!("WEBVTT\n\n{}", cue));
+        assert!(to_webvtt("sub", cue).is_err());
+    }
+
+    #[test]
+    fn decode_subtitle_reads_unicode_encodings() {
+        assert_eq!(decode_subtitle(b"\xEF\xBB\xBFCaf\xC3\xA9", None), "Café");
+        assert_eq!(decode_subtitle(b"\xFF\xFEH\0i\0", None), "Hi");
+        assert_eq!(decode_subtitle(b"H\0e\0l\0l\0o\0", None), "Hello");
+        assert_eq!(decode_subtitle(b"\0H\0e\0l\0l\0o", None), "Hello");
+        assert_eq!(decode_subtitle("Привет".as_bytes(), Some("fr")), "Привет");
+    }
+
+    #[test]
+    fn decode_subtitle_uses_the_codepage_of_the_language() {
+        assert_eq!(
+            decode_subtitle(b"\xCF\xF0\xE8\xE2\xE5\xF2", Some("ru")),
+            "Привет"
+        );
+        assert_eq!(decode_subtitle(b"\xB3\xF3\xBF", Some("pl")), "łóż");
+        assert_eq!(decode_subtitle(b"caf\xE9", Some("fr")), "café");
+    }
+
+    #[test]
+    fn decode_subtitle_guesses_the_codepage_without_language() {
+        assert_eq!(
+            decode_subtitle(b"\xCF\xF0\xE8\xE2\xE5\xF2 1", None),
+            "Привет 1"
+        );
+        assert_eq!(decode_subtitle(b"Caf\xE9 cr\xE8me", None), "Café crème");
+    }
+}
@@ -0,0 +1,2 @@
+pub mod thumbnails;
+pub mod trickplay;
@@ -0,0 +1,189 @@
+use crate::{
+    db_interface::{data_getter::DataGetter, data_saver::DataSaver},
+    directory_explorer::media_file::FileSource,
+    movie_data::movie_data::MediaInfo,
+    os_interface::file_interface::create_dir,
+    stream_server::stream_server::stream_url,
+    thumbnails::trickplay::{generate_trickplay, has_trickplay},
+    transcoder::ffmpeg::{ffmpeg_path, run_ffmpeg},
+};
+use anyhow::{bail, Context, Result};
+
+/// Position of the frame taken as poster, as a fraction of the video duration.
+const POSTER_POSITION: f64 = 0.2;
+
+/// Position of the frame taken as backdrop, as a fraction of the video duration.
+const BACKDROP_POSITION: f64 = 0.5;
+
+/// Position of the frames of a video whose duration is unknown, in seconds.
+const DEFAULT_FRAME_POSITION: f64 = 120.0;
+
+/// Consecutive frames ffmpeg picks the most representative one from, skipping black frames
+/// and transitions.
+const THUMBNAIL_BATCH: u32 = 100;
+
+/// Widths of the generated artwork, matching the TMDB sizes downloaded.
+const POSTER_WIDTH: i32 = 780;
+const BACKDROP_WIDTH: i32 = 1280;
+
+/// Generates artwork from video frames for the media without any, and the seek previews of
+/// every probed media. Returns the number of media given new images.
+///
+/// Previously generated images are only made again when `regenerate` is set. A media failing
+/// is logged and skipped. A SMB library is read through the running streaming server.
+pub async fn generate_thumbnails(source: &FileSource, regenerate: bool) -> Result<u32> {
+    if ffmpeg_path().is_none() {
+        bail!("ffmpeg is not installed");
+    }
+
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    let mut data_saver = DataSaver::new("movie_db.db".to_owned())?;
+    let media = data_getter.get_media_artwork()?;
+    let locked_fields = data_getter.get_locked_fields()?;
+
+    let mut generated_count = 0;
+    for (media_id, file_path, poster, backdrop) in media {
+        let info = data_getter.get_media_info(media_id)?;
+        let locked = locked_fields.get(&file_path);
+        let is_locked = |field: &str| locked.is_some_and(|fields| fields.contains(field));
+        let artwork = MediaArtwork {
+            media_id,
+            file_path: &file_path,
+            poster: (!is_locked("poster")).then_some(poster.as_str()),
+            backdrop: (!is_locked("backdrop")).then_some(backdrop.as_str()),
+            info: info.as_ref(),
+        };
+        match generate_media_thumbnails(source, &mut data_saver, &artwork, regenerate).await {
+            Ok(true) => generated_count += 1,
+            Ok(false) => {}
+            Err(e) => {
+                tracing::error!(
+                    "Failed to generate thumbnails of {} \n Caused by {:?}",
+                    file_path,
+                    e
+                );
+            }
+        }
+    }
+
+    tracing::info!(media = generated_count, "Thumbnail generation ended");
+    Ok(generated_count)
+}
+
+/// Stored artwork and probed info of a media, artwork locked by the user being None.
+struct MediaArtwork<'a> {
+    media_id: i64,
+    file_path: &'a str,
+    poster: Option<&'a str>,
+    backdrop: Option<&'a str>,
+    info: Option<&'a MediaInfo>,
+}
+
+/// Generates the missing images of a media, returning whether any was made.
+async fn generate_media_thumbnails(
+    source: &FileSource,
+    data_saver: &mut DataSaver,
+    artwork: &MediaArtwork<'_>,
+    regenerate: bool,
+) -> Result<bool> {
+    let media_id = artwork.media_id;
+    let (_, poster_path) = create_dir("movie", "frame", &format!("{}_poster.jpg", media_id))?;
+    let (_, backdrop_path) = create_dir("movie", "frame", &format!("{}_backdrop.jpg", media_id))?;
+    let is_wanted = |stored: Option<&str>, generated: &str| {
+        stored.is_some_and(|stored| stored.is_empty() || (regenerate && stored == generated))
+    };
+    let wants_poster = is_wanted(artwork.poster, &poster_path);
+    let wants_backdrop = is_wanted(artwork.backdrop, &backdrop_path);
+
+    let duration = artwork.info.and_then(|info| info.duration);
+    let trickplay_size = artwork
+        .info
+        .and_then(|info| Some((info.duration?, info.width?, info.height?)));
+    let wants_trickplay = trickplay_size.is_some() && (regenerate || !has_trickplay(media_id));
+
+    if !wants_poster && !wants_backdrop && !wants_trickplay {
+        return Ok(false);
+    }
+    let input = ffmpeg_input(source, media_id, artwork.file_path).await?;
+
+    if wants_poster {
+        // A centered 2:3 crop, posters being portrait
+        let filter = format!(
+            "thumbnail={},crop='min(iw,ih*2/3)':ih,scale={}:-2",
+            THUMBNAIL_BATCH, POSTER_WIDTH
+        );
+        grab_frame(
+            &input,
+            frame_position(duration, POSTER_POSITION),
+            &filter,
+            &poster_path,
+        )
+        .await
+        .context("Failed to generate poster")?;
+    }
+    if wants_backdrop {
+        let filter = format!("thumbnail={},scale={}:-2", THUMBNAIL_BATCH, BACKDROP_WIDTH);
+        grab_frame(
+            &input,
+            frame_position(duration, BACKDROP_POSITION),
+            &filter,
+            &backdrop_path,
+        )
+        .await
+        .context("Failed to generate backdrop")?;
+    }
+    data_saver.set_generated_artwork(
+        media_id,
+        wants_poster.then_some(poster_path.as_str()),
+        wants_backdrop.then_some(backdrop_path.as_str()),
+    )?;
+
+    if let (true, Some((duration, width, height))) = (wants_trickplay, trickplay_size) {
+        generate_trickplay(&input, media_id, duration, width, height).await?;
+    }
+    Ok(true)
+}
+
+/// Saves the most representative frame around `position` as a JPEG image.
+async fn grab_frame(input: &str, position: f64, filter: &str, output: &str) -> Result<()> {
+    let args: Vec<String> = [
+        "-hide_banner",
+        "-loglevel",
+        "error",
+        "-nostdin",
+        "-y",
+        "-ss",
+        &format!("{:.3}", position),
+        "-i",
+        input,
+        "-an",
+        "-sn",
+        "-vf",
+        filter,
+        "-frames:v",
+        "1",
+        "-q:v",
+        "3",
+        output,
+    ]
+    .map(str::to_owned)
+    .to_vec();
+    run_ffmpeg(&args).await
+}
+
+/// Returns what ffmpeg reads a library file from: its path in a local library, its stream on
+/// the running streaming server for a SMB share.
+async fn ffmpeg_input(source: &FileSource, media_id: i64, file_path: &str) -> Result<String> {
+    match source {
+        FileSource::Local(root) => Ok(root.join(file_path).to_string_lossy().into_owned()),
+        FileSource::Smb(_) => stream_url(media_id)
+            .await
+            .context("SMB libraries are read through the streaming server"),
+    }
+}
+
+fn frame_position(duration: Option<f64>, fraction: f64) -> f64 {
+    duration
+        .filter(|duration| *duration > 0.0)
+        .map_or(DEFAULT_FRAME_POSITION, |duration| duration * fraction)
+}
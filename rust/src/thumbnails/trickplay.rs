@@ -0,0 +1,129 @@
+use crate::{subtitles::webvtt::timestamp, transcoder::ffmpeg::run_ffmpeg};
+use anyhow::{anyhow, Context, Result};
+use std::path::PathBuf;
+
+/// Seconds of video between two seek previews.
+pub const TRICKPLAY_INTERVAL: f64 = 10.0;
+
+/// Width of a seek preview, its height following the video aspect ratio.
+const PREVIEW_WIDTH: i32 = 320;
+
+/// Previews per row and per column of a sprite sheet.
+const SPRITE_TILES: u32 = 10;
+
+/// File of the WebVTT index of the seek previews, written once every sprite sheet is.
+pub const INDEX_FILE: &str = "index.vtt";
+
+/// Returns the image cache folder holding the seek previews of a media.
+pub fn trickplay_folder(media_id: i64) -> Result<PathBuf> {
+    let mut folder =
+        std::env::current_dir().context("Failed to retrieve current working directory")?;
+    folder.push("images");
+    folder.push("trickplay");
+    folder.push(media_id.to_string());
+    Ok(folder)
+}
+
+/// Whether the seek previews of a media were generated.
+pub fn has_trickplay(media_id: i64) -> bool {
+    trickplay_folder(media_id).is_ok_and(|folder| folder.join(INDEX_FILE).exists())
+}
+
+/// Tells whether a file of a trickplay folder may be served, keeping requests inside it.
+pub fn is_trickplay_file(file_name: &str) -> bool {
+    file_name == INDEX_FILE
+        || file_name
+            .strip_prefix("sprite_")
+            .and_then(|name| name.strip_suffix(".jpg"))
+            .is_some_and(|index| !index.is_empty() && index.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Generates the seek previews of a video: a frame every `TRICKPLAY_INTERVAL` seconds tiled
+/// into JPEG sprite sheets, with a WebVTT index giving the sheet and area of each preview.
+///
+/// Only key frames are decoded, the preview of a position being the key frame nearest to it.
+pub async fn generate_trickplay(
+    input: &str,
+    media_id: i64,
+    duration: f64,
+    width: i32,
+    height: i32,
+) -> Result<()> {
+    if duration <= 0.0 || width <= 0 || height <= 0 {
+        return Err(anyhow!("Media {} has no known duration or size", media_id));
+    }
+    // Rounded to an even height for the 4:2:0 chroma subsampling of JPEG
+    let preview_height = ((PREVIEW_WIDTH * height / width + 1) / 2 * 2).max(2);
+
+    let folder = trickplay_folder(media_id)?;
+    if folder.exists() {
+        tokio::fs::remove_dir_all(&folder)
+            .await
+            .with_context(|| format!("Failed to clear trickplay folder {:?}", folder))?;
+    }
+    tokio::fs::create_dir_all(&folder)
+        .await
+        .with_context(|| format!("Failed to create trickplay folder {:?}", folder))?;
+
+    let mut args: Vec<String> = [
+        "-hide_banner",
+        "-loglevel",
+        "error",
+        "-nostdin",
+        "-y",
+        "-skip_frame",
+        "nokey",
+        "-i",
+    ]
+    .map(str::to_owned)
+    .to_vec();
+    args.extend([
+        input.to_owned(),
+        "-an".to_owned(),
+        "-sn".to_owned(),
+        "-vf".to_owned(),
+        format!(
+            "fps=1/{},scale={}:{},tile={}x{}",
+            TRICKPLAY_INTERVAL, PREVIEW_WIDTH, preview_height, SPRITE_TILES, SPRITE_TILES
+        ),
+        "-q:v".to_owned(),
+        "5".to_owned(),
+        "-start_number".to_owned(),
+        "0".to_owned(),
+        folder.join("sprite_%d.jpg").to_string_lossy().into_owned(),
+    ]);
+    run_ffmpeg(&args)
+        .await
+        .with_context(|| format!("Failed to generate seek previews of media {}", media_id))?;
+
+    let index = trickplay_index(duration, preview_height);
+    tokio::fs::write(folder.join(INDEX_FILE), index)
+        .await
+        .with_context(|| format!("Failed to write trickplay index of media {}", media_id))?;
+    Ok(())
+}
+
+/// Builds the WebVTT index of the seek previews, each cue pointing at an area of a sprite
+/// sheet as "sprite_0.jpg#xywh=0,0,320,180".
+fn trickplay_index(duration: f64, preview_height: i32) -> String {
+    let count = (duration / TRICKPLAY_INTERVAL).ceil() as u32;
+    let per_sprite = SPRITE_TILES * SPRITE_TILES;
+
+    let mut vtt = String::from("WEBVTT\n");
+    for index in 0..count {
+        let start = index as f64 * TRICKPLAY_INTERVAL;
+        let end = (start + TRICKPLAY_INTERVAL).min(duration);
+        let tile = index % per_sprite;
+        vtt.push_str(&format!(
+            "\n{} --> {}\nsprite_{}.jpg#xywh={},{},{},{}\n",
+            timestamp((start * 1000.0) as u64),
+            timestamp((end * 1000.0) as u64),
+            index / per_sprite,
+            (tile % SPRITE_TILES) as i32 * PREVIEW_WIDTH,
+            (tile / SPRITE_TILES) as i32 * preview_height,
+            PREVIEW_WIDTH,
+            preview_height
+        ));
+    }
+    vtt
+}
@@ -1,5 +1,8 @@
 use crate::{
-    movie_data::movie_data::{Cast, CreditsMovie, Crew, Genre, MovieData, PersonData},
+    movie_data::movie_data::{
+        CollectionData, CollectionInfo, Company, Country, CreditsMovie, Genre, Keyword, Language,
+        MovieData, PersonData,
+    },
     os_interface::file_interface::{create_dir, save_image},
 };
 use anyhow::{anyhow, Context, Result};
@@ -8,7 +11,7 @@ use reqwest::{
     Client, Response,
 };
 use serde::{Deserialize, Serialize};
-use std::io;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 const TMDB_BASE_URL: &str = "https://api.themoviedb.org/3";
 
@@ -52,10 +55,6 @@ impl SearchedMovie {
         &self.genre_ids
     }
 
-    pub fn popularity(&self) -> f32 {
-        self.popularity
-    }
-
     pub fn vote_average(&self) -> f32 {
         self.vote_average
     }
@@ -87,22 +86,264 @@ impl MovieSearchResult {
 }
 // endregion
 
-// region: MOVIE GENRES STRUCT
+// region: MOVIE DETAILS STRUCT
+#[derive(Deserialize, Debug, Clone, Default)]
+struct KeywordList {
+    keywords: Vec<Keyword>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct ImageInfo {
+    file_path: String,
+    #[serde(default)]
+    vote_average: f32,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+struct ImageList {
+    #[serde(default)]
+    posters: Vec<ImageInfo>,
+    #[serde(default)]
+    backdrops: Vec<ImageInfo>,
+}
+
+impl ImageList {
+    fn best(images: &[ImageInfo]) -> Option<String> {
+        images
+            .iter()
+            .max_by(|a, b| a.vote_average.total_cmp(&b.vote_average))
+            .map(|image| image.file_path.clone())
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+struct ExternalIds {
+    imdb_id: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct ReleaseDate {
+    #[serde(default)]
+    certification: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct CountryReleaseDates {
+    iso_3166_1: String,
+    release_dates: Vec<ReleaseDate>,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+struct ReleaseDateList {
+    results: Vec<CountryReleaseDates>,
+}
+
+/// Movie details with credits, images, external ids and release dates appended in the same response.
 #[derive(Deserialize, Debug, Clone)]
-pub struct MovieGenres {
+pub struct MovieDetails {
+    #[serde(default)]
+    original_title: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
     genres: Vec<Genre>,
+    #[serde(default)]
+    vote_average: f32,
+    #[serde(default)]
+    release_date: String,
+    #[serde(default)]
+    overview: String,
+    poster_path: Option<String>,
+    backdrop_path: Option<String>,
+    runtime: Option<i32>,
+    #[serde(default)]
+    tagline: Option<String>,
+    #[serde(default)]
+    status: String,
+    #[serde(default)]
+    budget: i64,
+    #[serde(default)]
+    revenue: i64,
+    imdb_id: Option<String>,
+    #[serde(default)]
+    spoken_languages: Vec<Language>,
+    #[serde(default)]
+    production_companies: Vec<Company>,
+    #[serde(default)]
+    production_countries: Vec<Country>,
+    #[serde(default)]
+    keywords: KeywordList,
+    belongs_to_collection: Option<CollectionInfo>,
+    #[serde(default = "CreditsMovie::new")]
+    credits: CreditsMovie,
+    #[serde(default)]
+    images: ImageList,
+    #[serde(default)]
+    external_ids: ExternalIds,
+    #[serde(default)]
+    release_dates: ReleaseDateList,
 }
-impl MovieGenres {
+impl MovieDetails {
+    pub fn original_title(&self) -> &str {
+        &self.original_title
+    }
+    pub fn title(&self) -> &str {
+        &self.title
+    }
     pub fn genres(&self) -> Vec<Genre> {
         self.genres.clone()
     }
+    pub fn vote_average(&self) -> f32 {
+        self.vote_average
+    }
+    pub fn release_date(&self) -> &str {
+        &self.release_date
+    }
+    pub fn overview(&self) -> &str {
+        &self.overview
+    }
+    /// Main poster, falling back to the best voted one.
+    pub fn poster_path(&self) -> Option<String> {
+        self.poster_path.clone().or_else(|| self.best_poster())
+    }
+    /// Main backdrop, falling back to the best voted one.
+    pub fn backdrop_path(&self) -> Option<String> {
+        self.backdrop_path.clone().or_else(|| self.best_backdrop())
+    }
+    pub fn runtime(&self) -> Option<i32> {
+        self.runtime.filter(|runtime| *runtime > 0)
+    }
+    pub fn tagline(&self) -> &str {
+        self.tagline.as_deref().unwrap_or_default()
+    }
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+    pub fn budget(&self) -> i64 {
+        self.budget
+    }
+    pub fn revenue(&self) -> i64 {
+        self.revenue
+    }
+    pub fn imdb_id(&self) -> Option<String> {
+        self.imdb_id
+            .clone()
+            .or_else(|| self.external_ids.imdb_id.clone())
+            .filter(|id| !id.is_empty())
+    }
+    pub fn spoken_languages(&self) -> Vec<Language> {
+        self.spoken_languages.clone()
+    }
+    pub fn production_companies(&self) -> Vec<Company> {
+        self.production_companies.clone()
+    }
+    pub fn production_countries(&self) -> Vec<Country> {
+        self.production_countries.clone()
+    }
+    pub fn keywords(&self) -> Vec<Keyword> {
+        self.keywords.keywords.clone()
+    }
+    pub fn collection(&self) -> Option<CollectionInfo> {
+        self.belongs_to_collection.clone()
+    }
+    pub fn credits(&self) -> CreditsMovie {
+        self.credits.clone()
+    }
+    /// Best voted poster, used when the movie has no main poster.
+    fn best_poster(&self) -> Option<String> {
+        ImageList::best(&self.images.posters)
+    }
+    /// Best voted backdrop, used when the movie has no main backdrop.
+    fn best_backdrop(&self) -> Option<String> {
+        ImageList::best(&self.images.backdrops)
+    }
+    /// Content rating for the given country, falling back to the first certified release.
+    pub fn certification(&self, country: &str) -> String {
+        let certified = |release: &CountryReleaseDates| {
+            release
+                .release_dates
+                .iter()
+                .map(|date| date.certification.trim())
+                .find(|certification| !certification.is_empty())
+                .map(|certification| certification.to_owned())
+        };
+
+        self.release_dates
+            .results
+            .iter()
+            .filter(|release| release.iso_3166_1 == country)
+            .find_map(certified)
+            .or_else(|| self.release_dates.results.iter().find_map(certified))
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct GenreList {
+    genres: Vec<Genre>,
 }
 
 // endregion
 
+// region: PERSON MOVIE CREDITS STRUCT
+#[derive(Deserialize, Debug, Clone)]
+pub struct PersonMovieCredit {
+    id: i64,
+    title: String,
+    #[serde(default)]
+    release_date: String,
+    poster_path: Option<String>,
+    #[serde(default)]
+    character: String,
+    #[serde(default = "acting_department")]
+    department: String,
+    #[serde(default)]
+    job: String,
+}
+impl PersonMovieCredit {
+    pub fn id(&self) -> i64 {
+        self.id
+    }
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+    pub fn release_date(&self) -> &str {
+        &self.release_date
+    }
+    pub fn poster_path(&self) -> &Option<String> {
+        &self.poster_path
+    }
+    pub fn character(&self) -> &str {
+        &self.character
+    }
+    pub fn department(&self) -> &str {
+        &self.department
+    }
+    pub fn job(&self) -> &str {
+        &self.job
+    }
+}
+
+fn acting_department() -> String {
+    "Acting".to_owned()
+}
+
+#[derive(Deserialize, Debug)]
+pub struct PersonMovieCredits {
+    cast: Vec<PersonMovieCredit>,
+    crew: Vec<PersonMovieCredit>,
+}
+impl PersonMovieCredits {
+    pub fn credits(&self) -> impl Iterator<Item = &PersonMovieCredit> {
+        self.cast.iter().chain(self.crew.iter())
+    }
+}
+// endregion
+
 /// Represents a client for retrieving movie information from the TMDB API.
 pub struct TMDBClient {
     client: Client,
+    requests: AtomicU64,
 }
 
 impl TMDBClient {
@@ -120,7 +361,19 @@ impl TMDBClient {
             .build()
             .context("Failed to build client")?;
 
-        Ok(Self { client: client })
+        Ok(Self {
+            client,
+            requests: AtomicU64::new(0),
+        })
+    }
+
+    /// Number of API requests sent by this client, images excluded.
+    pub fn request_count(&self) -> u64 {
+        self.requests.load(Ordering::Relaxed)
+    }
+
+    fn count_request(&self) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
     }
 
     // region: ----- GET MOVIE DATA -----
@@ -170,6 +423,7 @@ impl TMDBClient {
 
         let url = format!("{}/search/movie", TMDB_BASE_URL);
 
+        self.count_request();
         let response = self
             .client
             .get(&url)
@@ -211,48 +465,48 @@ impl TMDBClient {
                 result_movie = movie.to_owned();
             }
         }
-        return result_movie;
+        result_movie
     }
 
-    /// Fetches movie genres from the TMDB API by tmdbId.
-    pub async fn fetch_movie_genres(&self, tmdb_id: i64) -> Result<MovieGenres> {
-        let url = format!("{}/movie/{}?language=en-US", TMDB_BASE_URL, &tmdb_id);
+    /// Fetches the official movie genre list from the TMDB API.
+    pub async fn fetch_genre_list(&self) -> Result<Vec<Genre>> {
+        let url = format!("{}/genre/movie/list?language=en-US", TMDB_BASE_URL);
 
+        self.count_request();
         let response = self
             .client
             .get(&url)
             .send()
             .await
-            .with_context(|| {
-                format!(
-                    "Failed to get detail response for movie id: {} , from url: {}",
-                    tmdb_id, &url
-                )
-            })?
+            .with_context(|| format!("Failed to get genre list response from url: {}", &url))?
             .error_for_status()
             .with_context(|| {
                 format!(
-                    "TMDB returned error status for movie id: {} , from url: {}",
-                    tmdb_id, &url
+                    "TMDB returned error status for genre list, from url: {}",
+                    &url
                 )
             })?;
 
-        let movie_details = response.json::<MovieGenres>().await.with_context(|| {
+        let genre_list = response.json::<GenreList>().await.with_context(|| {
             format!(
-                "Failed to deserialize detail response for movie id: {}, from url: {}",
-                tmdb_id, &url
+                "Failed to deserialize genre list response from url: {}",
+                &url
             )
         })?;
-        Ok(movie_details)
+        Ok(genre_list.genres)
     }
 
-    /// Fetches movie credits from the TMDB API by tmdbId.
-    pub async fn fetch_movie_credits(&self, tmdb_id: i64) -> Result<CreditsMovie> {
+    /// Fetches movie details from the TMDB API by tmdbId.
+    ///
+    /// Keywords, credits, images, external ids and release dates are appended to the same
+    /// request, so a single call gathers everything the scan needs once the movie is found.
+    pub async fn fetch_movie_details(&self, tmdb_id: i64) -> Result<MovieDetails> {
         let url = format!(
-            "{}/movie/{}/credits?language=en-US",
+            "{}/movie/{}?language=en-US&append_to_response=keywords,credits,images,external_ids,release_dates&include_image_language=en,null",
             TMDB_BASE_URL, &tmdb_id
         );
 
+        self.count_request();
         let response = self
             .client
             .get(&url)
@@ -260,7 +514,7 @@ impl TMDBClient {
             .await
             .with_context(|| {
                 format!(
-                    "Failed to get credit response for movie id: {} , from url: {}",
+                    "Failed to get detail response for movie id: {} , from url: {}",
                     tmdb_id, &url
                 )
             })?
@@ -272,18 +526,19 @@ impl TMDBClient {
                 )
             })?;
 
-        let credits_details = response.json::<CreditsMovie>().await.with_context(|| {
+        let movie_details = response.json::<MovieDetails>().await.with_context(|| {
             format!(
-                "Failed to deserialize credit response for movie id: {}, from url: {}",
+                "Failed to deserialize detail response for movie id: {}, from url: {}",
                 tmdb_id, &url
             )
         })?;
-        Ok(credits_details)
+        Ok(movie_details)
     }
 
     pub async fn fetch_person_details(&self, tmdb_id: i64) -> Result<PersonData> {
         let url = format!("{}/person/{}", TMDB_BASE_URL, &tmdb_id);
 
+        self.count_request();
         let response = self
             .client
             .get(&url)
@@ -311,23 +566,96 @@ impl TMDBClient {
         })?;
         Ok(person_details)
     }
+
+    /// Fetches a collection and the list of movies it contains from the TMDB API by collection id.
+    pub async fn fetch_collection_details(&self, collection_id: i64) -> Result<CollectionData> {
+        let url = format!(
+            "{}/collection/{}?language=en-US",
+            TMDB_BASE_URL, &collection_id
+        );
+
+        self.count_request();
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to get collection response for collection id: {} , from url: {}",
+                    collection_id, &url
+                )
+            })?
+            .error_for_status()
+            .with_context(|| {
+                format!(
+                    "TMDB returned error status for collection id: {} , from url: {}",
+                    collection_id, &url
+                )
+            })?;
+
+        let collection = response.json::<CollectionData>().await.with_context(|| {
+            format!(
+                "Failed to deserialize collection response for collection id: {}, from url: {}",
+                collection_id, &url
+            )
+        })?;
+        Ok(collection)
+    }
+
+    /// Fetches every movie a person is credited in from the TMDB API by tmdbId.
+    pub async fn fetch_person_movie_credits(&self, tmdb_id: i64) -> Result<PersonMovieCredits> {
+        let url = format!(
+            "{}/person/{}/movie_credits?language=en-US",
+            TMDB_BASE_URL, &tmdb_id
+        );
+
+        self.count_request();
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to get movie credits response for person id: {} , from url: {}",
+                    tmdb_id, &url
+                )
+            })?
+            .error_for_status()
+            .with_context(|| {
+                format!(
+                    "TMDB returned error status for person id: {} , from url: {}",
+                    tmdb_id, &url
+                )
+            })?;
+
+        let person_credits = response
+            .json::<PersonMovieCredits>()
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to deserialize movie credits response for person id: {}, from url: {}",
+                    tmdb_id, &url
+                )
+            })?;
+        Ok(person_credits)
+    }
     // endregion
 
     // region: ----- GET IMAGES -----
 
-    async fn update_images<T, FGet>(
+    /// Downloads an image unless it is already stored, or when `overwrite` asks to replace it.
+    async fn update_images(
         &self,
-        item: &T,
+        picture_path: Option<&String>,
         category: &str,
         name: &str,
         subdir: &str,
         image_size: &str,
-        get_path: FGet,
-    ) -> Result<String>
-    where
-        FGet: Fn(&T) -> Option<&String>,
-    {
-        let picture_path = match get_path(item) {
+        overwrite: bool,
+    ) -> Result<String> {
+        let picture_path = match picture_path {
             Some(p) => p,
             None => return Err(anyhow!("Picture path empty")),
         };
@@ -335,7 +663,7 @@ impl TMDBClient {
         let (created, dir_path) = create_dir(category, subdir, name)
             .with_context(|| format!("Error creating directory for: {}", name))?;
 
-        if !created {
+        if !created && !overwrite {
             tracing::debug!("Picture path already exists: {}", dir_path);
             return Ok(dir_path);
         }
@@ -349,30 +677,79 @@ impl TMDBClient {
             .await
             .with_context(|| format!("Failed to save image for: {}", name))?;
 
-        return Ok(dir_path);
+        Ok(dir_path)
     }
 
-    pub async fn update_person_images(&self, person: &mut PersonData) -> Result<String> {
+    pub async fn update_person_images(
+        &self,
+        person: &mut PersonData,
+        overwrite: bool,
+    ) -> Result<String> {
         let person_name = person.name().to_owned();
-        self.update_images(person, "person", &person_name, &person_name, "w300", |c| {
-            c.picture_path()
-        })
+        self.update_images(
+            person.picture_path(),
+            "person",
+            &person_name,
+            &person_name,
+            "w300",
+            overwrite,
+        )
         .await
     }
 
-    pub async fn update_movie_poster(&self, movie: &MovieData) -> Result<String> {
+    pub async fn update_movie_poster(&self, movie: &MovieData, overwrite: bool) -> Result<String> {
         let movie_name = movie.title().to_owned();
-        self.update_images(movie, "movie", "poster", &movie_name, "w780", |m| {
-            m.poster()
-        })
+        self.update_images(
+            movie.poster(),
+            "movie",
+            "poster",
+            &movie_name,
+            "w780",
+            overwrite,
+        )
         .await
     }
 
-    pub async fn update_movie_backdrop(&self, movie: &MovieData) -> Result<String> {
+    pub async fn update_movie_backdrop(
+        &self,
+        movie: &MovieData,
+        overwrite: bool,
+    ) -> Result<String> {
         let movie_name = movie.title().to_owned();
-        self.update_images(movie, "movie", "backdrop", &movie_name, "original", |m| {
-            m.backdrop()
-        })
+        self.update_images(
+            movie.backdrop(),
+            "movie",
+            "backdrop",
+            &movie_name,
+            "original",
+            overwrite,
+        )
+        .await
+    }
+
+    pub async fn update_collection_poster(&self, collection: &CollectionData) -> Result<String> {
+        let collection_name = collection.name().to_owned();
+        self.update_images(
+            collection.poster(),
+            "collection",
+            "poster",
+            &collection_name,
+            "w780",
+            false,
+        )
+        .await
+    }
+
+    pub async fn update_collection_backdrop(&self, collection: &CollectionData) -> Result<String> {
+        let collection_name = collection.name().to_owned();
+        self.update_images(
+            collection.backdrop(),
+            "collection",
+            "backdrop",
+            &collection_name,
+            "original",
+            false,
+        )
         .await
     }
 
@@ -404,7 +781,7 @@ impl TMDBClient {
                 }
             }
         }
-        return Ok(response);
+        Ok(response)
     }
     // endregion
 }
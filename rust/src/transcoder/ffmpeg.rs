@@ -0,0 +1,237 @@
+use crate::transcoder::playback_decision::{PlaybackMethod, PlaybackPlan, SubtitlePlan};
+use anyhow::{anyhow, bail, Context, Result};
+use std::{
+    path::{Path, PathBuf},
+    process::Stdio,
+    sync::LazyLock,
+};
+use tokio::process::Command;
+
+/// Environment variable pointing at the ffmpeg binary, looked up in the `PATH` otherwise.
+pub const FFMPEG_PATH_VARIABLE: &str = "FFMPEG_PATH";
+
+/// Seconds of media in a HLS segment.
+pub const SEGMENT_DURATION: f64 = 6.0;
+
+/// Name of the playlist ffmpeg writes in the output folder, served for a remux.
+pub const ENCODER_PLAYLIST: &str = "encoder.m3u8";
+
+/// Name of the text subtitle burned into a transcode, extracted in the output folder.
+pub const SUBTITLE_FILE: &str = "subtitle.ass";
+
+/// The ffmpeg binary, looked up once per process.
+static FFMPEG: LazyLock<Option<PathBuf>> = LazyLock::new(find_ffmpeg);
+
+/// Returns the ffmpeg binary, None when it is not installed.
+pub fn ffmpeg_path() -> Option<&'static Path> {
+    FFMPEG.as_deref()
+}
+
+fn find_ffmpeg() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(FFMPEG_PATH_VARIABLE) {
+        let path = PathBuf::from(path);
+        if path.is_file() {
+            return Some(path);
+        }
+        tracing::warn!(path = ?path, "{} does not point at a file", FFMPEG_PATH_VARIABLE);
+    }
+
+    let file_name = if cfg!(windows) {
+        "ffmpeg.exe"
+    } else {
+        "ffmpeg"
+    };
+    let path = std::env::split_paths(&std::env::var_os("PATH")?)
+        .map(|folder| folder.join(file_name))
+        .find(|path| path.is_file());
+    if path.is_none() {
+        tracing::info!("ffmpeg not found, media will only be played as is");
+    }
+    path
+}
+
+/// Runs ffmpeg to completion, failing with its error output when it does not succeed.
+pub async fn run_ffmpeg(args: &[String]) -> Result<()> {
+    let ffmpeg = ffmpeg_path().ok_or_else(|| anyhow!("ffmpeg is not installed"))?;
+    let output = Command::new(ffmpeg)
+        .args(args)
+        .stdin(Stdio::null())
+        .kill_on_drop(true)
+        .output()
+        .await
+        .context("Failed to run ffmpeg")?;
+    if !output.status.success() {
+        bail!(
+            "ffmpeg ended with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+/// Builds the ffmpeg arguments writing the subtitle stream at `position` of `input` to
+/// `SUBTITLE_FILE` in `output_folder`, a subtitle file holding a single stream.
+pub fn subtitle_arguments(input: &str, position: usize, output_folder: &Path) -> Vec<String> {
+    let mut args: Vec<String> = vec!["-hide_banner", "-loglevel", "error", "-nostdin", "-y"]
+        .into_iter()
+        .map(str::to_owned)
+        .collect();
+    args.extend([
+        "-i".to_owned(),
+        input.to_owned(),
+        "-map".to_owned(),
+        format!("0:s:{}", position),
+        "-c:s".to_owned(),
+        "ass".to_owned(),
+        output_folder
+            .join(SUBTITLE_FILE)
+            .to_string_lossy()
+            .into_owned(),
+    ]);
+    args
+}
+
+/// Name of the segment file holding the segment at `index`.
+pub fn segment_file_name(index: u32) -> String {
+    format!("segment_{}.ts", index)
+}
+
+/// Builds the ffmpeg arguments encoding `input` into HLS segments in `output_folder`, from
+/// the segment at `start_index` to the end.
+///
+/// Segments are written under a temporary name and renamed once complete, so that an existing
+/// segment file can be served. Their timestamps are offset to the position they start at,
+/// letting a player seek across encodes. A copied video can only be cut on its key frames,
+/// its segments then lasting about `SEGMENT_DURATION` rather than exactly, so a remux is
+/// encoded once from the start and played from the growing `ENCODER_PLAYLIST`.
+pub fn hls_arguments(
+    input: &str,
+    plan: &PlaybackPlan,
+    start_index: u32,
+    output_folder: &Path,
+) -> Vec<String> {
+    let start = start_index as f64 * SEGMENT_DURATION;
+    let mut args: Vec<String> = vec!["-hide_banner", "-loglevel", "error", "-nostdin", "-y"]
+        .into_iter()
+        .map(str::to_owned)
+        .collect();
+    if start_index > 0 {
+        args.extend(["-ss".to_owned(), format!("{:.3}", start)]);
+    }
+    args.extend(["-i".to_owned(), input.to_owned()]);
+
+    if plan.method == PlaybackMethod::Transcode {
+        let mut filters = vec![];
+        let mut video = "[0:v:0]".to_owned();
+        match plan.subtitle.as_ref() {
+            Some(SubtitlePlan::Image { position }) => {
+                video.push_str(&format!("[0:s:{}]overlay", position));
+            }
+            Some(_) => {
+                // The subtitle file is read from its start, the video timestamps being
+                // moved back to the seeked position while it is drawn
+                let subtitle_path = output_folder.join(SUBTITLE_FILE);
+                filters.push(format!("setpts=PTS+{:.3}/TB", start));
+                filters.push(format!(
+                    "subtitles=filename='{}'",
+                    escape_filter_value(&subtitle_path.to_string_lossy())
+                ));
+                filters.push("setpts=PTS-STARTPTS".to_owned());
+            }
+            None => {}
+        }
+        if plan.max_width.is_some() || plan.max_height.is_some() {
+            // Fits the frame in both limits keeping its aspect ratio, sizes of H.264 frames
+            // having to be even
+            filters.push(format!(
+                "scale=w='{}':h='{}':force_original_aspect_ratio=decrease:\
+                 force_divisible_by=2",
+                plan.max_width
+                    .map_or("iw".to_owned(), |width| format!("min(iw,{})", width)),
+                plan.max_height
+                    .map_or("ih".to_owned(), |height| format!("min(ih,{})", height)),
+            ));
+        }
+        filters.push("format=yuv420p".to_owned());
+        let separator = if video.ends_with(']') { "" } else { "," };
+        args.extend([
+            "-filter_complex".to_owned(),
+            format!("{}{}{}[video]", video, separator, filters.join(",")),
+            "-map".to_owned(),
+            "[video]".to_owned(),
+            "-c:v".to_owned(),
+            "libx264".to_owned(),
+            "-preset".to_owned(),
+            "veryfast".to_owned(),
+            "-b:v".to_owned(),
+            plan.video_bitrate.to_string(),
+            "-maxrate".to_owned(),
+            plan.video_bitrate.to_string(),
+            "-bufsize".to_owned(),
+            (plan.video_bitrate * 2).to_string(),
+            // A key frame opening every segment keeps them exactly `SEGMENT_DURATION` long
+            "-force_key_frames".to_owned(),
+            format!("expr:gte(t,n_forced*{})", SEGMENT_DURATION),
+            "-sc_threshold".to_owned(),
+            "0".to_owned(),
+        ]);
+    } else {
+        args.extend(["-map", "0:v:0", "-c:v", "copy"].map(str::to_owned));
+    }
+
+    if let Some(audio) = plan.audio.as_ref() {
+        args.extend(["-map".to_owned(), format!("0:a:{}", audio.position)]);
+        if audio.copy {
+            args.extend(["-c:a", "copy"].map(str::to_owned));
+        } else {
+            args.extend([
+                "-c:a".to_owned(),
+                "aac".to_owned(),
+                "-ac".to_owned(),
+                audio.channels.to_string(),
+                "-b:a".to_owned(),
+                (audio.channels * 64_000).to_string(),
+            ]);
+        }
+    }
+
+    if plan.method == PlaybackMethod::Remux {
+        args.extend(["-hls_playlist_type", "event"].map(str::to_owned));
+    }
+    args.extend([
+        "-output_ts_offset".to_owned(),
+        format!("{:.3}", start),
+        "-f".to_owned(),
+        "hls".to_owned(),
+        "-hls_time".to_owned(),
+        SEGMENT_DURATION.to_string(),
+        "-hls_list_size".to_owned(),
+        "0".to_owned(),
+        "-hls_flags".to_owned(),
+        "temp_file".to_owned(),
+        "-hls_segment_type".to_owned(),
+        "mpegts".to_owned(),
+        "-start_number".to_owned(),
+        start_index.to_string(),
+        "-hls_segment_filename".to_owned(),
+        output_folder
+            .join("segment_%d.ts")
+            .to_string_lossy()
+            .into_owned(),
+        output_folder
+            .join(ENCODER_PLAYLIST)
+            .to_string_lossy()
+            .into_owned(),
+    ]);
+    args
+}
+
+/// Escapes a value for a filter option written between quotes in a filter graph,
+/// where a `:` would otherwise end the option.
+fn escape_filter_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(':', "\\:")
+        .replace('\'', "\\'")
+}
@@ -0,0 +1,3 @@
+pub mod ffmpeg;
+pub mod playback_decision;
+pub mod transcoder;
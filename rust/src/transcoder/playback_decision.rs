@@ -0,0 +1,486 @@
+use crate::movie_data::movie_data::{MediaInfo, MediaStream, PlaybackRequest};
+use anyhow::{anyhow, bail, Result};
+
+/// Video codecs a HLS MPEG-TS segment can carry as is.
+const HLS_VIDEO_CODECS: [&str; 2] = ["h264", "hevc"];
+
+/// Audio codecs a HLS MPEG-TS segment can carry as is.
+const HLS_AUDIO_CODECS: [&str; 4] = ["aac", "ac3", "eac3", "mp3"];
+
+/// Subtitle codecs drawn as pictures, overlaid on the video rather than rendered from text.
+const IMAGE_SUBTITLE_CODECS: [&str; 3] = ["hdmv_pgs_subtitle", "dvd_subtitle", "dvb_subtitle"];
+
+/// Video bitrate of a transcode by output height, the lowest height not below it being used.
+const TRANSCODE_BITRATES: [(i32, i64); 4] = [
+    (2160, 20_000_000),
+    (1080, 8_000_000),
+    (720, 4_000_000),
+    (480, 1_500_000),
+];
+
+/// Audio channels of a stream whose header does not give them.
+const DEFAULT_AUDIO_CHANNELS: i32 = 2;
+
+/// How a media reaches a player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaybackMethod {
+    /// The file is streamed as is.
+    DirectPlay,
+    /// The video is copied into HLS segments cut on its key frames, the audio being converted
+    /// if needed.
+    Remux,
+    /// The video is encoded again into HLS segments.
+    Transcode,
+}
+
+impl PlaybackMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlaybackMethod::DirectPlay => "direct_play",
+            PlaybackMethod::Remux => "remux",
+            PlaybackMethod::Transcode => "transcode",
+        }
+    }
+}
+
+/// Audio of a HLS stream, positions counting the streams of a kind as ffmpeg maps them.
+#[derive(Debug, Clone)]
+pub struct AudioPlan {
+    pub position: usize,
+    /// Copied as is, converted to AAC otherwise.
+    pub copy: bool,
+    pub channels: i32,
+}
+
+/// A subtitle burned into the video, embedded positions counting the subtitle streams.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubtitlePlan {
+    /// An embedded subtitle drawn as pictures, overlaid on the video.
+    Image { position: usize },
+    /// An embedded text subtitle, extracted to a file before encoding.
+    Text { position: usize },
+    /// A subtitle file next to the video, by id.
+    File { subtitle_id: i64 },
+}
+
+/// Everything a HLS stream of a media is encoded with.
+#[derive(Debug, Clone)]
+pub struct PlaybackPlan {
+    pub method: PlaybackMethod,
+    pub reasons: Vec<String>,
+    pub audio: Option<AudioPlan>,
+    pub subtitle: Option<SubtitlePlan>,
+    /// Output width limit of a transcode, None when the source width is within the limits.
+    pub max_width: Option<i32>,
+    /// Output height limit of a transcode, None when the source height is within the limits.
+    pub max_height: Option<i32>,
+    /// Video bitrate of a transcode.
+    pub video_bitrate: i64,
+}
+
+/// Decides whether a probed media is played as is, remuxed or transcoded for a player.
+///
+/// A video the player can not decode, or above its resolution, bitrate or HDR limits, is
+/// transcoded, as is one with a burned subtitle. Otherwise an audio track or a container
+/// the player does not support only needs a remux.
+pub fn decide_playback(info: &MediaInfo, request: &PlaybackRequest) -> Result<PlaybackPlan> {
+    let capabilities = &request.capabilities;
+    let max_height = min_limit(capabilities.max_height, request.max_height);
+    let max_bitrate = min_limit(capabilities.max_bitrate, request.max_bitrate);
+
+    let mut video_reasons = vec![];
+    let video_codec = info.video_codec.clone().unwrap_or_default();
+    if !contains(&capabilities.video_codecs, &video_codec) {
+        video_reasons.push(format!("Video codec {} is not supported", video_codec));
+    }
+    if let (Some(width), Some(max_width)) = (info.width, capabilities.max_width) {
+        if width > max_width {
+            video_reasons.push(format!("Width {} is above {}", width, max_width));
+        }
+    }
+    if let (Some(height), Some(max_height)) = (info.height, max_height) {
+        if height > max_height {
+            video_reasons.push(format!("Height {} is above {}", height, max_height));
+        }
+    }
+    if let (Some(bitrate), Some(max_bitrate)) = (info.bitrate, max_bitrate) {
+        if bitrate > max_bitrate {
+            video_reasons.push(format!("Bitrate {} is above {}", bitrate, max_bitrate));
+        }
+    }
+    if let Some(hdr_format) = info.hdr_format.as_ref() {
+        if !capabilities.supports_hdr {
+            video_reasons.push(format!("{} is not supported", hdr_format));
+        }
+    }
+
+    let subtitle = match (request.subtitle_stream, request.subtitle_file) {
+        (Some(_), Some(_)) => bail!("Only one subtitle can be burned into the video"),
+        (Some(index), None) => {
+            let (position, stream) = find_stream(info, "subtitle", index)?;
+            Some(if IMAGE_SUBTITLE_CODECS.contains(&stream.codec.as_str()) {
+                SubtitlePlan::Image { position }
+            } else {
+                SubtitlePlan::Text { position }
+            })
+        }
+        (None, Some(subtitle_id)) => Some(SubtitlePlan::File { subtitle_id }),
+        (None, None) => None,
+    };
+    if subtitle.is_some() {
+        video_reasons.push("Subtitle is burned into the video".to_owned());
+    }
+
+    let audio_stream = match request.audio_stream {
+        Some(index) => Some(find_stream(info, "audio", index)?),
+        None => default_stream(info, "audio"),
+    };
+    let mut audio_reasons = vec![];
+    if let Some((_, stream)) = audio_stream {
+        if !contains(&capabilities.audio_codecs, &stream.codec) {
+            audio_reasons.push(format!("Audio codec {} is not supported", stream.codec));
+        }
+        if let (Some(channels), Some(max_channels)) =
+            (stream.channels, capabilities.max_audio_channels)
+        {
+            if channels > max_channels {
+                audio_reasons.push(format!(
+                    "{} audio channels are above {}",
+                    channels, max_channels
+                ));
+            }
+        }
+    }
+    let mut container_reasons = vec![];
+    if !container_names(&info.container)
+        .iter()
+        .any(|name| contains(&capabilities.containers, name))
+    {
+        container_reasons.push(format!("Container {} is not supported", info.container));
+    }
+
+    let needs_remux = !audio_reasons.is_empty() || !container_reasons.is_empty();
+    if video_reasons.is_empty() && needs_remux && !HLS_VIDEO_CODECS.contains(&video_codec.as_str())
+    {
+        video_reasons.push(format!("Video codec {} can not be remuxed", video_codec));
+    }
+
+    let method = if !video_reasons.is_empty() {
+        PlaybackMethod::Transcode
+    } else if needs_remux {
+        PlaybackMethod::Remux
+    } else {
+        PlaybackMethod::DirectPlay
+    };
+
+    let audio = audio_stream.map(|(position, stream)| {
+        let copy = audio_reasons.is_empty() && HLS_AUDIO_CODECS.contains(&stream.codec.as_str());
+        let channels = stream.channels.unwrap_or(DEFAULT_AUDIO_CHANNELS);
+        AudioPlan {
+            position,
+            copy,
+            channels: capabilities
+                .max_audio_channels
+                .map_or(channels, |max_channels| channels.min(max_channels)),
+        }
+    });
+
+    let width_limit = exceeded_limit(info.width, capabilities.max_width);
+    let height_limit = exceeded_limit(info.height, max_height);
+    // The frame is scaled down to fit both limits, keeping its aspect ratio
+    let mut output_height = height_limit.or(info.height).unwrap_or(1080);
+    if let (Some(max_width), Some(width), Some(height)) = (width_limit, info.width, info.height) {
+        let scaled_height = height as i64 * max_width as i64 / width as i64;
+        output_height = output_height.min(scaled_height as i32);
+    }
+    let quality_bitrate = TRANSCODE_BITRATES
+        .iter()
+        .rev()
+        .find(|(height, _)| output_height <= *height)
+        .map_or(TRANSCODE_BITRATES[0].1, |(_, bitrate)| *bitrate);
+
+    let mut reasons = video_reasons;
+    reasons.extend(audio_reasons);
+    reasons.extend(container_reasons);
+    Ok(PlaybackPlan {
+        method,
+        reasons,
+        audio,
+        subtitle,
+        max_width: width_limit,
+        max_height: height_limit,
+        video_bitrate: max_bitrate.map_or(quality_bitrate, |max| max.min(quality_bitrate)),
+    })
+}
+
+/// Returns the stream of a kind with the given track number, with its position among them.
+fn find_stream<'a>(
+    info: &'a MediaInfo,
+    kind: &str,
+    index: i32,
+) -> Result<(usize, &'a MediaStream)> {
+    info.streams
+        .iter()
+        .filter(|stream| stream.kind == kind)
+        .enumerate()
+        .find(|(_, stream)| stream.index == index)
+        .ok_or_else(|| anyhow!("No {} stream found for track {}", kind, index))
+}
+
+/// Returns the default stream of a kind, else the first one, with its position among them.
+fn default_stream<'a>(info: &'a MediaInfo, kind: &str) -> Option<(usize, &'a MediaStream)> {
+    let mut streams = info
+        .streams
+        .iter()
+        .filter(|stream| stream.kind == kind)
+        .enumerate();
+    streams
+        .clone()
+        .find(|(_, stream)| stream.is_default)
+        .or_else(|| streams.next())
+}
+
+/// Names a client may declare a probed container as.
+fn container_names(container: &str) -> &'static [&'static str] {
+    match container {
+        "matroska" => &["mkv", "matroska"],
+        "webm" => &["webm"],
+        "mp4" => &["mp4", "m4v"],
+        "mov" => &["mov"],
+        _ => &[],
+    }
+}
+
+/// Returns the limit when a source dimension is above it, or unknown.
+fn exceeded_limit(value: Option<i32>, limit: Option<i32>) -> Option<i32> {
+    match (value, limit) {
+        (Some(value), Some(limit)) if value > limit => Some(limit),
+        (None, Some(limit)) => Some(limit),
+        _ => None,
+    }
+}
+
+fn contains(values: &[String], wanted: &str) -> bool {
+    values
+        .iter()
+        .any(|value| value.eq_ignore_ascii_case(wanted))
+}
+
+fn min_limit<T: Ord>(first: Option<T>, second: Option<T>) -> Option<T> {
+    match (first, second) {
+        (Some(first), Some(second)) => Some(first.min(second)),
+        (first, second) => first.or(second),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::movie_data::movie_data::ClientCapabilities;
+
+    fn stream(index: i32, kind: &str, codec: &str, channels: Option<i32>) -> MediaStream {
+        MediaStream {
+            index,
+            kind: kind.to_owned(),
+            codec: codec.to_owned(),
+            language: None,
+            title: None,
+            channels,
+            is_default: false,
+            is_forced: false,
+        }
+    }
+
+    /// A 1080p H.264 Matroska file with a 5.1 E-AC-3 track, a default stereo AAC track,
+    /// a text and a picture subtitle.
+    fn movie() -> MediaInfo {
+        let mut stereo = stream(3, "audio", "aac", Some(2));
+        stereo.is_default = true;
+        MediaInfo {
+            container: "matroska".to_owned(),
+            duration: Some(7200.0),
+            width: Some(1920),
+            height: Some(1080),
+            video_codec: Some("h264".to_owned()),
+            hdr_format: None,
+            bitrate: Some(6_000_000),
+            streams: vec![
+                stream(1, "video", "h264", None),
+                stream(2, "audio", "eac3", Some(6)),
+                stereo,
+                stream(4, "subtitle", "subrip", None),
+                stream(5, "subtitle", "hdmv_pgs_subtitle", None),
+            ],
+            chapters: vec![],
+        }
+    }
+
+    fn request() -> PlaybackRequest {
+        let names = |names: &[&str]| names.iter().map(|name| name.to_string()).collect();
+        PlaybackRequest {
+            capabilities: ClientCapabilities {
+                containers: names(&["MKV", "mp4"]),
+                video_codecs: names(&["h264", "hevc"]),
+                audio_codecs: names(&["aac", "eac3"]),
+                max_width: Some(3840),
+                max_height: Some(2160),
+                max_bitrate: None,
+                max_audio_channels: Some(6),
+                supports_hdr: false,
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn supported_media_is_played_directly() {
+        let plan = decide_playback(&movie(), &request()).unwrap();
+        assert_eq!(plan.method, PlaybackMethod::DirectPlay);
+        assert!(plan.reasons.is_empty());
+        assert_eq!((plan.max_width, plan.max_height), (None, None));
+
+        let audio = plan.audio.unwrap();
+        assert_eq!(audio.position, 1);
+        assert!(audio.copy);
+        assert_eq!(audio.channels, 2);
+    }
+
+    #[test]
+    fn unsupported_audio_or_container_is_remuxed() {
+        let mut aac_only = request();
+        aac_only.capabilities.audio_codecs = vec!["aac".to_owned()];
+        aac_only.capabilities.max_audio_channels = Some(2);
+        aac_only.audio_stream = Some(2);
+
+        let plan = decide_playback(&movie(), &aac_only).unwrap();
+        assert_eq!(plan.method, PlaybackMethod::Remux);
+        assert_eq!(
+            plan.reasons,
+            vec![
+                "Audio codec eac3 is not supported",
+                "6 audio channels are above 2"
+            ]
+        );
+        let audio = plan.audio.unwrap();
+        assert_eq!(audio.position, 0);
+        assert!(!audio.copy);
+        assert_eq!(audio.channels, 2);
+
+        let mut mp4_only = request();
+        mp4_only.capabilities.containers = vec!["mp4".to_owned()];
+        let plan = decide_playback(&movie(), &mp4_only).unwrap();
+        assert_eq!(plan.method, PlaybackMethod::Remux);
+        assert_eq!(plan.reasons, vec!["Container matroska is not supported"]);
+        assert!(plan.audio.unwrap().copy);
+    }
+
+    #[test]
+    fn video_that_can_not_be_remuxed_is_transcoded() {
+        let mut info = movie();
+        info.container = "webm".to_owned();
+        info.video_codec = Some("vp9".to_owned());
+        let mut request = request();
+        request.capabilities.video_codecs.push("vp9".to_owned());
+
+        let plan = decide_playback(&info, &request).unwrap();
+        assert_eq!(plan.method, PlaybackMethod::Transcode);
+        assert_eq!(
+            plan.reasons,
+            vec![
+                "Video codec vp9 can not be remuxed",
+                "Container webm is not supported"
+            ]
+        );
+    }
+
+    #[test]
+    fn video_above_the_limits_is_scaled_down() {
+        let mut info = movie();
+        info.width = Some(3840);
+        info.height = Some(1600);
+        info.hdr_format = Some("HDR10".to_owned());
+        info.bitrate = Some(40_000_000);
+        let mut request = request();
+        request.capabilities.max_width = Some(1920);
+        request.capabilities.max_bitrate = Some(30_000_000);
+
+        let plan = decide_playback(&info, &request).unwrap();
+        assert_eq!(plan.method, PlaybackMethod::Transcode);
+        assert_eq!(
+            plan.reasons,
+            vec![
+                "Width 3840 is above 1920",
+                "Bitrate 40000000 is above 30000000",
+                "HDR10 is not supported"
+            ]
+        );
+        assert_eq!((plan.max_width, plan.max_height), (Some(1920), None));
+        // 3840x1600 fits 1920 wide at 800 lines, encoded at the 1080p bitrate
+        assert_eq!(plan.video_bitrate, 8_000_000);
+    }
+
+    #[test]
+    fn quality_chosen_by_the_user_lowers_the_client_limits() {
+        let mut request = request();
+        request.max_height = Some(720);
+        request.max_bitrate = Some(3_000_000);
+
+        let plan = decide_playback(&movie(), &request).unwrap();
+        assert_eq!(plan.method, PlaybackMethod::Transcode);
+        assert_eq!(
+            plan.reasons,
+            vec![
+                "Height 1080 is above 720",
+                "Bitrate 6000000 is above 3000000"
+            ]
+        );
+        assert_eq!(plan.max_height, Some(720));
+        assert_eq!(plan.video_bitrate, 3_000_000);
+    }
+
+    #[test]
+    fn unknown_dimensions_are_limited() {
+        let mut info = movie();
+        info.width = None;
+        info.height = None;
+        let mut request = request();
+        request.capabilities.video_codecs.clear();
+
+        let plan = decide_playback(&info, &request).unwrap();
+        assert_eq!(plan.method, PlaybackMethod::Transcode);
+        assert_eq!((plan.max_width, plan.max_height), (Some(3840), Some(2160)));
+        assert_eq!(plan.video_bitrate, 20_000_000);
+    }
+
+    #[test]
+    fn burned_subtitles_are_planned_by_kind() {
+        let burn = |stream: Option<i32>, file: Option<i64>| {
+            let mut request = request();
+            request.subtitle_stream = stream;
+            request.subtitle_file = file;
+            decide_playback(&movie(), &request)
+        };
+
+        let plan = burn(Some(5), None).unwrap();
+        assert_eq!(plan.method, PlaybackMethod::Transcode);
+        assert_eq!(plan.reasons, vec!["Subtitle is burned into the video"]);
+        assert_eq!(plan.subtitle, Some(SubtitlePlan::Image { position: 1 }));
+        assert_eq!(
+            burn(Some(4), None).unwrap().subtitle,
+            Some(SubtitlePlan::Text { position: 0 })
+        );
+        assert_eq!(
+            burn(None, Some(12)).unwrap().subtitle,
+            Some(SubtitlePlan::File { subtitle_id: 12 })
+        );
+        assert!(burn(Some(4), Some(12)).is_err());
+        assert!(burn(Some(2), None).is_err());
+    }
+
+    #[test]
+    fn unknown_audio_track_is_an_error() {
+        let mut request = request();
+        request.audio_stream = Some(9);
+        assert!(decide_playback(&movie(), &request).is_err());
+    }
+}
@@ -0,0 +1,528 @@
+use crate::{
+    db_interface::data_getter::DataGetter,
+    movie_data::movie_data::{PlaybackDecision, PlaybackRequest},
+    stream_server::stream_server::{stream_url, subtitle_url},
+    transcoder::{
+        ffmpeg::{
+            ffmpeg_path, hls_arguments, run_ffmpeg, segment_file_name, subtitle_arguments,
+            ENCODER_PLAYLIST, SEGMENT_DURATION,
+        },
+        playback_decision::{decide_playback, PlaybackMethod, PlaybackPlan, SubtitlePlan},
+    },
+};
+use anyhow::{anyhow, bail, Context, Result};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    process::Stdio,
+    sync::{Arc, LazyLock},
+    time::{Duration, Instant},
+};
+use tokio::{
+    io::{AsyncBufReadExt, BufReader},
+    process::{Child, Command},
+    sync::Mutex,
+    task::JoinHandle,
+};
+
+/// Folder of the working directory the segments of every session are cached in.
+const CACHE_FOLDER: &str = "transcode_cache";
+
+/// Segments a request may lie past the last encoded one before encoding restarts there.
+const MAX_SEGMENT_GAP: u32 = 3;
+
+/// Segments encoded ahead of the last requested one before the encoder is stopped,
+/// to be restarted once the player catches up.
+const MAX_SEGMENTS_AHEAD: u32 = 50;
+
+/// Segments kept behind the last requested one, older ones being deleted.
+const KEPT_SEGMENTS_BEHIND: u32 = 30;
+
+/// Longest wait for a segment to be encoded.
+const SEGMENT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Delay between two checks for an encoded segment.
+const SEGMENT_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Sessions not requested for this long are stopped and their segments deleted.
+const SESSION_IDLE_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// Delay between two cleanups of the segment cache.
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The transcoding sessions of the process, by id.
+static SESSIONS: LazyLock<Mutex<HashMap<String, Arc<Mutex<Session>>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// HLS stream of a media for a player, its segments being encoded on demand.
+struct Session {
+    profile_id: i64,
+    media_id: i64,
+    /// URL ffmpeg reads the file from.
+    input: String,
+    plan: PlaybackPlan,
+    folder: PathBuf,
+    duration: f64,
+    encoder: Option<Encoder>,
+    last_index: u32,
+    last_request: Instant,
+}
+
+/// A running ffmpeg, encoding from `start_index` to the end.
+struct Encoder {
+    child: Child,
+    start_index: u32,
+}
+
+impl Session {
+    /// Whether the segments are cut on the key frames of a copied video, their count and
+    /// lengths only being known from the encoder playlist.
+    fn is_remux(&self) -> bool {
+        self.plan.method == PlaybackMethod::Remux
+    }
+
+    /// Number of segments of a transcode, each lasting `SEGMENT_DURATION`.
+    fn segment_count(&self) -> u32 {
+        (self.duration / SEGMENT_DURATION).ceil() as u32
+    }
+
+    fn segment_path(&self, index: u32) -> PathBuf {
+        self.folder.join(segment_file_name(index))
+    }
+
+    /// Index of the first segment not encoded yet by the running encoder.
+    fn encoded_end(&self) -> Option<u32> {
+        let encoder = self.encoder.as_ref()?;
+        let mut index = encoder.start_index;
+        while index < self.segment_count() && self.segment_path(index).exists() {
+            index += 1;
+        }
+        Some(index)
+    }
+
+    /// Whether the segment at `index` is neither encoded nor about to be.
+    /// A remux only restarts from the start, once its encoder was stopped.
+    fn needs_restart(&mut self, index: u32) -> bool {
+        if self.is_remux() {
+            return self.encoder.is_none();
+        }
+        let running = match self.encoder.as_mut() {
+            Some(encoder) => matches!(encoder.child.try_wait(), Ok(None)),
+            None => false,
+        };
+        match (running, self.encoded_end()) {
+            (true, Some(end)) => {
+                let start_index = self.encoder.as_ref().map_or(0, |e| e.start_index);
+                index < start_index || index > end + MAX_SEGMENT_GAP
+            }
+            _ => true,
+        }
+    }
+
+    /// Starts encoding from the segment at `index`, stopping the running encoder.
+    fn start_encoder(&mut self, index: u32) -> Result<()> {
+        self.encoder = None;
+        let ffmpeg = ffmpeg_path().ok_or_else(|| anyhow!("ffmpeg is not installed"))?;
+        let args = hls_arguments(&self.input, &self.plan, index, &self.folder);
+        let mut child = Command::new(ffmpeg)
+            .args(&args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .context("Failed to start ffmpeg")?;
+
+        if let Some(stderr) = child.stderr.take() {
+            let media_id = self.media_id;
+            tokio::spawn(async move {
+                let mut lines = BufReader::new(stderr).lines();
+                while let Ok(Some(line)) = lines.next_line().await {
+                    tracing::warn!(media_id = media_id, "ffmpeg: {}", line);
+                }
+            });
+        }
+
+        tracing::info!(
+            media_id = self.media_id,
+            segment = index,
+            method = self.plan.method.as_str(),
+            "Transcoding started"
+        );
+        self.encoder = Some(Encoder {
+            child,
+            start_index: index,
+        });
+        Ok(())
+    }
+}
+
+/// Decides how a media is played by a player, starting the transcoding session a remux or
+/// transcode needs. URLs are relative to the streaming server root.
+/// Returns None when the media is not found or the profile may not see it.
+pub async fn prepare_playback(
+    profile_id: i64,
+    media_id: i64,
+    request: &PlaybackRequest,
+) -> Result<Option<PlaybackDecision>> {
+    let data_getter = DataGetter::new("movie_db.db".to_owned())?;
+    if !data_getter.is_media_allowed(profile_id, media_id)? {
+        return Ok(None);
+    }
+    let Some(info) = data_getter.get_media_info(media_id)? else {
+        bail!("Media {} is not probed yet", media_id);
+    };
+
+    let plan = decide_playback(&info, request)?;
+    if let Some(SubtitlePlan::File { subtitle_id }) = plan.subtitle {
+        if data_getter
+            .get_subtitle_file(media_id, subtitle_id)?
+            .is_none()
+        {
+            bail!("No subtitle file found for id: {}", subtitle_id);
+        }
+    }
+    if plan.method == PlaybackMethod::DirectPlay {
+        return Ok(Some(PlaybackDecision {
+            method: plan.method.as_str().to_owned(),
+            reasons: plan.reasons,
+            url: format!("/media/{}", media_id),
+            session_id: None,
+        }));
+    }
+
+    if ffmpeg_path().is_none() {
+        bail!(
+            "Media {} needs a {} but ffmpeg is not installed",
+            media_id,
+            plan.method.as_str()
+        );
+    }
+    let duration = info
+        .duration
+        .filter(|duration| *duration > 0.0)
+        .ok_or_else(|| anyhow!("Duration of media {} is unknown", media_id))?;
+
+    let session_id = new_session_id();
+    let folder = cache_folder()?.join(&session_id);
+    tokio::fs::create_dir_all(&folder)
+        .await
+        .with_context(|| format!("Failed to create transcoding folder {:?}", folder))?;
+
+    if let Err(e) = extract_subtitle(media_id, &plan, &folder).await {
+        tokio::fs::remove_dir_all(&folder).await.ok();
+        return Err(e);
+    }
+
+    let mut session = Session {
+        profile_id,
+        media_id,
+        input: stream_url(media_id).await?,
+        plan,
+        folder,
+        duration,
+        encoder: None,
+        last_index: 0,
+        last_request: Instant::now(),
+    };
+    let start_index = if session.is_remux() {
+        0
+    } else {
+        ((request.start_position.max(0.0) / SEGMENT_DURATION) as u32)
+            .min(session.segment_count().saturating_sub(1))
+    };
+    session.start_encoder(start_index)?;
+    session.last_index = start_index;
+
+    let decision = PlaybackDecision {
+        method: session.plan.method.as_str().to_owned(),
+        reasons: session.plan.reasons.clone(),
+        url: format!("/transcode/{}/index.m3u8", session_id),
+        session_id: Some(session_id.clone()),
+    };
+    SESSIONS
+        .lock()
+        .await
+        .insert(session_id, Arc::new(Mutex::new(session)));
+    Ok(Some(decision))
+}
+
+/// Writes the text subtitle a plan burns into the video to the session folder, so that
+/// encodes read it rather than the whole media. Sidecar files are read as served in WebVTT,
+/// already decoded to UTF-8.
+async fn extract_subtitle(media_id: i64, plan: &PlaybackPlan, folder: &Path) -> Result<()> {
+    let args = match plan.subtitle {
+        Some(SubtitlePlan::Text { position }) => {
+            subtitle_arguments(&stream_url(media_id).await?, position, folder)
+        }
+        Some(SubtitlePlan::File { subtitle_id }) => {
+            subtitle_arguments(&subtitle_url(media_id, subtitle_id).await?, 0, folder)
+        }
+        _ => return Ok(()),
+    };
+    run_ffmpeg(&args)
+        .await
+        .with_context(|| format!("Failed to extract the subtitle of media {}", media_id))
+}
+
+/// Returns the HLS playlist of a session. A transcode lists every segment of the media upfront,
+/// a remux the segments encoded so far, waiting for the first one.
+/// `query` is appended to the segment URLs, carrying the device token of the playlist request.
+/// Returns None when the session is not found for the profile.
+pub async fn session_playlist(
+    session_id: &str,
+    profile_id: i64,
+    query: Option<&str>,
+) -> Result<Option<String>> {
+    let Some(session) = find_session(session_id, profile_id).await else {
+        return Ok(None);
+    };
+    let query = query.map(|query| format!("?{}", query)).unwrap_or_default();
+
+    let (is_remux, playlist_path) = {
+        let mut session = session.lock().await;
+        session.last_request = Instant::now();
+        (session.is_remux(), session.folder.join(ENCODER_PLAYLIST))
+    };
+    if is_remux {
+        return remux_playlist(&session, &playlist_path, &query)
+            .await
+            .map(Some);
+    }
+
+    let session = session.lock().await;
+    let mut playlist = format!(
+        "#EXTM3U\n#EXT-X-VERSION:3\n#EXT-X-TARGETDURATION:{}\n\
+         #EXT-X-MEDIA-SEQUENCE:0\n#EXT-X-PLAYLIST-TYPE:VOD\n",
+        SEGMENT_DURATION.ceil() as u32
+    );
+    for index in 0..session.segment_count() {
+        let start = index as f64 * SEGMENT_DURATION;
+        let length = (session.duration - start).min(SEGMENT_DURATION);
+        playlist.push_str(&format!(
+            "#EXTINF:{:.6},\n{}{}\n",
+            length,
+            segment_file_name(index),
+            query
+        ));
+    }
+    playlist.push_str("#EXT-X-ENDLIST\n");
+    Ok(Some(playlist))
+}
+
+/// Reads the playlist ffmpeg writes for a remux once it lists a segment, appending `query`
+/// to the segment URLs.
+async fn remux_playlist(
+    session: &Mutex<Session>,
+    playlist_path: &Path,
+    query: &str,
+) -> Result<String> {
+    let deadline = Instant::now() + SEGMENT_TIMEOUT;
+    let playlist = loop {
+        if let Ok(playlist) = tokio::fs::read_to_string(playlist_path).await {
+            break playlist;
+        }
+
+        {
+            let mut session = session.lock().await;
+            let Some(encoder) = session.encoder.as_mut() else {
+                bail!("Remux of media {} was stopped", session.media_id);
+            };
+            if let Some(status) = encoder.child.try_wait()? {
+                if !playlist_path.exists() {
+                    bail!("ffmpeg ended with {} before the first segment", status);
+                }
+            }
+        }
+
+        if Instant::now() > deadline {
+            bail!("Timed out waiting for the first segment to be remuxed");
+        }
+        tokio::time::sleep(SEGMENT_POLL_INTERVAL).await;
+    };
+
+    Ok(playlist
+        .lines()
+        .map(|line| {
+            if line.is_empty() || line.starts_with('#') {
+                return format!("{}\n", line);
+            }
+            let file_name = line.rsplit(['/', '\\']).next().unwrap_or(line);
+            format!("{}{}\n", file_name, query)
+        })
+        .collect())
+}
+
+/// Returns the file of a segment of a session once encoded, restarting the encoder at the
+/// segment when it lies before the running encode or too far past it, as after a seek.
+/// Returns None when the session or segment is not found for the profile.
+pub async fn session_segment(
+    session_id: &str,
+    profile_id: i64,
+    index: u32,
+) -> Result<Option<PathBuf>> {
+    let Some(session) = find_session(session_id, profile_id).await else {
+        return Ok(None);
+    };
+
+    let path = {
+        let mut session = session.lock().await;
+        if !session.is_remux() && index >= session.segment_count() {
+            return Ok(None);
+        }
+        session.last_request = Instant::now();
+        session.last_index = index;
+        let path = session.segment_path(index);
+        if !path.exists() && session.needs_restart(index) {
+            session.start_encoder(index)?;
+        }
+        path
+    };
+
+    let deadline = Instant::now() + SEGMENT_TIMEOUT;
+    loop {
+        if path.exists() {
+            return Ok(Some(path));
+        }
+
+        {
+            let mut session = session.lock().await;
+            let Some(encoder) = session.encoder.as_mut() else {
+                bail!("Transcoding of session {} was stopped", session_id);
+            };
+            if let Some(status) = encoder.child.try_wait()? {
+                if path.exists() {
+                    return Ok(Some(path));
+                }
+                // Past the last segment of a remux
+                if status.success() {
+                    return Ok(None);
+                }
+                bail!("ffmpeg ended with {} before segment {}", status, index);
+            }
+        }
+
+        if Instant::now() > deadline {
+            bail!("Timed out waiting for segment {} to be encoded", index);
+        }
+        tokio::time::sleep(SEGMENT_POLL_INTERVAL).await;
+    }
+}
+
+/// Stops a session and deletes its segments, returning false when not found for the profile.
+pub async fn stop_session(session_id: &str, profile_id: i64) -> bool {
+    if find_session(session_id, profile_id).await.is_none() {
+        return false;
+    }
+    let Some(session) = SESSIONS.lock().await.remove(session_id) else {
+        return false;
+    };
+    close_session(session).await;
+    true
+}
+
+/// Stops every session and deletes the whole segment cache, leftovers of a crash included.
+pub async fn stop_all_sessions() {
+    let sessions: Vec<_> = SESSIONS.lock().await.drain().map(|(_, s)| s).collect();
+    for session in sessions {
+        close_session(session).await;
+    }
+
+    if let Ok(folder) = cache_folder() {
+        if folder.exists() {
+            if let Err(e) = tokio::fs::remove_dir_all(&folder).await {
+                tracing::error!("Failed to delete {:?} \n Caused by {:?}", folder, e);
+            }
+        }
+    }
+}
+
+/// Periodically stops the idle sessions and trims the segments of the others.
+pub fn spawn_cache_cleanup() -> JoinHandle<()> {
+    tokio::spawn(async {
+        let mut interval = tokio::time::interval(CLEANUP_INTERVAL);
+        loop {
+            interval.tick().await;
+            clean_up_cache().await;
+        }
+    })
+}
+
+/// Stops the sessions idle for `SESSION_IDLE_TIMEOUT`. Other transcodes lose the segments far
+/// behind the last requested one, and their encoder once too far ahead of it. A remux keeps
+/// every segment, its playlist listing them all.
+async fn clean_up_cache() {
+    let sessions: Vec<_> = SESSIONS
+        .lock()
+        .await
+        .iter()
+        .map(|(id, session)| (id.clone(), session.clone()))
+        .collect();
+
+    for (session_id, session) in sessions {
+        let mut locked = session.lock().await;
+        if locked.last_request.elapsed() > SESSION_IDLE_TIMEOUT {
+            drop(locked);
+            SESSIONS.lock().await.remove(&session_id);
+            tracing::info!(session = session_id, "Idle transcoding session stopped");
+            close_session(session).await;
+            continue;
+        }
+        if locked.is_remux() {
+            continue;
+        }
+
+        if locked
+            .encoded_end()
+            .is_some_and(|end| end > locked.last_index + MAX_SEGMENTS_AHEAD)
+        {
+            locked.encoder = None;
+        }
+
+        let oldest_kept = locked.last_index.saturating_sub(KEPT_SEGMENTS_BEHIND);
+        let Ok(mut entries) = tokio::fs::read_dir(&locked.folder).await else {
+            continue;
+        };
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let index = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.strip_prefix("segment_")?.strip_suffix(".ts"))
+                .and_then(|index| index.parse::<u32>().ok());
+            if index.is_some_and(|index| index < oldest_kept) {
+                tokio::fs::remove_file(entry.path()).await.ok();
+            }
+        }
+    }
+}
+
+/// Stops the encoder of a session and deletes its segments.
+async fn close_session(session: Arc<Mutex<Session>>) {
+    let mut session = session.lock().await;
+    if let Some(mut encoder) = session.encoder.take() {
+        encoder.child.kill().await.ok();
+    }
+    if let Err(e) = tokio::fs::remove_dir_all(&session.folder).await {
+        tracing::error!("Failed to delete {:?} \n Caused by {:?}", session.folder, e);
+    }
+}
+
+async fn find_session(session_id: &str, profile_id: i64) -> Option<Arc<Mutex<Session>>> {
+    let session = SESSIONS.lock().await.get(session_id)?.clone();
+    if session.lock().await.profile_id != profile_id {
+        return None;
+    }
+    Some(session)
+}
+
+fn cache_folder() -> Result<PathBuf> {
+    let mut folder =
+        std::env::current_dir().context("Failed to retrieve current working directory")?;
+    folder.push(CACHE_FOLDER);
+    Ok(folder)
+}
+
+fn new_session_id() -> String {
+    let id: [u8; 16] = rand::random();
+    id.iter().map(|byte| format!("{:02x}", byte)).collect()
+}